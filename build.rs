@@ -0,0 +1,13 @@
+use std::env;
+
+fn main() {
+    // `has_fs` is set on all targets with a file system, i.e. all but the
+    // bare `wasm32-unknown-unknown` target. It guards the APIs which read or
+    // write files, so that the crate can be used from a browser.
+    println!("cargo:rustc-check-cfg=cfg(has_fs)");
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if !(arch == "wasm32" && os == "unknown") {
+        println!("cargo:rustc-cfg=has_fs");
+    }
+}
@@ -0,0 +1,143 @@
+#!/usr/bin/env python3
+"""Generates src/runtime/syscalls.rs and src/runtime/syscall_names.rs.
+
+The syscall numbers are read from the Linux UAPI headers by running the C
+preprocessor over them, so the headers of the wanted kernel version have to be
+installed, e.g. by `make headers_install INSTALL_HDR_PATH=...` in a kernel
+tree. The x86 headers are expected in the multiarch directory of Debian based
+distributions, pass `--arch-include` if they live somewhere else.
+
+Usage: scripts/gen-syscalls.py [--include DIR] [--arch-include DIR] [--kernel VERSION]
+"""
+
+import argparse
+import os
+import re
+import subprocess
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+
+# Macros with the `__NR_` prefix which are not syscalls.
+NOT_SYSCALLS = {"syscalls", "arch_specific_syscall"}
+
+# The `__ARCH_WANT_*` flags set by `arch/arm64/include/uapi/asm/unistd.h`.
+AARCH64_DEFINES = [
+    "__BITS_PER_LONG=64",
+    "__ARCH_WANT_RENAMEAT",
+    "__ARCH_WANT_NEW_STAT",
+    "__ARCH_WANT_SET_GET_RLIMIT",
+    "__ARCH_WANT_TIME32_SYSCALLS",
+    "__ARCH_WANT_SYS_CLONE3",
+    "__ARCH_WANT_MEMFD_SECRET",
+]
+
+# (constant, description, header, preprocessor defines, hexadecimal numbers)
+ARCHES = [
+    ("X86_64", "the x86-64 architecture", "asm/unistd_64.h", [], False),
+    ("X86", "the x86 (32-bit) architecture", "asm/unistd_32.h", [], False),
+    ("X32", "the x32 architecture", "asm/unistd_x32.h", ["__X32_SYSCALL_BIT=0x40000000"], True),
+    ("AARCH64", "the AArch64 architecture", "asm-generic/unistd.h", AARCH64_DEFINES, False),
+]
+
+
+def syscalls(include, arch_include, header, defines):
+    """Returns the syscall names and numbers defined by the header."""
+    flags = ["-I", arch_include, "-I", include] + ["-D" + d for d in defines]
+    prelude = "#include <{}>\n".format(header)
+    macros = subprocess.run(
+        ["cpp", "-dM"] + flags + ["-"],
+        input=prelude,
+        check=True,
+        capture_output=True,
+        text=True,
+    ).stdout
+    names = sorted(
+        {m for m in re.findall(r"^#define __NR_(\w+) ", macros, re.M) if m not in NOT_SYSCALLS}
+    )
+
+    source = "".join("{} __NR_{}\n".format(name, name) for name in names)
+    expanded = subprocess.run(
+        ["cpp", "-P"] + flags + ["-"],
+        input=prelude + source,
+        check=True,
+        capture_output=True,
+        text=True,
+    ).stdout
+    table = []
+    for line in expanded.splitlines():
+        name, _, value = line.strip().partition(" ")
+        if name in names:
+            table.append((name, eval(value, {})))
+    return table
+
+
+def render_syscalls(kernel, tables):
+    out = [
+        "// This file is generated by `scripts/gen-syscalls.py` from the Linux {} UAPI\n".format(kernel),
+        "// headers (`asm/unistd_64.h`, `asm/unistd_32.h`, `asm/unistd_x32.h` and\n",
+        "// `asm-generic/unistd.h`). Do not edit it by hand, rerun the script when\n",
+        "// updating to newer kernel headers.\n",
+        "//\n",
+        "// Every table is sorted by syscall name to allow binary searching.\n",
+    ]
+    for (const, description, _, _, hexadecimal), table in zip(ARCHES, tables):
+        out.append("\n/// Syscall numbers for {}.\n".format(description))
+        out.append("pub(crate) const {}: &[(&str, u32)] = &[\n".format(const))
+        for name, number in table:
+            value = "{:#010x}".format(number) if hexadecimal else str(number)
+            out.append('    ("{}", {}),\n'.format(name, value))
+        out.append("];\n")
+    return "".join(out)
+
+
+def render_names(tables):
+    names = sorted({name for table in tables for name, _ in table})
+    out = [
+        "// This file is generated by `scripts/gen-syscalls.py` together with the\n",
+        "// syscall tables in `syscalls.rs`. Do not edit it by hand.\n",
+        "\n",
+        "use std::borrow::Cow;\n",
+        "\n",
+        "use super::Syscall;\n",
+        "\n",
+        "impl Syscall {\n",
+    ]
+    for name in names:
+        out.append("    /// The `{}` syscall.\n".format(name))
+        out.append(
+            '    pub const {}: Syscall = Syscall(Cow::Borrowed("{}"));\n'.format(name.upper(), name)
+        )
+    out.append("\n")
+    out.append("    /// The names of all syscalls known to any of the supported architectures,\n")
+    out.append("    /// in sorted order.\n")
+    out.append("    pub const KNOWN: &'static [&'static str] = &[\n")
+    out.extend('        "{}",\n'.format(name) for name in names)
+    out.append("    ];\n")
+    out.append("}\n")
+    return "".join(out)
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--include", default="/usr/include")
+    parser.add_argument("--arch-include", default="/usr/include/x86_64-linux-gnu")
+    parser.add_argument("--kernel", default="6.1")
+    args = parser.parse_args()
+
+    tables = [
+        syscalls(args.include, args.arch_include, header, defines)
+        for _, _, header, defines, _ in ARCHES
+    ]
+    runtime = os.path.join(ROOT, "src", "runtime")
+    files = {
+        os.path.join(runtime, "syscalls.rs"): render_syscalls(args.kernel, tables),
+        os.path.join(runtime, "syscall_names.rs"): render_names(tables),
+    }
+    for path, content in files.items():
+        with open(path, "w") as f:
+            f.write(content)
+    subprocess.run(["rustfmt", "--edition", "2018"] + list(files), check=True)
+
+
+if __name__ == "__main__":
+    main()
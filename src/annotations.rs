@@ -0,0 +1,207 @@
+//! A map type for annotations and labels.
+
+use std::{
+    collections::{hash_map, HashMap},
+    convert::TryFrom,
+    iter::FromIterator,
+    ops::Deref,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{oci_error, OciSpecError, Result};
+
+/// Annotations are arbitrary metadata as key-value pairs, used by the image and
+/// runtime spec. The type converts into a `HashMap<String, String>` and can
+/// therefore be passed to any builder setter expecting annotations or labels.
+///
+/// Annotations never contain an empty key. The fallible ways of adding
+/// annotations, i.e. [Annotations::insert], the `TryFrom<HashMap>` conversion
+/// and deserialization, reject empty keys with an error. The infallible
+/// conversions from arrays and iterators skip pairs with an empty key.
+/// # Example
+/// ```
+/// use oci_spec::Annotations;
+/// use std::{collections::HashMap, convert::TryFrom};
+///
+/// let annotations = Annotations::from([("org.opencontainers.image.title", "hello")]);
+/// assert_eq!(annotations.get("org.opencontainers.image.title").unwrap(), "hello");
+///
+/// let mut map = HashMap::new();
+/// map.insert(String::new(), "value".to_owned());
+/// assert!(Annotations::try_from(map).is_err());
+///
+/// let annotations = Annotations::from([("", "skipped")]);
+/// assert!(annotations.is_empty());
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "HashMap<String, String>", into = "HashMap<String, String>")]
+pub struct Annotations(HashMap<String, String>);
+
+/// Labels are arbitrary metadata of an image configuration as key-value pairs.
+pub type Labels = Annotations;
+
+impl Annotations {
+    /// Creates empty annotations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts an annotation, returning the previous value of the key.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the key is empty.
+    pub fn insert<K: Into<String>, V: Into<String>>(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<Option<String>> {
+        let key = key.into();
+        validate_key(&key)?;
+        Ok(self.0.insert(key, value.into()))
+    }
+
+    /// Removes an annotation, returning its value.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.0.remove(key)
+    }
+
+    /// Returns the underlying map.
+    pub fn into_inner(self) -> HashMap<String, String> {
+        self.0
+    }
+}
+
+impl Deref for Annotations {
+    type Target = HashMap<String, String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl TryFrom<HashMap<String, String>> for Annotations {
+    type Error = OciSpecError;
+
+    fn try_from(map: HashMap<String, String>) -> Result<Self> {
+        map.keys().try_for_each(|key| validate_key(key))?;
+        Ok(Annotations(map))
+    }
+}
+
+/// Collects key-value pairs into annotations, skipping pairs with an empty
+/// key.
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Annotations {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Annotations(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .filter(|(k, _)| !k.is_empty())
+                .collect(),
+        )
+    }
+}
+
+/// Converts key-value pairs into annotations, skipping pairs with an empty
+/// key.
+impl<K: Into<String>, V: Into<String>, const N: usize> From<[(K, V); N]> for Annotations {
+    fn from(pairs: [(K, V); N]) -> Self {
+        IntoIterator::into_iter(pairs).collect()
+    }
+}
+
+impl From<Annotations> for HashMap<String, String> {
+    fn from(annotations: Annotations) -> Self {
+        annotations.0
+    }
+}
+
+impl From<Annotations> for Option<HashMap<String, String>> {
+    fn from(annotations: Annotations) -> Self {
+        Some(annotations.0)
+    }
+}
+
+impl IntoIterator for Annotations {
+    type Item = (String, String);
+    type IntoIter = hash_map::IntoIter<String, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+fn validate_key(key: &str) -> Result<()> {
+    if key.is_empty() {
+        return Err(oci_error("annotation keys must not be empty"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_annotations() {
+        let annotations: Annotations = vec![("a", "1"), ("", "skipped"), ("b", "2")]
+            .into_iter()
+            .collect();
+        assert_eq!(annotations.len(), 2);
+        assert_eq!(annotations.get("b").map(String::as_str), Some("2"));
+
+        let map: HashMap<String, String> = annotations.clone().into();
+        assert_eq!(Annotations::try_from(map).unwrap(), annotations);
+    }
+
+    #[test]
+    fn insert_annotations() {
+        let mut annotations = Annotations::new();
+        assert_eq!(annotations.insert("a", "1").unwrap(), None);
+        assert_eq!(annotations.insert("a", "2").unwrap(), Some("1".to_owned()));
+        assert!(annotations.insert("", "3").is_err());
+        assert_eq!(annotations.remove("a"), Some("2".to_owned()));
+        assert!(annotations.is_empty());
+    }
+
+    #[test]
+    fn array_annotations() {
+        let annotations = Annotations::from([("a", "1"), ("", "skipped")]);
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations.get("a").map(String::as_str), Some("1"));
+    }
+
+    #[test]
+    fn serialize_annotations() {
+        let annotations = Annotations::from([("key", "value")]);
+        let json = serde_json::to_string(&annotations).unwrap();
+        assert_eq!(json, r#"{"key":"value"}"#);
+        assert_eq!(
+            serde_json::from_str::<Annotations>(&json).unwrap(),
+            annotations
+        );
+        assert!(serde_json::from_str::<Annotations>(r#"{"":"value"}"#).is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "builder", feature = "image"))]
+    fn builder_accepts_annotations() {
+        use crate::image::{ConfigBuilder, DescriptorBuilder, MediaType};
+
+        let descriptor = DescriptorBuilder::default()
+            .media_type(MediaType::ImageLayer)
+            .size(0)
+            .digest("sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+            .annotations(Annotations::from([("a", "1")]))
+            .build()
+            .unwrap();
+        assert_eq!(descriptor.annotations().as_ref().unwrap()["a"], "1");
+
+        let config = ConfigBuilder::default()
+            .labels(Labels::from([("maintainer", "me")]))
+            .build()
+            .unwrap();
+        assert_eq!(config.labels().as_ref().unwrap()["maintainer"], "me");
+    }
+}
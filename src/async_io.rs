@@ -0,0 +1,112 @@
+//! Asynchronous reading and writing of the spec documents.
+
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::error::Result;
+
+/// Reads the whole stream and deserializes the JSON document, as serde_json
+/// cannot parse incrementally from an asynchronous source.
+async fn from_reader_async<R: AsyncRead + Unpin, T: DeserializeOwned>(mut reader: R) -> Result<T> {
+    let mut buffer = Vec::new();
+    reader.read_to_end(&mut buffer).await?;
+    crate::from_slice(&buffer)
+}
+
+async fn to_writer_async<W: AsyncWrite + Unpin, T: Serialize>(
+    item: &T,
+    writer: &mut W,
+    pretty: bool,
+) -> Result<()> {
+    let buffer = crate::to_vec(item, pretty)?;
+    writer.write_all(&buffer).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+macro_rules! impl_async_io {
+    ($($(#[$cfg:meta])* $name:path),* $(,)?) => {
+        $(
+            $(#[$cfg])*
+            impl $name {
+                /// Attempts to load the document from an asynchronous JSON
+                /// stream, e.g. the body of a registry response.
+                /// # Errors
+                /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+                /// if the stream cannot be read or an
+                /// [OciSpecError::SerDe](crate::OciSpecError::SerDe) if the
+                /// document cannot be deserialized.
+                pub async fn from_reader_async<R: AsyncRead + Unpin>(reader: R) -> Result<Self> {
+                    from_reader_async(reader).await
+                }
+
+                /// Attempts to write the document to an asynchronous stream
+                /// as JSON and flushes it.
+                /// # Errors
+                /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+                /// if the stream cannot be written or an
+                /// [OciSpecError::SerDe](crate::OciSpecError::SerDe) if the
+                /// document cannot be serialized.
+                pub async fn to_writer_async<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> Result<()> {
+                    to_writer_async(self, writer, false).await
+                }
+
+                /// Attempts to write the document to an asynchronous stream
+                /// as pretty printed JSON and flushes it.
+                /// # Errors
+                /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+                /// if the stream cannot be written or an
+                /// [OciSpecError::SerDe](crate::OciSpecError::SerDe) if the
+                /// document cannot be serialized.
+                pub async fn to_writer_pretty_async<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> Result<()> {
+                    to_writer_async(self, writer, true).await
+                }
+            }
+        )*
+    };
+}
+
+for_each_document!(impl_async_io);
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    #[cfg(feature = "runtime")]
+    async fn spec_async_round_trip() -> crate::error::Result<()> {
+        use crate::runtime::Spec;
+
+        // arrange
+        let spec = Spec::rootless(1000, 1000);
+        let mut written = Vec::new();
+
+        // act
+        spec.to_writer_async(&mut written).await?;
+        let loaded = Spec::from_reader_async(written.as_slice()).await?;
+
+        // assert
+        assert_eq!(loaded, spec);
+        assert!(Spec::from_reader_async(&b"{"[..]).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "distribution")]
+    async fn tag_list_async_pretty() -> crate::error::Result<()> {
+        use crate::distribution::TagList;
+
+        // arrange
+        let body = r#"{"name":"alpine","tags":["latest"]}"#;
+        let mut written = Vec::new();
+
+        // act
+        let list = TagList::from_reader_async(body.as_bytes()).await?;
+        list.to_writer_pretty_async(&mut written).await?;
+
+        // assert
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            "{\n  \"name\": \"alpine\",\n  \"tags\": [\n    \"latest\"\n  ]\n}"
+        );
+        Ok(())
+    }
+}
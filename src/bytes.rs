@@ -0,0 +1,86 @@
+//! Reading and writing of the spec documents from and to byte buffers.
+
+use crate::error::Result;
+
+macro_rules! impl_bytes {
+    ($($(#[$cfg:meta])* $name:path),* $(,)?) => {
+        $(
+            $(#[$cfg])*
+            impl $name {
+                /// Attempts to load the document from a JSON byte slice, e.g.
+                /// the body of a registry response which is already in memory.
+                /// # Errors
+                /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+                /// if the document cannot be deserialized.
+                pub fn from_slice(slice: &[u8]) -> Result<Self> {
+                    crate::from_slice(slice)
+                }
+
+                /// Attempts to serialize the document as JSON bytes. The
+                /// digest of the document is computed over exactly these
+                /// bytes if it is pushed as is.
+                /// # Errors
+                /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+                /// if the document cannot be serialized.
+                pub fn to_vec(&self) -> Result<Vec<u8>> {
+                    crate::to_vec(self, false)
+                }
+
+                /// Attempts to serialize the document as pretty printed JSON
+                /// bytes.
+                /// # Errors
+                /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+                /// if the document cannot be serialized.
+                pub fn to_vec_pretty(&self) -> Result<Vec<u8>> {
+                    crate::to_vec(self, true)
+                }
+            }
+        )*
+    };
+}
+
+for_each_document!(impl_bytes);
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "image")]
+    fn manifest_bytes() -> crate::error::Result<()> {
+        use crate::image::{Digest, ImageManifest};
+
+        // arrange
+        let body = br#"{"schemaVersion":2,"config":{"mediaType":"application/vnd.oci.image.config.v1+json","digest":"sha256:b5b2b2c507a0944348e0303114d8d93aaaa081732b86451d9bce1f432a537bc7","size":7023},"layers":[]}"#;
+
+        // act
+        let manifest = ImageManifest::from_slice(body)?;
+        let written = manifest.to_vec()?;
+
+        // assert
+        assert_eq!(written, body.to_vec());
+        assert_eq!(
+            Digest::sha256_from_reader(written.as_slice())?,
+            Digest::sha256_from_reader(&body[..])?
+        );
+        assert!(manifest
+            .to_vec_pretty()?
+            .starts_with(b"{\n  \"schemaVersion\": 2"));
+        assert!(ImageManifest::from_slice(b"{} x").is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "runtime")]
+    fn spec_bytes() -> crate::error::Result<()> {
+        use crate::runtime::Spec;
+
+        // arrange
+        let spec = Spec::rootless(1000, 1000);
+
+        // act
+        let loaded = Spec::from_slice(&spec.to_vec_pretty()?)?;
+
+        // assert
+        assert_eq!(loaded, spec);
+        Ok(())
+    }
+}
@@ -0,0 +1,542 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{oci_error, Result},
+    runtime::{Hook, LinuxDevice, LinuxDeviceCgroup, LinuxDeviceType, Mount, Spec},
+};
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// ContainerEdits are the changes to the runtime spec of a container
+    /// which make a device available to it.
+    struct ContainerEdits {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Env are `key=value` environment variables of the container
+        /// process.
+        env: Option<Vec<String>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// DeviceNodes are the device nodes to create in the container.
+        device_nodes: Option<Vec<CdiDeviceNode>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Hooks are the hooks to run in the lifecycle of the container.
+        hooks: Option<Vec<CdiHook>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Mounts are the mounts to add to the container.
+        mounts: Option<Vec<CdiMount>>,
+
+        #[serde(
+            default,
+            rename = "additionalGids",
+            skip_serializing_if = "Option::is_none"
+        )]
+        /// AdditionalGIDs are added to the groups of the container process.
+        additional_gids: Option<Vec<u32>>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get_mut = "pub", set = "pub")
+    )]
+    /// CdiDeviceNode is a device node to create in the container. Unset
+    /// properties are taken from the device on the host.
+    struct CdiDeviceNode {
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzz::absolute_path))]
+        /// Path of the device within the container.
+        path: PathBuf,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzz::optional_absolute_path))]
+        /// HostPath is the path of the device on the host, which defaults to
+        /// `path`.
+        host_path: Option<PathBuf>,
+
+        #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Device type, block, char, etc.
+        typ: Option<LinuxDeviceType>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Major is the device's major number.
+        major: Option<i64>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Minor is the device's minor number.
+        minor: Option<i64>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// FileMode permission bits for the device.
+        file_mode: Option<u32>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// Cgroup access permissions of the device, which default to `rwm`.
+        permissions: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// UID of the device.
+        uid: Option<u32>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Gid of the device.
+        gid: Option<u32>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// CdiMount is a mount to add to the container.
+    struct CdiMount {
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzz::absolute_path))]
+        /// HostPath is the source of the mount on the host.
+        host_path: PathBuf,
+
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzz::absolute_path))]
+        /// ContainerPath is the destination of the mount in the container.
+        container_path: PathBuf,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Options are fstab style mount options.
+        options: Option<Vec<String>>,
+
+        #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+        /// Type specifies the mount kind.
+        typ: Option<String>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get_mut = "pub", set = "pub")
+    )]
+    /// CdiHook is a hook to run in the lifecycle of the container.
+    struct CdiHook {
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// HookName is the lifecycle event of the hook in the camel case
+        /// form of the runtime spec, e.g. `createContainer`.
+        hook_name: String,
+
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzz::absolute_path))]
+        /// Path to the binary to be executed.
+        path: PathBuf,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// Arguments used for the binary, including the binary name itself.
+        args: Option<Vec<String>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// Additional `key=value` environment variables.
+        env: Option<Vec<String>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Timeout is the number of seconds before aborting the hook.
+        timeout: Option<i64>,
+    }
+);
+
+/// The hook names of the runtime spec which can be used by a [CdiHook].
+const HOOK_NAMES: &[&str] = &[
+    "prestart",
+    "createRuntime",
+    "createContainer",
+    "startContainer",
+    "poststart",
+    "poststop",
+];
+
+impl ContainerEdits {
+    /// Validates the environment variables, device nodes, hooks and mounts.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// describing the first invalid edit.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(env) = self.env.iter().flatten().find(|e| !e.contains('=')) {
+            return Err(oci_error(format!(
+                "invalid environment variable {:?}, expected key=value",
+                env
+            )));
+        }
+        for node in self.device_nodes.iter().flatten() {
+            if !node.path.is_absolute() {
+                return Err(oci_error(format!(
+                    "device node path {} must be absolute",
+                    node.path.display()
+                )));
+            }
+            if let Some(permissions) = &node.permissions {
+                if permissions.is_empty()
+                    || !permissions.chars().all(|c| matches!(c, 'r' | 'w' | 'm'))
+                {
+                    return Err(oci_error(format!(
+                        "invalid device permissions {:?}",
+                        permissions
+                    )));
+                }
+            }
+        }
+        for hook in self.hooks.iter().flatten() {
+            if !HOOK_NAMES.contains(&hook.hook_name.as_str()) {
+                return Err(oci_error(format!("invalid hook name {:?}", hook.hook_name)));
+            }
+            hook.to_hook().validate()?;
+        }
+        for mount in self.mounts.iter().flatten() {
+            if !mount.container_path.is_absolute() {
+                return Err(oci_error(format!(
+                    "mount path {} must be absolute",
+                    mount.container_path.display()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies the edits to the runtime spec. Environment variables replace
+    /// existing ones with the same key, device nodes and mounts replace
+    /// existing ones with the same container path, hooks are appended and
+    /// device nodes are allowed in the device cgroup.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the edits are invalid or the missing properties of a device node
+    /// cannot be taken from the host.
+    pub fn apply_to(&self, spec: &mut Spec) -> Result<()> {
+        self.validate()?;
+
+        if self.env.is_some() || self.additional_gids.is_some() {
+            let process = spec.process.get_or_insert_with(Default::default);
+            let env = process.env.get_or_insert_with(Vec::new);
+            for var in self.env.iter().flatten() {
+                let key = var.split('=').next().unwrap_or_default();
+                env.retain(|e| e.split('=').next() != Some(key));
+                env.push(var.clone());
+            }
+            let gids = process.user.additional_gids.get_or_insert_with(Vec::new);
+            for gid in self.additional_gids.iter().flatten() {
+                if *gid != 0 && !gids.contains(gid) {
+                    gids.push(*gid);
+                }
+            }
+        }
+
+        if let Some(nodes) = &self.device_nodes {
+            let linux = spec.linux.get_or_insert_with(Default::default);
+            for node in nodes {
+                let device = node.to_linux_device()?;
+                let devices = linux.devices.get_or_insert_with(Vec::new);
+                devices.retain(|d| d.path != device.path);
+                devices.push(device.clone());
+
+                if matches!(device.typ, LinuxDeviceType::B | LinuxDeviceType::C) {
+                    linux
+                        .resources
+                        .get_or_insert_with(Default::default)
+                        .devices
+                        .get_or_insert_with(Vec::new)
+                        .push(LinuxDeviceCgroup {
+                            access: Some(
+                                node.permissions
+                                    .clone()
+                                    .unwrap_or_else(|| "rwm".to_string()),
+                            ),
+                            ..(&device).into()
+                        });
+                }
+            }
+        }
+
+        for hook in self.hooks.iter().flatten() {
+            let hooks = spec.hooks.get_or_insert_with(Default::default);
+            #[allow(deprecated)]
+            let list = match hook.hook_name.as_str() {
+                "prestart" => &mut hooks.prestart,
+                "createRuntime" => &mut hooks.create_runtime,
+                "createContainer" => &mut hooks.create_container,
+                "startContainer" => &mut hooks.start_container,
+                "poststart" => &mut hooks.poststart,
+                _ => &mut hooks.poststop,
+            };
+            list.get_or_insert_with(Vec::new).push(hook.to_hook());
+        }
+
+        if let Some(mounts) = &self.mounts {
+            let spec_mounts = spec.mounts.get_or_insert_with(Vec::new);
+            for mount in mounts {
+                spec_mounts.retain(|m| m.destination != mount.container_path);
+                spec_mounts.push(mount.into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl CdiDeviceNode {
+    /// Creates the device node configuration of the runtime spec. If the
+    /// type or device numbers are not set, they are taken from the device
+    /// on the host.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the host device cannot be inspected or an
+    /// [OciSpecError::Other](crate::OciSpecError::Other) if the host path is
+    /// not a device.
+    pub fn to_linux_device(&self) -> Result<LinuxDevice> {
+        let mut device = LinuxDevice {
+            path: self.path.clone(),
+            typ: self.typ.unwrap_or_default(),
+            major: self.major.unwrap_or_default(),
+            minor: self.minor.unwrap_or_default(),
+            file_mode: self.file_mode,
+            uid: self.uid,
+            gid: self.gid,
+        };
+        let complete = match self.typ {
+            Some(LinuxDeviceType::P) => true,
+            Some(_) => self.major.is_some() && self.minor.is_some(),
+            None => false,
+        };
+        if !complete {
+            let host = self.host_device()?;
+            device.typ = self.typ.unwrap_or(host.typ);
+            device.major = self.major.unwrap_or(host.major);
+            device.minor = self.minor.unwrap_or(host.minor);
+            device.file_mode = self.file_mode.or(host.file_mode);
+        }
+        Ok(device)
+    }
+
+    #[cfg(unix)]
+    fn host_device(&self) -> Result<LinuxDevice> {
+        LinuxDevice::from_path(self.host_path.as_ref().unwrap_or(&self.path))
+    }
+
+    #[cfg(not(unix))]
+    fn host_device(&self) -> Result<LinuxDevice> {
+        Err(oci_error(format!(
+            "type and device numbers of {} are required",
+            self.path.display()
+        )))
+    }
+}
+
+impl CdiHook {
+    /// Creates the hook of the runtime spec.
+    pub fn to_hook(&self) -> Hook {
+        Hook {
+            path: self.path.clone(),
+            args: self.args.clone(),
+            env: self.env.clone(),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl From<&CdiMount> for Mount {
+    fn from(mount: &CdiMount) -> Mount {
+        Mount {
+            destination: mount.container_path.clone(),
+            typ: mount.typ.clone(),
+            source: Some(mount.host_path.clone()),
+            options: mount.options.clone(),
+            uid_mappings: None,
+            gid_mappings: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn apply_to() {
+        // arrange
+        let edits: ContainerEdits = serde_json::from_value(json!({
+            "env": ["TERM=dumb", "VENDOR=1"],
+            "deviceNodes": [{
+                "path": "/dev/vendor0",
+                "type": "c",
+                "major": 195,
+                "minor": 0,
+                "permissions": "rw"
+            }],
+            "hooks": [{
+                "hookName": "createContainer",
+                "path": "/usr/bin/vendor-hook",
+                "args": ["vendor-hook", "inject"]
+            }],
+            "mounts": [{
+                "hostPath": "/usr/lib/vendor",
+                "containerPath": "/usr/lib/vendor",
+                "options": ["ro", "nosuid", "bind"]
+            }],
+            "additionalGids": [0, 44]
+        }))
+        .unwrap();
+        let mut spec = Spec::default();
+
+        // act
+        edits.apply_to(&mut spec).unwrap();
+
+        // assert
+        let process = spec.process.as_ref().unwrap();
+        let env = process.env.as_ref().unwrap();
+        assert_eq!(env.iter().filter(|e| e.starts_with("TERM=")).count(), 1);
+        assert!(env.contains(&"TERM=dumb".to_string()));
+        assert_eq!(process.user.additional_gids, Some(vec![44]));
+
+        let linux = spec.linux.as_ref().unwrap();
+        let device = linux.devices.as_ref().unwrap().last().unwrap();
+        assert_eq!(device.path, PathBuf::from("/dev/vendor0"));
+        assert_eq!((device.major, device.minor), (195, 0));
+        let rule = linux
+            .resources
+            .as_ref()
+            .unwrap()
+            .devices
+            .as_ref()
+            .unwrap()
+            .last()
+            .unwrap();
+        assert_eq!(rule.to_string(), "c 195:0 rw");
+
+        let hooks = spec.hooks.as_ref().unwrap().create_container.as_ref();
+        assert_eq!(
+            hooks.unwrap()[0].path,
+            PathBuf::from("/usr/bin/vendor-hook")
+        );
+
+        let mount = spec.mounts.as_ref().unwrap().last().unwrap();
+        assert_eq!(mount.destination, PathBuf::from("/usr/lib/vendor"));
+        assert_eq!(mount.source, Some(PathBuf::from("/usr/lib/vendor")));
+    }
+
+    #[test]
+    fn validate() {
+        // arrange
+        let cases = vec![
+            json!({"env": ["NOVALUE"]}),
+            json!({"deviceNodes": [{"path": "dev/null"}]}),
+            json!({"deviceNodes": [{"path": "/dev/null", "permissions": "x"}]}),
+            json!({"hooks": [{"hookName": "prestop", "path": "/bin/true"}]}),
+            json!({"hooks": [{"hookName": "poststop", "path": "/bin/true", "timeout": 0}]}),
+            json!({"mounts": [{"hostPath": "/a", "containerPath": "a"}]}),
+        ];
+
+        for case in cases {
+            // act
+            let edits: ContainerEdits = serde_json::from_value(case.clone()).unwrap();
+
+            // assert
+            assert!(edits.validate().is_err(), "{}", case);
+            assert!(edits.apply_to(&mut Spec::default()).is_err(), "{}", case);
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn device_node_from_host() {
+        // arrange
+        let node = CdiDeviceNode {
+            path: PathBuf::from("/dev/null"),
+            ..Default::default()
+        };
+
+        // act
+        let device = node.to_linux_device().unwrap();
+
+        // assert
+        assert_eq!(device.typ, LinuxDeviceType::C);
+        assert_eq!((device.major, device.minor), (1, 3));
+    }
+}
@@ -0,0 +1,362 @@
+//! [Container Device Interface](https://github.com/cncf-tags/container-device-interface)
+//! types and definitions.
+
+mod edits;
+
+#[cfg(has_fs)]
+use std::path::Path;
+use std::{
+    collections::HashMap,
+    io::{Read, Write},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{oci_error, Result},
+    from_reader,
+    runtime::Spec,
+    to_writer,
+};
+#[cfg(has_fs)]
+use crate::{from_file, to_file};
+
+pub use edits::*;
+
+/// The CDI specification version implemented by this module.
+pub const CDI_VERSION: &str = "0.7.0";
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// CdiSpec is a CDI specification, which describes the devices of a
+    /// single vendor and class and how they are made available to a
+    /// container.
+    struct CdiSpec {
+        #[serde(rename = "cdiVersion")]
+        /// Version of the CDI specification the spec conforms to.
+        version: String,
+
+        /// Kind of the devices in the form `vendor/class`, e.g.
+        /// `nvidia.com/gpu`.
+        kind: String,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Annotations are arbitrary metadata of the spec.
+        annotations: Option<HashMap<String, String>>,
+
+        #[serde(default)]
+        /// Devices are the devices described by the spec.
+        devices: Vec<CdiDevice>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// ContainerEdits are applied to the container whenever any of the
+        /// devices of the spec is injected.
+        container_edits: Option<ContainerEdits>,
+
+        #[cfg_attr(feature = "lossless", serde(flatten))]
+        #[cfg_attr(not(feature = "lossless"), serde(skip))]
+        #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+        /// Properties which are not defined by the CDI specification, e.g.
+        /// fields of a newer spec version. Only filled with the `lossless`
+        /// feature.
+        extensions: HashMap<String, serde_json::Value>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// CdiDevice is a single device of a [CdiSpec].
+    struct CdiDevice {
+        /// Name of the device, which is unique within its kind.
+        name: String,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Annotations are arbitrary metadata of the device.
+        annotations: Option<HashMap<String, String>>,
+
+        #[serde(default)]
+        /// ContainerEdits are applied to the container if the device is
+        /// injected.
+        container_edits: ContainerEdits,
+    }
+);
+
+impl CdiSpec {
+    #[cfg(has_fs)]
+    /// Attempts to load a CDI spec from a JSON file.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the file does not exist or an
+    /// [OciSpecError::SerDe](crate::OciSpecError::SerDe) if the spec cannot
+    /// be deserialized.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<CdiSpec> {
+        from_file(path)
+    }
+
+    /// Attempts to load a CDI spec from a JSON stream.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the spec cannot be deserialized.
+    pub fn from_reader<R: Read>(reader: R) -> Result<CdiSpec> {
+        from_reader(reader)
+    }
+
+    #[cfg(has_fs)]
+    /// Attempts to write a CDI spec to a file as JSON. If the file already
+    /// exists, it will be overwritten.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the spec cannot be serialized.
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        to_file(&self, path, false)
+    }
+
+    /// Attempts to write a CDI spec to a stream as JSON.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the spec cannot be serialized.
+    pub fn to_writer<W: Write>(&self, writer: &mut W) -> Result<()> {
+        to_writer(&self, writer, false)
+    }
+
+    /// Returns the vendor and class of the kind, e.g. `("nvidia.com", "gpu")`
+    /// for the kind `nvidia.com/gpu`.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the kind is not of the form `vendor/class`.
+    pub fn vendor_and_class(&self) -> Result<(&str, &str)> {
+        let (vendor, class) = self
+            .kind
+            .split_once('/')
+            .ok_or_else(|| oci_error(format!("invalid CDI kind {:?}", self.kind)))?;
+        validate_vendor(vendor)?;
+        validate_class(class)?;
+        Ok((vendor, class))
+    }
+
+    /// Returns the fully qualified name of a device of the spec, e.g.
+    /// `nvidia.com/gpu=0`, which is how devices are requested.
+    pub fn qualified_name(&self, device: &str) -> String {
+        format!("{}={}", self.kind, device)
+    }
+
+    /// Returns the device with the given name.
+    pub fn device(&self, name: &str) -> Option<&CdiDevice> {
+        self.devices.iter().find(|device| device.name == name)
+    }
+
+    /// Validates the kind, the device names and the container edits of the
+    /// spec. Device names have to be unique and consist of alphanumeric
+    /// characters, `-`, `_`, `.` and `:`, starting and ending with an
+    /// alphanumeric character.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// describing the first invalid part of the spec.
+    pub fn validate(&self) -> Result<()> {
+        self.vendor_and_class()?;
+        if let Some(edits) = &self.container_edits {
+            edits.validate()?;
+        }
+        for (i, device) in self.devices.iter().enumerate() {
+            validate_device_name(&device.name)?;
+            if self.devices[..i].iter().any(|d| d.name == device.name) {
+                return Err(oci_error(format!(
+                    "duplicate CDI device {}",
+                    self.qualified_name(&device.name)
+                )));
+            }
+            device.container_edits.validate()?;
+        }
+        Ok(())
+    }
+
+    /// Injects the device with the given name into the runtime spec by
+    /// applying the container edits of the spec, followed by the ones of the
+    /// device.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the device does not exist or the edits cannot be applied.
+    /// # Example
+    /// ```
+    /// use oci_spec::cdi::CdiSpec;
+    /// use oci_spec::runtime::Spec;
+    ///
+    /// let cdi: CdiSpec = serde_json::from_value(serde_json::json!({
+    ///     "cdiVersion": "0.7.0",
+    ///     "kind": "vendor.com/device",
+    ///     "devices": [{
+    ///         "name": "foo",
+    ///         "containerEdits": {"env": ["FOO_VISIBLE=1"]}
+    ///     }]
+    /// }))
+    /// .unwrap();
+    ///
+    /// let mut spec = Spec::default();
+    /// cdi.apply_device("foo", &mut spec).unwrap();
+    /// let env = serde_json::to_value(&spec).unwrap()["process"]["env"].clone();
+    /// assert!(env.as_array().unwrap().contains(&"FOO_VISIBLE=1".into()));
+    /// ```
+    pub fn apply_device(&self, name: &str, spec: &mut Spec) -> Result<()> {
+        let device = self.device(name).ok_or_else(|| {
+            oci_error(format!("unknown CDI device {}", self.qualified_name(name)))
+        })?;
+        if let Some(edits) = &self.container_edits {
+            edits.apply_to(spec)?;
+        }
+        device.container_edits.apply_to(spec)
+    }
+}
+
+/// Validates the vendor part of a kind, which consists of alphanumeric
+/// characters, `-`, `_` and `.`, starting with a letter and ending with an
+/// alphanumeric character.
+fn validate_vendor(vendor: &str) -> Result<()> {
+    validate_name("vendor", vendor, |c| matches!(c, '-' | '_' | '.'))?;
+    if !vendor.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Err(oci_error(format!(
+            "invalid CDI vendor {:?}, must start with a letter",
+            vendor
+        )));
+    }
+    Ok(())
+}
+
+/// Validates the class part of a kind, which consists of alphanumeric
+/// characters, `-` and `_`, starting with a letter and ending with an
+/// alphanumeric character.
+fn validate_class(class: &str) -> Result<()> {
+    validate_name("class", class, |c| matches!(c, '-' | '_'))?;
+    if !class.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Err(oci_error(format!(
+            "invalid CDI class {:?}, must start with a letter",
+            class
+        )));
+    }
+    Ok(())
+}
+
+fn validate_device_name(name: &str) -> Result<()> {
+    validate_name("device name", name, |c| matches!(c, '-' | '_' | '.' | ':'))
+}
+
+fn validate_name(kind: &str, name: &str, allowed: fn(char) -> bool) -> Result<()> {
+    let valid = name.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && name.ends_with(|c: char| c.is_ascii_alphanumeric())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || allowed(c));
+    if !valid {
+        return Err(oci_error(format!("invalid CDI {} {:?}", kind, name)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn cdi_spec() -> CdiSpec {
+        serde_json::from_value(json!({
+            "cdiVersion": CDI_VERSION,
+            "kind": "vendor.com/gpu",
+            "devices": [
+                {"name": "0", "containerEdits": {"env": ["GPU=0"]}},
+                {"name": "1", "containerEdits": {"env": ["GPU=1"]}}
+            ],
+            "containerEdits": {"env": ["GPU_DRIVER=1"]}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn vendor_and_class() {
+        // arrange
+        let mut spec = cdi_spec();
+
+        // act
+        let parsed = spec.vendor_and_class().unwrap();
+
+        // assert
+        assert_eq!(parsed, ("vendor.com", "gpu"));
+        assert_eq!(spec.qualified_name("0"), "vendor.com/gpu=0");
+        for kind in ["vendor.com", "vendor.com/", "1vendor/gpu", "vendor/gp.u"] {
+            spec.kind = kind.to_string();
+            assert!(spec.vendor_and_class().is_err(), "{}", kind);
+        }
+    }
+
+    #[test]
+    fn validate() {
+        // arrange
+        let mut spec = cdi_spec();
+
+        // act
+        let valid = spec.validate();
+        spec.devices[1].name = "0".to_string();
+        let duplicate = spec.validate();
+        spec.devices[1].name = "-1".to_string();
+        let invalid = spec.validate();
+
+        // assert
+        assert!(valid.is_ok());
+        assert!(duplicate.is_err());
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn apply_device() {
+        // arrange
+        let cdi = cdi_spec();
+        let mut spec = Spec::default();
+
+        // act
+        cdi.apply_device("1", &mut spec).unwrap();
+        let unknown = cdi.apply_device("2", &mut spec);
+
+        // assert
+        let env = spec.process.unwrap().env.unwrap();
+        assert!(env.contains(&"GPU_DRIVER=1".to_string()));
+        assert!(env.contains(&"GPU=1".to_string()));
+        assert!(!env.contains(&"GPU=0".to_string()));
+        assert!(unknown.is_err());
+    }
+}
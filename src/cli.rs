@@ -0,0 +1,152 @@
+//! Implementations of [clap::ValueEnum] for the enum types of the image spec,
+//! so that command line tools get argument parsing and shell completion for
+//! the known values.
+
+use clap::{builder::PossibleValue, ValueEnum};
+
+use crate::image::{Arch, MediaType, Os};
+
+const MEDIA_TYPES: &[MediaType] = &[
+    MediaType::Descriptor,
+    MediaType::LayoutHeader,
+    MediaType::ImageManifest,
+    MediaType::ImageIndex,
+    MediaType::ImageLayer,
+    MediaType::ImageLayerGzip,
+    MediaType::ImageLayerZstd,
+    MediaType::ImageLayerNonDistributable,
+    MediaType::ImageLayerNonDistributableGzip,
+    MediaType::ImageLayerNonDistributableZstd,
+    MediaType::ImageConfig,
+];
+
+const OPERATING_SYSTEMS: &[Os] = &[
+    Os::AIX,
+    Os::Android,
+    Os::Darwin,
+    Os::DragonFlyBSD,
+    Os::FreeBSD,
+    Os::Hurd,
+    Os::Illumos,
+    Os::iOS,
+    Os::Js,
+    Os::Linux,
+    Os::Nacl,
+    Os::NetBSD,
+    Os::OpenBSD,
+    Os::Plan9,
+    Os::Solaris,
+    Os::Wasip1,
+    Os::Windows,
+    Os::zOS,
+];
+
+const ARCHITECTURES: &[Arch] = &[
+    Arch::i386,
+    Arch::Amd64,
+    Arch::Amd64p32,
+    Arch::ARM,
+    Arch::ARMbe,
+    Arch::ARM64,
+    Arch::ARM64be,
+    Arch::LoongArch64,
+    Arch::Mips,
+    Arch::Mipsle,
+    Arch::Mips64,
+    Arch::Mips64le,
+    Arch::Mips64p32,
+    Arch::Mips64p32le,
+    Arch::PowerPC,
+    Arch::PowerPC64,
+    Arch::PowerPC64le,
+    Arch::RISCV,
+    Arch::RISCV64,
+    Arch::s390,
+    Arch::s390x,
+    Arch::SPARC,
+    Arch::SPARC64,
+    Arch::Wasm,
+];
+
+macro_rules! impl_value_enum {
+    ($($name:ident => $variants:ident),* $(,)?) => {
+        $(
+            impl ValueEnum for $name {
+                fn value_variants<'a>() -> &'a [Self] {
+                    $variants
+                }
+
+                fn to_possible_value(&self) -> Option<PossibleValue> {
+                    match self {
+                        $name::Other(_) => None,
+                        known => Some(PossibleValue::new(known.to_string())),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_value_enum!(
+    MediaType => MEDIA_TYPES,
+    Os => OPERATING_SYSTEMS,
+    Arch => ARCHITECTURES,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::image::Platform;
+    use clap::{Arg, Command};
+
+    #[test]
+    fn value_enums() {
+        // arrange
+        let command = Command::new("pull")
+            .arg(
+                Arg::new("os")
+                    .long("os")
+                    .value_parser(clap::value_parser!(Os)),
+            )
+            .arg(
+                Arg::new("arch")
+                    .long("arch")
+                    .value_parser(clap::value_parser!(Arch)),
+            )
+            .arg(
+                Arg::new("platform")
+                    .long("platform")
+                    .value_parser(clap::value_parser!(Platform)),
+            );
+
+        // act
+        let matches = command
+            .clone()
+            .try_get_matches_from([
+                "pull",
+                "--os",
+                "linux",
+                "--arch",
+                "arm64",
+                "--platform",
+                "linux/arm64/v8",
+            ])
+            .unwrap();
+
+        // assert
+        assert_eq!(matches.get_one::<Os>("os"), Some(&Os::Linux));
+        assert_eq!(matches.get_one::<Arch>("arch"), Some(&Arch::ARM64));
+        let platform = matches.get_one::<Platform>("platform").unwrap();
+        assert_eq!(platform.to_string(), "linux/arm64/v8");
+        assert!(command
+            .try_get_matches_from(["pull", "--arch", "m68k"])
+            .is_err());
+        assert_eq!(
+            MediaType::from_str("application/vnd.oci.image.index.v1+json", false),
+            Ok(MediaType::ImageIndex)
+        );
+        assert!(MediaType::Other("text/plain".to_owned())
+            .to_possible_value()
+            .is_none());
+    }
+}
@@ -0,0 +1,160 @@
+//! Structural comparison of serializable documents.
+
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::Result;
+
+/// A single difference between two documents, as returned by [diff].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Difference {
+    /// The location of the difference as a JSON pointer (RFC 6901), e.g.
+    /// `/process/env/1`. The empty string refers to the whole document.
+    pub path: String,
+    /// The value in the first document, or `None` if it was added.
+    pub before: Option<Value>,
+    /// The value in the second document, or `None` if it was removed.
+    pub after: Option<Value>,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.before, &self.after) {
+            (Some(before), Some(after)) => write!(f, "{}: {} -> {}", self.path, before, after),
+            (Some(before), None) => write!(f, "{}: removed {}", self.path, before),
+            (None, Some(after)) => write!(f, "{}: added {}", self.path, after),
+            (None, None) => write!(f, "{}: unchanged", self.path),
+        }
+    }
+}
+
+/// Compares the JSON representation of two documents, e.g. two runtime specs
+/// or image configurations, and returns the differing leaf values ordered by
+/// their location. Objects and arrays are compared element by element, so
+/// only the values which actually changed are reported. Fields which are not
+/// serialized, like unset optional fields, are reported as added or removed.
+/// # Errors
+/// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+/// if one of the documents cannot be serialized.
+/// # Example
+/// ```
+/// use oci_spec::diff;
+/// use serde_json::json;
+///
+/// let before = json!({"hostname": "a", "process": {"env": ["A=1", "B=2"]}});
+/// let after = json!({"process": {"env": ["A=1", "B=3"]}});
+///
+/// let changes: Vec<String> = diff(&before, &after)
+///     .unwrap()
+///     .iter()
+///     .map(ToString::to_string)
+///     .collect();
+/// assert_eq!(
+///     changes,
+///     vec![r#"/hostname: removed "a""#, r#"/process/env/1: "B=2" -> "B=3""#]
+/// );
+/// ```
+pub fn diff<T: Serialize, U: Serialize>(before: &T, after: &U) -> Result<Vec<Difference>> {
+    let before = serde_json::to_value(before)?;
+    let after = serde_json::to_value(after)?;
+    let mut differences = Vec::new();
+    diff_values(String::new(), Some(&before), Some(&after), &mut differences);
+    Ok(differences)
+}
+
+fn diff_values(
+    path: String,
+    before: Option<&Value>,
+    after: Option<&Value>,
+    differences: &mut Vec<Difference>,
+) {
+    match (before, after) {
+        (Some(Value::Object(before)), Some(Value::Object(after))) => {
+            let mut keys: Vec<&String> = before.keys().chain(after.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                diff_values(
+                    format!("{}/{}", path, escape(key)),
+                    before.get(key),
+                    after.get(key),
+                    differences,
+                );
+            }
+        }
+        (Some(Value::Array(before)), Some(Value::Array(after))) => {
+            for i in 0..before.len().max(after.len()) {
+                diff_values(
+                    format!("{}/{}", path, i),
+                    before.get(i),
+                    after.get(i),
+                    differences,
+                );
+            }
+        }
+        (before, after) if before != after => differences.push(Difference {
+            path,
+            before: before.cloned(),
+            after: after.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+/// Escapes a key for use as a JSON pointer reference token.
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn diff_documents() {
+        // arrange
+        let before = json!({
+            "annotations": {"a/b": "1", "c~d": "2"},
+            "mounts": [{"destination": "/proc"}, {"destination": "/dev"}],
+            "root": {"path": "rootfs"}
+        });
+        let after = json!({
+            "annotations": {"a/b": "1", "c~d": "3"},
+            "mounts": [{"destination": "/proc"}],
+            "root": "rootfs"
+        });
+
+        // act
+        let differences = diff(&before, &after).unwrap();
+
+        // assert
+        assert_eq!(
+            differences,
+            vec![
+                Difference {
+                    path: "/annotations/c~0d".to_string(),
+                    before: Some(json!("2")),
+                    after: Some(json!("3")),
+                },
+                Difference {
+                    path: "/mounts/1".to_string(),
+                    before: Some(json!({"destination": "/dev"})),
+                    after: None,
+                },
+                Difference {
+                    path: "/root".to_string(),
+                    before: Some(json!({"path": "rootfs"})),
+                    after: Some(json!("rootfs")),
+                },
+            ]
+        );
+        assert!(diff(&before, &before).unwrap().is_empty());
+        assert_eq!(
+            diff(&json!(1), &json!(2)).unwrap()[0].to_string(),
+            ": 1 -> 2"
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! URL paths of the endpoints defined by the distribution spec.
+//!
+//! # Example
+//! ```
+//! use oci_spec::distribution::{endpoints::Endpoints, Pagination};
+//!
+//! let endpoints = Endpoints::new("https://registry.example.com/", "library/alpine").unwrap();
+//! assert_eq!(
+//!     endpoints.manifest("3.19").unwrap(),
+//!     "https://registry.example.com/v2/library/alpine/manifests/3.19"
+//! );
+//! assert_eq!(
+//!     endpoints.tags(Some(&Pagination::new(10))),
+//!     "https://registry.example.com/v2/library/alpine/tags/list?n=10"
+//! );
+//! ```
+
+use super::{
+    pagination::encode, validate_digest, validate_reference, validate_repository, Pagination,
+    ValidationError, EXTENSIONS_DISCOVER_PATH,
+};
+
+/// Returns the URL of the API version check endpoint `/v2/`, which is
+/// relative if `base_url` is empty.
+pub fn version_check(base_url: &str) -> String {
+    format!("{}/v2/", base_url.trim_end_matches('/'))
+}
+
+/// Returns the URL of the catalog endpoint `/v2/_catalog`, listing the
+/// repositories of the registry.
+pub fn catalog(base_url: &str, pagination: Option<&Pagination>) -> String {
+    with_query(
+        format!("{}/v2/_catalog", base_url.trim_end_matches('/')),
+        pagination.map(Pagination::to_query),
+    )
+}
+
+/// Returns the URL of the extension discovery endpoint of the registry.
+pub fn extensions(base_url: &str) -> String {
+    format!(
+        "{}/v2/{}",
+        base_url.trim_end_matches('/'),
+        EXTENSIONS_DISCOVER_PATH
+    )
+}
+
+/// Endpoints builds the URLs of the endpoints of a single repository. The
+/// repository name, references and digests are validated, so they do not
+/// need to be escaped.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Endpoints {
+    base: String,
+}
+
+impl Endpoints {
+    /// Creates the endpoints of the repository `name` of the registry at
+    /// `base_url`, e.g. `https://registry.example.com`. The URLs are relative
+    /// if `base_url` is empty.
+    /// # Errors
+    /// This function will return a [ValidationError] if the repository name
+    /// is invalid.
+    pub fn new(base_url: &str, name: &str) -> Result<Self, ValidationError> {
+        validate_repository(name)?;
+        Ok(Endpoints {
+            base: format!("{}/v2/{}", base_url.trim_end_matches('/'), name),
+        })
+    }
+
+    /// Returns the URL of the manifest with the given tag or digest.
+    /// # Errors
+    /// This function will return a [ValidationError] if the reference is
+    /// neither a valid tag nor a valid digest.
+    pub fn manifest(&self, reference: &str) -> Result<String, ValidationError> {
+        validate_reference(reference)?;
+        Ok(format!("{}/manifests/{}", self.base, reference))
+    }
+
+    /// Returns the URL of the blob with the given digest.
+    /// # Errors
+    /// This function will return a [ValidationError] if the digest is
+    /// invalid.
+    pub fn blob(&self, digest: &str) -> Result<String, ValidationError> {
+        validate_digest(digest)?;
+        Ok(format!("{}/blobs/{}", self.base, digest))
+    }
+
+    /// Returns the URL to start a blob upload at, or to upload a blob in a
+    /// single request if `digest` is given.
+    /// # Errors
+    /// This function will return a [ValidationError] if the digest is
+    /// invalid.
+    pub fn uploads(&self, digest: Option<&str>) -> Result<String, ValidationError> {
+        digest.map(validate_digest).transpose()?;
+        Ok(with_query(
+            format!("{}/blobs/uploads/", self.base),
+            digest.map(|digest| format!("digest={}", digest)),
+        ))
+    }
+
+    /// Returns the URL to mount the blob with the given digest from the
+    /// repository `from` of the same registry, instead of uploading it.
+    /// # Errors
+    /// This function will return a [ValidationError] if the digest or the
+    /// repository name are invalid.
+    pub fn mount(&self, digest: &str, from: &str) -> Result<String, ValidationError> {
+        validate_digest(digest)?;
+        validate_repository(from)?;
+        Ok(format!(
+            "{}/blobs/uploads/?mount={}&from={}",
+            self.base, digest, from
+        ))
+    }
+
+    /// Returns the URL of the extension discovery endpoint of the
+    /// repository.
+    pub fn extensions(&self) -> String {
+        format!("{}/{}", self.base, EXTENSIONS_DISCOVER_PATH)
+    }
+
+    /// Returns the URL listing the tags of the repository.
+    pub fn tags(&self, pagination: Option<&Pagination>) -> String {
+        with_query(
+            format!("{}/tags/list", self.base),
+            pagination.map(Pagination::to_query),
+        )
+    }
+
+    /// Returns the URL listing the manifests referring to the manifest with
+    /// the given digest, optionally filtered by artifact type.
+    /// # Errors
+    /// This function will return a [ValidationError] if the digest is
+    /// invalid.
+    pub fn referrers(
+        &self,
+        digest: &str,
+        artifact_type: Option<&str>,
+    ) -> Result<String, ValidationError> {
+        validate_digest(digest)?;
+        Ok(with_query(
+            format!("{}/referrers/{}", self.base, digest),
+            artifact_type.map(|artifact_type| format!("artifactType={}", encode(artifact_type))),
+        ))
+    }
+}
+
+/// Appends the query to the URL, unless it is missing or empty.
+fn with_query(url: String, query: Option<String>) -> String {
+    match query {
+        Some(query) if !query.is_empty() => format!("{}?{}", url, query),
+        _ => url,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGEST: &str = "sha256:6c3c624b58dbbcd3c0dd82b4c53f04194d1247c6eebdaab7c610cf7d66709b3b";
+
+    #[test]
+    fn repository_endpoints() -> Result<(), ValidationError> {
+        // arrange
+        let endpoints = Endpoints::new("", "library/alpine")?;
+
+        // act
+        let manifest = endpoints.manifest(DIGEST)?;
+        let blob = endpoints.blob(DIGEST)?;
+        let upload = endpoints.uploads(Some(DIGEST))?;
+        let mount = endpoints.mount(DIGEST, "library/base")?;
+        let referrers = endpoints.referrers(DIGEST, Some("application/vnd.example+json"))?;
+
+        // assert
+        assert_eq!(manifest, format!("/v2/library/alpine/manifests/{}", DIGEST));
+        assert_eq!(blob, format!("/v2/library/alpine/blobs/{}", DIGEST));
+        assert_eq!(
+            upload,
+            format!("/v2/library/alpine/blobs/uploads/?digest={}", DIGEST)
+        );
+        assert_eq!(
+            endpoints.uploads(None)?,
+            "/v2/library/alpine/blobs/uploads/"
+        );
+        assert_eq!(
+            mount,
+            format!(
+                "/v2/library/alpine/blobs/uploads/?mount={}&from=library/base",
+                DIGEST
+            )
+        );
+        assert_eq!(
+            referrers,
+            format!(
+                "/v2/library/alpine/referrers/{}?artifactType=application/vnd.example%2Bjson",
+                DIGEST
+            )
+        );
+        assert_eq!(endpoints.tags(None), "/v2/library/alpine/tags/list");
+        assert_eq!(
+            endpoints.extensions(),
+            "/v2/library/alpine/_oci/ext/discover"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_endpoints() {
+        assert!(Endpoints::new("", "Library").is_err());
+        let endpoints = Endpoints::new("https://registry.example.com", "alpine").unwrap();
+        assert!(endpoints.manifest("../latest").is_err());
+        assert!(endpoints.blob("latest").is_err());
+        assert!(endpoints.mount(DIGEST, "a//b").is_err());
+        assert!(endpoints.referrers("sha256:a?b", None).is_err());
+    }
+
+    #[test]
+    fn registry_endpoints() {
+        assert_eq!(
+            version_check("https://registry.example.com/"),
+            "https://registry.example.com/v2/"
+        );
+        assert_eq!(
+            catalog(
+                "https://registry.example.com",
+                Some(&Pagination::new(5).after("a/b"))
+            ),
+            "https://registry.example.com/v2/_catalog?n=5&last=a/b"
+        );
+        assert_eq!(catalog("", None), "/v2/_catalog");
+        assert_eq!(extensions(""), "/v2/_oci/ext/discover");
+    }
+}
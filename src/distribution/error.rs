@@ -1,15 +1,19 @@
 //! Error types of the distribution spec.
 
 use serde::{Deserialize, Serialize};
-use std::fmt::{self, Display, Formatter};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
 use thiserror::Error;
 
+use crate::error::{oci_error, OciSpecError};
+
 /// The string returned by and ErrorResponse error.
 pub const ERR_REGISTRY: &str = "distribution: registry returned error";
 
 /// Unique identifier representing error code.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ErrorCode {
     /// Blob unknown to registry.
     BlobUnknown,
@@ -38,20 +42,167 @@ pub enum ErrorCode {
     /// The operation is unsupported.
     Unsupported,
     /// Too many requests.
-    #[serde(rename = "TOOMANYREQUESTS")]
     TooManyRequests,
+    /// A registry specific error code not defined by the spec.
+    Other(String),
+}
+
+impl ErrorCode {
+    /// All error codes defined by the spec.
+    pub const KNOWN: [ErrorCode; 14] = [
+        ErrorCode::BlobUnknown,
+        ErrorCode::BlobUploadInvalid,
+        ErrorCode::BlobUploadUnknown,
+        ErrorCode::DigestInvalid,
+        ErrorCode::ManifestBlobUnknown,
+        ErrorCode::ManifestInvalid,
+        ErrorCode::ManifestUnknown,
+        ErrorCode::NameInvalid,
+        ErrorCode::NameUnknown,
+        ErrorCode::SizeInvalid,
+        ErrorCode::Unauthorized,
+        ErrorCode::Denied,
+        ErrorCode::Unsupported,
+        ErrorCode::TooManyRequests,
+    ];
+
+    /// Returns the HTTP status code a registry responds with for the error,
+    /// following the reference implementation of the distribution spec.
+    /// Registry specific codes are mapped to 500 Internal Server Error.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            ErrorCode::DigestInvalid
+            | ErrorCode::ManifestBlobUnknown
+            | ErrorCode::ManifestInvalid
+            | ErrorCode::NameInvalid
+            | ErrorCode::SizeInvalid => 400,
+            ErrorCode::Unauthorized => 401,
+            ErrorCode::Denied => 403,
+            ErrorCode::BlobUnknown
+            | ErrorCode::BlobUploadInvalid
+            | ErrorCode::BlobUploadUnknown
+            | ErrorCode::ManifestUnknown
+            | ErrorCode::NameUnknown => 404,
+            ErrorCode::Unsupported => 405,
+            ErrorCode::TooManyRequests => 429,
+            ErrorCode::Other(_) => 500,
+        }
+    }
+
+    /// Returns the error codes of the spec which are returned with the given
+    /// HTTP status code, in the order of [ErrorCode::KNOWN].
+    /// # Example
+    /// ```
+    /// use oci_spec::distribution::ErrorCode;
+    ///
+    /// assert_eq!(ErrorCode::from_status_code(429), vec![ErrorCode::TooManyRequests]);
+    /// assert!(ErrorCode::from_status_code(200).is_empty());
+    /// ```
+    pub fn from_status_code(status: u16) -> Vec<ErrorCode> {
+        Self::KNOWN
+            .iter()
+            .filter(|code| code.status_code() == status)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the code as it is transmitted by the registry, e.g.
+    /// `BLOB_UNKNOWN`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ErrorCode::BlobUnknown => "BLOB_UNKNOWN",
+            ErrorCode::BlobUploadInvalid => "BLOB_UPLOAD_INVALID",
+            ErrorCode::BlobUploadUnknown => "BLOB_UPLOAD_UNKNOWN",
+            ErrorCode::DigestInvalid => "DIGEST_INVALID",
+            ErrorCode::ManifestBlobUnknown => "MANIFEST_BLOB_UNKNOWN",
+            ErrorCode::ManifestInvalid => "MANIFEST_INVALID",
+            ErrorCode::ManifestUnknown => "MANIFEST_UNKNOWN",
+            ErrorCode::NameInvalid => "NAME_INVALID",
+            ErrorCode::NameUnknown => "NAME_UNKNOWN",
+            ErrorCode::SizeInvalid => "SIZE_INVALID",
+            ErrorCode::Unauthorized => "UNAUTHORIZED",
+            ErrorCode::Denied => "DENIED",
+            ErrorCode::Unsupported => "UNSUPPORTED",
+            ErrorCode::TooManyRequests => "TOOMANYREQUESTS",
+            ErrorCode::Other(code) => code,
+        }
+    }
+}
+
+impl From<&str> for ErrorCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "BLOB_UNKNOWN" => ErrorCode::BlobUnknown,
+            "BLOB_UPLOAD_INVALID" => ErrorCode::BlobUploadInvalid,
+            "BLOB_UPLOAD_UNKNOWN" => ErrorCode::BlobUploadUnknown,
+            "DIGEST_INVALID" => ErrorCode::DigestInvalid,
+            "MANIFEST_BLOB_UNKNOWN" => ErrorCode::ManifestBlobUnknown,
+            "MANIFEST_INVALID" => ErrorCode::ManifestInvalid,
+            "MANIFEST_UNKNOWN" => ErrorCode::ManifestUnknown,
+            "NAME_INVALID" => ErrorCode::NameInvalid,
+            "NAME_UNKNOWN" => ErrorCode::NameUnknown,
+            "SIZE_INVALID" => ErrorCode::SizeInvalid,
+            "UNAUTHORIZED" => ErrorCode::Unauthorized,
+            "DENIED" => ErrorCode::Denied,
+            "UNSUPPORTED" => ErrorCode::Unsupported,
+            "TOOMANYREQUESTS" => ErrorCode::TooManyRequests,
+            code => ErrorCode::Other(code.to_owned()),
+        }
+    }
+}
+
+impl FromStr for ErrorCode {
+    type Err = OciSpecError;
+
+    /// Parses an error code, which must only contain uppercase alphabetic
+    /// characters and underscores. Codes not defined by the spec are
+    /// returned as [ErrorCode::Other].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || !s.chars().all(|c| c.is_ascii_uppercase() || c == '_') {
+            return Err(oci_error(format!("invalid error code {:?}", s)));
+        }
+        Ok(s.into())
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let code = String::deserialize(deserializer)?;
+        Ok(code.as_str().into())
+    }
 }
 
 make_pub!(
     #[derive(Clone, Debug, Deserialize, Eq, Error, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder),
+        derive(derive_builder::Builder, getset::MutGetters, getset::Setters),
         builder(
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// ErrorResponse is returned by a registry on an invalid request.
     struct ErrorResponse {
@@ -75,15 +226,22 @@ impl ErrorResponse {
 
 make_pub!(
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get = "pub")
+        getset(get = "pub", get_mut = "pub", set = "pub")
     )]
     /// Describes a server error returned from a registry.
     struct ErrorInfo {
@@ -106,12 +264,70 @@ make_pub!(
 );
 
 #[cfg(test)]
-#[cfg(feature = "builder")]
 mod tests {
     use super::*;
     use crate::error::Result;
 
     #[test]
+    fn error_code_round_trip() -> Result<()> {
+        // arrange
+        let codes = ["BLOB_UNKNOWN", "TOOMANYREQUESTS", "QUOTA_EXCEEDED"];
+
+        // act
+        let parsed = codes
+            .iter()
+            .map(|code| code.parse())
+            .collect::<Result<Vec<ErrorCode>>>()?;
+
+        // assert
+        assert_eq!(parsed[0], ErrorCode::BlobUnknown);
+        assert_eq!(parsed[1], ErrorCode::TooManyRequests);
+        assert_eq!(parsed[2], ErrorCode::Other("QUOTA_EXCEEDED".to_string()));
+        for (code, parsed) in codes.iter().zip(&parsed) {
+            assert_eq!(&parsed.to_string(), code);
+        }
+        assert!("".parse::<ErrorCode>().is_err());
+        assert!("blob_unknown".parse::<ErrorCode>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn error_code_status() {
+        // arrange
+        let other = ErrorCode::Other("QUOTA_EXCEEDED".to_string());
+
+        // act
+        let not_found = ErrorCode::from_status_code(404);
+
+        // assert
+        assert_eq!(ErrorCode::BlobUnknown.status_code(), 404);
+        assert_eq!(ErrorCode::DigestInvalid.status_code(), 400);
+        assert_eq!(ErrorCode::TooManyRequests.status_code(), 429);
+        assert_eq!(other.status_code(), 500);
+        assert_eq!(not_found.len(), 5);
+        assert!(not_found.iter().all(|code| code.status_code() == 404));
+        assert!(ErrorCode::from_status_code(500).is_empty());
+    }
+
+    #[test]
+    fn error_response_unknown_code() -> Result<()> {
+        // arrange
+        let json = r#"{"errors":[{"code":"QUOTA_EXCEEDED","message":"quota exceeded"}]}"#;
+
+        // act
+        let response: ErrorResponse = serde_json::from_str(json)?;
+
+        // assert
+        assert_eq!(
+            response.detail()[0].code,
+            ErrorCode::Other("QUOTA_EXCEEDED".to_string())
+        );
+        assert_eq!(serde_json::to_string(&response)?, json);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "builder")]
     fn error_response_success() -> Result<()> {
         let response = ErrorResponseBuilder::default().errors(vec![]).build()?;
         assert!(response.detail().is_empty());
@@ -120,11 +336,13 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "builder")]
     fn error_response_failure() {
         assert!(ErrorResponseBuilder::default().build().is_err());
     }
 
     #[test]
+    #[cfg(feature = "builder")]
     fn error_info_success() -> Result<()> {
         let info = ErrorInfoBuilder::default()
             .code(ErrorCode::BlobUnknown)
@@ -136,6 +354,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "builder")]
     fn error_info_failure() {
         assert!(ErrorInfoBuilder::default().build().is_err());
     }
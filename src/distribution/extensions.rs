@@ -0,0 +1,187 @@
+//! Extension discovery types of the distribution spec.
+
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::Result, from_reader, to_writer};
+
+/// The path of the extension discovery endpoint relative to `/v2/` or to
+/// the repository path `/v2/<name>/`.
+pub const EXTENSIONS_DISCOVER_PATH: &str = "_oci/ext/discover";
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// ExtensionList is the response of the extension discovery endpoint,
+    /// listing the extensions a registry or repository supports.
+    struct ExtensionList {
+        #[serde(default)]
+        /// The supported extensions.
+        extensions: Vec<Extension>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// Extension describes a single extension of the distribution API.
+    struct Extension {
+        /// The name of the extension in the form `_<extension>`, e.g.
+        /// `_oci`.
+        name: String,
+
+        /// A URL pointing to the documentation of the extension.
+        url: String,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", builder(default = "None"))]
+        /// A human readable description of the extension.
+        description: Option<String>,
+
+        #[serde(default)]
+        #[cfg_attr(feature = "builder", builder(default = "Vec::new()"))]
+        /// The endpoints provided by the extension, e.g. `_oci/ext/discover`.
+        endpoints: Vec<String>,
+    }
+);
+
+impl ExtensionList {
+    /// Attempts to load an extension list from a stream, e.g. the body of a
+    /// `/v2/_oci/ext/discover` response.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the extension list cannot be deserialized.
+    /// # Example
+    /// ```
+    /// use oci_spec::distribution::ExtensionList;
+    ///
+    /// let body = r#"{"extensions": [{
+    ///     "name": "_oci",
+    ///     "url": "https://github.com/opencontainers/distribution-spec/tree/main/extensions",
+    ///     "endpoints": ["_oci/ext/discover"]
+    /// }]}"#;
+    /// let list = ExtensionList::from_reader(body.as_bytes()).unwrap();
+    /// assert!(list.supports_endpoint("_oci/ext/discover"));
+    /// ```
+    pub fn from_reader<R: Read>(reader: R) -> Result<ExtensionList> {
+        from_reader(reader)
+    }
+
+    /// Attempts to write an extension list to a stream as JSON.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the extension list cannot be serialized.
+    pub fn to_writer<W: Write>(&self, writer: &mut W) -> Result<()> {
+        to_writer(&self, writer, false)
+    }
+
+    /// Returns the extension with the given name.
+    pub fn extension(&self, name: &str) -> Option<&Extension> {
+        self.extensions
+            .iter()
+            .find(|extension| extension.name == name)
+    }
+
+    /// Returns true if any extension provides the given endpoint. Leading
+    /// slashes are ignored.
+    pub fn supports_endpoint(&self, endpoint: &str) -> bool {
+        self.extensions
+            .iter()
+            .any(|extension| extension.provides(endpoint))
+    }
+}
+
+impl Extension {
+    /// Returns true if the extension provides the given endpoint. Leading
+    /// slashes are ignored.
+    pub fn provides(&self, endpoint: &str) -> bool {
+        let endpoint = endpoint.trim_start_matches('/');
+        self.endpoints
+            .iter()
+            .any(|e| e.trim_start_matches('/') == endpoint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extension_list() -> Result<()> {
+        // arrange
+        let body = r#"{"extensions":[{"name":"_oci","url":"https://example.com/ext","description":"discovery","endpoints":["_oci/ext/discover"]},{"name":"_example","url":"https://example.com/example"}]}"#;
+
+        // act
+        let list = ExtensionList::from_reader(body.as_bytes())?;
+
+        // assert
+        assert_eq!(list.extensions.len(), 2);
+        let oci = list.extension("_oci").expect("_oci extension");
+        assert_eq!(oci.description.as_deref(), Some("discovery"));
+        assert!(oci.provides("/_oci/ext/discover"));
+        assert!(list.extension("_example").unwrap().endpoints.is_empty());
+        assert!(list.extension("_missing").is_none());
+        assert!(list.supports_endpoint(EXTENSIONS_DISCOVER_PATH));
+        assert!(!list.supports_endpoint("_example/query"));
+        assert!(ExtensionList::from_reader("{}".as_bytes())?
+            .extensions
+            .is_empty());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "builder")]
+    fn extension_builder() -> Result<()> {
+        let extension = ExtensionBuilder::default()
+            .name("_oci")
+            .url("https://example.com/ext")
+            .build()?;
+        assert!(extension.description().is_none());
+        assert!(ExtensionBuilder::default().name("_oci").build().is_err());
+
+        let list = ExtensionListBuilder::default()
+            .extensions(vec![extension])
+            .build()?;
+        let mut written = Vec::new();
+        list.to_writer(&mut written)?;
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            r#"{"extensions":[{"name":"_oci","url":"https://example.com/ext","endpoints":[]}]}"#
+        );
+        Ok(())
+    }
+}
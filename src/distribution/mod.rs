@@ -11,12 +11,27 @@
 //! To support other artifact types, please see the [Open Container Initiative Artifact Authors
 //! Guide](https://github.com/opencontainers/artifacts) (a.k.a. "OCI Artifacts").
 
+pub mod endpoints;
 mod error;
+mod extensions;
+mod pagination;
+mod referrers;
 mod repository;
 mod tag;
+mod token;
+mod upload;
+mod validation;
 mod version;
+mod warning;
 
 pub use error::*;
+pub use extensions::*;
+pub use pagination::*;
+pub use referrers::*;
 pub use repository::*;
 pub use tag::*;
+pub use token::*;
+pub use upload::*;
+pub use validation::*;
 pub use version::*;
+pub use warning::*;
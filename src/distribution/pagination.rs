@@ -0,0 +1,249 @@
+//! Pagination of the list endpoints of the distribution spec.
+
+use std::fmt::Write;
+
+use crate::error::{oci_error, Result};
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+/// Pagination specifies the page of a list endpoint like
+/// `/v2/<name>/tags/list` to request, using the `n` and `last` query
+/// parameters of the distribution spec.
+pub struct Pagination {
+    /// The maximum number of results to return.
+    pub n: Option<usize>,
+    /// The last result of the previous page. Results are returned in lexical
+    /// order, starting after this value.
+    pub last: Option<String>,
+}
+
+impl Pagination {
+    /// Creates the pagination for the first page with at most `n` results.
+    pub fn new(n: usize) -> Self {
+        Pagination {
+            n: Some(n),
+            last: None,
+        }
+    }
+
+    /// Returns the pagination for the page following the result `last`.
+    pub fn after<S: Into<String>>(&self, last: S) -> Self {
+        Pagination {
+            n: self.n,
+            last: Some(last.into()),
+        }
+    }
+
+    /// Formats the pagination as a URL query without the leading `?`, which
+    /// is empty if no parameter is set.
+    /// # Example
+    /// ```
+    /// use oci_spec::distribution::Pagination;
+    ///
+    /// let pagination = Pagination::new(100).after("v1.0");
+    /// assert_eq!(pagination.to_query(), "n=100&last=v1.0");
+    /// ```
+    pub fn to_query(&self) -> String {
+        let mut params = Vec::new();
+        if let Some(n) = self.n {
+            params.push(format!("n={}", n));
+        }
+        if let Some(last) = &self.last {
+            params.push(format!("last={}", encode(last)));
+        }
+        params.join("&")
+    }
+
+    /// Parses the `n` and `last` parameters from a URL query, with or
+    /// without the leading `?`. Other parameters are ignored.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if `n` is not a number or a parameter is not properly percent-encoded.
+    pub fn from_query(query: &str) -> Result<Self> {
+        let mut pagination = Pagination::default();
+        let query = query.strip_prefix('?').unwrap_or(query);
+        for param in query.split('&').filter(|p| !p.is_empty()) {
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+            match key {
+                "n" => {
+                    pagination.n = Some(value.parse().map_err(|_| {
+                        oci_error(format!("invalid pagination parameter n={}", value))
+                    })?)
+                }
+                "last" => pagination.last = Some(decode(value)?),
+                _ => {}
+            }
+        }
+        Ok(pagination)
+    }
+
+    /// Parses the pagination of the next page from the value of a `Link`
+    /// header as returned by the registry, or returns `None` if there is no
+    /// link with the relation type `next`, i.e. the last page was reached.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the query of the next link is invalid.
+    /// # Example
+    /// ```
+    /// use oci_spec::distribution::Pagination;
+    ///
+    /// let header = r#"</v2/library/alpine/tags/list?n=2&last=3.19>; rel="next""#;
+    /// let next = Pagination::from_link_header(header).unwrap().unwrap();
+    /// assert_eq!(next, Pagination::new(2).after("3.19"));
+    /// ```
+    pub fn from_link_header(value: &str) -> Result<Option<Self>> {
+        next_link(value)
+            .map(|target| {
+                let query = target.split_once('?').map_or("", |(_, query)| query);
+                let query = query.split('#').next().unwrap_or_default();
+                Pagination::from_query(query)
+            })
+            .transpose()
+    }
+
+    /// Formats the value of the `Link` header which points to the next page
+    /// of the list at `path`, e.g. `/v2/library/alpine/tags/list`.
+    /// # Example
+    /// ```
+    /// use oci_spec::distribution::Pagination;
+    ///
+    /// let next = Pagination::new(2).after("3.19");
+    /// assert_eq!(
+    ///     next.to_link_header("/v2/library/alpine/tags/list"),
+    ///     r#"</v2/library/alpine/tags/list?n=2&last=3.19>; rel="next""#
+    /// );
+    /// ```
+    pub fn to_link_header(&self, path: &str) -> String {
+        let query = self.to_query();
+        if query.is_empty() {
+            format!(r#"<{}>; rel="next""#, path)
+        } else {
+            format!(r#"<{}?{}>; rel="next""#, path, query)
+        }
+    }
+}
+
+/// Returns the target of the first link with the relation type `next` of an
+/// RFC 5988 `Link` header value, which may contain several links.
+/// # Example
+/// ```
+/// use oci_spec::distribution::next_link;
+///
+/// let header = r#"<https://example.com/a>; rel="prev", <https://example.com/b>; rel=next"#;
+/// assert_eq!(next_link(header).as_deref(), Some("https://example.com/b"));
+/// ```
+pub fn next_link(value: &str) -> Option<String> {
+    let mut rest = value;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+        let (target, after) = rest.strip_prefix('<')?.split_once('>')?;
+        let (params, remainder) = split_params(after);
+        let is_next = params.split(';').any(|param| {
+            param.split_once('=').is_some_and(|(key, value)| {
+                key.trim().eq_ignore_ascii_case("rel")
+                    && value
+                        .trim()
+                        .trim_matches('"')
+                        .split_whitespace()
+                        .any(|rel| rel.eq_ignore_ascii_case("next"))
+            })
+        });
+        if is_next {
+            return Some(target.to_string());
+        }
+        rest = remainder;
+    }
+}
+
+/// Splits the parameters of a link from the following links at the first
+/// comma which is not part of a quoted string.
+fn split_params(value: &str) -> (&str, &str) {
+    let mut quoted = false;
+    for (i, c) in value.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => return (&value[..i], &value[i..]),
+            _ => {}
+        }
+    }
+    (value, "")
+}
+
+/// Percent-encodes all characters of a query value except unreserved ones
+/// and `/`.
+pub(crate) fn encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~/".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            let _ = write!(encoded, "%{:02X}", byte);
+        }
+    }
+    encoded
+}
+
+fn decode(value: &str) -> Result<String> {
+    let invalid = || oci_error(format!("invalid percent-encoding in {:?}", value));
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut iter = value.bytes();
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let hex = [
+                iter.next().ok_or_else(invalid)?,
+                iter.next().ok_or_else(invalid)?,
+            ];
+            let hex = std::str::from_utf8(&hex).map_err(|_| invalid())?;
+            bytes.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_round_trip() -> Result<()> {
+        // arrange
+        let pagination = Pagination::new(50).after("library/a b");
+
+        // act
+        let query = pagination.to_query();
+
+        // assert
+        assert_eq!(query, "n=50&last=library/a%20b");
+        assert_eq!(
+            Pagination::from_query(&format!("?{}&x=1", query))?,
+            pagination
+        );
+        assert_eq!(Pagination::default().to_query(), "");
+        assert!(Pagination::from_query("n=many").is_err());
+        assert!(Pagination::from_query("last=%4").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn link_header() -> Result<()> {
+        // arrange
+        let header = concat!(
+            r#"<https://registry.example.com/v2/_catalog?n=1&last=a>; rel="prev"; title="a, b", "#,
+            r#"<https://registry.example.com/v2/_catalog?n=1&last=b#frag>; rel="last next""#
+        );
+
+        // act
+        let next = Pagination::from_link_header(header)?;
+
+        // assert
+        assert_eq!(next, Some(Pagination::new(1).after("b")));
+        assert_eq!(
+            Pagination::from_link_header(r#"</v2/_catalog?n=1>; rel="prev""#)?,
+            None
+        );
+        assert_eq!(next_link(""), None);
+        assert_eq!(next_link("garbage"), None);
+        Ok(())
+    }
+}
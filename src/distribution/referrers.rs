@@ -0,0 +1,217 @@
+//! Filtering of the referrers API of the distribution spec.
+
+use std::collections::HashMap;
+
+#[cfg(feature = "image")]
+use crate::image::{Descriptor, ImageIndex};
+
+/// The header of a referrers response listing the filters which were applied
+/// by the registry, e.g. `artifactType`.
+pub const HEADER_FILTERS_APPLIED: &str = "OCI-Filters-Applied";
+
+/// The name of the filter by artifact type, as used in the query of the
+/// referrers endpoint and in the [HEADER_FILTERS_APPLIED] header.
+pub const FILTER_ARTIFACT_TYPE: &str = "artifactType";
+
+/// The name of the filter by annotations in the [HEADER_FILTERS_APPLIED]
+/// header. This filter is not defined by the spec, so registries do not
+/// apply it.
+pub const FILTER_ANNOTATIONS: &str = "annotations";
+
+/// ReferrersFilter selects the descriptors of a referrers index. Registries
+/// may apply the filters themselves, which they report in the
+/// [HEADER_FILTERS_APPLIED] header, so clients only need to apply the
+/// remaining ones, see [ReferrersFilter::apply].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReferrersFilter {
+    /// Only descriptors with this artifact type are selected.
+    pub artifact_type: Option<String>,
+    /// Only descriptors with all of these annotations are selected.
+    pub annotations: HashMap<String, String>,
+}
+
+impl ReferrersFilter {
+    /// Creates a filter selecting descriptors with the given artifact type.
+    pub fn artifact_type<S: Into<String>>(artifact_type: S) -> Self {
+        ReferrersFilter {
+            artifact_type: Some(artifact_type.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Adds an annotation which descriptors need to have to be selected.
+    pub fn annotation<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.annotations.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns the names of the filters which are set.
+    pub fn filters(&self) -> Vec<&'static str> {
+        let mut filters = Vec::new();
+        if self.artifact_type.is_some() {
+            filters.push(FILTER_ARTIFACT_TYPE);
+        }
+        if !self.annotations.is_empty() {
+            filters.push(FILTER_ANNOTATIONS);
+        }
+        filters
+    }
+
+    /// Returns the value of the [HEADER_FILTERS_APPLIED] header for a
+    /// registry which applied this filter, or `None` if no filter is set and
+    /// the header is omitted.
+    pub fn filters_applied(&self) -> Option<String> {
+        let filters = self.filters();
+        if filters.is_empty() {
+            None
+        } else {
+            Some(filters.join(","))
+        }
+    }
+}
+
+/// Parses the value of the [HEADER_FILTERS_APPLIED] header into the names
+/// of the applied filters.
+/// # Example
+/// ```
+/// use oci_spec::distribution::parse_filters_applied;
+///
+/// assert_eq!(parse_filters_applied("artifactType, annotations"), vec!["artifactType", "annotations"]);
+/// ```
+pub fn parse_filters_applied(value: &str) -> Vec<&str> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|filter| !filter.is_empty())
+        .collect()
+}
+
+#[cfg(feature = "image")]
+impl ReferrersFilter {
+    /// Returns true if the descriptor is selected by the filter.
+    pub fn matches(&self, descriptor: &Descriptor) -> bool {
+        let artifact_type_matches = self
+            .artifact_type
+            .as_ref()
+            .is_none_or(|artifact_type| descriptor.artifact_type.as_ref() == Some(artifact_type));
+        let annotations_match = self.annotations.iter().all(|(key, value)| {
+            descriptor
+                .annotations
+                .as_ref()
+                .and_then(|annotations| annotations.get(key))
+                == Some(value)
+        });
+        artifact_type_matches && annotations_match
+    }
+
+    /// Returns the referrers index with only the selected descriptors. The
+    /// filters listed in `filters_applied`, the value of the
+    /// [HEADER_FILTERS_APPLIED] header of the response, have already been
+    /// applied by the registry and are skipped. Registries pass `None` to
+    /// apply all filters.
+    /// # Example
+    /// ```
+    /// use oci_spec::distribution::ReferrersFilter;
+    /// use oci_spec::image::ImageIndex;
+    ///
+    /// let index: ImageIndex = serde_json::from_str(r#"{
+    ///     "schemaVersion": 2,
+    ///     "manifests": [
+    ///         {"mediaType": "application/vnd.oci.image.manifest.v1+json", "size": 1,
+    ///          "digest": "sha256:a", "artifactType": "application/vnd.example.sbom"},
+    ///         {"mediaType": "application/vnd.oci.image.manifest.v1+json", "size": 1,
+    ///          "digest": "sha256:b", "artifactType": "application/vnd.example.signature"}
+    ///     ]
+    /// }"#).unwrap();
+    ///
+    /// let filter = ReferrersFilter::artifact_type("application/vnd.example.sbom");
+    /// let filtered = serde_json::to_value(filter.apply(&index, None)).unwrap();
+    /// assert_eq!(filtered["manifests"].as_array().unwrap().len(), 1);
+    /// ```
+    pub fn apply(&self, index: &ImageIndex, filters_applied: Option<&str>) -> ImageIndex {
+        let applied = filters_applied
+            .map(parse_filters_applied)
+            .unwrap_or_default();
+        let remaining = ReferrersFilter {
+            artifact_type: self
+                .artifact_type
+                .clone()
+                .filter(|_| !applied.contains(&FILTER_ARTIFACT_TYPE)),
+            annotations: if applied.contains(&FILTER_ANNOTATIONS) {
+                HashMap::new()
+            } else {
+                self.annotations.clone()
+            },
+        };
+        let mut filtered = index.clone();
+        filtered
+            .manifests
+            .retain(|descriptor| remaining.matches(descriptor));
+        filtered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_applied() {
+        // arrange
+        let filter = ReferrersFilter::artifact_type("application/vnd.example").annotation("a", "b");
+
+        // act
+        let header = filter.filters_applied();
+
+        // assert
+        assert_eq!(header.as_deref(), Some("artifactType,annotations"));
+        assert_eq!(ReferrersFilter::default().filters_applied(), None);
+        assert_eq!(
+            parse_filters_applied(" artifactType ,"),
+            vec!["artifactType"]
+        );
+        assert!(parse_filters_applied("").is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn apply_filter() {
+        use crate::image::MediaType;
+
+        // arrange
+        let descriptor = |digest: &str, artifact_type: &str, annotation: &str| {
+            let mut descriptor = Descriptor::new(MediaType::ImageManifest, 1, digest);
+            descriptor.artifact_type = Some(artifact_type.to_owned());
+            descriptor.annotations = Some(HashMap::from([(
+                "org.example.kind".to_owned(),
+                annotation.to_owned(),
+            )]));
+            descriptor
+        };
+        let index = ImageIndex {
+            manifests: vec![
+                descriptor("sha256:a", "application/vnd.sbom", "full"),
+                descriptor("sha256:b", "application/vnd.sbom", "partial"),
+                descriptor("sha256:c", "application/vnd.sig", "full"),
+            ],
+            ..Default::default()
+        };
+        let filter = ReferrersFilter::artifact_type("application/vnd.sbom")
+            .annotation("org.example.kind", "full");
+
+        // act
+        let filtered = filter.apply(&index, None);
+        let registry_filtered = filter.apply(&index, Some("artifactType"));
+
+        // assert
+        let digests = |index: &ImageIndex| -> Vec<String> {
+            index.manifests.iter().map(|d| d.digest.clone()).collect()
+        };
+        assert_eq!(digests(&filtered), vec!["sha256:a"]);
+        assert_eq!(digests(&registry_filtered), vec!["sha256:a", "sha256:c"]);
+        assert_eq!(
+            digests(&ReferrersFilter::default().apply(&index, None)).len(),
+            3
+        );
+    }
+}
@@ -1,33 +1,134 @@
 //! Repository types of the distribution spec.
 
-use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::{validate_repository, ValidationError};
+use crate::{error::Result, from_reader, to_writer};
 
 make_pub!(
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get = "pub")
+        getset(get = "pub", get_mut = "pub", set = "pub")
     )]
     /// RepositoryList returns a catalog of repositories maintained on the registry.
     struct RepositoryList {
-        /// The items of the RepositoryList.
+        #[serde(deserialize_with = "deserialize_repositories")]
+        #[cfg_attr(feature = "schemars", schemars(with = "Option<Vec<String>>"))]
+        /// The items of the RepositoryList. A `null` list is deserialized as
+        /// an empty list.
         repositories: Vec<String>,
     }
 );
 
+impl RepositoryList {
+    /// Attempts to load a repository list from a stream, e.g. the body of a
+    /// `/v2/_catalog` response.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the repository list cannot be deserialized.
+    /// # Example
+    /// ```
+    /// use oci_spec::distribution::RepositoryList;
+    ///
+    /// let body = r#"{"repositories": ["library/alpine", "library/busybox"]}"#;
+    /// let list = RepositoryList::from_reader(body.as_bytes()).unwrap();
+    /// assert!(list.validate().is_ok());
+    /// ```
+    pub fn from_reader<R: Read>(reader: R) -> Result<RepositoryList> {
+        from_reader(reader)
+    }
+
+    /// Attempts to write a repository list to a stream as JSON.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the repository list cannot be serialized.
+    pub fn to_writer<W: Write>(&self, writer: &mut W) -> Result<()> {
+        to_writer(&self, writer, false)
+    }
+
+    /// Attempts to write a repository list to a stream as pretty printed
+    /// JSON.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the repository list cannot be serialized.
+    pub fn to_writer_pretty<W: Write>(&self, writer: &mut W) -> Result<()> {
+        to_writer(&self, writer, true)
+    }
+
+    /// Validates all repository names of the list, see
+    /// [validate_repository].
+    /// # Errors
+    /// This function will return the [ValidationError] of the first invalid
+    /// repository name.
+    pub fn validate(&self) -> std::result::Result<(), ValidationError> {
+        self.repositories
+            .iter()
+            .try_for_each(|name| validate_repository(name))
+    }
+}
+
+fn deserialize_repositories<'de, D>(deserializer: D) -> std::result::Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<Vec<String>>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 #[cfg(test)]
-#[cfg(feature = "builder")]
 mod tests {
     use super::*;
     use crate::error::Result;
 
     #[test]
+    fn repository_list_reader_writer() -> Result<()> {
+        // arrange
+        let body = r#"{"repositories":["library/alpine","library/busybox"]}"#;
+
+        // act
+        let list = RepositoryList::from_reader(body.as_bytes())?;
+        let mut written = Vec::new();
+        list.to_writer(&mut written)?;
+
+        // assert
+        assert_eq!(list.repositories, vec!["library/alpine", "library/busybox"]);
+        assert_eq!(String::from_utf8(written).unwrap(), body);
+        Ok(())
+    }
+
+    #[test]
+    fn repository_list_validate() -> Result<()> {
+        // arrange
+        let body = r#"{"repositories": null}"#;
+
+        // act
+        let mut list = RepositoryList::from_reader(body.as_bytes())?;
+
+        // assert
+        assert!(list.repositories.is_empty());
+        assert!(list.validate().is_ok());
+        list.repositories.push("Library/alpine".to_string());
+        assert!(list.validate().is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "builder")]
     fn repository_list_success() -> Result<()> {
         let list = RepositoryListBuilder::default()
             .repositories(vec![])
@@ -37,6 +138,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "builder")]
     fn repository_list_failure() {
         assert!(RepositoryListBuilder::default().build().is_err());
     }
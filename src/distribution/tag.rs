@@ -1,36 +1,147 @@
 //! Tag types of the distribution spec.
 
-use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::{validate_repository, validate_tag, ValidationError};
+use crate::{error::Result, from_reader, to_writer};
 
 make_pub!(
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get = "pub")
+        getset(get = "pub", get_mut = "pub", set = "pub")
     )]
     /// A list of tags for a given repository.
     struct TagList {
         /// The namespace of the repository.
         name: String,
 
-        /// Each tags on the repository.
+        #[serde(deserialize_with = "deserialize_tags")]
+        #[cfg_attr(feature = "schemars", schemars(with = "Option<Vec<String>>"))]
+        /// Each tags on the repository. Registries return `null` for a
+        /// repository without tags, which is deserialized as an empty list.
         tags: Vec<String>,
     }
 );
 
+impl TagList {
+    /// Attempts to load a tag list from a stream, e.g. the body of a
+    /// `/v2/<name>/tags/list` response.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the tag list cannot be deserialized.
+    /// # Example
+    /// ```
+    /// use oci_spec::distribution::TagList;
+    ///
+    /// let body = r#"{"name": "library/alpine", "tags": ["3.19", "latest"]}"#;
+    /// let list = TagList::from_reader(body.as_bytes()).unwrap();
+    /// assert!(list.validate().is_ok());
+    /// ```
+    pub fn from_reader<R: Read>(reader: R) -> Result<TagList> {
+        from_reader(reader)
+    }
+
+    /// Attempts to write a tag list to a stream as JSON.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the tag list cannot be serialized.
+    pub fn to_writer<W: Write>(&self, writer: &mut W) -> Result<()> {
+        to_writer(&self, writer, false)
+    }
+
+    /// Attempts to write a tag list to a stream as pretty printed JSON.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the tag list cannot be serialized.
+    pub fn to_writer_pretty<W: Write>(&self, writer: &mut W) -> Result<()> {
+        to_writer(&self, writer, true)
+    }
+
+    /// Validates the repository name and all tags of the list, see
+    /// [validate_repository] and [validate_tag].
+    /// # Errors
+    /// This function will return the [ValidationError] of the repository name
+    /// or the first invalid tag.
+    pub fn validate(&self) -> std::result::Result<(), ValidationError> {
+        validate_repository(&self.name)?;
+        self.tags.iter().try_for_each(|tag| validate_tag(tag))
+    }
+}
+
+fn deserialize_tags<'de, D>(deserializer: D) -> std::result::Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<Vec<String>>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 #[cfg(test)]
-#[cfg(feature = "builder")]
 mod tests {
     use super::*;
     use crate::error::Result;
 
     #[test]
+    fn tag_list_reader_writer() -> Result<()> {
+        // arrange
+        let body = r#"{"name":"library/alpine","tags":["3.19","latest"]}"#;
+
+        // act
+        let list = TagList::from_reader(body.as_bytes())?;
+        let mut written = Vec::new();
+        list.to_writer(&mut written)?;
+
+        // assert
+        assert_eq!(list.name, "library/alpine");
+        assert_eq!(list.tags, vec!["3.19", "latest"]);
+        assert_eq!(String::from_utf8(written).unwrap(), body);
+        Ok(())
+    }
+
+    #[test]
+    fn tag_list_null_tags() -> Result<()> {
+        // arrange
+        let body = r#"{"name": "library/alpine", "tags": null}"#;
+
+        // act
+        let list = TagList::from_reader(body.as_bytes())?;
+
+        // assert
+        assert!(list.tags.is_empty());
+        assert!(TagList::from_reader(r#"{"name": "a"}"#.as_bytes()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn tag_list_validate() -> Result<()> {
+        // arrange
+        let body = r#"{"name": "library/alpine", "tags": ["latest", ".hidden"]}"#;
+
+        // act
+        let list = TagList::from_reader(body.as_bytes())?;
+
+        // assert
+        assert_eq!(list.validate(), Err(ValidationError::InvalidTagStart('.')));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "builder")]
     fn tag_list_success() -> Result<()> {
         let list = TagListBuilder::default()
             .name("name")
@@ -42,6 +153,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "builder")]
     fn tag_list_failure() {
         assert!(TagListBuilder::default().build().is_err());
     }
@@ -0,0 +1,197 @@
+//! Token authentication types as used by the authorization servers of
+//! registries.
+
+use std::{
+    io::{Read, Write},
+    time::Duration,
+};
+
+#[cfg(feature = "timestamps")]
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "timestamps")]
+use crate::error::oci_error;
+use crate::{error::Result, from_reader, to_writer};
+
+/// The lifetime of a token in seconds if the token response does not
+/// specify it.
+pub const DEFAULT_TOKEN_LIFETIME: u64 = 60;
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// TokenResponse is returned by the token endpoint of an authorization
+    /// server, which a registry refers to in the `WWW-Authenticate` header
+    /// of an unauthorized response.
+    struct TokenResponse {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// An opaque bearer token to present in the `Authorization` header of
+        /// requests to the registry.
+        token: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The same token as `token`, provided for compatibility with OAuth 2.0.
+        access_token: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The number of seconds the token remains valid after it was issued.
+        /// Defaults to [DEFAULT_TOKEN_LIFETIME].
+        expires_in: Option<u64>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The time the token was issued at as RFC 3339 timestamp.
+        issued_at: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// A token to obtain new tokens for the same subject with different
+        /// scopes, which is only returned if it was requested.
+        refresh_token: Option<String>,
+    }
+);
+
+impl TokenResponse {
+    /// Attempts to load a token response from a stream, e.g. the body of a
+    /// response of the token endpoint.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the token response cannot be deserialized.
+    /// # Example
+    /// ```
+    /// use oci_spec::distribution::TokenResponse;
+    ///
+    /// let body = r#"{"token": "abc", "expires_in": 300}"#;
+    /// let response = TokenResponse::from_reader(body.as_bytes()).unwrap();
+    /// assert_eq!(response.bearer_token(), Some("abc"));
+    /// assert_eq!(response.lifetime().as_secs(), 300);
+    /// ```
+    pub fn from_reader<R: Read>(reader: R) -> Result<TokenResponse> {
+        from_reader(reader)
+    }
+
+    /// Attempts to write a token response to a stream as JSON.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the token response cannot be serialized.
+    pub fn to_writer<W: Write>(&self, writer: &mut W) -> Result<()> {
+        to_writer(&self, writer, false)
+    }
+
+    /// Returns the token to present to the registry, which is `token` or, if
+    /// it is missing, `access_token`.
+    pub fn bearer_token(&self) -> Option<&str> {
+        self.token.as_deref().or(self.access_token.as_deref())
+    }
+
+    /// Returns the duration the token remains valid after it was issued.
+    pub fn lifetime(&self) -> Duration {
+        Duration::from_secs(self.expires_in.unwrap_or(DEFAULT_TOKEN_LIFETIME))
+    }
+}
+
+#[cfg(feature = "timestamps")]
+impl TokenResponse {
+    /// Parses the `issued_at` property as an RFC 3339 timestamp.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the timestamp is not a valid RFC 3339 date-time.
+    pub fn issued_at_parsed(&self) -> Result<Option<DateTime<Utc>>> {
+        self.issued_at
+            .as_deref()
+            .map(|timestamp| {
+                DateTime::parse_from_rfc3339(timestamp)
+                    .map(|t| t.with_timezone(&Utc))
+                    .map_err(|e| oci_error(format!("invalid timestamp {}: {}", timestamp, e)))
+            })
+            .transpose()
+    }
+
+    /// Returns the time the token expires at. As `issued_at` is optional,
+    /// `received_at`, the time the response was received, is used if it is
+    /// missing.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if `issued_at` is not a valid RFC 3339 date-time or the expiry is out
+    /// of range.
+    pub fn expires_at(&self, received_at: DateTime<Utc>) -> Result<DateTime<Utc>> {
+        let issued_at = self.issued_at_parsed()?.unwrap_or(received_at);
+        chrono::Duration::from_std(self.lifetime())
+            .ok()
+            .and_then(|lifetime| issued_at.checked_add_signed(lifetime))
+            .ok_or_else(|| oci_error("token expiry is out of range"))
+    }
+
+    /// Returns true if the token has expired at `now`, see
+    /// [TokenResponse::expires_at].
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the expiry cannot be determined.
+    pub fn is_expired(&self, received_at: DateTime<Utc>, now: DateTime<Utc>) -> Result<bool> {
+        Ok(self.expires_at(received_at)? <= now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_response() -> Result<()> {
+        // arrange
+        let body = r#"{"access_token":"abc","refresh_token":"def"}"#;
+
+        // act
+        let response = TokenResponse::from_reader(body.as_bytes())?;
+
+        // assert
+        assert_eq!(response.bearer_token(), Some("abc"));
+        assert_eq!(response.refresh_token.as_deref(), Some("def"));
+        assert_eq!(response.lifetime(), Duration::from_secs(60));
+        let mut written = Vec::new();
+        response.to_writer(&mut written)?;
+        assert_eq!(written, body.as_bytes());
+        assert_eq!(TokenResponse::default().bearer_token(), None);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "timestamps")]
+    fn token_expiry() -> Result<()> {
+        // arrange
+        let received_at = DateTime::parse_from_rfc3339("2024-01-01T00:10:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut response: TokenResponse = serde_json::from_str(
+            r#"{"token":"abc","expires_in":300,"issued_at":"2024-01-01T00:00:00Z"}"#,
+        )?;
+
+        // act
+        let expires_at = response.expires_at(received_at)?;
+
+        // assert
+        assert_eq!(expires_at.to_rfc3339(), "2024-01-01T00:05:00+00:00");
+        assert!(response.is_expired(received_at, received_at)?);
+        response.issued_at = None;
+        assert!(!response.is_expired(received_at, received_at)?);
+        response.issued_at = Some("yesterday".to_string());
+        assert!(response.expires_at(received_at).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+//! State of chunked blob uploads as defined by the distribution spec.
+
+use super::validate_digest;
+use crate::error::{oci_error, Result};
+
+/// The header of the response starting an upload, which specifies the
+/// minimum length of all chunks but the last one.
+pub const HEADER_CHUNK_MIN_LENGTH: &str = "OCI-Chunk-Min-Length";
+
+/// UploadSession tracks a chunked blob upload, which is started with a
+/// `POST` to `/v2/<name>/blobs/uploads/`. Every response of the registry
+/// contains the `Location` to send the next request to and the `Range`
+/// received so far, which have to be passed to [UploadSession::update].
+/// # Example
+/// ```
+/// use oci_spec::distribution::UploadSession;
+///
+/// let mut session =
+///     UploadSession::new("/v2/alpine/blobs/uploads/1", None, Some("1024")).unwrap();
+/// assert_eq!(session.chunk_length(512), 1024);
+/// assert_eq!(session.next_content_range(1024).unwrap(), "0-1023");
+///
+/// session.update("/v2/alpine/blobs/uploads/1?state=x", Some("0-1023")).unwrap();
+/// assert_eq!(session.offset(), 1024);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UploadSession {
+    location: String,
+    offset: u64,
+    min_chunk_length: Option<u64>,
+}
+
+impl UploadSession {
+    /// Creates the session from the `Location`, `Range` and
+    /// [HEADER_CHUNK_MIN_LENGTH] headers of the response which started the
+    /// upload.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the location is empty or a header value is invalid.
+    pub fn new(
+        location: &str,
+        range: Option<&str>,
+        min_chunk_length: Option<&str>,
+    ) -> Result<Self> {
+        let min_chunk_length = min_chunk_length
+            .map(|length| {
+                length.trim().parse().map_err(|_| {
+                    oci_error(format!(
+                        "invalid {} header {:?}",
+                        HEADER_CHUNK_MIN_LENGTH, length
+                    ))
+                })
+            })
+            .transpose()?;
+        let mut session = UploadSession {
+            location: String::new(),
+            offset: 0,
+            min_chunk_length,
+        };
+        session.update(location, range)?;
+        Ok(session)
+    }
+
+    /// Updates the session from the `Location` and `Range` headers of the
+    /// response to a chunk or status request.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the location is empty or the range is invalid.
+    pub fn update(&mut self, location: &str, range: Option<&str>) -> Result<()> {
+        if location.is_empty() {
+            return Err(oci_error("upload location must not be empty"));
+        }
+        self.offset = range.map(parse_range).transpose()?.unwrap_or_default();
+        self.location = location.to_owned();
+        Ok(())
+    }
+
+    /// Returns the location the next request of the upload is sent to, which
+    /// may be relative to the registry, see [UploadSession::url].
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// Returns the number of bytes received by the registry, which is the
+    /// offset of the next chunk.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the minimum length of all chunks but the last one, if the
+    /// registry requires one.
+    pub fn min_chunk_length(&self) -> Option<u64> {
+        self.min_chunk_length
+    }
+
+    /// Returns the length of the chunks to upload, which is `preferred`
+    /// raised to the minimum chunk length of the registry.
+    pub fn chunk_length(&self, preferred: u64) -> u64 {
+        preferred.max(self.min_chunk_length.unwrap_or_default())
+    }
+
+    /// Returns the value of the `Content-Range` header of the next chunk
+    /// with `length` bytes, e.g. `0-1023` for the first chunk of 1024 bytes.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the chunk is empty or the range overflows.
+    pub fn next_content_range(&self, length: u64) -> Result<String> {
+        let end = length
+            .checked_sub(1)
+            .and_then(|last| self.offset.checked_add(last))
+            .ok_or_else(|| oci_error(format!("invalid chunk length {}", length)))?;
+        Ok(format!("{}-{}", self.offset, end))
+    }
+
+    /// Returns the absolute URL of the location. Relative locations, which
+    /// registries may return, are resolved against `base_url`, e.g.
+    /// `https://registry.example.com`.
+    pub fn url(&self, base_url: &str) -> String {
+        if self.location.starts_with("http://") || self.location.starts_with("https://") {
+            self.location.clone()
+        } else {
+            format!(
+                "{}/{}",
+                base_url.trim_end_matches('/'),
+                self.location.trim_start_matches('/')
+            )
+        }
+    }
+
+    /// Returns the URL of the final `PUT` request which completes the upload
+    /// of the blob with the given digest, see [UploadSession::url].
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the digest is invalid.
+    pub fn commit_url(&self, base_url: &str, digest: &str) -> Result<String> {
+        validate_digest(digest).map_err(|e| oci_error(e.to_string()))?;
+        let url = self.url(base_url);
+        let separator = if url.contains('?') { '&' } else { '?' };
+        Ok(format!("{}{}digest={}", url, separator, digest))
+    }
+}
+
+/// Parses the value of a `Range` header of an upload response, which is the
+/// inclusive range `0-<end>` of the received bytes, optionally prefixed with
+/// `bytes=`, and returns the number of received bytes.
+fn parse_range(range: &str) -> Result<u64> {
+    let invalid = || oci_error(format!("invalid upload range {:?}", range));
+    let value = range.trim();
+    let value = value.strip_prefix("bytes=").unwrap_or(value);
+    let (start, end) = value.split_once('-').ok_or_else(invalid)?;
+    let start: u64 = start.parse().map_err(|_| invalid())?;
+    let end: u64 = end.parse().map_err(|_| invalid())?;
+    if start != 0 {
+        return Err(invalid());
+    }
+    end.checked_add(1).ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGEST: &str = "sha256:6c3c624b58dbbcd3c0dd82b4c53f04194d1247c6eebdaab7c610cf7d66709b3b";
+
+    #[test]
+    fn upload_session() -> Result<()> {
+        // arrange
+        let mut session = UploadSession::new("/v2/a/blobs/uploads/1", None, None)?;
+
+        // act
+        let first = session.next_content_range(10)?;
+        session.update("/v2/a/blobs/uploads/1?_state=x", Some("bytes=0-9"))?;
+        let second = session.next_content_range(5)?;
+
+        // assert
+        assert_eq!(first, "0-9");
+        assert_eq!(second, "10-14");
+        assert_eq!(session.chunk_length(8), 8);
+        assert!(session.next_content_range(0).is_err());
+        assert_eq!(
+            session.url("https://registry.example.com/"),
+            "https://registry.example.com/v2/a/blobs/uploads/1?_state=x"
+        );
+        assert_eq!(
+            session.commit_url("https://registry.example.com", DIGEST)?,
+            format!(
+                "https://registry.example.com/v2/a/blobs/uploads/1?_state=x&digest={}",
+                DIGEST
+            )
+        );
+        assert!(session.commit_url("", "abc").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn upload_session_headers() -> Result<()> {
+        // arrange
+        let location = "https://blobs.example.com/upload/1";
+
+        // act
+        let session = UploadSession::new(location, Some("0-99"), Some(" 4096 "))?;
+
+        // assert
+        assert_eq!(session.offset(), 100);
+        assert_eq!(session.min_chunk_length(), Some(4096));
+        assert_eq!(session.url("https://registry.example.com"), location);
+        assert_eq!(
+            session.commit_url("", DIGEST)?,
+            format!("https://blobs.example.com/upload/1?digest={}", DIGEST)
+        );
+        assert!(UploadSession::new("", None, None).is_err());
+        assert!(UploadSession::new(location, Some("1-99"), None).is_err());
+        assert!(UploadSession::new(location, Some("0-x"), None).is_err());
+        assert!(UploadSession::new(location, None, Some("-1")).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,320 @@
+//! Validation of repository names, tags and digests as defined by the
+//! distribution spec.
+
+use thiserror::Error;
+
+/// The maximum length of a tag.
+pub const TAG_MAX_LENGTH: usize = 128;
+
+/// A violation of the rules for repository names or tags of the distribution
+/// spec.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ValidationError {
+    /// The repository name is empty.
+    #[error("repository name must not be empty")]
+    EmptyRepository,
+    /// The repository name contains an empty path component, e.g. because
+    /// of a leading, trailing or double slash.
+    #[error("repository name must not contain empty path components")]
+    EmptyPathComponent,
+    /// The repository name contains a character which is not a lowercase
+    /// letter, digit, separator or slash.
+    #[error("invalid character {character:?} at position {position} of repository name, allowed are [a-z0-9._/-]")]
+    InvalidRepositoryCharacter {
+        /// The invalid character.
+        character: char,
+        /// The byte position of the character in the name.
+        position: usize,
+    },
+    /// A path component of the repository name does not start and end with
+    /// an alphanumeric character or contains an invalid sequence of
+    /// separators. Allowed separators are `.`, `_`, `__` and one or more `-`.
+    #[error("invalid separators in path component {0:?} of repository name, components must match [a-z0-9]+((\\.|_|__|-+)[a-z0-9]+)*")]
+    InvalidSeparator(String),
+    /// The tag is empty.
+    #[error("tag must not be empty")]
+    EmptyTag,
+    /// The tag is longer than [TAG_MAX_LENGTH] characters.
+    #[error("tag must not be longer than 128 characters, got {0}")]
+    TagTooLong(usize),
+    /// The tag starts with a period or dash.
+    #[error("tag must start with [a-zA-Z0-9_], got {0:?}")]
+    InvalidTagStart(char),
+    /// The tag contains a character which is not allowed.
+    #[error(
+        "invalid character {character:?} at position {position} of tag, allowed are [a-zA-Z0-9._-]"
+    )]
+    InvalidTagCharacter {
+        /// The invalid character.
+        character: char,
+        /// The byte position of the character in the tag.
+        position: usize,
+    },
+    /// The digest does not match `algorithm:encoded`, or the encoded part of a
+    /// registered algorithm is not a hash of the right length, see
+    /// [validate_digest].
+    #[error("invalid digest {0:?}, must match [a-z0-9]+([+._-][a-z0-9]+)*:[a-zA-Z0-9=_-]+ with a lowercase hex hash for sha256 and sha512")]
+    InvalidDigest(String),
+}
+
+/// Validates a repository name against the regular expression of the
+/// distribution spec:
+/// `[a-z0-9]+((\.|_|__|-+)[a-z0-9]+)*(\/[a-z0-9]+((\.|_|__|-+)[a-z0-9]+)*)*`
+/// # Example
+/// ```
+/// use oci_spec::distribution::{validate_repository, ValidationError};
+///
+/// assert!(validate_repository("library/alpine").is_ok());
+/// assert_eq!(
+///     validate_repository("library//alpine"),
+///     Err(ValidationError::EmptyPathComponent)
+/// );
+/// ```
+pub fn validate_repository(name: &str) -> Result<(), ValidationError> {
+    if name.is_empty() {
+        return Err(ValidationError::EmptyRepository);
+    }
+
+    if let Some((position, character)) = name.char_indices().find(|(_, c)| {
+        !(c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '_' | '-' | '/'))
+    }) {
+        return Err(ValidationError::InvalidRepositoryCharacter {
+            character,
+            position,
+        });
+    }
+
+    for component in name.split('/') {
+        if component.is_empty() {
+            return Err(ValidationError::EmptyPathComponent);
+        }
+        if !valid_separators(component) {
+            return Err(ValidationError::InvalidSeparator(component.to_owned()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates a tag against the regular expression of the distribution spec:
+/// `[a-zA-Z0-9_][a-zA-Z0-9._-]{0,127}`
+/// # Example
+/// ```
+/// use oci_spec::distribution::{validate_tag, ValidationError};
+///
+/// assert!(validate_tag("v1.0.0").is_ok());
+/// assert_eq!(validate_tag(".hidden"), Err(ValidationError::InvalidTagStart('.')));
+/// ```
+pub fn validate_tag(tag: &str) -> Result<(), ValidationError> {
+    let first = tag.chars().next().ok_or(ValidationError::EmptyTag)?;
+    if !(first.is_ascii_alphanumeric() || first == '_') {
+        return Err(ValidationError::InvalidTagStart(first));
+    }
+
+    if let Some((position, character)) = tag
+        .char_indices()
+        .find(|(_, c)| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')))
+    {
+        return Err(ValidationError::InvalidTagCharacter {
+            character,
+            position,
+        });
+    }
+
+    if tag.len() > TAG_MAX_LENGTH {
+        return Err(ValidationError::TagTooLong(tag.len()));
+    }
+
+    Ok(())
+}
+
+/// Validates a digest against the grammar of the image spec:
+/// `[a-z0-9]+([+._-][a-z0-9]+)*:[a-zA-Z0-9=_-]+`. The encoded part of the
+/// registered algorithms `sha256` and `sha512` must be a lowercase hex hash
+/// of 64 or 128 characters, like the image `Digest` type requires.
+/// # Example
+/// ```
+/// use oci_spec::distribution::validate_digest;
+///
+/// assert!(validate_digest("sha256:6c3c624b58dbbcd3c0dd82b4c53f04194d1247c6eebdaab7c610cf7d66709b3b").is_ok());
+/// assert!(validate_digest("sha256:6c3c624b").is_err());
+/// assert!(validate_digest("latest").is_err());
+/// ```
+pub fn validate_digest(digest: &str) -> Result<(), ValidationError> {
+    let invalid = || ValidationError::InvalidDigest(digest.to_owned());
+    let (algorithm, encoded) = digest.split_once(':').ok_or_else(invalid)?;
+    let valid_algorithm = algorithm.split(['+', '.', '_', '-']).all(|c| {
+        !c.is_empty()
+            && c.bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+    });
+    let valid_encoded = !encoded.is_empty()
+        && encoded
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'=' | b'_' | b'-'));
+    if !valid_algorithm || !valid_encoded {
+        return Err(invalid());
+    }
+
+    let hex_len = match algorithm {
+        "sha256" => Some(64),
+        "sha512" => Some(128),
+        _ => None,
+    };
+    if let Some(len) = hex_len {
+        let lower_hex = encoded
+            .bytes()
+            .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b));
+        if encoded.len() != len || !lower_hex {
+            return Err(invalid());
+        }
+    }
+    Ok(())
+}
+
+/// Validates a manifest reference, which is either a digest if it contains a
+/// colon, see [validate_digest], or a tag, see [validate_tag].
+pub fn validate_reference(reference: &str) -> Result<(), ValidationError> {
+    if reference.contains(':') {
+        validate_digest(reference)
+    } else {
+        validate_tag(reference)
+    }
+}
+
+/// Checks that the component matches `[a-z0-9]+((\.|_|__|-+)[a-z0-9]+)*`,
+/// assuming it only contains valid characters.
+fn valid_separators(component: &str) -> bool {
+    let is_separator = |c: char| matches!(c, '.' | '_' | '-');
+    if component.starts_with(is_separator) || component.ends_with(is_separator) {
+        return false;
+    }
+
+    component
+        .split(|c: char| c.is_ascii_alphanumeric())
+        .filter(|separator| !separator.is_empty())
+        .all(|separator| {
+            matches!(separator, "." | "_" | "__") || separator.chars().all(|c| c == '-')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_repositories() {
+        for name in &[
+            "alpine",
+            "library/alpine",
+            "a/b/c/d",
+            "my.registry_name__x--y---z/0image",
+            "a-b.c_d",
+        ] {
+            assert_eq!(validate_repository(name), Ok(()), "{}", name);
+        }
+    }
+
+    #[test]
+    fn invalid_repositories() {
+        let cases = [
+            ("", ValidationError::EmptyRepository),
+            ("/alpine", ValidationError::EmptyPathComponent),
+            ("alpine/", ValidationError::EmptyPathComponent),
+            (
+                "Alpine",
+                ValidationError::InvalidRepositoryCharacter {
+                    character: 'A',
+                    position: 0,
+                },
+            ),
+            (
+                "library/alp:ine",
+                ValidationError::InvalidRepositoryCharacter {
+                    character: ':',
+                    position: 11,
+                },
+            ),
+            (
+                "-alpine",
+                ValidationError::InvalidSeparator("-alpine".into()),
+            ),
+            (
+                "alpine.",
+                ValidationError::InvalidSeparator("alpine.".into()),
+            ),
+            ("a..b", ValidationError::InvalidSeparator("a..b".into())),
+            ("a___b", ValidationError::InvalidSeparator("a___b".into())),
+            ("x/a._b", ValidationError::InvalidSeparator("a._b".into())),
+        ];
+        for (name, expected) in cases.iter() {
+            assert_eq!(
+                validate_repository(name).as_ref(),
+                Err(expected),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn validate_tags() {
+        assert_eq!(validate_tag("latest"), Ok(()));
+        assert_eq!(validate_tag("_v1.2-rc.1"), Ok(()));
+        assert_eq!(validate_tag(&"a".repeat(128)), Ok(()));
+
+        assert_eq!(validate_tag(""), Err(ValidationError::EmptyTag));
+        assert_eq!(
+            validate_tag("-x"),
+            Err(ValidationError::InvalidTagStart('-'))
+        );
+        assert_eq!(
+            validate_tag("v1+build"),
+            Err(ValidationError::InvalidTagCharacter {
+                character: '+',
+                position: 2
+            })
+        );
+        assert_eq!(
+            validate_tag(&"a".repeat(129)),
+            Err(ValidationError::TagTooLong(129))
+        );
+        assert_eq!(
+            validate_tag("").unwrap_err().to_string(),
+            "tag must not be empty"
+        );
+    }
+
+    #[test]
+    fn validate_digests() {
+        let sha256 = format!("sha256:{}", "a".repeat(64));
+        let sha512 = format!("sha512:{}", "0".repeat(128));
+        assert_eq!(validate_digest(&sha256), Ok(()));
+        assert_eq!(validate_digest("multihash+base58:QmRZxt2b1F"), Ok(()));
+        assert_eq!(validate_reference(&sha512), Ok(()));
+        assert_eq!(validate_reference("latest"), Ok(()));
+
+        let uppercase = format!("sha256:{}", "A".repeat(64));
+        let long = format!("{}0", sha256);
+        for digest in [
+            "sha256:abc",
+            "sha512:abc",
+            uppercase.as_str(),
+            long.as_str(),
+            "sha256",
+            "sha256:",
+            ":abc",
+            "SHA256:abc",
+            "sha256:a/b",
+            "a..b:c",
+        ] {
+            assert_eq!(
+                validate_digest(digest),
+                Err(ValidationError::InvalidDigest(digest.to_owned())),
+                "{}",
+                digest
+            );
+        }
+        assert!(validate_reference("sha256:").is_err());
+    }
+}
@@ -0,0 +1,240 @@
+//! Warning headers as returned by registries.
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::error::{oci_error, OciSpecError, Result};
+
+/// The warning code registries use for deprecation notices and other
+/// messages which should be shown to users.
+pub const WARNING_CODE_MISCELLANEOUS: u16 = 299;
+
+/// The warning agent registries use, as the warning is not attributed to a
+/// particular host.
+pub const WARNING_AGENT_UNKNOWN: &str = "-";
+
+/// Warning is a single value of a `Warning` header (RFC 7234), in the form
+/// `<code> <agent> "<text>" ["<date>"]`.
+/// # Example
+/// ```
+/// use oci_spec::distribution::Warning;
+///
+/// let warning: Warning = r#"299 - "this repository is deprecated""#.parse().unwrap();
+/// assert!(warning.is_registry_warning());
+/// assert_eq!(warning.text, "this repository is deprecated");
+/// assert_eq!(warning.to_string(), r#"299 - "this repository is deprecated""#);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Warning {
+    /// The three digit warning code.
+    pub code: u16,
+    /// The host or pseudonym of the server adding the warning, or `-`.
+    pub agent: String,
+    /// The warning message.
+    pub text: String,
+    /// The optional date of the warning as HTTP date.
+    pub date: Option<String>,
+}
+
+impl Warning {
+    /// Creates a warning with the code and agent used by registries.
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Warning {
+            code: WARNING_CODE_MISCELLANEOUS,
+            agent: WARNING_AGENT_UNKNOWN.to_owned(),
+            text: text.into(),
+            date: None,
+        }
+    }
+
+    /// Returns true if the warning uses the code and agent of the
+    /// distribution spec, so it should be shown to users.
+    pub fn is_registry_warning(&self) -> bool {
+        self.code == WARNING_CODE_MISCELLANEOUS && self.agent == WARNING_AGENT_UNKNOWN
+    }
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:03} {} {}", self.code, self.agent, quote(&self.text))?;
+        if let Some(date) = &self.date {
+            write!(f, " {}", quote(date))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Warning {
+    type Err = OciSpecError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (warning, rest) = parse_warning(s)?;
+        if !rest.trim().is_empty() {
+            return Err(oci_error(format!("invalid warning {:?}", s)));
+        }
+        Ok(warning)
+    }
+}
+
+/// Parses the values of all `Warning` headers of a response, each of which
+/// may contain several comma separated warnings.
+/// # Errors
+/// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+/// if a header value is malformed.
+pub fn parse_warnings<'a, I>(values: I) -> Result<Vec<Warning>>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut warnings = Vec::new();
+    for value in values {
+        let mut rest = value;
+        loop {
+            rest = rest.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+            if rest.is_empty() {
+                break;
+            }
+            let (warning, remainder) = parse_warning(rest)?;
+            warnings.push(warning);
+            rest = remainder;
+        }
+    }
+    Ok(warnings)
+}
+
+/// Returns the texts of the registry warnings of a response, see
+/// [Warning::is_registry_warning], without duplicates and in the order they
+/// were received. Malformed header values are skipped, as warnings are
+/// informational only.
+/// # Example
+/// ```
+/// use oci_spec::distribution::registry_warnings;
+///
+/// let headers = [r#"299 - "deprecated", 299 - "deprecated""#, "garbage"];
+/// assert_eq!(registry_warnings(headers), vec!["deprecated"]);
+/// ```
+pub fn registry_warnings<'a, I>(values: I) -> Vec<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut texts: Vec<String> = Vec::new();
+    for value in values {
+        let warnings = parse_warnings(std::iter::once(value)).unwrap_or_default();
+        for warning in warnings {
+            if warning.is_registry_warning() && !texts.contains(&warning.text) {
+                texts.push(warning.text);
+            }
+        }
+    }
+    texts
+}
+
+/// Parses a single warning from the start of the value and returns it with
+/// the remainder of the value.
+fn parse_warning(value: &str) -> Result<(Warning, &str)> {
+    let invalid = || oci_error(format!("invalid warning {:?}", value));
+    let value = value.trim_start();
+    let (code, rest) = value.split_once(' ').ok_or_else(invalid)?;
+    if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let code = code.parse().map_err(|_| invalid())?;
+    let (agent, rest) = rest.trim_start().split_once(' ').ok_or_else(invalid)?;
+    let (text, rest) = unquote(rest.trim_start()).ok_or_else(invalid)?;
+
+    let mut date = None;
+    let mut rest = rest.trim_start();
+    if rest.starts_with('"') {
+        let (value, remainder) = unquote(rest).ok_or_else(invalid)?;
+        date = Some(value);
+        rest = remainder.trim_start();
+    }
+    if !(rest.is_empty() || rest.starts_with(',')) {
+        return Err(invalid());
+    }
+
+    Ok((
+        Warning {
+            code,
+            agent: agent.to_owned(),
+            text,
+            date,
+        },
+        rest,
+    ))
+}
+
+/// Parses a quoted string with backslash escapes from the start of the
+/// value and returns its contents with the remainder of the value.
+fn unquote(value: &str) -> Option<(String, &str)> {
+    let mut unquoted = String::new();
+    let mut chars = value.strip_prefix('"')?.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((unquoted, &value[i + 2..])),
+            '\\' => unquoted.push(chars.next()?.1),
+            c => unquoted.push(c),
+        }
+    }
+    None
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warning_round_trip() -> Result<()> {
+        // arrange
+        let mut warning = Warning::new(r#"use "v2" \ instead"#);
+        warning.date = Some("Wed, 21 Oct 2015 07:28:00 GMT".to_owned());
+
+        // act
+        let formatted = warning.to_string();
+
+        // assert
+        assert_eq!(
+            formatted,
+            r#"299 - "use \"v2\" \\ instead" "Wed, 21 Oct 2015 07:28:00 GMT""#
+        );
+        assert_eq!(formatted.parse::<Warning>()?, warning);
+        for invalid in [
+            "",
+            "299",
+            "299 -",
+            "29 - \"a\"",
+            "299 - a",
+            "299 - \"a",
+            "299 - \"a\" b",
+        ] {
+            assert!(invalid.parse::<Warning>().is_err(), "{}", invalid);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_warnings() -> Result<()> {
+        // arrange
+        let values = [
+            r#"299 - "a, b", 199 proxy.example.com:8080 "miscellaneous""#,
+            r#"299 - "a, b""#,
+            r#"299 - "c""#,
+        ];
+
+        // act
+        let warnings = parse_warnings(values.iter().copied())?;
+
+        // assert
+        assert_eq!(warnings.len(), 4);
+        assert_eq!(warnings[1].agent, "proxy.example.com:8080");
+        assert!(!warnings[1].is_registry_warning());
+        assert_eq!(registry_warnings(values.iter().copied()), vec!["a, b", "c"]);
+        assert!(parse_warnings(["299 - \"a\" x"]).is_err());
+        Ok(())
+    }
+}
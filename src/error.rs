@@ -1,5 +1,6 @@
 //! Error types of the crate.
 
+use serde_json::error::Category;
 use std::{borrow::Cow, io};
 use thiserror::Error;
 
@@ -25,15 +26,54 @@ pub enum OciSpecError {
 
     /// Will be returned when an error happens during
     /// serialization or deserialization.
-    #[error("serde failed")]
+    #[error("serde failed: {0}")]
     SerDe(#[from] serde_json::Error),
 
+    /// Will be returned when an error happens during YAML
+    /// serialization or deserialization.
+    #[cfg(feature = "yaml")]
+    #[error("yaml serde failed: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
     /// Builder specific errors.
     #[cfg(feature = "builder")]
     #[error("uninitialized field")]
     Builder(#[from] derive_builder::UninitializedFieldError),
 }
 
+impl OciSpecError {
+    /// Returns the corresponding [io::ErrorKind] of the error, which is used
+    /// when converting it into an [io::Error]. Serialization errors map to
+    /// [io::ErrorKind::InvalidData] or [io::ErrorKind::UnexpectedEof],
+    /// whereas errors which cannot be mapped to a more specialized kind
+    /// result in [io::ErrorKind::Other].
+    pub fn kind(&self) -> io::ErrorKind {
+        match self {
+            OciSpecError::Other(_) => io::ErrorKind::Other,
+            OciSpecError::Io(err) => err.kind(),
+            OciSpecError::SerDe(err) => match err.classify() {
+                Category::Io => io::ErrorKind::Other,
+                Category::Syntax | Category::Data => io::ErrorKind::InvalidData,
+                Category::Eof => io::ErrorKind::UnexpectedEof,
+            },
+            #[cfg(feature = "yaml")]
+            OciSpecError::Yaml(_) => io::ErrorKind::InvalidData,
+            #[cfg(feature = "builder")]
+            OciSpecError::Builder(_) => io::ErrorKind::InvalidInput,
+        }
+    }
+}
+
+impl From<OciSpecError> for io::Error {
+    fn from(err: OciSpecError) -> Self {
+        match err {
+            OciSpecError::Io(err) => err,
+            OciSpecError::SerDe(err) => err.into(),
+            err => io::Error::new(err.kind(), err),
+        }
+    }
+}
+
 pub(crate) fn oci_error<'a, M>(message: M) -> OciSpecError
 where
     M: Into<Cow<'a, str>>,
@@ -44,3 +84,59 @@ where
         Cow::Owned(s) => OciSpecError::Other(s),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_io_error() {
+        let err: io::Error = oci_error("invalid").into();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+        assert_eq!(err.to_string(), "invalid");
+
+        let err = OciSpecError::from(io::Error::from(io::ErrorKind::NotFound));
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::NotFound);
+
+        let err = OciSpecError::from(serde_json::from_str::<u32>("\"a\"").unwrap_err());
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::InvalidData);
+
+        let err = OciSpecError::from(serde_json::from_str::<u32>("").unwrap_err());
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn deserialization_path() {
+        #[derive(Debug, serde::Deserialize)]
+        struct User {
+            #[allow(dead_code)]
+            uid: u32,
+        }
+
+        #[derive(Debug, serde::Deserialize)]
+        struct Process {
+            #[allow(dead_code)]
+            users: Vec<User>,
+        }
+
+        // arrange
+        let json = "{\n  \"users\": [\n    {\"uid\": \"root\"}\n  ]\n}";
+
+        // act
+        let err = crate::from_reader::<_, Process>(json.as_bytes()).unwrap_err();
+
+        // assert
+        assert_eq!(
+            err.to_string(),
+            "serde failed: users[0].uid: invalid type: string \"root\", expected u32 at line 3 column 18"
+        );
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let err = crate::from_reader::<_, Process>(&b"{\"users\": ["[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        let err = crate::from_reader::<_, Process>(&b"{\"users\": []} x"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,312 @@
+//! Implementations of [arbitrary::Arbitrary] for types which cannot be
+//! derived, and generators for constrained fields, so that fuzzers produce
+//! structurally valid documents instead of failing on the first digest or
+//! media type.
+
+#[cfg(feature = "runtime")]
+use std::collections::HashMap;
+#[cfg(any(feature = "cdi", feature = "runtime"))]
+use std::path::PathBuf;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+#[cfg(any(feature = "cdi", feature = "runtime"))]
+const PATH_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789_-";
+
+/// Generates a sha256 digest with a valid hex encoded part.
+#[cfg(feature = "image")]
+pub(crate) fn digest(u: &mut Unstructured) -> Result<String> {
+    let hash: [u8; 32] = u.arbitrary()?;
+    let encoded: String = hash.iter().map(|b| format!("{:02x}", b)).collect();
+    Ok(format!("sha256:{}", encoded))
+}
+
+/// Generates a non-negative content size.
+#[cfg(feature = "image")]
+pub(crate) fn size(u: &mut Unstructured) -> Result<i64> {
+    u.int_in_range(0..=i64::MAX)
+}
+
+/// Generates an absolute, normalized path, e.g. `/dev/a_1`.
+#[cfg(any(feature = "cdi", feature = "runtime"))]
+pub(crate) fn absolute_path(u: &mut Unstructured) -> Result<PathBuf> {
+    let mut path = PathBuf::from("/");
+    for _ in 0..u.int_in_range(0..=4)? {
+        let len = u.int_in_range(1..=8)?;
+        let component = (0..len)
+            .map(|_| u.choose(PATH_CHARS).map(|c| *c as char))
+            .collect::<Result<String>>()?;
+        path.push(component);
+    }
+    Ok(path)
+}
+
+/// Generates an optional absolute path, see [absolute_path].
+#[cfg(feature = "cdi")]
+pub(crate) fn optional_absolute_path(u: &mut Unstructured) -> Result<Option<PathBuf>> {
+    match u.arbitrary()? {
+        true => absolute_path(u).map(Some),
+        false => Ok(None),
+    }
+}
+
+/// Generates a map of scalar JSON values, as [serde_json::Value] does not
+/// implement [Arbitrary].
+#[cfg(feature = "runtime")]
+pub(crate) fn json_map(
+    u: &mut Unstructured,
+) -> Result<Option<HashMap<String, Option<serde_json::Value>>>> {
+    if !u.arbitrary::<bool>()? {
+        return Ok(None);
+    }
+    let mut map = HashMap::new();
+    for _ in 0..u.arbitrary_len::<(String, u8)>()? {
+        let value = match u.int_in_range(0..=3)? {
+            0 => None,
+            1 => Some(serde_json::Value::Bool(u.arbitrary()?)),
+            2 => Some(serde_json::Value::from(u.arbitrary::<i64>()?)),
+            _ => Some(serde_json::Value::String(u.arbitrary()?)),
+        };
+        map.insert(u.arbitrary()?, value);
+    }
+    Ok(Some(map))
+}
+
+#[cfg(feature = "image")]
+const MEDIA_TYPES: &[&str] = &[
+    "application/vnd.oci.descriptor",
+    "application/vnd.oci.layout.header.v1+json",
+    "application/vnd.oci.image.manifest.v1+json",
+    "application/vnd.oci.image.index.v1+json",
+    "application/vnd.oci.image.layer.v1.tar",
+    "application/vnd.oci.image.layer.v1.tar+gzip",
+    "application/vnd.oci.image.layer.v1.tar+zstd",
+    "application/vnd.oci.image.layer.nondistributable.v1.tar",
+    "application/vnd.oci.image.layer.nondistributable.v1.tar+gzip",
+    "application/vnd.oci.image.layer.nondistributable.v1.tar+zstd",
+    "application/vnd.oci.image.config.v1+json",
+    "application/vnd.oci.empty.v1+json",
+    "application/vnd.docker.distribution.manifest.v2+json",
+    "application/vnd.docker.distribution.manifest.list.v2+json",
+    "application/vnd.docker.image.rootfs.diff.tar.gzip",
+];
+
+#[cfg(feature = "image")]
+const OPERATING_SYSTEMS: &[&str] = &[
+    "aix",
+    "android",
+    "darwin",
+    "dragonfly",
+    "freebsd",
+    "hurd",
+    "illumos",
+    "ios",
+    "js",
+    "linux",
+    "nacl",
+    "netbsd",
+    "openbsd",
+    "plan9",
+    "solaris",
+    "wasip1",
+    "windows",
+    "zos",
+];
+
+#[cfg(feature = "image")]
+const ARCHITECTURES: &[&str] = &[
+    "386",
+    "amd64",
+    "amd64p32",
+    "arm",
+    "armbe",
+    "arm64",
+    "arm64be",
+    "loong64",
+    "mips",
+    "mipsle",
+    "mips64",
+    "mips64le",
+    "mips64p32",
+    "mips64p32le",
+    "ppc",
+    "ppc64",
+    "ppc64le",
+    "riscv",
+    "riscv64",
+    "s390",
+    "s390x",
+    "sparc",
+    "sparc64",
+    "wasm",
+];
+
+#[cfg(feature = "image")]
+impl<'a> Arbitrary<'a> for crate::image::Digest {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        digest(u)?
+            .parse()
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(feature = "image")]
+impl<'a> Arbitrary<'a> for crate::image::MediaType {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(crate::image::MediaType::from(*u.choose(MEDIA_TYPES)?))
+    }
+}
+
+#[cfg(feature = "image")]
+impl<'a> Arbitrary<'a> for crate::image::Os {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(crate::image::Os::from(*u.choose(OPERATING_SYSTEMS)?))
+    }
+}
+
+#[cfg(feature = "image")]
+impl<'a> Arbitrary<'a> for crate::image::Arch {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(crate::image::Arch::from(*u.choose(ARCHITECTURES)?))
+    }
+}
+
+#[cfg(feature = "distribution")]
+impl<'a> Arbitrary<'a> for crate::distribution::ErrorCode {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&crate::distribution::ErrorCode::KNOWN).cloned()
+    }
+}
+
+#[cfg(feature = "runtime")]
+impl<'a> Arbitrary<'a> for crate::runtime::Syscall {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(crate::runtime::Syscall::other(
+            *u.choose(crate::runtime::Syscall::KNOWN)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: [u8; 256] = {
+        let mut data = [0u8; 256];
+        let mut i = 0;
+        while i < data.len() {
+            data[i] = (i as u8).wrapping_mul(113).wrapping_add(7);
+            i += 1;
+        }
+        data
+    };
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn constrained_digest() -> Result<()> {
+        // arrange
+        let mut u = Unstructured::new(&DATA);
+
+        // act
+        let digest = digest(&mut u)?;
+
+        // assert
+        assert_eq!(digest.len(), 71);
+        assert!(digest.starts_with("sha256:"));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(feature = "cdi", feature = "runtime"))]
+    fn constrained_path() -> Result<()> {
+        // arrange
+        let mut u = Unstructured::new(&DATA);
+
+        // act
+        let path = absolute_path(&mut u)?;
+
+        // assert
+        assert!(path.is_absolute());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn arbitrary_manifest() -> Result<()> {
+        use crate::image::{ImageManifest, MediaType};
+
+        // arrange
+        let mut u = Unstructured::new(&DATA);
+
+        // act
+        let manifest = ImageManifest::arbitrary(&mut u)?;
+
+        // assert
+        assert!(manifest
+            .config
+            .digest
+            .parse::<crate::image::Digest>()
+            .is_ok());
+        assert!(manifest.config.size >= 0);
+        assert!(!matches!(manifest.config.media_type, MediaType::Other(ref m) if m.is_empty()));
+        let json = serde_json::to_string(&manifest).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ImageManifest>(&json).unwrap(),
+            manifest
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn arbitrary_configuration() -> Result<()> {
+        use crate::image::ImageConfiguration;
+
+        // arrange
+        let mut u = Unstructured::new(&DATA);
+
+        // act
+        let config = ImageConfiguration::arbitrary(&mut u)?;
+
+        // assert
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(serde_json::from_str::<ImageConfiguration>(&json).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "distribution")]
+    fn arbitrary_error_code() -> Result<()> {
+        use crate::distribution::ErrorCode;
+
+        // arrange
+        let mut u = Unstructured::new(&DATA);
+
+        // act
+        let code = ErrorCode::arbitrary(&mut u)?;
+
+        // assert
+        assert!(ErrorCode::KNOWN.contains(&code));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "runtime")]
+    fn arbitrary_spec() -> Result<()> {
+        use crate::runtime::Spec;
+
+        // arrange
+        let mut u = Unstructured::new(&DATA);
+
+        // act
+        let spec = Spec::arbitrary(&mut u)?;
+
+        // assert
+        for mount in spec.mounts.iter().flatten() {
+            assert!(mount.destination.is_absolute());
+        }
+        let json = serde_json::to_string(&spec).unwrap();
+        assert!(serde_json::from_str::<Spec>(&json).is_ok());
+        Ok(())
+    }
+}
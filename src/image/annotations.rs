@@ -1,3 +1,14 @@
+use std::collections::HashMap;
+
+use crate::error::Result;
+
+use super::Digest;
+
+#[cfg(feature = "timestamps")]
+use super::config::{format_timestamp, parse_timestamp};
+#[cfg(feature = "timestamps")]
+use chrono::{DateTime, Utc};
+
 /// AnnotationCreated is the annotation key for the date and time on which the
 /// image was built (date-time string as defined by RFC 3339).
 pub const ANNOTATION_CREATED: &str = "org.opencontainers.image.created";
@@ -55,3 +66,118 @@ pub const ANNOTATION_BASE_IMAGE_DIGEST: &str = "org.opencontainers.image.base.di
 /// AnnotationBaseImageName is the annotation key for the image reference of the
 /// image's base image.
 pub const ANNOTATION_BASE_IMAGE_NAME: &str = "org.opencontainers.image.base.name";
+
+/// AnnotationEndOfLife is the annotation key for the date and time after which
+/// the artifact is no longer supported (date-time string as defined by RFC
+/// 3339). It is not defined by the OCI image spec, it is the key of the
+/// lifecycle metadata of Azure Container Registry, which is usually attached
+/// to an image as a referrer artifact, e.g. with `oras attach`.
+pub const ANNOTATION_END_OF_LIFE: &str = "vnd.microsoft.artifact.lifecycle.end-of-life.date";
+
+/// Returns the end of life date of an artifact from its annotations, if set.
+/// # Errors
+/// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+/// if the annotation is not a valid RFC 3339 timestamp.
+#[cfg(feature = "timestamps")]
+pub fn end_of_life(annotations: &HashMap<String, String>) -> Result<Option<DateTime<Utc>>> {
+    annotations
+        .get(ANNOTATION_END_OF_LIFE)
+        .map(|date| parse_timestamp(date))
+        .transpose()
+}
+
+/// Sets the end of life date of an artifact in its annotations.
+#[cfg(feature = "timestamps")]
+pub fn set_end_of_life(annotations: &mut HashMap<String, String>, date: DateTime<Utc>) {
+    annotations.insert(ANNOTATION_END_OF_LIFE.to_owned(), format_timestamp(&date));
+}
+
+/// Returns true if the artifact has an end of life date which is not after
+/// `now`.
+/// # Errors
+/// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+/// if the annotation is not a valid RFC 3339 timestamp.
+#[cfg(feature = "timestamps")]
+pub fn is_expired(annotations: &HashMap<String, String>, now: DateTime<Utc>) -> Result<bool> {
+    Ok(end_of_life(annotations)?.is_some_and(|date| date <= now))
+}
+
+/// Returns the image reference of the base image from the annotations, if
+/// set.
+pub fn base_image_name(annotations: &HashMap<String, String>) -> Option<&str> {
+    annotations
+        .get(ANNOTATION_BASE_IMAGE_NAME)
+        .map(String::as_str)
+}
+
+/// Returns the digest of the base image from the annotations, if set.
+/// # Errors
+/// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+/// if the annotation is not a valid digest.
+pub fn base_image_digest(annotations: &HashMap<String, String>) -> Result<Option<Digest>> {
+    annotations
+        .get(ANNOTATION_BASE_IMAGE_DIGEST)
+        .map(|digest| digest.parse())
+        .transpose()
+}
+
+/// Sets the image reference and the digest of the base image in the
+/// annotations. Both are set together, so that the reference can always be
+/// resolved to the exact image which was used.
+pub fn set_base_image<S: Into<String>>(
+    annotations: &mut HashMap<String, String>,
+    name: S,
+    digest: &Digest,
+) {
+    annotations.insert(ANNOTATION_BASE_IMAGE_NAME.to_owned(), name.into());
+    annotations.insert(ANNOTATION_BASE_IMAGE_DIGEST.to_owned(), digest.to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_image_annotations() {
+        let mut annotations = HashMap::new();
+        assert_eq!(base_image_name(&annotations), None);
+        assert_eq!(base_image_digest(&annotations).unwrap(), None);
+
+        let digest: Digest =
+            "sha256:9834876dcfb05cb167a5c24953eba58c4ac89b1adf57f28f2f9d09af107ee8f0"
+                .parse()
+                .unwrap();
+        set_base_image(&mut annotations, "docker.io/library/alpine:3.14", &digest);
+        assert_eq!(
+            base_image_name(&annotations),
+            Some("docker.io/library/alpine:3.14")
+        );
+        assert_eq!(base_image_digest(&annotations).unwrap(), Some(digest));
+
+        annotations.insert(ANNOTATION_BASE_IMAGE_DIGEST.to_owned(), "latest".to_owned());
+        assert!(base_image_digest(&annotations).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "timestamps")]
+    fn end_of_life_annotation() {
+        let mut annotations = HashMap::new();
+        let now = parse_timestamp("2021-08-01T00:00:00Z").unwrap();
+        assert_eq!(end_of_life(&annotations).unwrap(), None);
+        assert!(!is_expired(&annotations, now).unwrap());
+
+        set_end_of_life(
+            &mut annotations,
+            parse_timestamp("2021-07-01T12:00:00+02:00").unwrap(),
+        );
+        assert_eq!(
+            annotations.get(ANNOTATION_END_OF_LIFE).unwrap(),
+            "2021-07-01T10:00:00Z"
+        );
+        assert!(annotations.contains_key("vnd.microsoft.artifact.lifecycle.end-of-life.date"));
+        assert!(is_expired(&annotations, now).unwrap());
+
+        annotations.insert(ANNOTATION_END_OF_LIFE.to_owned(), "tomorrow".to_owned());
+        assert!(end_of_life(&annotations).is_err());
+    }
+}
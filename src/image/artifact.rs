@@ -0,0 +1,130 @@
+//! Artifact types of supply chain documents, like SBOMs and attestations,
+//! and constructors for the manifests which attach them to an image through
+//! the referrers API.
+
+use sha2::{Digest as _, Sha256};
+
+use super::{Descriptor, ImageManifest, MediaType, DIGEST_ALGORITHM_SHA256};
+
+/// ArtifactTypeSpdx is the artifact type of an SPDX software bill of
+/// materials in JSON format.
+pub const ARTIFACT_TYPE_SPDX: &str = "application/spdx+json";
+
+/// ArtifactTypeCycloneDx is the artifact type of a CycloneDX software bill of
+/// materials in JSON format.
+pub const ARTIFACT_TYPE_CYCLONEDX: &str = "application/vnd.cyclonedx+json";
+
+/// ArtifactTypeInToto is the artifact type of an in-toto attestation
+/// statement.
+pub const ARTIFACT_TYPE_IN_TOTO: &str = "application/vnd.in-toto+json";
+
+/// ArtifactTypeDsseEnvelope is the artifact type of a DSSE envelope, which
+/// commonly wraps a signed in-toto attestation.
+pub const ARTIFACT_TYPE_DSSE_ENVELOPE: &str = "application/vnd.dsse.envelope.v1+json";
+
+/// ArtifactTypeSigstoreBundle is the artifact type of a Sigstore bundle,
+/// which contains a signed attestation together with its verification
+/// material.
+pub const ARTIFACT_TYPE_SIGSTORE_BUNDLE: &str = "application/vnd.dev.sigstore.bundle.v0.3+json";
+
+/// MediaTypeEmptyJson is the media type of the empty JSON object `{}`, which
+/// is used as the config of artifacts that have no configuration.
+pub const MEDIA_TYPE_EMPTY_JSON: &str = "application/vnd.oci.empty.v1+json";
+
+/// The digest of the empty JSON object `{}`.
+pub const EMPTY_JSON_DIGEST: &str =
+    "sha256:44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a";
+
+impl Descriptor {
+    /// Construct the descriptor of the empty JSON object `{}`, which is used
+    /// as the config of artifact manifests.
+    pub fn empty_json() -> Self {
+        Descriptor::new(MediaType::from(MEDIA_TYPE_EMPTY_JSON), 2, EMPTY_JSON_DIGEST)
+    }
+
+    /// Construct a descriptor of the given content, e.g. of an SBOM which is
+    /// pushed as a layer of an artifact manifest.
+    pub fn from_content(media_type: MediaType, content: &[u8]) -> Self {
+        let digest = format!("{}:{:x}", DIGEST_ALGORITHM_SHA256, Sha256::digest(content));
+        Descriptor::new(media_type, content.len() as i64, digest)
+    }
+}
+
+impl ImageManifest {
+    /// Construct a manifest which attaches an artifact to the `subject`, e.g.
+    /// an SBOM to the manifest of the image which it describes. The config is
+    /// the empty JSON object and the artifact itself is stored in `layers`.
+    /// # Example
+    /// ```
+    /// use oci_spec::image::{
+    ///     Descriptor, ImageManifest, MediaType, ARTIFACT_TYPE_SPDX,
+    /// };
+    ///
+    /// let image = Descriptor::new(
+    ///     MediaType::ImageManifest,
+    ///     7682,
+    ///     "sha256:5b0bcabd1ed22e9fb1310cf6c2dec7cdef19f0ad69efa1f392e94a4333501270",
+    /// );
+    /// let sbom = br#"{"spdxVersion": "SPDX-2.3"}"#;
+    /// let layer = Descriptor::from_content(MediaType::from(ARTIFACT_TYPE_SPDX), sbom);
+    ///
+    /// let manifest = ImageManifest::referrer(ARTIFACT_TYPE_SPDX, image, vec![layer]);
+    /// let json = serde_json::to_value(&manifest).unwrap();
+    /// assert_eq!(json["artifactType"], ARTIFACT_TYPE_SPDX);
+    /// assert_eq!(json["config"]["mediaType"], "application/vnd.oci.empty.v1+json");
+    /// ```
+    pub fn referrer<S: Into<String>>(
+        artifact_type: S,
+        subject: Descriptor,
+        layers: Vec<Descriptor>,
+    ) -> Self {
+        ImageManifest {
+            schema_version: super::SCHEMA_VERSION,
+            media_type: Some(MediaType::ImageManifest),
+            artifact_type: Some(artifact_type.into()),
+            config: Descriptor::empty_json(),
+            layers,
+            subject: Some(subject),
+            annotations: None,
+            extensions: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_json_descriptor() {
+        // act
+        let descriptor = Descriptor::from_content(MediaType::from(MEDIA_TYPE_EMPTY_JSON), b"{}");
+
+        // assert
+        assert_eq!(descriptor, Descriptor::empty_json());
+    }
+
+    #[test]
+    fn attestation_referrer() {
+        // arrange
+        let subject = Descriptor::from_content(MediaType::ImageManifest, b"{}");
+        let envelope = Descriptor::from_content(
+            MediaType::from(ARTIFACT_TYPE_DSSE_ENVELOPE),
+            br#"{"payloadType":"application/vnd.in-toto+json"}"#,
+        );
+
+        // act
+        let manifest =
+            ImageManifest::referrer(ARTIFACT_TYPE_IN_TOTO, subject.clone(), vec![envelope]);
+
+        // assert
+        assert_eq!(
+            manifest.artifact_type.as_deref(),
+            Some(ARTIFACT_TYPE_IN_TOTO)
+        );
+        assert_eq!(manifest.subject, Some(subject));
+        assert_eq!(manifest.config, Descriptor::empty_json());
+        let json = manifest.to_vec().expect("serialize manifest");
+        assert_eq!(ImageManifest::from_slice(&json).expect("parse"), manifest);
+    }
+}
@@ -0,0 +1,141 @@
+//! Downgrading of image documents for consumers which implement an older
+//! version of the specification.
+
+use super::{Descriptor, ImageIndex, ImageManifest};
+
+/// A released version of the image specification which a document can be
+/// written for, see [ImageManifest::to_version] and [ImageIndex::to_version].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SpecVersion {
+    /// The 1.0.x releases.
+    V1_0,
+    /// The 1.1.x releases and later, which added artifacts, i.e. the
+    /// `artifactType` of manifests, indexes and descriptors and the `subject`
+    /// of manifests and indexes. Documents are written unchanged.
+    V1_1,
+}
+
+fn downgrade_descriptor(
+    descriptor: &mut Descriptor,
+    version: SpecVersion,
+    path: &str,
+    dropped: &mut Vec<String>,
+) {
+    if version < SpecVersion::V1_1 && descriptor.artifact_type.take().is_some() {
+        dropped.push(format!("{}/artifactType", path));
+    }
+}
+
+impl ImageManifest {
+    /// Returns a copy of the manifest without the properties which the given
+    /// version of the specification does not define. The second value
+    /// contains the JSON pointers of the values which were dropped, so that
+    /// callers can warn about them before pushing the manifest to an older
+    /// registry.
+    /// # Example
+    /// ```
+    /// use oci_spec::image::{ImageManifest, SpecVersion};
+    ///
+    /// let manifest = ImageManifest::from_reader(r#"{
+    ///     "schemaVersion": 2,
+    ///     "config": {
+    ///         "mediaType": "application/vnd.oci.image.config.v1+json",
+    ///         "digest": "sha256:b5b2b2c507a0944348e0303114d8d93aaaa081732b86451d9bce1f432a537bc7",
+    ///         "size": 7023,
+    ///         "artifactType": "application/vnd.example.sbom"
+    ///     },
+    ///     "layers": []
+    /// }"#.as_bytes()).unwrap();
+    ///
+    /// let (_, dropped) = manifest.to_version(SpecVersion::V1_0);
+    /// assert_eq!(dropped, vec!["/config/artifactType"]);
+    /// ```
+    pub fn to_version(&self, version: SpecVersion) -> (ImageManifest, Vec<String>) {
+        let mut manifest = self.clone();
+        let mut dropped = Vec::new();
+        if version < SpecVersion::V1_1 {
+            if manifest.artifact_type.take().is_some() {
+                dropped.push("/artifactType".to_owned());
+            }
+            if manifest.subject.take().is_some() {
+                dropped.push("/subject".to_owned());
+            }
+        }
+        downgrade_descriptor(&mut manifest.config, version, "/config", &mut dropped);
+        for (i, layer) in manifest.layers.iter_mut().enumerate() {
+            downgrade_descriptor(layer, version, &format!("/layers/{}", i), &mut dropped);
+        }
+        (manifest, dropped)
+    }
+}
+
+impl ImageIndex {
+    /// Returns a copy of the index without the properties which the given
+    /// version of the specification does not define, together with the JSON
+    /// pointers of the values which were dropped.
+    pub fn to_version(&self, version: SpecVersion) -> (ImageIndex, Vec<String>) {
+        let mut index = self.clone();
+        let mut dropped = Vec::new();
+        if version < SpecVersion::V1_1 {
+            if index.artifact_type.take().is_some() {
+                dropped.push("/artifactType".to_owned());
+            }
+            if index.subject.take().is_some() {
+                dropped.push("/subject".to_owned());
+            }
+        }
+        for (i, manifest) in index.manifests.iter_mut().enumerate() {
+            downgrade_descriptor(
+                manifest,
+                version,
+                &format!("/manifests/{}", i),
+                &mut dropped,
+            );
+        }
+        (index, dropped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::image::MediaType;
+
+    fn descriptor(artifact_type: Option<&str>) -> Descriptor {
+        let mut descriptor = Descriptor::new(
+            MediaType::ImageManifest,
+            7143,
+            "sha256:e692418e4cbaf90ca69d05a66403747baa33ee08806650b51fab815ad7fc331f",
+        );
+        descriptor.artifact_type = artifact_type.map(ToOwned::to_owned);
+        descriptor
+    }
+
+    #[test]
+    fn index_to_version() {
+        // arrange
+        let index = ImageIndex {
+            manifests: vec![
+                descriptor(None),
+                descriptor(Some("application/vnd.example.sbom")),
+            ],
+            artifact_type: Some("application/vnd.example.signature".to_owned()),
+            subject: Some(descriptor(None)),
+            ..Default::default()
+        };
+
+        // act
+        let (current, unchanged) = index.to_version(SpecVersion::V1_1);
+        let (older, dropped) = index.to_version(SpecVersion::V1_0);
+
+        // assert
+        assert_eq!(current, index);
+        assert!(unchanged.is_empty());
+        assert_eq!(
+            dropped,
+            vec!["/artifactType", "/subject", "/manifests/1/artifactType"]
+        );
+        assert!(older.subject.is_none());
+        assert!(older.manifests.iter().all(|m| m.artifact_type.is_none()));
+    }
+}
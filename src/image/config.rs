@@ -1,29 +1,45 @@
 #[cfg(test)]
 use std::collections::BTreeMap;
+#[cfg(has_fs)]
+use std::path::Path;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     io::{Read, Write},
-    path::Path,
 };
 
+#[cfg(feature = "timestamps")]
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{error::Result, from_file, from_reader, to_file, to_writer};
+use crate::{
+    diff,
+    error::{oci_error, Result},
+    from_reader, to_writer, Difference,
+};
+#[cfg(has_fs)]
+use crate::{from_file, to_file};
 
-use super::{Arch, Os};
+use super::{annotations, Arch, DiffId, Digest, Os};
 
 make_pub!(
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get = "pub")
+        getset(get = "pub", get_mut = "pub", set = "pub")
     )]
     /// The image configuration is associated with an image and describes some
     /// basic information about the image such as date created, author, as
@@ -80,10 +96,20 @@ make_pub!(
         /// Describes the history of each layer. The array is ordered from first
         /// to last.
         history: Vec<History>,
+
+        #[cfg_attr(feature = "lossless", serde(flatten))]
+        #[cfg_attr(not(feature = "lossless"), serde(skip))]
+        #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+        /// Properties which are not defined by the image specification, e.g.
+        /// fields written by Docker such as `container_config`, which are kept
+        /// so that rewriting a configuration does not change its content. This
+        /// map stays empty unless the `lossless` feature is enabled.
+        extensions: HashMap<String, serde_json::Value>,
     }
 );
 
 impl ImageConfiguration {
+    #[cfg(has_fs)]
     /// Attempts to load an image configuration from a file.
     /// # Errors
     /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
@@ -116,6 +142,7 @@ impl ImageConfiguration {
         from_reader(reader)
     }
 
+    #[cfg(has_fs)]
     /// Attempts to write an image configuration to a file as JSON. If the file already exists, it
     /// will be overwritten.
     /// # Errors
@@ -132,6 +159,7 @@ impl ImageConfiguration {
         to_file(&self, path, false)
     }
 
+    #[cfg(has_fs)]
     /// Attempts to write an image configuration to a file as pretty printed JSON. If the file
     /// already exists, it will be overwritten.
     /// # Errors
@@ -179,6 +207,249 @@ impl ImageConfiguration {
     pub fn to_writer_pretty<W: Write>(&self, writer: &mut W) -> Result<()> {
         to_writer(&self, writer, true)
     }
+
+    /// Validates that the history of the image configuration is consistent
+    /// with its root filesystem. The rootfs type MUST be `layers` and, if a
+    /// history is present, every history entry which is not marked as
+    /// `empty_layer` MUST correspond to one of the `diff_ids` in order, so
+    /// their counts MUST be equal.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// describing the first inconsistency found.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::image::ImageConfiguration;
+    ///
+    /// let image_configuration = ImageConfiguration::from_file("config.json").unwrap();
+    /// image_configuration.validate().unwrap();
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        if self.rootfs.typ != "layers" {
+            return Err(oci_error(format!(
+                "rootfs type must be layers, got {}",
+                self.rootfs.typ
+            )));
+        }
+
+        if self.history.is_empty() {
+            return Ok(());
+        }
+
+        let layers = self
+            .history
+            .iter()
+            .filter(|h| !h.empty_layer.unwrap_or(false))
+            .count();
+        let diff_ids = self.rootfs.diff_ids.len();
+        if layers != diff_ids {
+            return Err(oci_error(format!(
+                "history contains {} non-empty layers, but rootfs contains {} diff_ids",
+                layers, diff_ids
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Normalizes the execution parameters of the image configuration. This is
+    /// not done on load and has to be requested explicitly, since it changes the
+    /// content digest of the configuration. Duplicate environment variables are
+    /// removed, where the last definition of a variable wins and keeps its
+    /// position, and surrounding whitespace is trimmed from label keys. If a
+    /// trimmed label key collides with an existing key, the existing label wins,
+    /// and among several untrimmed keys the lexicographically smallest wins.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::image::ImageConfiguration;
+    ///
+    /// let mut image_configuration = ImageConfiguration::from_file("config.json").unwrap();
+    /// let report = image_configuration.normalize();
+    /// println!("removed duplicate env variables: {:?}", report.removed_env);
+    /// ```
+    pub fn normalize(&mut self) -> NormalizationReport {
+        let mut report = NormalizationReport::default();
+        let config = match self.config.as_mut() {
+            Some(config) => config,
+            None => return report,
+        };
+
+        if let Some(env) = config.env.as_mut() {
+            let mut seen = HashSet::new();
+            let mut deduped = Vec::with_capacity(env.len());
+            for var in env.drain(..).rev() {
+                let key = var.split('=').next().unwrap_or_default().to_owned();
+                if seen.insert(key) {
+                    deduped.push(var);
+                } else {
+                    report.removed_env.push(var);
+                }
+            }
+            deduped.reverse();
+            report.removed_env.reverse();
+            *env = deduped;
+        }
+
+        if let Some(labels) = config.labels.as_mut() {
+            let mut untrimmed: Vec<String> = labels
+                .keys()
+                .filter(|key| key.trim() != key.as_str())
+                .cloned()
+                .collect();
+            // Sort the keys so that the first of several keys trimming to
+            // the same label wins independently of the hash map order.
+            untrimmed.sort();
+            for key in untrimmed {
+                if let Some(value) = labels.remove(&key) {
+                    labels.entry(key.trim().to_owned()).or_insert(value);
+                }
+                report.trimmed_labels.push(key);
+            }
+        }
+
+        report
+    }
+
+    /// Sets the environment variable `key` to `value` in the execution
+    /// parameters, replacing a previous definition of the same variable.
+    pub fn add_env(&mut self, key: &str, value: &str) {
+        let var = format!("{}={}", key, value);
+        let env = self
+            .config
+            .get_or_insert_with(Config::default)
+            .env
+            .get_or_insert_with(Vec::new);
+        match env.iter_mut().find(|v| v.split('=').next() == Some(key)) {
+            Some(existing) => *existing = var,
+            None => env.push(var),
+        }
+    }
+
+    /// Sets the label `key` to `value` in the execution parameters, returning
+    /// the previous value if there was one.
+    pub fn set_label(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Option<String> {
+        self.config
+            .get_or_insert_with(Config::default)
+            .labels
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into())
+    }
+
+    /// Returns the image reference of the base image which the image was
+    /// built from, as recorded in the labels of the execution parameters.
+    pub fn base_image_name(&self) -> Option<&str> {
+        self.config
+            .as_ref()
+            .and_then(|config| config.labels.as_ref())
+            .and_then(|labels| annotations::base_image_name(labels))
+    }
+
+    /// Returns the digest of the base image which the image was built from,
+    /// as recorded in the labels of the execution parameters.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the label is not a valid digest.
+    pub fn base_image_digest(&self) -> Result<Option<Digest>> {
+        self.config
+            .as_ref()
+            .and_then(|config| config.labels.as_ref())
+            .map_or(Ok(None), annotations::base_image_digest)
+    }
+
+    /// Records the base image which the image was built from in the labels
+    /// of the execution parameters.
+    pub fn set_base_image<S: Into<String>>(&mut self, name: S, digest: &Digest) {
+        let labels = self
+            .config
+            .get_or_insert_with(Config::default)
+            .labels
+            .get_or_insert_with(HashMap::new);
+        annotations::set_base_image(labels, name, digest);
+    }
+
+    /// Sets the entrypoint of the execution parameters.
+    pub fn set_entrypoint(&mut self, entrypoint: Vec<String>) {
+        self.config.get_or_insert_with(Config::default).entrypoint = Some(entrypoint);
+    }
+
+    /// Sets the default arguments of the entrypoint of the execution
+    /// parameters.
+    pub fn set_cmd(&mut self, cmd: Vec<String>) {
+        self.config.get_or_insert_with(Config::default).cmd = Some(cmd);
+    }
+
+    /// Appends a history entry. Use [ImageConfiguration::add_diff_id] as well
+    /// for entries which are not marked as `empty_layer`.
+    pub fn add_history_entry(&mut self, history: History) {
+        self.history.push(history);
+    }
+
+    /// Appends the DiffID of a layer to the root filesystem.
+    pub fn add_diff_id(&mut self, diff_id: impl Into<DiffId>) {
+        self.rootfs.diff_ids.push(diff_id.into());
+    }
+
+    /// Appends a layer together with the history entry describing how it was
+    /// created, keeping both consistent.
+    pub fn add_layer(&mut self, diff_id: impl Into<DiffId>, mut history: History) {
+        history.empty_layer = None;
+        self.add_diff_id(diff_id);
+        self.add_history_entry(history);
+    }
+
+    /// Compares the configuration with `other` and returns the differing
+    /// values, see [diff](crate::diff).
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if one of the configurations cannot be serialized.
+    pub fn diff(&self, other: &ImageConfiguration) -> Result<Vec<Difference>> {
+        diff(self, other)
+    }
+}
+
+/// Report of the changes made by [ImageConfiguration::normalize].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NormalizationReport {
+    /// The environment variables which have been removed because they were
+    /// overridden by a later definition, in their original order.
+    pub removed_env: Vec<String>,
+    /// The original label keys which have been trimmed, in sorted order.
+    pub trimmed_labels: Vec<String>,
+}
+
+impl NormalizationReport {
+    /// Returns true if the normalization did not change anything.
+    pub fn is_empty(&self) -> bool {
+        self.removed_env.is_empty() && self.trimmed_labels.is_empty()
+    }
+}
+
+#[cfg(feature = "timestamps")]
+impl ImageConfiguration {
+    /// Parses the `created` property as an RFC 3339 timestamp.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the timestamp is not a valid RFC 3339 date-time.
+    pub fn created_parsed(&self) -> Result<Option<DateTime<Utc>>> {
+        self.created.as_deref().map(parse_timestamp).transpose()
+    }
+
+    /// Clamps the `created` timestamps of the configuration and of all history
+    /// entries to `max`, which is commonly derived from `SOURCE_DATE_EPOCH`
+    /// for reproducible builds. Timestamps older than `max` are kept as is.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if any of the timestamps is not a valid RFC 3339 date-time.
+    pub fn clamp_created(&mut self, max: DateTime<Utc>) -> Result<()> {
+        clamp_timestamp(&mut self.created, max)?;
+        for history in self.history.iter_mut() {
+            history.clamp_created(max)?;
+        }
+        Ok(())
+    }
 }
 
 impl Default for ImageConfiguration {
@@ -194,23 +465,31 @@ impl Default for ImageConfiguration {
             config: Default::default(),
             rootfs: Default::default(),
             history: Default::default(),
+            extensions: Default::default(),
         }
     }
 }
 
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "PascalCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get = "pub")
+        getset(get = "pub", get_mut = "pub", set = "pub")
     )]
     /// The execution parameters which SHOULD be used as a base when
     /// running a container using the image.
@@ -238,6 +517,10 @@ make_pub!(
             deserialize_with = "deserialize_as_vec",
             serialize_with = "serialize_as_map"
         )]
+        #[cfg_attr(
+            feature = "schemars",
+            schemars(with = "Option<HashMap<String, GoMapSerde>>")
+        )]
         exposed_ports: Option<Vec<String>>,
         /// Entries are in the format of VARNAME=VARVALUE. These
         /// values act as defaults and are merged with any
@@ -265,6 +548,10 @@ make_pub!(
             deserialize_with = "deserialize_as_vec",
             serialize_with = "serialize_as_map"
         )]
+        #[cfg_attr(
+            feature = "schemars",
+            schemars(with = "Option<HashMap<String, GoMapSerde>>")
+        )]
         volumes: Option<Vec<String>>,
         /// Sets the current working directory of the entrypoint process
         /// in the container. This value acts as a default and may be
@@ -294,6 +581,7 @@ make_pub!(
 // }
 // Instead we treat this as a list
 #[derive(Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 struct GoMapSerde {}
 
 fn deserialize_as_vec<'de, D>(deserializer: D) -> std::result::Result<Option<Vec<String>>, D::Error>
@@ -341,16 +629,23 @@ where
 
 make_pub!(
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get = "pub")
+        getset(get = "pub", get_mut = "pub", set = "pub")
     )]
     /// RootFs references the layer content addresses used by the image.
     struct RootFs {
@@ -359,7 +654,7 @@ make_pub!(
         typ: String,
         /// An array of layer content hashes (DiffIDs), in order
         /// from first to last.
-        diff_ids: Vec<String>,
+        diff_ids: Vec<DiffId>,
     }
 );
 
@@ -374,15 +669,24 @@ impl Default for RootFs {
 
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// Describes the history of a layer.
     struct History {
@@ -412,6 +716,47 @@ make_pub!(
     }
 );
 
+#[cfg(feature = "timestamps")]
+impl History {
+    /// Parses the `created` property as an RFC 3339 timestamp.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the timestamp is not a valid RFC 3339 date-time.
+    pub fn created_parsed(&self) -> Result<Option<DateTime<Utc>>> {
+        self.created.as_deref().map(parse_timestamp).transpose()
+    }
+
+    /// Clamps the `created` timestamp of the history entry to `max`.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the timestamp is not a valid RFC 3339 date-time.
+    pub fn clamp_created(&mut self, max: DateTime<Utc>) -> Result<()> {
+        clamp_timestamp(&mut self.created, max)
+    }
+}
+
+#[cfg(feature = "timestamps")]
+pub(crate) fn parse_timestamp(timestamp: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|e| oci_error(format!("invalid timestamp {}: {}", timestamp, e)))
+}
+
+#[cfg(feature = "timestamps")]
+pub(crate) fn format_timestamp(timestamp: &DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+#[cfg(feature = "timestamps")]
+fn clamp_timestamp(created: &mut Option<String>, max: DateTime<Utc>) -> Result<()> {
+    if let Some(timestamp) = created {
+        if parse_timestamp(timestamp)? > max {
+            *timestamp = format_timestamp(&max);
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::{fs, path::PathBuf};
@@ -421,7 +766,7 @@ mod tests {
 
     #[cfg(feature = "builder")]
     fn create_config() -> ImageConfiguration {
-        let configuration = ImageConfigurationBuilder::default()
+        ImageConfigurationBuilder::default()
             .created("2015-10-31T22:22:56.015925234Z".to_owned())
             .author("Alyssa P. Hacker <alyspdev@example.com>".to_owned())
             .architecture(Arch::Amd64)
@@ -450,8 +795,8 @@ mod tests {
             )
             .rootfs(RootFsBuilder::default()
             .diff_ids(vec![
-                "sha256:c6f988f4874bb0add23a778f753c65efe992244e148a1d2ec2a8b664fb66bbd1".to_owned(),
-                "sha256:5f70bf18a086007016e948b04aed3b82103a36bea41755b6cddfaf10ace3c6ef".to_owned(),
+                "sha256:c6f988f4874bb0add23a778f753c65efe992244e148a1d2ec2a8b664fb66bbd1".parse().expect("parse diff id"),
+                "sha256:5f70bf18a086007016e948b04aed3b82103a36bea41755b6cddfaf10ace3c6ef".parse().expect("parse diff id"),
             ])
             .build()
             .expect("build rootfs"))
@@ -469,9 +814,7 @@ mod tests {
                 .expect("build history"),
             ])
             .build()
-            .expect("build configuration");
-
-        configuration
+            .expect("build configuration")
     }
 
     #[cfg(not(feature = "builder"))]
@@ -502,9 +845,11 @@ mod tests {
         let rootfs = RootFs {
             diff_ids: vec![
                 "sha256:c6f988f4874bb0add23a778f753c65efe992244e148a1d2ec2a8b664fb66bbd1"
-                    .to_owned(),
+                    .parse()
+                    .expect("parse diff id"),
                 "sha256:5f70bf18a086007016e948b04aed3b82103a36bea41755b6cddfaf10ace3c6ef"
-                    .to_owned(),
+                    .parse()
+                    .expect("parse diff id"),
             ],
             ..Default::default()
         };
@@ -526,7 +871,7 @@ mod tests {
             }
         ];
 
-        let configuration = ImageConfiguration {
+        ImageConfiguration {
             created: Some("2015-10-31T22:22:56.015925234Z".to_owned()),
             author: Some("Alyssa P. Hacker <alyspdev@example.com>".to_owned()),
             architecture: Arch::Amd64,
@@ -537,9 +882,8 @@ mod tests {
             config: Some(config),
             rootfs,
             history,
-        };
-
-        configuration
+            extensions: Default::default(),
+        }
     }
 
     fn get_config_path() -> PathBuf {
@@ -594,6 +938,166 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn validate_history() {
+        // arrange
+        let mut config = create_config();
+
+        // act
+        let mismatch = config.validate();
+        config.history[1].empty_layer = None;
+        let valid = config.validate();
+        config.history.clear();
+        let no_history = config.validate();
+        config.rootfs.typ = "other".to_owned();
+        let invalid_type = config.validate();
+
+        // assert
+        assert_eq!(
+            mismatch.unwrap_err().to_string(),
+            "history contains 1 non-empty layers, but rootfs contains 2 diff_ids"
+        );
+        assert!(valid.is_ok());
+        assert!(no_history.is_ok());
+        assert!(invalid_type.is_err());
+    }
+
+    #[test]
+    fn normalize_env_and_labels() {
+        // arrange
+        let mut config = create_config();
+        let mut labels = HashMap::new();
+        labels.insert(" version ".to_owned(), "1".to_owned());
+        labels.insert("name".to_owned(), "app".to_owned());
+        labels.insert("name\t".to_owned(), "other".to_owned());
+        labels.insert("tier ".to_owned(), "backend".to_owned());
+        labels.insert(" tier".to_owned(), "frontend".to_owned());
+        let execution = config.config.as_mut().unwrap();
+        execution
+            .env
+            .as_mut()
+            .unwrap()
+            .push("FOO=override".to_owned());
+        execution.labels = Some(labels);
+
+        // act
+        let report = config.normalize();
+        let second = config.normalize();
+
+        // assert
+        assert_eq!(report.removed_env, vec!["FOO=oci_is_a".to_owned()]);
+        assert_eq!(
+            report.trimmed_labels,
+            vec![
+                " tier".to_owned(),
+                " version ".to_owned(),
+                "name\t".to_owned(),
+                "tier ".to_owned()
+            ]
+        );
+        let execution = config.config.as_ref().unwrap();
+        assert_eq!(
+            execution.env.as_ref().unwrap()[1..],
+            [
+                "BAR=well_written_spec".to_owned(),
+                "FOO=override".to_owned()
+            ]
+        );
+        let labels = execution.labels.as_ref().unwrap();
+        assert_eq!(labels.len(), 3);
+        assert_eq!(labels["tier"], "frontend");
+        assert_eq!(labels["version"], "1");
+        assert_eq!(labels["name"], "app");
+        assert!(second.is_empty());
+    }
+
+    #[test]
+    fn mutate_configuration() {
+        // arrange
+        let mut config = ImageConfiguration::default();
+        let diff_id: DiffId =
+            "sha256:5f70bf18a086007016e948b04aed3b82103a36bea41755b6cddfaf10ace3c6ef"
+                .parse()
+                .expect("parse diff id");
+
+        // act
+        config.add_env("PATH", "/bin");
+        config.add_env("FOO", "bar");
+        config.add_env("PATH", "/usr/bin:/bin");
+        let previous = config.set_label("version", "1");
+        let replaced = config.set_label("version", "2");
+        config.set_entrypoint(vec!["/bin/app".to_owned()]);
+        config.add_layer(diff_id.clone(), History::default());
+        config.add_history_entry(History {
+            empty_layer: Some(true),
+            ..Default::default()
+        });
+
+        // assert
+        let execution = config.config.as_ref().unwrap();
+        assert_eq!(
+            execution.env,
+            Some(vec!["PATH=/usr/bin:/bin".to_owned(), "FOO=bar".to_owned()])
+        );
+        assert_eq!(previous, None);
+        assert_eq!(replaced, Some("1".to_owned()));
+        assert_eq!(execution.labels.as_ref().unwrap()["version"], "2");
+        assert_eq!(execution.entrypoint, Some(vec!["/bin/app".to_owned()]));
+        assert_eq!(config.rootfs.diff_ids, vec![diff_id]);
+        assert_eq!(config.history.len(), 2);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "timestamps")]
+    fn parse_created_timestamps() {
+        // arrange
+        let config = create_config();
+
+        // act
+        let created = config.created_parsed().expect("parse created");
+        let history_created = config.history[1].created_parsed().expect("parse created");
+
+        // assert
+        assert_eq!(
+            created.map(|t| format_timestamp(&t)).as_deref(),
+            Some("2015-10-31T22:22:56.015925234Z")
+        );
+        assert!(history_created < created);
+    }
+
+    #[test]
+    #[cfg(feature = "timestamps")]
+    fn clamp_created_timestamps() {
+        // arrange
+        let mut config = create_config();
+        let max = parse_timestamp("2015-10-31T22:22:55Z").expect("parse max");
+
+        // act
+        config.clamp_created(max).expect("clamp created");
+
+        // assert
+        assert_eq!(config.created.as_deref(), Some("2015-10-31T22:22:55Z"));
+        assert_eq!(
+            config.history[0].created.as_deref(),
+            Some("2015-10-31T22:22:54.690851953Z")
+        );
+        assert_eq!(
+            config.history[1].created.as_deref(),
+            Some("2015-10-31T22:22:55Z")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "timestamps")]
+    fn parse_invalid_timestamp() {
+        let history = History {
+            created: Some("yesterday".to_owned()),
+            ..Default::default()
+        };
+        assert!(history.created_parsed().is_err());
+    }
+
     #[test]
     fn save_config_to_writer() {
         // arrange
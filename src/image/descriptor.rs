@@ -1,19 +1,35 @@
-use std::collections::HashMap;
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 
 use serde::{Deserialize, Serialize};
 
 use super::{Arch, MediaType, Os};
+use crate::error::{oci_error, OciSpecError, Result};
 
 make_pub!(
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// A Content Descriptor (or simply Descriptor) describes the disposition of
     /// the targeted content. It includes the type of the content, a content
@@ -32,6 +48,7 @@ make_pub!(
         /// content SHOULD be verified against this digest when consumed via
         /// untrusted sources.
         #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzz::digest))]
         digest: String,
         /// This REQUIRED property specifies the size, in bytes, of the raw
         /// content. This property exists so that a client will have an
@@ -39,6 +56,7 @@ make_pub!(
         /// length of the retrieved content does not match the specified
         /// length, the content SHOULD NOT be trusted.
         #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzz::size))]
         size: i64,
         /// This OPTIONAL property specifies a list of URIs from which this
         /// object MAY be downloaded. Each entry MUST conform to [RFC 3986](https://tools.ietf.org/html/rfc3986).
@@ -59,20 +77,34 @@ make_pub!(
         #[serde(skip_serializing_if = "Option::is_none")]
         #[cfg_attr(feature = "builder", getset(get = "pub"), builder(default))]
         platform: Option<Platform>,
+        /// This OPTIONAL property contains the type of an artifact when the
+        /// descriptor points to an artifact. This is the value of the config
+        /// descriptor `mediaType` when the descriptor references an image
+        /// manifest.
+        #[serde(rename = "artifactType", skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"), builder(default))]
+        artifact_type: Option<String>,
     }
 );
 
 make_pub!(
-    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get = "pub")
+        getset(get = "pub", get_mut = "pub", set = "pub")
     )]
     /// Describes the minimum runtime requirements of the image.
     struct Platform {
@@ -127,6 +159,180 @@ impl Default for Platform {
     }
 }
 
+impl Platform {
+    /// Returns the platform this crate has been compiled for. On 32 bit ARM
+    /// the variant is derived from the enabled target features.
+    pub fn current() -> Platform {
+        let architecture = Arch::current();
+        let variant = match architecture {
+            Arch::ARM if cfg!(target_feature = "v7") => Some("v7".to_owned()),
+            Arch::ARM if cfg!(target_feature = "v6") => Some("v6".to_owned()),
+            Arch::ARM => Some("v5".to_owned()),
+            _ => None,
+        };
+
+        Platform {
+            architecture,
+            os: Os::current(),
+            variant,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a normalized copy of the platform, following the rules of
+    /// containerd's platforms package. Common aliases of architectures and
+    /// operating systems are resolved (e.g. `x86_64` to `amd64`, `aarch64` to
+    /// `arm64`, `armhf` to `arm/v7`) and variants are brought into their
+    /// canonical form: `arm64/v8` is represented without a variant and `arm`
+    /// without a variant means `arm/v7`.
+    pub fn normalize(&self) -> Platform {
+        let os = match &self.os {
+            Os::Other(os) => match os.to_lowercase().as_str() {
+                "macos" => Os::Darwin,
+                os => Os::from(os),
+            },
+            os => os.clone(),
+        };
+
+        let variant = self.variant.as_deref().unwrap_or("");
+        let (architecture, variant) = match &self.architecture {
+            Arch::Other(arch) => match arch.to_lowercase().as_str() {
+                "i386" => (Arch::i386, ""),
+                "x86_64" | "x86-64" => (Arch::Amd64, variant),
+                "aarch64" => (Arch::ARM64, variant),
+                "armhf" => (Arch::ARM, "v7"),
+                "armel" => (Arch::ARM, "v6"),
+                arch => (Arch::from(arch), variant),
+            },
+            arch => (arch.clone(), variant),
+        };
+        let variant = match architecture {
+            Arch::Amd64 => match variant {
+                "v1" => String::new(),
+                variant => variant.to_owned(),
+            },
+            Arch::ARM64 => match variant {
+                "8" | "v8" | "v8.0" => String::new(),
+                variant if variant.starts_with(char::is_numeric) => format!("v{}", variant),
+                variant => variant.to_owned(),
+            },
+            Arch::ARM => match variant {
+                "" | "7" => "v7".to_owned(),
+                "5" | "6" | "8" => format!("v{}", variant),
+                variant => variant.to_owned(),
+            },
+            _ => variant.to_owned(),
+        };
+
+        Platform {
+            architecture,
+            os,
+            os_version: self.os_version.clone(),
+            os_features: self.os_features.clone(),
+            variant: Some(variant).filter(|v| !v.is_empty()),
+        }
+    }
+
+    /// Returns the normalized platforms which can run on this platform,
+    /// ordered from the most to the least preferred one. This is the order in
+    /// which manifests of an image index should be considered when selecting
+    /// an image for this platform, e.g. `arm/v7` can run `arm/v7`, `arm/v6`
+    /// and `arm/v5` images and `arm64` can additionally run 32 bit `arm`
+    /// images.
+    pub fn compatible_platforms(&self) -> Vec<Platform> {
+        let platform = self.normalize();
+        let with = |architecture: Arch, variant: Option<String>| Platform {
+            architecture,
+            variant,
+            ..platform.clone()
+        };
+        let version = |prefix: &str| -> Option<u32> {
+            platform
+                .variant
+                .as_deref()
+                .and_then(|v| v.strip_prefix(prefix))
+                .and_then(|v| v.parse().ok())
+        };
+
+        let mut platforms = vec![platform.clone()];
+        match platform.architecture {
+            Arch::Amd64 => {
+                if let Some(level) = version("v") {
+                    for level in (2..level).rev() {
+                        platforms.push(with(Arch::Amd64, Some(format!("v{}", level))));
+                    }
+                    if level > 1 {
+                        platforms.push(with(Arch::Amd64, None));
+                    }
+                }
+                platforms.push(with(Arch::i386, None));
+            }
+            Arch::ARM64 => {
+                if platform.variant.is_some() {
+                    platforms.push(with(Arch::ARM64, None));
+                }
+                for level in (5..=8).rev() {
+                    platforms.push(with(Arch::ARM, Some(format!("v{}", level))));
+                }
+            }
+            Arch::ARM => {
+                if let Some(level) = version("v") {
+                    for level in (5..level).rev() {
+                        platforms.push(with(Arch::ARM, Some(format!("v{}", level))));
+                    }
+                }
+            }
+            _ => {}
+        }
+        platforms
+    }
+
+    /// Parses the `os.version` of a Windows platform. Returns `None` if the
+    /// platform is not a Windows platform or does not specify a version.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the version is not in the `major.minor.build[.revision]` format.
+    pub fn windows_os_version(&self) -> Result<Option<WindowsOsVersion>> {
+        match (&self.os, &self.os_version) {
+            (Os::Windows, Some(version)) => version.parse().map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns true if a Windows image built for the `image` platform can run
+    /// on this Windows host platform, i.e. the build of the host is greater
+    /// than or equal to the build of the image. The revision is not taken
+    /// into account. Images without an `os.version` can run on any Windows
+    /// host, whereas a host without an `os.version` can only run those.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if one of the versions cannot be parsed.
+    pub fn can_run_windows_image(&self, image: &Platform) -> Result<bool> {
+        if self.os != Os::Windows || image.os != Os::Windows {
+            return Ok(false);
+        }
+        Ok(
+            match (self.windows_os_version()?, image.windows_os_version()?) {
+                (_, None) => true,
+                (None, Some(_)) => false,
+                (Some(host), Some(image)) => {
+                    (host.major, host.minor, host.build) >= (image.major, image.minor, image.build)
+                }
+            },
+        )
+    }
+
+    /// Returns true if an image built for the `image` platform can run on this
+    /// platform. Both platforms are normalized before being compared and only
+    /// the operating system, architecture and variant are taken into account.
+    pub fn is_compatible(&self, image: &Platform) -> bool {
+        let image = image.normalize();
+        self.compatible_platforms().iter().any(|p| {
+            p.os == image.os && p.architecture == image.architecture && p.variant == image.variant
+        })
+    }
+}
+
 impl Descriptor {
     /// Construct a new descriptor with the required fields.
     pub fn new(media_type: MediaType, size: i64, digest: impl Into<String>) -> Self {
@@ -137,6 +343,373 @@ impl Descriptor {
             urls: Default::default(),
             annotations: Default::default(),
             platform: Default::default(),
+            artifact_type: Default::default(),
+        }
+    }
+}
+
+impl Hash for Descriptor {
+    /// Hashes all properties except the annotations, whose iteration order
+    /// is not stable. This is consistent with [Eq], as equal descriptors
+    /// have equal remaining properties.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.media_type.hash(state);
+        self.digest.hash(state);
+        self.size.hash(state);
+        self.urls.hash(state);
+        self.platform.hash(state);
+        self.artifact_type.hash(state);
+    }
+}
+
+impl PartialOrd for Descriptor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Descriptor {
+    /// Orders descriptors by their digest first, so that descriptors of the
+    /// same content are adjacent, and by their remaining properties after.
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn sorted(annotations: &Option<HashMap<String, String>>) -> Option<BTreeMap<&str, &str>> {
+            annotations.as_ref().map(|annotations| {
+                annotations
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v.as_str()))
+                    .collect()
+            })
         }
+
+        self.digest
+            .cmp(&other.digest)
+            .then_with(|| self.media_type.cmp(&other.media_type))
+            .then_with(|| self.size.cmp(&other.size))
+            .then_with(|| self.urls.cmp(&other.urls))
+            .then_with(|| self.platform.cmp(&other.platform))
+            .then_with(|| self.artifact_type.cmp(&other.artifact_type))
+            .then_with(|| sorted(&self.annotations).cmp(&sorted(&other.annotations)))
+    }
+}
+
+impl fmt::Display for Platform {
+    /// Formats the platform as `os/architecture[/variant]`, e.g.
+    /// `linux/arm64/v8`, as used by the `--platform` option of container
+    /// tools.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.os, self.architecture)?;
+        if let Some(variant) = &self.variant {
+            write!(f, "/{}", variant)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Platform {
+    type Err = OciSpecError;
+
+    /// Parses a platform in the form `os/architecture[/variant]`, e.g.
+    /// `linux/arm64/v8`.
+    fn from_str(platform: &str) -> Result<Self> {
+        let mut parts = platform.split('/');
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(os), Some(architecture), variant, None)
+                if !os.is_empty()
+                    && !architecture.is_empty()
+                    && variant.is_none_or(|v| !v.is_empty()) =>
+            {
+                Ok(Platform {
+                    architecture: architecture.into(),
+                    os: os.into(),
+                    variant: variant.map(ToOwned::to_owned),
+                    ..Default::default()
+                })
+            }
+            _ => Err(oci_error(format!(
+                "invalid platform {}: expected os/architecture[/variant]",
+                platform
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Descriptor {
+    /// Formats the descriptor as its digest shortened to 12 characters of the
+    /// encoded part and its media type, e.g.
+    /// `sha256:b5b2b2c507a0 (application/vnd.oci.image.config.v1+json)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.digest.split_once(':') {
+            Some((algorithm, encoded)) => {
+                write!(f, "{}:{}", algorithm, encoded.get(..12).unwrap_or(encoded))?
+            }
+            None => write!(f, "{}", self.digest)?,
+        }
+        write!(f, " ({})", self.media_type)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// The version of a Windows operating system as used in the `os.version`
+/// field of a platform, e.g. `10.0.17763.1879`. Versions are ordered by
+/// their components from major to revision.
+pub struct WindowsOsVersion {
+    /// The major version, e.g. `10`.
+    pub major: u32,
+    /// The minor version, e.g. `0`.
+    pub minor: u32,
+    /// The build number, e.g. `17763` for Windows Server 2019.
+    pub build: u32,
+    /// The update build revision, e.g. `1879`. Defaults to `0` if the version
+    /// does not contain a revision.
+    pub revision: u32,
+}
+
+impl FromStr for WindowsOsVersion {
+    type Err = OciSpecError;
+
+    fn from_str(version: &str) -> Result<Self> {
+        let parts = version
+            .split('.')
+            .map(|p| p.parse::<u32>())
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| oci_error(format!("invalid windows os version {}: {}", version, e)))?;
+
+        match parts.as_slice() {
+            [major, minor, build] => Ok(WindowsOsVersion {
+                major: *major,
+                minor: *minor,
+                build: *build,
+                revision: 0,
+            }),
+            [major, minor, build, revision] => Ok(WindowsOsVersion {
+                major: *major,
+                minor: *minor,
+                build: *build,
+                revision: *revision,
+            }),
+            _ => Err(oci_error(format!(
+                "invalid windows os version {}: expected major.minor.build[.revision]",
+                version
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for WindowsOsVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}.{}",
+            self.major, self.minor, self.build, self.revision
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn platform(os: &str, architecture: &str, variant: Option<&str>) -> Platform {
+        Platform {
+            architecture: architecture.into(),
+            os: os.into(),
+            variant: variant.map(ToOwned::to_owned),
+            ..Default::default()
+        }
+    }
+
+    fn names(platforms: &[Platform]) -> Vec<String> {
+        platforms.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn current_platform() {
+        let platform = Platform::current();
+        assert_eq!(platform.architecture, Arch::current());
+        assert_eq!(platform.os, Os::current());
+        assert!(platform.is_compatible(&platform));
+    }
+
+    #[test]
+    fn normalize_platform() {
+        let cases = [
+            (platform("linux", "aarch64", None), "linux/arm64"),
+            (platform("linux", "arm64", Some("v8")), "linux/arm64"),
+            (platform("linux", "arm64", Some("8.2")), "linux/arm64/v8.2"),
+            (platform("linux", "arm", None), "linux/arm/v7"),
+            (platform("linux", "arm", Some("6")), "linux/arm/v6"),
+            (platform("linux", "armhf", None), "linux/arm/v7"),
+            (platform("linux", "armel", None), "linux/arm/v6"),
+            (platform("Linux", "x86_64", None), "linux/amd64"),
+            (platform("linux", "amd64", Some("v1")), "linux/amd64"),
+            (platform("macOS", "i386", None), "darwin/386"),
+        ];
+        for (platform, expected) in cases.iter() {
+            assert_eq!(names(&[platform.normalize()]), vec![expected.to_string()]);
+        }
+    }
+
+    #[test]
+    fn compatible_platforms() {
+        assert_eq!(
+            names(&platform("linux", "arm", Some("v7")).compatible_platforms()),
+            vec!["linux/arm/v7", "linux/arm/v6", "linux/arm/v5"]
+        );
+        assert_eq!(
+            names(&platform("linux", "aarch64", None).compatible_platforms()),
+            vec![
+                "linux/arm64",
+                "linux/arm/v8",
+                "linux/arm/v7",
+                "linux/arm/v6",
+                "linux/arm/v5"
+            ]
+        );
+        assert_eq!(
+            names(&platform("linux", "amd64", Some("v3")).compatible_platforms()),
+            vec![
+                "linux/amd64/v3",
+                "linux/amd64/v2",
+                "linux/amd64",
+                "linux/386"
+            ]
+        );
+        assert_eq!(
+            names(&platform("linux", "s390x", None).compatible_platforms()),
+            vec!["linux/s390x"]
+        );
+    }
+
+    #[test]
+    fn platform_is_compatible() {
+        let host = platform("linux", "arm", Some("v6"));
+        assert!(host.is_compatible(&platform("linux", "armel", None)));
+        assert!(!host.is_compatible(&platform("linux", "arm", None)));
+
+        let host = platform("linux", "arm64", None);
+        assert!(host.is_compatible(&platform("linux", "arm64", Some("v8"))));
+        assert!(host.is_compatible(&platform("linux", "arm", Some("7"))));
+        assert!(!host.is_compatible(&platform("windows", "arm64", None)));
+    }
+
+    fn windows(os_version: Option<&str>) -> Platform {
+        Platform {
+            architecture: Arch::Amd64,
+            os: Os::Windows,
+            os_version: os_version.map(ToOwned::to_owned),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parse_windows_os_version() {
+        let version: WindowsOsVersion = "10.0.17763.1879".parse().unwrap();
+        assert_eq!(version.build, 17763);
+        assert_eq!(version.revision, 1879);
+        assert_eq!(version.to_string(), "10.0.17763.1879");
+        assert_eq!(
+            "10.0.20348"
+                .parse::<WindowsOsVersion>()
+                .unwrap()
+                .to_string(),
+            "10.0.20348.0"
+        );
+        assert!("10.0".parse::<WindowsOsVersion>().is_err());
+        assert!("10.0.x".parse::<WindowsOsVersion>().is_err());
+        assert!(
+            "10.0.17763.2000".parse::<WindowsOsVersion>().unwrap()
+                < "10.0.20348.1".parse::<WindowsOsVersion>().unwrap()
+        );
+    }
+
+    #[test]
+    fn windows_image_compatibility() {
+        let host = windows(Some("10.0.20348.169"));
+        assert!(host
+            .can_run_windows_image(&windows(Some("10.0.17763.1879")))
+            .unwrap());
+        assert!(host
+            .can_run_windows_image(&windows(Some("10.0.20348.2000")))
+            .unwrap());
+        assert!(!host
+            .can_run_windows_image(&windows(Some("10.0.26100.1")))
+            .unwrap());
+        assert!(host.can_run_windows_image(&windows(None)).unwrap());
+        assert!(!windows(None)
+            .can_run_windows_image(&windows(Some("10.0.17763.1")))
+            .unwrap());
+
+        let linux = Platform {
+            os: Os::Linux,
+            ..windows(None)
+        };
+        assert!(!host.can_run_windows_image(&linux).unwrap());
+        assert!(host.can_run_windows_image(&windows(Some("bad"))).is_err());
+    }
+
+    #[test]
+    fn display() {
+        // arrange
+        let descriptor = Descriptor::new(
+            MediaType::ImageConfig,
+            7023,
+            "sha256:b5b2b2c507a0944348e0303114d8d93aaaa081732b86451d9bce1f432a537bc7",
+        );
+
+        // assert
+        assert_eq!(
+            platform("linux", "arm64", Some("v8")).to_string(),
+            "linux/arm64/v8"
+        );
+        assert_eq!(
+            platform("windows", "amd64", None).to_string(),
+            "windows/amd64"
+        );
+        assert_eq!(
+            "linux/arm64/v8".parse::<Platform>().unwrap(),
+            platform("linux", "arm64", Some("v8"))
+        );
+        for invalid in ["linux", "linux/", "/amd64", "linux/arm/", "linux/arm/v7/x"] {
+            assert!(invalid.parse::<Platform>().is_err(), "{}", invalid);
+        }
+        assert_eq!(
+            descriptor.to_string(),
+            "sha256:b5b2b2c507a0 (application/vnd.oci.image.config.v1+json)"
+        );
+        assert_eq!(
+            Descriptor::new(MediaType::ImageLayer, 0, "sha256:abc").to_string(),
+            "sha256:abc (application/vnd.oci.image.layer.v1.tar)"
+        );
+    }
+
+    #[test]
+    fn descriptor_keys() {
+        use std::collections::{BTreeSet, HashSet};
+
+        // arrange
+        let config = Descriptor::new(MediaType::ImageConfig, 7023, "sha256:b5b2");
+        let mut annotated = Descriptor::new(MediaType::ImageLayer, 32, "sha256:9834");
+        annotated.annotations = Some(HashMap::from([
+            ("a".to_owned(), "1".to_owned()),
+            ("b".to_owned(), "2".to_owned()),
+        ]));
+        let descriptors = vec![annotated.clone(), config.clone(), annotated.clone(), config];
+
+        // act
+        let hashed: HashSet<_> = descriptors.iter().cloned().collect();
+        let ordered: BTreeSet<_> = descriptors.into_iter().collect();
+
+        // assert
+        assert_eq!(hashed.len(), 2);
+        assert_eq!(ordered.len(), 2);
+        assert_eq!(ordered.iter().next().unwrap().digest, "sha256:9834");
+        let mut other = annotated.clone();
+        other
+            .annotations
+            .as_mut()
+            .unwrap()
+            .insert("c".to_owned(), "3".to_owned());
+        assert_eq!(annotated.cmp(&other), Ordering::Less);
+        assert!(platform("linux", "amd64", None) < platform("linux", "amd64", Some("v2")));
     }
 }
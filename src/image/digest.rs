@@ -0,0 +1,290 @@
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display},
+    io::{self, Read, Write},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+
+use crate::error::{oci_error, OciSpecError, Result};
+
+/// The digest algorithm used by default to address content.
+pub const DIGEST_ALGORITHM_SHA256: &str = "sha256";
+
+/// A content identifier as defined by the OCI image spec in the form
+/// `algorithm:encoded`, e.g. `sha256:6c3c624b58dbbcd3c0dd82b4c53f04194d1247c6eebdaab7c610cf7d66709b3b`.
+/// The encoded part of the registered algorithms `sha256` and `sha512` is
+/// validated to be a lowercase hex string of the correct length.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "String", into = "String")]
+pub struct Digest {
+    digest: String,
+    separator: usize,
+}
+
+impl Digest {
+    /// Computes the sha256 digest of the content read from the reader.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the content cannot be read.
+    pub fn sha256_from_reader<R: Read>(mut reader: R) -> Result<Digest> {
+        let mut writer = DigestWriter::new(io::sink());
+        io::copy(&mut reader, &mut writer)?;
+        Ok(writer.finish().1)
+    }
+
+    /// Returns the algorithm of the digest, e.g. `sha256`.
+    pub fn algorithm(&self) -> &str {
+        &self.digest[..self.separator]
+    }
+
+    /// Returns the encoded part of the digest, e.g. the hex encoded hash for
+    /// `sha256`.
+    pub fn encoded(&self) -> &str {
+        &self.digest[self.separator + 1..]
+    }
+
+    /// Returns the digest as string.
+    pub fn as_str(&self) -> &str {
+        &self.digest
+    }
+}
+
+impl FromStr for Digest {
+    type Err = OciSpecError;
+
+    fn from_str(digest: &str) -> Result<Self> {
+        let invalid = |reason: &str| oci_error(format!("invalid digest {}: {}", digest, reason));
+        let separator = digest
+            .find(':')
+            .ok_or_else(|| invalid("missing algorithm"))?;
+        let (algorithm, encoded) = (&digest[..separator], &digest[separator + 1..]);
+
+        let valid_algorithm = algorithm.split(['+', '.', '_', '-']).all(|c| {
+            !c.is_empty()
+                && c.bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        });
+        if !valid_algorithm {
+            return Err(invalid("invalid algorithm"));
+        }
+
+        let valid_encoded = !encoded.is_empty()
+            && encoded
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'=' | b'_' | b'-'));
+        if !valid_encoded {
+            return Err(invalid("invalid encoding"));
+        }
+
+        let hex_len = match algorithm {
+            "sha256" => Some(64),
+            "sha512" => Some(128),
+            _ => None,
+        };
+        if let Some(len) = hex_len {
+            let lower_hex = encoded
+                .bytes()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b));
+            if encoded.len() != len || !lower_hex {
+                return Err(invalid("invalid hash"));
+            }
+        }
+
+        Ok(Digest {
+            digest: digest.to_owned(),
+            separator,
+        })
+    }
+}
+
+impl TryFrom<String> for Digest {
+    type Error = OciSpecError;
+
+    fn try_from(digest: String) -> Result<Self> {
+        digest.parse()
+    }
+}
+
+impl From<Digest> for String {
+    fn from(digest: Digest) -> Self {
+        digest.digest
+    }
+}
+
+impl Display for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.digest)
+    }
+}
+
+impl AsRef<str> for Digest {
+    fn as_ref(&self) -> &str {
+        &self.digest
+    }
+}
+
+/// The digest of an uncompressed layer tar archive as listed in the
+/// `rootfs.diff_ids` of an image configuration. DiffIDs are intentionally a
+/// distinct type, because they differ from the digests of the (usually
+/// compressed) layer blobs referenced by the image manifest.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct DiffId(Digest);
+
+impl DiffId {
+    /// Computes the DiffID of an uncompressed layer tar archive.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the archive cannot be read.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::image::DiffId;
+    /// use std::fs::File;
+    ///
+    /// let layer = File::open("layer.tar").unwrap();
+    /// let diff_id = DiffId::from_tar_reader(layer).unwrap();
+    /// ```
+    pub fn from_tar_reader<R: Read>(reader: R) -> Result<DiffId> {
+        Digest::sha256_from_reader(reader).map(DiffId)
+    }
+
+    /// Returns the underlying digest.
+    pub fn digest(&self) -> &Digest {
+        &self.0
+    }
+}
+
+impl From<Digest> for DiffId {
+    fn from(digest: Digest) -> Self {
+        DiffId(digest)
+    }
+}
+
+impl From<DiffId> for Digest {
+    fn from(diff_id: DiffId) -> Self {
+        diff_id.0
+    }
+}
+
+impl FromStr for DiffId {
+    type Err = OciSpecError;
+
+    fn from_str(diff_id: &str) -> Result<Self> {
+        diff_id.parse().map(DiffId)
+    }
+}
+
+impl Display for DiffId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Computes the sha256 digest and size of the content written through it.
+pub(crate) struct DigestWriter<W: Write> {
+    inner: W,
+    hasher: Sha256,
+    size: u64,
+}
+
+impl<W: Write> DigestWriter<W> {
+    pub(crate) fn new(inner: W) -> Self {
+        DigestWriter {
+            inner,
+            hasher: Sha256::new(),
+            size: 0,
+        }
+    }
+
+    /// Returns the inner writer together with the digest and size of the
+    /// written content.
+    pub(crate) fn finish(self) -> (W, Digest, u64) {
+        let encoded: String = self
+            .hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        let digest = Digest {
+            separator: DIGEST_ALGORITHM_SHA256.len(),
+            digest: format!("{}:{}", DIGEST_ALGORITHM_SHA256, encoded),
+        };
+        (self.inner, digest, self.size)
+    }
+}
+
+impl<W: Write> Write for DigestWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EMPTY_SHA256: &str =
+        "sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+    #[test]
+    fn parse_digest() {
+        let digest: Digest = EMPTY_SHA256.parse().expect("parse digest");
+        assert_eq!(digest.algorithm(), "sha256");
+        assert_eq!(&digest.encoded()[..8], "e3b0c442");
+        assert_eq!(digest.to_string(), EMPTY_SHA256);
+
+        let digest: Digest = "multihash+base58:QmRZxt2b1FVZPNqd8hsiykDL3TdBDeTSPX9Kv46HmX4Gx8"
+            .parse()
+            .expect("parse digest");
+        assert_eq!(digest.algorithm(), "multihash+base58");
+
+        for invalid in &[
+            "",
+            "sha256",
+            "sha256:",
+            ":abc",
+            "SHA256:abc",
+            "sha256:abc",
+            "sha256:E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855",
+            "foo:bar:baz",
+        ] {
+            assert!(invalid.parse::<Digest>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn serde_digest() {
+        let json = format!("\"{}\"", EMPTY_SHA256);
+        let digest: Digest = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(serde_json::to_string(&digest).expect("serialize"), json);
+        assert!(serde_json::from_str::<Digest>("\"sha256:abc\"").is_err());
+
+        let diff_id: DiffId = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(diff_id.digest(), &digest);
+    }
+
+    #[test]
+    fn compute_diff_id() {
+        let diff_id = DiffId::from_tar_reader(&b""[..]).expect("compute diff id");
+        assert_eq!(diff_id.to_string(), EMPTY_SHA256);
+
+        let diff_id = DiffId::from_tar_reader(&b"hello world"[..]).expect("compute diff id");
+        assert_eq!(
+            diff_id.to_string(),
+            "sha256:b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+    }
+}
@@ -0,0 +1,104 @@
+//! Annotations of eStargz layers, which allow snapshotters to lazily pull
+//! the files of a layer through its table of contents (TOC).
+
+use std::collections::HashMap;
+
+use super::{Descriptor, Digest};
+use crate::error::{oci_error, Result};
+
+/// AnnotationEstargzTocDigest is the annotation key for the digest of the
+/// table of contents of an eStargz layer.
+pub const ANNOTATION_ESTARGZ_TOC_DIGEST: &str = "containerd.io/snapshot/stargz/toc.digest";
+
+/// AnnotationEstargzUncompressedSize is the annotation key for the size of
+/// an eStargz layer after decompression, as a decimal string.
+pub const ANNOTATION_ESTARGZ_UNCOMPRESSED_SIZE: &str = "io.containers.estargz.uncompressed-size";
+
+impl Descriptor {
+    /// Returns true if the descriptor is annotated as an eStargz layer, i.e.
+    /// it has a TOC digest.
+    pub fn is_estargz(&self) -> bool {
+        self.annotations
+            .as_ref()
+            .is_some_and(|annotations| annotations.contains_key(ANNOTATION_ESTARGZ_TOC_DIGEST))
+    }
+
+    /// Returns the digest of the table of contents of an eStargz layer.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the annotation is not a valid digest.
+    pub fn estargz_toc_digest(&self) -> Result<Option<Digest>> {
+        self.annotations
+            .as_ref()
+            .and_then(|annotations| annotations.get(ANNOTATION_ESTARGZ_TOC_DIGEST))
+            .map(|digest| digest.parse())
+            .transpose()
+    }
+
+    /// Returns the uncompressed size of an eStargz layer.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the annotation is not a non-negative integer.
+    pub fn estargz_uncompressed_size(&self) -> Result<Option<u64>> {
+        self.annotations
+            .as_ref()
+            .and_then(|annotations| annotations.get(ANNOTATION_ESTARGZ_UNCOMPRESSED_SIZE))
+            .map(|size| {
+                size.parse().map_err(|_| {
+                    oci_error(format!(
+                        "invalid value {} for annotation {}",
+                        size, ANNOTATION_ESTARGZ_UNCOMPRESSED_SIZE
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    /// Annotates the descriptor as an eStargz layer with the digest of its
+    /// table of contents and its uncompressed size.
+    pub fn set_estargz(&mut self, toc_digest: &Digest, uncompressed_size: u64) {
+        let annotations = self.annotations.get_or_insert_with(HashMap::new);
+        annotations.insert(
+            ANNOTATION_ESTARGZ_TOC_DIGEST.to_owned(),
+            toc_digest.to_string(),
+        );
+        annotations.insert(
+            ANNOTATION_ESTARGZ_UNCOMPRESSED_SIZE.to_owned(),
+            uncompressed_size.to_string(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::image::MediaType;
+
+    #[test]
+    fn estargz_annotations() {
+        // arrange
+        let mut layer = Descriptor::new(
+            MediaType::ImageLayerGzip,
+            32654,
+            "sha256:9834876dcfb05cb167a5c24953eba58c4ac89b1adf57f28f2f9d09af107ee8f0",
+        );
+        let toc: Digest = "sha256:3c3a4604a545cdc127456d94e421cd355bca5b528f4a9c1905b15da2eb4a4c6b"
+            .parse()
+            .expect("parse digest");
+
+        // act
+        let plain = layer.is_estargz();
+        layer.set_estargz(&toc, 81920);
+
+        // assert
+        assert!(!plain);
+        assert!(layer.is_estargz());
+        assert_eq!(layer.estargz_toc_digest().unwrap(), Some(toc));
+        assert_eq!(layer.estargz_uncompressed_size().unwrap(), Some(81920));
+        layer.annotations.as_mut().unwrap().insert(
+            ANNOTATION_ESTARGZ_UNCOMPRESSED_SIZE.to_owned(),
+            "-1".to_owned(),
+        );
+        assert!(layer.estargz_uncompressed_size().is_err());
+    }
+}
@@ -1,10 +1,13 @@
-use super::{Descriptor, MediaType};
-use crate::{error::Result, from_file, from_reader, to_file, to_writer};
+use super::{Descriptor, MediaType, ANNOTATION_REF_NAME};
+use crate::{error::Result, from_reader, to_writer};
+#[cfg(has_fs)]
+use crate::{from_file, to_file};
 use serde::{Deserialize, Serialize};
+#[cfg(has_fs)]
+use std::path::Path;
 use std::{
     collections::HashMap,
     io::{Read, Write},
-    path::Path,
 };
 
 /// The expected schema version; equals 2 for compatibility with older versions of Docker.
@@ -12,15 +15,24 @@ pub const SCHEMA_VERSION: u32 = 2;
 
 make_pub!(
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// The image index is a higher-level manifest which points to specific
     /// image manifests, ideal for one or more platforms. While the use of
@@ -45,15 +57,36 @@ make_pub!(
         /// the array MAY be zero.
         #[cfg_attr(feature = "builder", getset(get = "pub"))]
         manifests: Vec<Descriptor>,
+        /// This OPTIONAL property contains the type of an artifact when the
+        /// index is used for an artifact.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"), builder(default))]
+        artifact_type: Option<String>,
+        /// This OPTIONAL property specifies a descriptor of another manifest.
+        /// This value defines a weak association to a separate manifest in
+        /// the repository, e.g. the image which a signed index refers to.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"), builder(default))]
+        subject: Option<Descriptor>,
         /// This OPTIONAL property contains arbitrary metadata for the image
         /// index. This OPTIONAL property MUST use the annotation rules.
         #[serde(skip_serializing_if = "Option::is_none")]
         #[cfg_attr(feature = "builder", getset(get = "pub"), builder(default))]
         annotations: Option<HashMap<String, String>>,
+
+        #[cfg_attr(feature = "lossless", serde(flatten))]
+        #[cfg_attr(not(feature = "lossless"), serde(skip))]
+        #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+        #[cfg_attr(feature = "builder", getset(get = "pub"), builder(default))]
+        /// Properties of the index which are not defined by the image
+        /// specification. With the `lossless` feature they are written back
+        /// as they were read, otherwise they are ignored.
+        extensions: HashMap<String, serde_json::Value>,
     }
 );
 
 impl ImageIndex {
+    #[cfg(has_fs)]
     /// Attempts to load an image index from a file.
     /// # Errors
     /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
@@ -86,6 +119,7 @@ impl ImageIndex {
         from_reader(reader)
     }
 
+    #[cfg(has_fs)]
     /// Attempts to write an image index to a file as JSON. If the file already exists, it
     /// will be overwritten.
     /// # Errors
@@ -102,6 +136,7 @@ impl ImageIndex {
         to_file(&self, path, false)
     }
 
+    #[cfg(has_fs)]
     /// Attempts to write an image index to a file as pretty printed JSON. If the file
     /// already exists, it will be overwritten.
     /// # Errors
@@ -149,6 +184,106 @@ impl ImageIndex {
     pub fn to_writer_pretty<W: Write>(&self, writer: &mut W) -> Result<()> {
         to_writer(&self, writer, true)
     }
+
+    /// Pins the manifests of the image index, which are referenced by a tag in
+    /// their `org.opencontainers.image.ref.name` annotation, to the digest the
+    /// tag currently resolves to. The resolver is called for every tagged
+    /// manifest and returns the descriptor of the content the tag points to,
+    /// or `None` if the tag cannot be resolved. The digest, size and media
+    /// type of the resolved descriptor replace those of the manifest, whereas
+    /// its annotations and platform are kept. The pinned image index is
+    /// returned together with a report of the changes.
+    /// # Errors
+    /// Errors returned by the resolver are passed through.
+    /// # Example
+    /// ```
+    /// use oci_spec::image::{Descriptor, ImageIndex, MediaType};
+    ///
+    /// let image_index = ImageIndex::from_reader(r#"{
+    ///     "schemaVersion": 2,
+    ///     "manifests": [{
+    ///         "mediaType": "application/vnd.oci.image.manifest.v1+json",
+    ///         "digest": "sha256:e692418e4cbaf90ca69d05a66403747baa33ee08806650b51fab815ad7fc331f",
+    ///         "size": 7143,
+    ///         "annotations": { "org.opencontainers.image.ref.name": "v1" }
+    ///     }]
+    /// }"#.as_bytes()).unwrap();
+    ///
+    /// let digest = "sha256:5b0bcabd1ed22e9fb1310cf6c2dec7cdef19f0ad69efa1f392e94a4333501270";
+    /// let (pinned, report) = image_index
+    ///     .pin_digests(|tag| {
+    ///         assert_eq!(tag, "v1");
+    ///         Ok(Some(Descriptor::new(MediaType::ImageManifest, 7682, digest)))
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(report.pinned.len(), 1);
+    /// assert_eq!(report.pinned[0].reference, "v1");
+    /// assert_eq!(report.pinned[0].digest, digest);
+    /// assert!(report.unresolved.is_empty());
+    ///
+    /// let manifest = &serde_json::to_value(&pinned).unwrap()["manifests"][0];
+    /// assert_eq!(manifest["digest"], digest);
+    /// assert_eq!(manifest["size"], 7682);
+    /// assert_eq!(manifest["annotations"]["org.opencontainers.image.ref.name"], "v1");
+    /// ```
+    pub fn pin_digests<F>(&self, mut resolver: F) -> Result<(ImageIndex, DigestPinReport)>
+    where
+        F: FnMut(&str) -> Result<Option<Descriptor>>,
+    {
+        let mut index = self.clone();
+        let mut report = DigestPinReport::default();
+
+        for manifest in &mut index.manifests {
+            let reference = match manifest
+                .annotations
+                .as_ref()
+                .and_then(|a| a.get(ANNOTATION_REF_NAME))
+            {
+                Some(reference) => reference.clone(),
+                None => continue,
+            };
+
+            match resolver(&reference)? {
+                Some(resolved) => {
+                    if resolved.digest != manifest.digest {
+                        report.pinned.push(PinnedDigest {
+                            reference,
+                            previous: manifest.digest.clone(),
+                            digest: resolved.digest.clone(),
+                        });
+                    }
+                    manifest.digest = resolved.digest;
+                    manifest.size = resolved.size;
+                    manifest.media_type = resolved.media_type;
+                }
+                None => report.unresolved.push(reference),
+            }
+        }
+
+        Ok((index, report))
+    }
+}
+
+/// Report of the changes made by [ImageIndex::pin_digests].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DigestPinReport {
+    /// The manifests whose digest has been changed.
+    pub pinned: Vec<PinnedDigest>,
+    /// The tags which could not be resolved and have been left untouched.
+    pub unresolved: Vec<String>,
+}
+
+/// A manifest of an image index whose digest has been pinned to the content
+/// its tag resolves to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PinnedDigest {
+    /// The tag of the manifest.
+    pub reference: String,
+    /// The digest of the manifest before pinning.
+    pub previous: String,
+    /// The digest the tag resolved to.
+    pub digest: String,
 }
 
 impl Default for ImageIndex {
@@ -157,11 +292,137 @@ impl Default for ImageIndex {
             schema_version: SCHEMA_VERSION,
             media_type: Default::default(),
             manifests: Default::default(),
+            artifact_type: Default::default(),
+            subject: Default::default(),
             annotations: Default::default(),
+            extensions: Default::default(),
         }
     }
 }
 
+/// Writes an image index to a stream one manifest descriptor at a time. This
+/// allows generating very large indexes (e.g. synthetic referrers indexes)
+/// without holding all descriptors in memory. The produced document is
+/// identical to the output of [ImageIndex::to_writer] for the same content.
+pub struct ImageIndexWriter<W: Write> {
+    writer: W,
+    artifact_type: Option<String>,
+    subject: Option<Descriptor>,
+    annotations: Option<HashMap<String, String>>,
+    count: usize,
+}
+
+impl<W: Write> ImageIndexWriter<W> {
+    /// Starts a new image index on the provided stream by writing the schema
+    /// version, the optional media type and the opening of the manifests
+    /// array.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the stream cannot be written to.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::image::{Descriptor, ImageIndexWriter, MediaType};
+    /// use std::fs::File;
+    ///
+    /// let file = File::create("index.json").unwrap();
+    /// let mut writer = ImageIndexWriter::new(file, Some(MediaType::ImageIndex)).unwrap();
+    /// writer
+    ///     .append(&Descriptor::new(
+    ///         MediaType::ImageManifest,
+    ///         7143,
+    ///         "sha256:e692418e4cbaf90ca69d05a66403747baa33ee08806650b51fab815ad7fc331f",
+    ///     ))
+    ///     .unwrap();
+    /// writer.finish().unwrap();
+    /// ```
+    pub fn new(mut writer: W, media_type: Option<MediaType>) -> Result<Self> {
+        write!(writer, "{{\"schemaVersion\":{}", SCHEMA_VERSION)?;
+        if let Some(media_type) = media_type {
+            writer.write_all(b",\"mediaType\":")?;
+            serde_json::to_writer(&mut writer, &media_type)?;
+        }
+        writer.write_all(b",\"manifests\":[")?;
+
+        Ok(Self {
+            writer,
+            artifact_type: None,
+            subject: None,
+            annotations: None,
+            count: 0,
+        })
+    }
+
+    /// Appends a manifest descriptor to the index.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the stream cannot be written to or an
+    /// [OciSpecError::SerDe](crate::OciSpecError::SerDe) if the descriptor
+    /// cannot be serialized.
+    pub fn append(&mut self, descriptor: &Descriptor) -> Result<()> {
+        if self.count > 0 {
+            self.writer.write_all(b",")?;
+        }
+        serde_json::to_writer(&mut self.writer, descriptor)?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Sets the artifact type of the image index. It is written after the
+    /// manifests when the index is finished.
+    pub fn set_artifact_type(&mut self, artifact_type: String) {
+        self.artifact_type = Some(artifact_type);
+    }
+
+    /// Sets the subject of the image index. It is written after the
+    /// manifests when the index is finished.
+    pub fn set_subject(&mut self, subject: Descriptor) {
+        self.subject = Some(subject);
+    }
+
+    /// Sets the annotations of the image index. They are written after the
+    /// manifests when the index is finished.
+    pub fn set_annotations(&mut self, annotations: HashMap<String, String>) {
+        self.annotations = Some(annotations);
+    }
+
+    /// Returns the number of manifest descriptors written so far.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns true if no manifest descriptor has been written yet.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Closes the manifests array, writes the artifact type, the subject and
+    /// the annotations and completes the image index. The underlying stream
+    /// is returned to the caller.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the stream cannot be written to or an
+    /// [OciSpecError::SerDe](crate::OciSpecError::SerDe) if the subject or
+    /// the annotations cannot be serialized.
+    pub fn finish(mut self) -> Result<W> {
+        self.writer.write_all(b"]")?;
+        if let Some(artifact_type) = &self.artifact_type {
+            self.writer.write_all(b",\"artifactType\":")?;
+            serde_json::to_writer(&mut self.writer, artifact_type)?;
+        }
+        if let Some(subject) = &self.subject {
+            self.writer.write_all(b",\"subject\":")?;
+            serde_json::to_writer(&mut self.writer, subject)?;
+        }
+        if let Some(annotations) = &self.annotations {
+            self.writer.write_all(b",\"annotations\":")?;
+            serde_json::to_writer(&mut self.writer, annotations)?;
+        }
+        self.writer.write_all(b"}")?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{fs, path::PathBuf};
@@ -203,13 +464,11 @@ mod tests {
             .build()
             .expect("build amd64 manifest descriptor");
 
-        let index = ImageIndexBuilder::default()
+        ImageIndexBuilder::default()
             .schema_version(SCHEMA_VERSION)
             .manifests(vec![ppc_manifest, amd64_manifest])
             .build()
-            .expect("build image index");
-
-        index
+            .expect("build image index")
     }
 
     #[cfg(not(feature = "builder"))]
@@ -244,16 +503,18 @@ mod tests {
                 os_features: None,
                 variant: None,
             }),
+            artifact_type: None,
         };
 
-        let index = ImageIndex {
+        ImageIndex {
             schema_version: SCHEMA_VERSION,
             media_type: None,
             manifests: vec![ppc_manifest, amd64_manifest],
+            artifact_type: None,
+            subject: None,
             annotations: None,
-        };
-
-        index
+            extensions: Default::default(),
+        }
     }
 
     fn get_index_path() -> PathBuf {
@@ -318,4 +579,92 @@ mod tests {
         let expected = fs::read(get_index_path()).expect("read expected");
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn stream_index_to_writer() {
+        // arrange
+        let index = create_index();
+        let mut annotations = HashMap::new();
+        annotations.insert("com.example.key".to_owned(), "value".to_owned());
+
+        // act
+        let mut writer =
+            ImageIndexWriter::new(Vec::new(), Some(MediaType::ImageIndex)).expect("new writer");
+        for manifest in index.manifests.iter() {
+            writer.append(manifest).expect("append descriptor");
+        }
+        let subject = index.manifests[0].clone();
+        writer.set_artifact_type("application/vnd.example.signature".to_owned());
+        writer.set_subject(subject.clone());
+        writer.set_annotations(annotations.clone());
+        assert_eq!(writer.len(), 2);
+        let actual = writer.finish().expect("finish writer");
+
+        // assert
+        let mut expected = Vec::new();
+        ImageIndex {
+            media_type: Some(MediaType::ImageIndex),
+            artifact_type: Some("application/vnd.example.signature".to_owned()),
+            subject: Some(subject),
+            annotations: Some(annotations),
+            ..index
+        }
+        .to_writer(&mut expected)
+        .expect("to writer");
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn stream_empty_index_to_writer() {
+        // arrange
+        let writer = ImageIndexWriter::new(Vec::new(), None).expect("new writer");
+        assert!(writer.is_empty());
+
+        // act
+        let actual = writer.finish().expect("finish writer");
+
+        // assert
+        let index = ImageIndex::from_reader(&*actual).expect("from reader");
+        assert_eq!(index, ImageIndex::default());
+    }
+
+    #[test]
+    fn pin_index_digests() {
+        // arrange
+        let mut index = create_index();
+        let mut annotations = HashMap::new();
+        annotations.insert(ANNOTATION_REF_NAME.to_owned(), "v1.0".to_owned());
+        index.manifests[0].annotations = Some(annotations.clone());
+        annotations.insert(ANNOTATION_REF_NAME.to_owned(), "latest".to_owned());
+        index.manifests[1].annotations = Some(annotations);
+        let pinned_digest =
+            "sha256:4e5f3a1b2c3d4e5f6a7b8c9d0e1f2a3b4c5d6e7f8a9b0c1d2e3f4a5b6c7d8e9f";
+
+        // act
+        let (pinned, report) = index
+            .pin_digests(|reference| match reference {
+                "v1.0" => Ok(Some(Descriptor::new(
+                    MediaType::ImageManifest,
+                    1234,
+                    pinned_digest,
+                ))),
+                _ => Ok(None),
+            })
+            .expect("pin digests");
+
+        // assert
+        assert_eq!(pinned.manifests[0].digest, pinned_digest);
+        assert_eq!(pinned.manifests[0].size, 1234);
+        assert_eq!(pinned.manifests[0].platform, index.manifests[0].platform);
+        assert_eq!(pinned.manifests[1], index.manifests[1]);
+        assert_eq!(
+            report.pinned,
+            vec![PinnedDigest {
+                reference: "v1.0".to_owned(),
+                previous: index.manifests[0].digest.clone(),
+                digest: pinned_digest.to_owned(),
+            }]
+        );
+        assert_eq!(report.unresolved, vec!["latest".to_owned()]);
+    }
 }
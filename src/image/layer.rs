@@ -0,0 +1,235 @@
+use std::io::{self, Read, Write};
+
+use super::{digest::DigestWriter, DiffId, Digest, MediaType};
+use crate::error::Result;
+
+#[cfg(not(feature = "builder"))]
+use super::Descriptor;
+#[cfg(feature = "builder")]
+use super::{Descriptor, DescriptorBuilder};
+
+/// The compression applied to a layer by the [LayerBuilder]. The available
+/// variants depend on the enabled `gzip` and `zstd` features, so the enum is
+/// not exhaustive.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Compression {
+    /// The layer is stored as plain tar archive.
+    #[default]
+    None,
+    /// The layer is compressed using gzip.
+    #[cfg(feature = "gzip")]
+    Gzip,
+    /// The layer is compressed using zstd.
+    #[cfg(feature = "zstd")]
+    Zstd,
+}
+
+impl Compression {
+    /// Returns the media type of a layer using the compression.
+    pub fn media_type(&self) -> MediaType {
+        match self {
+            Compression::None => MediaType::ImageLayer,
+            #[cfg(feature = "gzip")]
+            Compression::Gzip => MediaType::ImageLayerGzip,
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => MediaType::ImageLayerZstd,
+        }
+    }
+}
+
+/// Creates a layer blob from an uncompressed tar archive. The archive is
+/// streamed through the optional compression into a writer, while the
+/// [DiffId] of the archive and the digest and size of the written blob are
+/// computed on the fly.
+/// # Example
+/// ``` no_run
+/// use oci_spec::image::LayerBuilder;
+/// use std::fs::File;
+///
+/// let tar = File::open("layer.tar").unwrap();
+/// let blob = File::create("layer.blob").unwrap();
+/// let (descriptor, diff_id, _) = LayerBuilder::default().build(tar, blob).unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct LayerBuilder {
+    compression: Compression,
+    level: Option<i32>,
+}
+
+impl LayerBuilder {
+    /// Sets the compression of the layer, which defaults to none.
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Sets the compression level, which is interpreted by the chosen
+    /// compression. Defaults to the default level of the compression.
+    pub fn level(mut self, level: i32) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Writes the layer blob for the tar archive to the writer. Returns the
+    /// descriptor of the written blob, the DiffID of the tar archive for the
+    /// `rootfs` of the image configuration and the writer.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the archive cannot be read, compressed or written.
+    pub fn build<R: Read, W: Write>(
+        &self,
+        mut tar: R,
+        writer: W,
+    ) -> Result<(Descriptor, DiffId, W)> {
+        let mut encoder = DigestWriter::new(self.encoder(DigestWriter::new(writer))?);
+        io::copy(&mut tar, &mut encoder)?;
+        let (encoder, diff_id, _) = encoder.finish();
+        let (writer, digest, size) = encoder.finish()?.finish();
+
+        Ok((self.descriptor(digest, size)?, diff_id.into(), writer))
+    }
+
+    fn encoder<W: Write>(&self, writer: W) -> Result<Encoder<W>> {
+        Ok(match self.compression {
+            Compression::None => Encoder::None(writer),
+            #[cfg(feature = "gzip")]
+            Compression::Gzip => {
+                let level = match self.level {
+                    Some(level) => flate2::Compression::new(level.clamp(0, 9) as u32),
+                    None => flate2::Compression::default(),
+                };
+                Encoder::Gzip(flate2::write::GzEncoder::new(writer, level))
+            }
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => {
+                Encoder::Zstd(zstd::Encoder::new(writer, self.level.unwrap_or(0))?)
+            }
+        })
+    }
+
+    #[cfg(not(feature = "builder"))]
+    fn descriptor(&self, digest: Digest, size: u64) -> Result<Descriptor> {
+        Ok(Descriptor::new(
+            self.compression.media_type(),
+            size as i64,
+            digest.as_str(),
+        ))
+    }
+
+    #[cfg(feature = "builder")]
+    fn descriptor(&self, digest: Digest, size: u64) -> Result<Descriptor> {
+        DescriptorBuilder::default()
+            .media_type(self.compression.media_type())
+            .size(size as i64)
+            .digest(digest.as_str())
+            .build()
+    }
+}
+
+enum Encoder<W: Write> {
+    None(W),
+    #[cfg(feature = "gzip")]
+    Gzip(flate2::write::GzEncoder<W>),
+    #[cfg(feature = "zstd")]
+    Zstd(zstd::Encoder<'static, W>),
+}
+
+impl<W: Write> Encoder<W> {
+    fn finish(self) -> io::Result<W> {
+        match self {
+            Encoder::None(writer) => Ok(writer),
+            #[cfg(feature = "gzip")]
+            Encoder::Gzip(encoder) => encoder.finish(),
+            #[cfg(feature = "zstd")]
+            Encoder::Zstd(encoder) => encoder.finish(),
+        }
+    }
+}
+
+impl<W: Write> Write for Encoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Encoder::None(writer) => writer.write(buf),
+            #[cfg(feature = "gzip")]
+            Encoder::Gzip(encoder) => encoder.write(buf),
+            #[cfg(feature = "zstd")]
+            Encoder::Zstd(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Encoder::None(writer) => writer.flush(),
+            #[cfg(feature = "gzip")]
+            Encoder::Gzip(encoder) => encoder.flush(),
+            #[cfg(feature = "zstd")]
+            Encoder::Zstd(encoder) => encoder.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TAR: &[u8] = b"not really a tar archive, but good enough for hashing";
+
+    #[test]
+    fn build_uncompressed_layer() {
+        // act
+        let (descriptor, diff_id, blob) = LayerBuilder::default()
+            .build(TAR, Vec::new())
+            .expect("build layer");
+
+        // assert
+        let digest = Digest::sha256_from_reader(TAR).expect("digest");
+        assert_eq!(blob, TAR);
+        assert_eq!(descriptor.media_type, MediaType::ImageLayer);
+        assert_eq!(descriptor.size, TAR.len() as i64);
+        assert_eq!(descriptor.digest, digest.as_str());
+        assert_eq!(diff_id, DiffId::from(digest));
+    }
+
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn build_gzip_layer() {
+        // act
+        let (descriptor, diff_id, blob) = LayerBuilder::default()
+            .compression(Compression::Gzip)
+            .level(9)
+            .build(TAR, Vec::new())
+            .expect("build layer");
+
+        // assert
+        assert_eq!(descriptor.media_type, MediaType::ImageLayerGzip);
+        assert_eq!(descriptor.size, blob.len() as i64);
+        assert_eq!(
+            descriptor.digest,
+            Digest::sha256_from_reader(&*blob).unwrap().as_str()
+        );
+        assert_eq!(diff_id, DiffId::from_tar_reader(TAR).unwrap());
+
+        let mut decompressed = Vec::new();
+        flate2::read::GzDecoder::new(&*blob)
+            .read_to_end(&mut decompressed)
+            .expect("decompress");
+        assert_eq!(decompressed, TAR);
+    }
+
+    #[test]
+    #[cfg(feature = "zstd")]
+    fn build_zstd_layer() {
+        // act
+        let (descriptor, diff_id, blob) = LayerBuilder::default()
+            .compression(Compression::Zstd)
+            .build(TAR, Vec::new())
+            .expect("build layer");
+
+        // assert
+        assert_eq!(descriptor.media_type, MediaType::ImageLayerZstd);
+        assert_eq!(descriptor.size, blob.len() as i64);
+        assert_eq!(diff_id, DiffId::from_tar_reader(TAR).unwrap());
+        assert_eq!(zstd::decode_all(&*blob).expect("decompress"), TAR);
+    }
+}
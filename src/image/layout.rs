@@ -0,0 +1,579 @@
+#[cfg(has_fs)]
+use std::{
+    collections::HashSet,
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(has_fs)]
+use super::{Descriptor, Digest, ImageIndex, ImageManifest, MediaType, ANNOTATION_REF_NAME};
+#[cfg(has_fs)]
+use crate::{
+    error::{oci_error, Result},
+    from_file, to_file,
+};
+
+/// The name of the file marking the root of an image layout.
+pub const OCI_LAYOUT_FILE: &str = "oci-layout";
+
+/// The name of the image index file of an image layout.
+pub const INDEX_FILE: &str = "index.json";
+
+/// The name of the directory containing the blobs of an image layout.
+pub const BLOBS_DIR: &str = "blobs";
+
+/// The version of the image layout supported by this crate.
+pub const IMAGE_LAYOUT_VERSION: &str = "1.0.0";
+
+make_pub!(
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// The content of the `oci-layout` file, which marks the base of an image
+    /// layout and provides its version.
+    struct OciLayout {
+        /// The version of the image layout.
+        image_layout_version: String,
+    }
+);
+
+impl Default for OciLayout {
+    fn default() -> Self {
+        Self {
+            image_layout_version: IMAGE_LAYOUT_VERSION.to_owned(),
+        }
+    }
+}
+
+#[cfg(has_fs)]
+/// An image layout on the filesystem, which is a directory structure for
+/// content addressable blobs and location addressable references.
+///
+/// # Locking
+/// With the `flock` feature enabled, all accesses to `index.json` are guarded
+/// by an advisory `flock(2)` lock on the `oci-layout` file of the layout,
+/// which is never replaced. Locking is only available on Unix, the feature has
+/// no effect on other platforms. Updates take an exclusive lock for the
+/// whole read-modify-write cycle, reads take a shared lock. Other tools can
+/// participate in the protocol by locking the same file. The `index.json` is
+/// always replaced atomically by renaming a temporary file, so readers which
+/// do not lock never observe a partially written index.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageLayout {
+    path: PathBuf,
+}
+
+#[cfg(has_fs)]
+impl ImageLayout {
+    /// Creates an image layout at the path, creating the directory if
+    /// needed. Existing layout files and blobs are kept.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the layout cannot be created.
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<ImageLayout> {
+        let path = path.as_ref();
+        fs::create_dir_all(path.join(BLOBS_DIR))?;
+
+        let layout = path.join(OCI_LAYOUT_FILE);
+        if !layout.exists() {
+            to_file(&OciLayout::default(), layout, false)?;
+        }
+        let index = path.join(INDEX_FILE);
+        if !index.exists() {
+            to_file(&ImageIndex::default(), index, false)?;
+        }
+
+        ImageLayout::open(path)
+    }
+
+    /// Opens an existing image layout.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the `oci-layout` file cannot be read or an
+    /// [OciSpecError::Other](crate::OciSpecError::Other) if the layout version
+    /// is not supported.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<ImageLayout> {
+        let path = path.as_ref();
+        let layout: OciLayout = from_file(path.join(OCI_LAYOUT_FILE))?;
+        if layout.image_layout_version != IMAGE_LAYOUT_VERSION {
+            return Err(oci_error(format!(
+                "unsupported image layout version {}",
+                layout.image_layout_version
+            )));
+        }
+
+        Ok(ImageLayout {
+            path: path.to_owned(),
+        })
+    }
+
+    /// Returns the root directory of the image layout.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the path of the blob with the given digest.
+    pub fn blob_path(&self, digest: &Digest) -> PathBuf {
+        self.path
+            .join(BLOBS_DIR)
+            .join(digest.algorithm())
+            .join(digest.encoded())
+    }
+
+    /// Reads the image index of the layout.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the index cannot be read or an
+    /// [OciSpecError::SerDe](crate::OciSpecError::SerDe) if it cannot be
+    /// deserialized.
+    pub fn index(&self) -> Result<ImageIndex> {
+        let _lock = self.lock(false)?;
+        ImageIndex::from_file(self.path.join(INDEX_FILE))
+    }
+
+    /// Updates the image index of the layout by applying the closure to it.
+    /// The updated index is written atomically and only if the closure
+    /// succeeds. The result of the closure is returned.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// or [OciSpecError::SerDe](crate::OciSpecError::SerDe) if the index
+    /// cannot be read or written. Errors of the closure are passed through.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::image::{ImageIndex, ImageLayout};
+    ///
+    /// let layout = ImageLayout::open("layout").unwrap();
+    /// let previous = layout
+    ///     .update_index(|index| Ok(std::mem::take(index)))
+    ///     .unwrap();
+    /// assert_eq!(layout.index().unwrap(), ImageIndex::default());
+    /// ```
+    pub fn update_index<F, T>(&self, update: F) -> Result<T>
+    where
+        F: FnOnce(&mut ImageIndex) -> Result<T>,
+    {
+        let _lock = self.lock(true)?;
+        let mut index = ImageIndex::from_file(self.path.join(INDEX_FILE))?;
+        let result = update(&mut index)?;
+        self.write_index(&index)?;
+
+        Ok(result)
+    }
+
+    /// Replaces the image index atomically. The index is written to a
+    /// temporary file which is unique to the thread and synced before it is
+    /// renamed, so that the rename never exposes a partially written index.
+    fn write_index(&self, index: &ImageIndex) -> Result<()> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let tmp = self.path.join(format!(
+            ".{}.{}.{}.tmp",
+            INDEX_FILE,
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let content = crate::to_vec(index, false)?;
+        let written = File::create(&tmp).and_then(|mut file| {
+            file.write_all(&content)?;
+            file.sync_all()
+        });
+        if let Err(err) = written {
+            let _ = fs::remove_file(&tmp);
+            return Err(err.into());
+        }
+        fs::rename(&tmp, self.path.join(INDEX_FILE))?;
+
+        Ok(())
+    }
+
+    /// Removes all blobs which are not reachable from a tagged descriptor of
+    /// the image index, i.e. one with an `org.opencontainers.image.ref.name`
+    /// annotation. The graph is walked from the tagged descriptors through
+    /// nested image indexes to image manifests and their configs and layers.
+    /// Untagged manifests whose `subject` is reachable, like signatures or
+    /// SBOMs attached through the referrers API, are kept together with
+    /// their blobs. All other untagged descriptors are removed from the
+    /// image index. The new index is written before any blob is removed and
+    /// the layout is locked during the whole operation.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the layout cannot be read or a blob cannot be removed or an
+    /// [OciSpecError::SerDe](crate::OciSpecError::SerDe) if a reachable
+    /// index or manifest cannot be deserialized.
+    pub fn prune(&self) -> Result<PruneReport> {
+        let _lock = self.lock(true)?;
+        let mut index = ImageIndex::from_file(self.path.join(INDEX_FILE))?;
+        let mut report = PruneReport::default();
+
+        let mut kept: Vec<bool> = index
+            .manifests
+            .iter()
+            .map(|d| {
+                d.annotations
+                    .as_ref()
+                    .is_some_and(|a| a.contains_key(ANNOTATION_REF_NAME))
+            })
+            .collect();
+        let mut reachable = HashSet::new();
+        let mut pending: Vec<Descriptor> = index
+            .manifests
+            .iter()
+            .zip(&kept)
+            .filter(|(_, kept)| **kept)
+            .map(|(d, _)| d.clone())
+            .collect();
+        // A referrer can be the subject of another referrer, so look for
+        // referrers of the reachable manifests until no more are found.
+        while !pending.is_empty() {
+            self.mark_reachable(pending, &mut reachable)?;
+            pending = Vec::new();
+            for (descriptor, kept) in index.manifests.iter().zip(kept.iter_mut()) {
+                if *kept {
+                    continue;
+                }
+                if self
+                    .subject(descriptor)
+                    .is_some_and(|subject| reachable.contains(&subject.digest))
+                {
+                    *kept = true;
+                    pending.push(descriptor.clone());
+                }
+            }
+        }
+
+        let (manifests, untagged): (Vec<_>, Vec<_>) = index
+            .manifests
+            .drain(..)
+            .zip(kept)
+            .partition(|(_, kept)| *kept);
+        index.manifests = manifests.into_iter().map(|(d, _)| d).collect();
+        report.untagged = untagged.into_iter().map(|(d, _)| d).collect();
+        self.write_index(&index)?;
+
+        for algorithm in fs::read_dir(self.path.join(BLOBS_DIR))? {
+            let algorithm = algorithm?;
+            if !algorithm.file_type()?.is_dir() {
+                continue;
+            }
+            for blob in fs::read_dir(algorithm.path())? {
+                let blob = blob?;
+                let digest: Digest = match format!(
+                    "{}:{}",
+                    algorithm.file_name().to_string_lossy(),
+                    blob.file_name().to_string_lossy()
+                )
+                .parse()
+                {
+                    Ok(digest) => digest,
+                    Err(_) => continue,
+                };
+                if reachable.contains(digest.as_str()) {
+                    continue;
+                }
+
+                let size = blob.metadata()?.len();
+                fs::remove_file(blob.path())?;
+                report.freed_bytes += size;
+                report.removed.push(digest);
+            }
+        }
+        report.removed.sort();
+
+        Ok(report)
+    }
+
+    /// Adds the digests of the descriptors and of all descriptors referenced
+    /// by them to the reachable set.
+    fn mark_reachable(
+        &self,
+        mut pending: Vec<Descriptor>,
+        reachable: &mut HashSet<String>,
+    ) -> Result<()> {
+        while let Some(descriptor) = pending.pop() {
+            if !reachable.insert(descriptor.digest.clone()) {
+                continue;
+            }
+            let (path, media_type) = match self.blob(&descriptor) {
+                Some(blob) => blob,
+                None => continue,
+            };
+            match media_type {
+                MediaType::ImageIndex => {
+                    pending.extend(ImageIndex::from_file(&path)?.manifests);
+                }
+                MediaType::ImageManifest => {
+                    let manifest = ImageManifest::from_file(&path)?;
+                    pending.push(manifest.config);
+                    pending.extend(manifest.layers);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the subject of the manifest or index the descriptor points to,
+    /// if it exists in the layout and can be read.
+    fn subject(&self, descriptor: &Descriptor) -> Option<Descriptor> {
+        match self.blob(descriptor)? {
+            (path, MediaType::ImageManifest) => ImageManifest::from_file(path).ok()?.subject,
+            (path, MediaType::ImageIndex) => ImageIndex::from_file(path).ok()?.subject,
+            _ => None,
+        }
+    }
+
+    /// Returns the path and the OCI media type of the blob the descriptor
+    /// points to, if it exists in the layout.
+    fn blob(&self, descriptor: &Descriptor) -> Option<(PathBuf, MediaType)> {
+        let path = self.blob_path(&descriptor.digest.parse().ok()?);
+        if !path.exists() {
+            return None;
+        }
+        let media_type = MediaType::from_docker_v2s2(&descriptor.media_type.to_string())
+            .unwrap_or_else(|| descriptor.media_type.clone());
+        Some((path, media_type))
+    }
+
+    #[cfg(all(feature = "flock", unix))]
+    fn lock(&self, exclusive: bool) -> Result<Option<File>> {
+        use rustix::fs::{flock, FlockOperation};
+
+        let file = File::open(self.path.join(OCI_LAYOUT_FILE))?;
+        let operation = if exclusive {
+            FlockOperation::LockExclusive
+        } else {
+            FlockOperation::LockShared
+        };
+        flock(&file, operation).map_err(std::io::Error::from)?;
+        Ok(Some(file))
+    }
+
+    #[cfg(not(all(feature = "flock", unix)))]
+    fn lock(&self, _exclusive: bool) -> Result<Option<File>> {
+        Ok(None)
+    }
+}
+
+#[cfg(has_fs)]
+/// Report of [ImageLayout::prune].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PruneReport {
+    /// The digests of the removed blobs, in sorted order.
+    pub removed: Vec<Digest>,
+    /// The total size of the removed blobs in bytes.
+    pub freed_bytes: u64,
+    /// The untagged descriptors which have been removed from the image index.
+    pub untagged: Vec<Descriptor>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::image::{Descriptor, MediaType};
+
+    fn descriptor(i: usize) -> Descriptor {
+        Descriptor::new(
+            MediaType::ImageManifest,
+            i as i64,
+            format!("sha256:{:064x}", i),
+        )
+    }
+
+    #[test]
+    fn create_and_update_layout() {
+        // arrange
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        let layout = ImageLayout::create(tmp.path()).expect("create layout");
+
+        // act
+        layout
+            .update_index(|index| {
+                index.manifests.push(descriptor(1));
+                Ok(())
+            })
+            .expect("update index");
+        let failed = layout.update_index(|index| {
+            index.manifests.push(descriptor(2));
+            Err::<(), _>(oci_error("abort"))
+        });
+
+        // assert
+        assert!(failed.is_err());
+        let layout = ImageLayout::open(tmp.path()).expect("open layout");
+        assert_eq!(
+            layout.index().expect("read index").manifests,
+            vec![descriptor(1)]
+        );
+        assert_eq!(
+            fs::read_to_string(tmp.path().join(OCI_LAYOUT_FILE)).expect("read layout"),
+            r#"{"imageLayoutVersion":"1.0.0"}"#
+        );
+        let digest: Digest = descriptor(1).digest.parse().expect("parse digest");
+        assert_eq!(
+            layout.blob_path(&digest),
+            tmp.path().join("blobs/sha256").join(digest.encoded())
+        );
+    }
+
+    #[test]
+    fn open_invalid_layout() {
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        assert!(ImageLayout::open(tmp.path()).is_err());
+
+        fs::write(
+            tmp.path().join(OCI_LAYOUT_FILE),
+            r#"{"imageLayoutVersion":"2.0.0"}"#,
+        )
+        .expect("write layout");
+        assert!(ImageLayout::open(tmp.path()).is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "flock", unix))]
+    fn concurrent_index_updates() {
+        // arrange
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        ImageLayout::create(tmp.path()).expect("create layout");
+
+        // act
+        let writers: Vec<_> = (0..8)
+            .map(|w| {
+                let path = tmp.path().to_owned();
+                std::thread::spawn(move || {
+                    let layout = ImageLayout::open(path).expect("open layout");
+                    for i in 0..10 {
+                        layout
+                            .update_index(|index| {
+                                index.manifests.push(descriptor(w * 10 + i));
+                                Ok(())
+                            })
+                            .expect("update index");
+                    }
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().expect("join writer");
+        }
+
+        // assert
+        let layout = ImageLayout::open(tmp.path()).expect("open layout");
+        assert_eq!(layout.index().expect("read index").manifests.len(), 80);
+        let leftovers = fs::read_dir(tmp.path())
+            .expect("read layout dir")
+            .filter(|e| {
+                e.as_ref()
+                    .expect("read entry")
+                    .file_name()
+                    .to_string_lossy()
+                    .ends_with(".tmp")
+            })
+            .count();
+        assert_eq!(leftovers, 0);
+    }
+
+    #[test]
+    fn prune_unreachable_blobs() {
+        // arrange
+        let tmp = tempfile::tempdir().expect("create temp dir");
+        let layout = ImageLayout::create(tmp.path()).expect("create layout");
+        let write_blob = |media_type: MediaType, content: &[u8]| {
+            let digest = Digest::sha256_from_reader(content).expect("digest");
+            let path = layout.blob_path(&digest);
+            fs::create_dir_all(path.parent().unwrap()).expect("create blob dir");
+            fs::write(path, content).expect("write blob");
+            Descriptor::new(media_type, content.len() as i64, digest.as_str())
+        };
+        let write_manifest = |layers: Vec<Descriptor>, subject: Option<Descriptor>| {
+            let manifest = ImageManifest {
+                schema_version: 2,
+                media_type: None,
+                artifact_type: None,
+                config: write_blob(MediaType::ImageConfig, b"{}"),
+                layers,
+                subject,
+                annotations: None,
+                extensions: Default::default(),
+            };
+            let content = serde_json::to_vec(&manifest).expect("serialize manifest");
+            write_blob(MediaType::ImageManifest, &content)
+        };
+
+        let shared = write_blob(MediaType::ImageLayerGzip, b"shared");
+        let mut tagged = write_manifest(vec![shared.clone()], None);
+        let sbom = write_manifest(
+            vec![write_blob(
+                MediaType::Other("application/spdx+json".to_owned()),
+                b"sbom",
+            )],
+            Some(tagged.clone()),
+        );
+        let signature = write_manifest(vec![], Some(sbom.clone()));
+        let annotations = [(ANNOTATION_REF_NAME.to_owned(), "latest".to_owned())];
+        tagged.annotations = Some(annotations.iter().cloned().collect());
+        let orphan_layer = write_blob(MediaType::ImageLayerGzip, b"orphan");
+        let untagged = write_manifest(vec![shared, orphan_layer.clone()], None);
+        let garbage = write_blob(MediaType::ImageLayer, b"garbage");
+        fs::write(tmp.path().join("blobs/sha256/not-a-digest"), b"").expect("write file");
+
+        let index = vec![
+            signature.clone(),
+            tagged.clone(),
+            untagged.clone(),
+            sbom.clone(),
+        ];
+        layout
+            .update_index(|i| {
+                i.manifests = index;
+                Ok(())
+            })
+            .expect("update index");
+
+        // act
+        let report = layout.prune().expect("prune layout");
+
+        // assert
+        let mut removed = vec![
+            untagged.digest.parse::<Digest>().unwrap(),
+            orphan_layer.digest.parse().unwrap(),
+            garbage.digest.parse().unwrap(),
+        ];
+        removed.sort();
+        assert_eq!(report.removed, removed);
+        assert_eq!(
+            report.freed_bytes,
+            (untagged.size + orphan_layer.size + garbage.size) as u64
+        );
+        assert_eq!(report.untagged, vec![untagged]);
+        assert_eq!(
+            layout.index().expect("read index").manifests,
+            vec![signature, tagged, sbom]
+        );
+        assert!(tmp.path().join("blobs/sha256/not-a-digest").exists());
+
+        let report = layout.prune().expect("prune layout again");
+        assert_eq!(report, PruneReport::default());
+    }
+}
@@ -1,26 +1,38 @@
+#[cfg(has_fs)]
+use std::path::Path;
 use std::{
     collections::HashMap,
     io::{Read, Write},
-    path::Path,
 };
 
-use crate::{error::Result, from_file, from_reader, to_file, to_writer};
+use crate::{error::Result, from_reader, to_writer};
+#[cfg(has_fs)]
+use crate::{from_file, to_file};
 
-use super::{Descriptor, MediaType};
+use super::{annotations, Descriptor, Digest, MediaType};
 
 use serde::{Deserialize, Serialize};
 
 make_pub!(
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// Unlike the image index, which contains information about a set of images
     /// that can span a variety of architectures and operating systems, an image
@@ -40,6 +52,12 @@ make_pub!(
         #[serde(skip_serializing_if = "Option::is_none")]
         #[cfg_attr(feature = "builder", getset(get = "pub"), builder(default))]
         media_type: Option<MediaType>,
+        /// This OPTIONAL property contains the type of an artifact when the
+        /// manifest is used for an artifact. This MUST be set when the config
+        /// media type is the empty value.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"), builder(default))]
+        artifact_type: Option<String>,
         /// This REQUIRED property references a configuration object for a
         /// container, by digest. Beyond the descriptor requirements,
         /// the value has the following additional restrictions:
@@ -59,16 +77,32 @@ make_pub!(
         /// attributes of the initial empty directory are unspecified.
         #[cfg_attr(feature = "builder", getset(get = "pub"))]
         layers: Vec<Descriptor>,
+        /// This OPTIONAL property specifies a descriptor of another manifest.
+        /// This value defines a weak association to a separate manifest in
+        /// the repository, e.g. the image which an SBOM describes.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"), builder(default))]
+        subject: Option<Descriptor>,
         /// This OPTIONAL property contains arbitrary metadata for the image
         /// manifest. This OPTIONAL property MUST use the annotation
         /// rules.
         #[serde(skip_serializing_if = "Option::is_none")]
         #[cfg_attr(feature = "builder", getset(get = "pub"), builder(default))]
         annotations: Option<HashMap<String, String>>,
+
+        #[cfg_attr(feature = "lossless", serde(flatten))]
+        #[cfg_attr(not(feature = "lossless"), serde(skip))]
+        #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+        #[cfg_attr(feature = "builder", getset(get = "pub"), builder(default))]
+        /// Properties which are not defined by the image specification, e.g.
+        /// vendor extensions. With the `lossless` feature they are written back
+        /// as they were read, otherwise they are ignored.
+        extensions: HashMap<String, serde_json::Value>,
     }
 );
 
 impl ImageManifest {
+    #[cfg(has_fs)]
     /// Attempts to load an image manifest from a file.
     /// # Errors
     /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
@@ -101,6 +135,7 @@ impl ImageManifest {
         from_reader(reader)
     }
 
+    #[cfg(has_fs)]
     /// Attempts to write an image manifest to a file as JSON. If the file already exists, it
     /// will be overwritten.
     /// # Errors
@@ -117,6 +152,7 @@ impl ImageManifest {
         to_file(&self, path, false)
     }
 
+    #[cfg(has_fs)]
     /// Attempts to write an image manifest to a file as pretty printed JSON. If the file already exists, it
     /// will be overwritten.
     /// # Errors
@@ -164,6 +200,35 @@ impl ImageManifest {
     pub fn to_writer_pretty<W: Write>(&self, writer: &mut W) -> Result<()> {
         to_writer(&self, writer, true)
     }
+
+    /// Returns the image reference of the base image which the image was
+    /// built from, as recorded in the annotations of the manifest.
+    pub fn base_image_name(&self) -> Option<&str> {
+        self.annotations
+            .as_ref()
+            .and_then(|annotations| annotations::base_image_name(annotations))
+    }
+
+    /// Returns the digest of the base image which the image was built from,
+    /// as recorded in the annotations of the manifest.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the annotation is not a valid digest.
+    pub fn base_image_digest(&self) -> Result<Option<Digest>> {
+        self.annotations
+            .as_ref()
+            .map_or(Ok(None), annotations::base_image_digest)
+    }
+
+    /// Records the base image which the image was built from in the
+    /// annotations of the manifest.
+    pub fn set_base_image<S: Into<String>>(&mut self, name: S, digest: &Digest) {
+        annotations::set_base_image(
+            self.annotations.get_or_insert_with(HashMap::new),
+            name,
+            digest,
+        );
+    }
 }
 
 #[cfg(test)]
@@ -212,14 +277,12 @@ mod tests {
         })
         .collect();
 
-        let manifest = ImageManifestBuilder::default()
+        ImageManifestBuilder::default()
             .schema_version(SCHEMA_VERSION)
             .config(config)
             .layers(layers)
             .build()
-            .expect("build image manifest");
-
-        manifest
+            .expect("build image manifest")
     }
 
     #[cfg(not(feature = "builder"))]
@@ -234,6 +297,7 @@ mod tests {
             urls: None,
             annotations: None,
             platform: None,
+            artifact_type: None,
         };
 
         let layers = vec![
@@ -245,6 +309,7 @@ mod tests {
                 urls: None,
                 annotations: None,
                 platform: None,
+                artifact_type: None,
             },
             Descriptor {
                 media_type: MediaType::ImageLayerGzip,
@@ -254,6 +319,7 @@ mod tests {
                 urls: None,
                 annotations: None,
                 platform: None,
+                artifact_type: None,
             },
             Descriptor {
                 media_type: MediaType::ImageLayerGzip,
@@ -263,18 +329,20 @@ mod tests {
                 urls: None,
                 annotations: None,
                 platform: None,
+                artifact_type: None,
             },
         ];
 
-        let manifest = ImageManifest {
+        ImageManifest {
             schema_version: SCHEMA_VERSION,
             media_type: None,
+            artifact_type: None,
             config,
             layers,
+            subject: None,
             annotations: None,
-        };
-
-        manifest
+            extensions: Default::default(),
+        }
     }
 
     fn get_manifest_path() -> PathBuf {
@@ -339,4 +407,55 @@ mod tests {
         let expected = fs::read(get_manifest_path()).expect("read expected");
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn base_image() {
+        // arrange
+        let mut manifest = create_manifest();
+        let digest: Digest =
+            "sha256:9834876dcfb05cb167a5c24953eba58c4ac89b1adf57f28f2f9d09af107ee8f0"
+                .parse()
+                .expect("parse digest");
+
+        // act
+        let unset = manifest.base_image_name().is_none();
+        manifest.set_base_image("docker.io/library/alpine:3.14", &digest);
+
+        // assert
+        assert!(unset);
+        assert_eq!(
+            manifest.base_image_name(),
+            Some("docker.io/library/alpine:3.14")
+        );
+        assert_eq!(
+            manifest.base_image_digest().expect("base digest"),
+            Some(digest)
+        );
+        manifest.annotations.as_mut().unwrap().insert(
+            crate::image::ANNOTATION_BASE_IMAGE_DIGEST.to_owned(),
+            "alpine".to_owned(),
+        );
+        assert!(manifest.base_image_digest().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "lossless")]
+    fn preserve_unknown_fields() {
+        // arrange
+        let json = r#"{"schemaVersion":2,"config":{"mediaType":"application/vnd.oci.image.config.v1+json","digest":"sha256:b5b2b2c507a0944348e0303114d8d93aaaa081732b86451d9bce1f432a537bc7","size":7023},"layers":[],"org.example.signed":{"by":"alice"}}"#;
+
+        // act
+        let mut manifest = ImageManifest::from_reader(json.as_bytes()).expect("from reader");
+        manifest.annotations = Some(HashMap::from([("a".to_owned(), "b".to_owned())]));
+        let written = serde_json::to_value(&manifest).expect("serialize manifest");
+
+        // assert
+        assert_eq!(
+            manifest.extensions["org.example.signed"],
+            serde_json::json!({"by": "alice"})
+        );
+        assert_eq!(written["org.example.signed"]["by"], "alice");
+        assert_eq!(written["annotations"]["a"], "b");
+        assert!(!manifest.extensions.contains_key("layers"));
+    }
 }
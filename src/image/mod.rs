@@ -1,10 +1,17 @@
 //! [OCI image spec](https://github.com/opencontainers/image-spec) types and definitions.
 
 mod annotations;
+mod artifact;
+mod compat;
 mod config;
 mod descriptor;
+mod digest;
+mod estargz;
 mod index;
+mod layer;
+mod layout;
 mod manifest;
+mod signature;
 mod version;
 
 use std::fmt::Display;
@@ -12,15 +19,22 @@ use std::fmt::Display;
 use serde::{Deserialize, Serialize};
 
 pub use annotations::*;
+pub use artifact::*;
+pub use compat::*;
 pub use config::*;
 pub use descriptor::*;
+pub use digest::*;
+pub use estargz::*;
 pub use index::*;
+pub use layer::*;
+pub use layout::*;
 pub use manifest::*;
+pub use signature::*;
 pub use version::*;
 
 /// Media types used by OCI image format spec. Values MUST comply with RFC 6838,
 /// including the naming requirements in its section 4.2.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum MediaType {
     /// MediaType Descriptor specifies the media type for a content descriptor.
     Descriptor,
@@ -109,6 +123,103 @@ impl From<&str> for MediaType {
     }
 }
 
+impl MediaType {
+    /// Splits the media type into its base type and its structured syntax
+    /// suffixes, e.g. `application/vnd.oci.image.layer.v1.tar+gzip+encrypted`
+    /// into `application/vnd.oci.image.layer.v1.tar` and the `gzip` and
+    /// `encrypted` suffixes. Parameters like `; charset=utf-8` are not part of
+    /// the result. This works for any media type, including unknown ones.
+    pub fn split_suffixes(&self) -> (String, Vec<MediaTypeSuffix>) {
+        let media_type = self.to_string();
+        let media_type = media_type.split(';').next().unwrap_or_default().trim();
+        let mut parts = media_type.split('+');
+        let base = parts.next().unwrap_or_default().to_owned();
+        let suffixes = parts.map(MediaTypeSuffix::from).collect();
+        (base, suffixes)
+    }
+
+    /// Returns the Docker image manifest v2, schema 2 equivalent of the OCI
+    /// media type, if there is one. The returned value is always a
+    /// [MediaType::Other].
+    pub fn to_docker_v2s2(&self) -> Option<MediaType> {
+        let media_type = match self {
+            Self::ImageManifest => "application/vnd.docker.distribution.manifest.v2+json",
+            Self::ImageIndex => "application/vnd.docker.distribution.manifest.list.v2+json",
+            Self::ImageConfig => "application/vnd.docker.container.image.v1+json",
+            Self::ImageLayer => "application/vnd.docker.image.rootfs.diff.tar",
+            Self::ImageLayerGzip => "application/vnd.docker.image.rootfs.diff.tar.gzip",
+            Self::ImageLayerNonDistributableGzip => {
+                "application/vnd.docker.image.rootfs.foreign.diff.tar.gzip"
+            }
+            _ => return None,
+        };
+        Some(MediaType::Other(media_type.to_owned()))
+    }
+
+    /// Returns the OCI equivalent of a Docker image manifest v2, schema 2
+    /// media type, if there is one.
+    pub fn from_docker_v2s2(media_type: &str) -> Option<MediaType> {
+        match media_type {
+            "application/vnd.docker.distribution.manifest.v2+json" => Some(Self::ImageManifest),
+            "application/vnd.docker.distribution.manifest.list.v2+json" => Some(Self::ImageIndex),
+            "application/vnd.docker.container.image.v1+json" => Some(Self::ImageConfig),
+            "application/vnd.docker.image.rootfs.diff.tar" => Some(Self::ImageLayer),
+            "application/vnd.docker.image.rootfs.diff.tar.gzip" => Some(Self::ImageLayerGzip),
+            "application/vnd.docker.image.rootfs.foreign.diff.tar.gzip" => {
+                Some(Self::ImageLayerNonDistributableGzip)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns true if the media type has the given structured syntax suffix.
+    pub fn has_suffix(&self, suffix: &MediaTypeSuffix) -> bool {
+        self.split_suffixes().1.contains(suffix)
+    }
+}
+
+/// Structured syntax suffix of a media type as defined by RFC 6838, section
+/// 4.2.8.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MediaTypeSuffix {
+    /// The content is JSON.
+    Json,
+    /// The content is gzip compressed.
+    Gzip,
+    /// The content is zstd compressed.
+    Zstd,
+    /// The content is encrypted.
+    Encrypted,
+    /// Suffix not known by this crate.
+    Other(String),
+}
+
+impl Display for MediaTypeSuffix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let print = match self {
+            Self::Json => "json",
+            Self::Gzip => "gzip",
+            Self::Zstd => "zstd",
+            Self::Encrypted => "encrypted",
+            Self::Other(suffix) => suffix,
+        };
+
+        write!(f, "{}", print)
+    }
+}
+
+impl From<&str> for MediaTypeSuffix {
+    fn from(suffix: &str) -> Self {
+        match suffix {
+            "json" => MediaTypeSuffix::Json,
+            "gzip" => MediaTypeSuffix::Gzip,
+            "zstd" => MediaTypeSuffix::Zstd,
+            "encrypted" => MediaTypeSuffix::Encrypted,
+            suffix => MediaTypeSuffix::Other(suffix.to_owned()),
+        }
+    }
+}
+
 impl Serialize for MediaType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -131,7 +242,7 @@ impl<'de> Deserialize<'de> for MediaType {
 
 /// Name of the target operating system.
 #[allow(missing_docs)]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Os {
     AIX,
     Android,
@@ -149,6 +260,7 @@ pub enum Os {
     OpenBSD,
     Plan9,
     Solaris,
+    Wasip1,
     Windows,
     #[allow(non_camel_case_types)]
     zOS,
@@ -173,6 +285,7 @@ impl From<&str> for Os {
             "openbsd" => Os::OpenBSD,
             "plan9" => Os::Plan9,
             "solaris" => Os::Solaris,
+            "wasip1" => Os::Wasip1,
             "windows" => Os::Windows,
             "zos" => Os::zOS,
             name => Os::Other(name.to_owned()),
@@ -180,6 +293,47 @@ impl From<&str> for Os {
     }
 }
 
+impl Os {
+    /// Converts the name of a Rust target operating system, as found in
+    /// `cfg!(target_os)` or [std::env::consts::OS], into the corresponding
+    /// Go `GOOS` value.
+    pub fn from_rust_target_os(os: &str) -> Os {
+        match os {
+            "macos" => Os::Darwin,
+            "wasi" => Os::Wasip1,
+            os => Os::from(os),
+        }
+    }
+
+    /// Returns the name of the Rust target operating system for this `GOOS`
+    /// value, if there is one.
+    pub fn to_rust_target_os(&self) -> Option<&'static str> {
+        let os = match self {
+            Os::AIX => "aix",
+            Os::Android => "android",
+            Os::Darwin => "macos",
+            Os::DragonFlyBSD => "dragonfly",
+            Os::FreeBSD => "freebsd",
+            Os::Hurd => "hurd",
+            Os::Illumos => "illumos",
+            Os::iOS => "ios",
+            Os::Linux => "linux",
+            Os::NetBSD => "netbsd",
+            Os::OpenBSD => "openbsd",
+            Os::Solaris => "solaris",
+            Os::Wasip1 => "wasi",
+            Os::Windows => "windows",
+            Os::Js | Os::Nacl | Os::Plan9 | Os::zOS | Os::Other(_) => return None,
+        };
+        Some(os)
+    }
+
+    /// Returns the operating system this crate has been compiled for.
+    pub fn current() -> Os {
+        Os::from_rust_target_os(std::env::consts::OS)
+    }
+}
+
 impl Display for Os {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let print = match self {
@@ -198,6 +352,7 @@ impl Display for Os {
             Os::OpenBSD => "openbsd",
             Os::Plan9 => "plan9",
             Os::Solaris => "solaris",
+            Os::Wasip1 => "wasip1",
             Os::Windows => "windows",
             Os::zOS => "zos",
             Os::Other(name) => name,
@@ -228,7 +383,7 @@ impl<'de> Deserialize<'de> for Os {
 }
 
 /// Name of the CPU target architecture.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Arch {
     /// 32 bit x86, little-endian
     #[allow(non_camel_case_types)]
@@ -285,6 +440,70 @@ pub enum Arch {
     Other(String),
 }
 
+impl Arch {
+    /// Converts the name of a Rust target architecture, as found in
+    /// `cfg!(target_arch)` or [std::env::consts::ARCH], into the corresponding
+    /// Go `GOARCH` value. The endianness is required to distinguish between
+    /// architectures like `ppc64` and `ppc64le`, which share a Rust target
+    /// architecture name.
+    pub fn from_rust_target_arch(arch: &str, little_endian: bool) -> Arch {
+        match (arch, little_endian) {
+            ("x86", _) => Arch::i386,
+            ("x86_64", _) => Arch::Amd64,
+            ("arm", true) => Arch::ARM,
+            ("arm", false) => Arch::ARMbe,
+            ("aarch64", true) => Arch::ARM64,
+            ("aarch64", false) => Arch::ARM64be,
+            ("loongarch64", _) => Arch::LoongArch64,
+            ("mips", true) => Arch::Mipsle,
+            ("mips", false) => Arch::Mips,
+            ("mips64", true) => Arch::Mips64le,
+            ("mips64", false) => Arch::Mips64,
+            ("powerpc", _) => Arch::PowerPC,
+            ("powerpc64", true) => Arch::PowerPC64le,
+            ("powerpc64", false) => Arch::PowerPC64,
+            ("riscv32", _) => Arch::RISCV,
+            ("riscv64", _) => Arch::RISCV64,
+            ("s390x", _) => Arch::s390x,
+            ("sparc", _) => Arch::SPARC,
+            ("sparc64", _) => Arch::SPARC64,
+            ("wasm32", _) => Arch::Wasm,
+            (arch, _) => Arch::Other(arch.to_owned()),
+        }
+    }
+
+    /// Returns the name of the Rust target architecture for this `GOARCH`
+    /// value, if there is one.
+    pub fn to_rust_target_arch(&self) -> Option<&'static str> {
+        let arch = match self {
+            Arch::i386 => "x86",
+            Arch::Amd64 => "x86_64",
+            Arch::ARM | Arch::ARMbe => "arm",
+            Arch::ARM64 | Arch::ARM64be => "aarch64",
+            Arch::LoongArch64 => "loongarch64",
+            Arch::Mips | Arch::Mipsle => "mips",
+            Arch::Mips64 | Arch::Mips64le => "mips64",
+            Arch::PowerPC => "powerpc",
+            Arch::PowerPC64 | Arch::PowerPC64le => "powerpc64",
+            Arch::RISCV => "riscv32",
+            Arch::RISCV64 => "riscv64",
+            Arch::s390x => "s390x",
+            Arch::SPARC => "sparc",
+            Arch::SPARC64 => "sparc64",
+            Arch::Wasm => "wasm32",
+            Arch::Amd64p32 | Arch::Mips64p32 | Arch::Mips64p32le | Arch::s390 | Arch::Other(_) => {
+                return None
+            }
+        };
+        Some(arch)
+    }
+
+    /// Returns the architecture this crate has been compiled for.
+    pub fn current() -> Arch {
+        Arch::from_rust_target_arch(std::env::consts::ARCH, cfg!(target_endian = "little"))
+    }
+}
+
 impl Display for Arch {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let print = match self {
@@ -326,7 +545,7 @@ impl From<&str> for Arch {
             "amd64" => Arch::Amd64,
             "amd64p32" => Arch::Amd64p32,
             "arm" => Arch::ARM,
-            "armbe" => Arch::ARM64be,
+            "armbe" => Arch::ARMbe,
             "arm64" => Arch::ARM64,
             "arm64be" => Arch::ARM64be,
             "loong64" => Arch::LoongArch64,
@@ -370,3 +589,138 @@ impl<'de> Deserialize<'de> for Arch {
         Ok(arch.as_str().into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn docker_media_types() {
+        for media_type in &[
+            MediaType::ImageManifest,
+            MediaType::ImageIndex,
+            MediaType::ImageConfig,
+            MediaType::ImageLayer,
+            MediaType::ImageLayerGzip,
+            MediaType::ImageLayerNonDistributableGzip,
+        ] {
+            let docker = media_type.to_docker_v2s2().expect("docker media type");
+            assert_eq!(
+                MediaType::from_docker_v2s2(&docker.to_string()).as_ref(),
+                Some(media_type)
+            );
+        }
+
+        assert_eq!(
+            MediaType::ImageLayerGzip.to_docker_v2s2(),
+            Some(MediaType::from(
+                "application/vnd.docker.image.rootfs.diff.tar.gzip"
+            ))
+        );
+        assert_eq!(MediaType::ImageLayerZstd.to_docker_v2s2(), None);
+        assert_eq!(
+            MediaType::from_docker_v2s2("application/vnd.oci.image.manifest.v1+json"),
+            None
+        );
+    }
+
+    #[test]
+    fn media_type_suffixes() {
+        let (base, suffixes) = MediaType::ImageLayerZstd.split_suffixes();
+        assert_eq!(base, "application/vnd.oci.image.layer.v1.tar");
+        assert_eq!(suffixes, vec![MediaTypeSuffix::Zstd]);
+
+        let media_type = MediaType::from("application/vnd.oci.image.layer.v1.tar+gzip+encrypted");
+        let (base, suffixes) = media_type.split_suffixes();
+        assert_eq!(base, "application/vnd.oci.image.layer.v1.tar");
+        assert_eq!(
+            suffixes,
+            vec![MediaTypeSuffix::Gzip, MediaTypeSuffix::Encrypted]
+        );
+        assert!(media_type.has_suffix(&MediaTypeSuffix::Encrypted));
+        assert!(!media_type.has_suffix(&MediaTypeSuffix::Json));
+
+        let media_type = MediaType::from("application/vnd.example.sbom+cbor; charset=utf-8");
+        let (base, suffixes) = media_type.split_suffixes();
+        assert_eq!(base, "application/vnd.example.sbom");
+        assert_eq!(suffixes, vec![MediaTypeSuffix::Other("cbor".to_owned())]);
+        assert_eq!(suffixes[0].to_string(), "cbor");
+
+        let (base, suffixes) = MediaType::Descriptor.split_suffixes();
+        assert_eq!(base, "application/vnd.oci.descriptor");
+        assert!(suffixes.is_empty());
+    }
+
+    #[test]
+    fn rust_target_conversion() {
+        assert_eq!(Arch::from_rust_target_arch("x86_64", true), Arch::Amd64);
+        assert_eq!(Arch::from_rust_target_arch("aarch64", true), Arch::ARM64);
+        assert_eq!(
+            Arch::from_rust_target_arch("powerpc64", true),
+            Arch::PowerPC64le
+        );
+        assert_eq!(
+            Arch::from_rust_target_arch("powerpc64", false),
+            Arch::PowerPC64
+        );
+        assert_eq!(
+            Arch::from_rust_target_arch("xtensa", true),
+            Arch::Other("xtensa".to_owned())
+        );
+        assert_eq!(Arch::PowerPC64le.to_rust_target_arch(), Some("powerpc64"));
+        assert_eq!(Arch::s390.to_rust_target_arch(), None);
+
+        assert_eq!(Os::from_rust_target_os("macos"), Os::Darwin);
+        assert_eq!(Os::from_rust_target_os("wasi"), Os::Wasip1);
+        assert_eq!(Os::from_rust_target_os("linux"), Os::Linux);
+        assert_eq!(Os::Darwin.to_rust_target_os(), Some("macos"));
+        assert_eq!(Os::Plan9.to_rust_target_os(), None);
+
+        assert_eq!(
+            Arch::current().to_rust_target_arch(),
+            Some(std::env::consts::ARCH)
+        );
+        assert_eq!(
+            Os::current().to_rust_target_os(),
+            Some(std::env::consts::OS)
+        );
+    }
+
+    #[test]
+    fn arch_round_trip() {
+        for name in &[
+            "386", "amd64", "arm", "armbe", "arm64", "arm64be", "loong64", "riscv64", "wasm",
+        ] {
+            let arch: Arch = serde_json::from_str(&format!("\"{}\"", name)).expect("deserialize");
+            assert!(!matches!(arch, Arch::Other(_)), "{} is unknown", name);
+            assert_eq!(arch.to_string(), *name);
+        }
+        assert_eq!(Arch::from("armbe"), Arch::ARMbe);
+    }
+
+    #[test]
+    fn os_round_trip() {
+        for name in &["linux", "windows", "wasip1", "js", "zos"] {
+            let os: Os = serde_json::from_str(&format!("\"{}\"", name)).expect("deserialize");
+            assert!(!matches!(os, Os::Other(_)), "{} is unknown", name);
+            assert_eq!(os.to_string(), *name);
+        }
+    }
+
+    #[test]
+    fn unknown_values_are_preserved() {
+        let arch: Arch = serde_json::from_str("\"sw64\"").expect("deserialize arch");
+        assert_eq!(arch, Arch::Other("sw64".to_owned()));
+        assert_eq!(
+            serde_json::to_string(&arch).expect("serialize arch"),
+            "\"sw64\""
+        );
+
+        let os: Os = serde_json::from_str("\"fuchsia\"").expect("deserialize os");
+        assert_eq!(os, Os::Other("fuchsia".to_owned()));
+        assert_eq!(
+            serde_json::to_string(&os).expect("serialize os"),
+            "\"fuchsia\""
+        );
+    }
+}
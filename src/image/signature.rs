@@ -0,0 +1,241 @@
+//! Media types and annotations of the signatures created by sigstore/cosign
+//! and Notation, and constructors for the manifests which attach them to an
+//! image.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Descriptor, Digest, ImageManifest, MediaType};
+use crate::error::Result;
+
+/// MediaTypeCosignSimpleSigning is the media type of the simple signing
+/// payload which is signed by cosign, see [SimpleSigning].
+pub const MEDIA_TYPE_COSIGN_SIMPLE_SIGNING: &str =
+    "application/vnd.dev.cosign.simplesigning.v1+json";
+
+/// ArtifactTypeCosignSignature is the artifact type of cosign signatures
+/// which are attached through the referrers API.
+pub const ARTIFACT_TYPE_COSIGN_SIGNATURE: &str = "application/vnd.dev.cosign.artifact.sig.v1+json";
+
+/// AnnotationCosignSignature is the annotation key for the base64 encoded
+/// signature of a cosign signature layer.
+pub const ANNOTATION_COSIGN_SIGNATURE: &str = "dev.cosignproject.cosign/signature";
+
+/// AnnotationCosignCertificate is the annotation key for the PEM encoded
+/// signing certificate of a cosign signature layer.
+pub const ANNOTATION_COSIGN_CERTIFICATE: &str = "dev.sigstore.cosign/certificate";
+
+/// AnnotationCosignChain is the annotation key for the PEM encoded
+/// certificate chain of a cosign signature layer.
+pub const ANNOTATION_COSIGN_CHAIN: &str = "dev.sigstore.cosign/chain";
+
+/// AnnotationCosignBundle is the annotation key for the transparency log
+/// bundle of a cosign signature layer.
+pub const ANNOTATION_COSIGN_BUNDLE: &str = "dev.sigstore.cosign/bundle";
+
+/// The type of the critical section of a cosign simple signing payload.
+pub const COSIGN_SIGNATURE_TYPE: &str = "cosign container image signature";
+
+/// ArtifactTypeNotationSignature is the artifact type of Notation signatures.
+pub const ARTIFACT_TYPE_NOTATION_SIGNATURE: &str = "application/vnd.cncf.notary.signature";
+
+/// MediaTypeNotationJws is the media type of a Notation signature envelope in
+/// the JWS format.
+pub const MEDIA_TYPE_NOTATION_JWS: &str = "application/jose+json";
+
+/// MediaTypeNotationCose is the media type of a Notation signature envelope
+/// in the COSE format.
+pub const MEDIA_TYPE_NOTATION_COSE: &str = "application/cose";
+
+/// AnnotationNotationThumbprints is the annotation key for the JSON encoded
+/// list of SHA-256 fingerprints of the signing certificate chain, which is
+/// REQUIRED on Notation signature manifests.
+pub const ANNOTATION_NOTATION_THUMBPRINTS: &str = "io.cncf.notary.x509chain.thumbprint#S256";
+
+/// The payload which is signed by cosign, identifying the signed image by
+/// its reference and manifest digest.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SimpleSigning {
+    /// The claims which a verifier MUST check.
+    pub critical: SimpleSigningCritical,
+    /// Additional claims, e.g. added with `cosign sign -a key=value`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optional: Option<HashMap<String, serde_json::Value>>,
+}
+
+/// The critical section of a [SimpleSigning] payload.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SimpleSigningCritical {
+    /// The reference of the signed image.
+    pub identity: SimpleSigningIdentity,
+    /// The manifest digest of the signed image.
+    pub image: SimpleSigningImage,
+    /// The type of the signature, see [COSIGN_SIGNATURE_TYPE].
+    #[serde(rename = "type")]
+    pub typ: String,
+}
+
+/// The identity of a [SimpleSigning] payload.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SimpleSigningIdentity {
+    /// The reference of the signed image, e.g. `docker.io/library/alpine`.
+    #[serde(rename = "docker-reference")]
+    pub docker_reference: String,
+}
+
+/// The image of a [SimpleSigning] payload.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SimpleSigningImage {
+    /// The digest of the manifest of the signed image.
+    #[serde(rename = "docker-manifest-digest")]
+    pub docker_manifest_digest: Digest,
+}
+
+impl SimpleSigning {
+    /// Creates the payload of a cosign signature of the image with the given
+    /// reference and manifest digest.
+    pub fn new<S: Into<String>>(reference: S, digest: Digest) -> Self {
+        SimpleSigning {
+            critical: SimpleSigningCritical {
+                identity: SimpleSigningIdentity {
+                    docker_reference: reference.into(),
+                },
+                image: SimpleSigningImage {
+                    docker_manifest_digest: digest,
+                },
+                typ: COSIGN_SIGNATURE_TYPE.to_owned(),
+            },
+            optional: None,
+        }
+    }
+
+    /// Attempts to serialize the payload as JSON bytes, which are the bytes
+    /// that need to be signed.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the payload cannot be serialized.
+    pub fn to_vec(&self) -> Result<Vec<u8>> {
+        crate::to_vec(self, false)
+    }
+}
+
+impl ImageManifest {
+    /// Construct a manifest which attaches a cosign signature to the
+    /// `subject`. The signed `payload`, usually a serialized [SimpleSigning],
+    /// is stored as the only layer, annotated with the base64 encoded
+    /// `signature`.
+    pub fn cosign_signature<S: Into<String>>(
+        subject: Descriptor,
+        payload: &[u8],
+        signature: S,
+    ) -> Self {
+        let mut layer =
+            Descriptor::from_content(MediaType::from(MEDIA_TYPE_COSIGN_SIMPLE_SIGNING), payload);
+        layer.annotations = Some(HashMap::from([(
+            ANNOTATION_COSIGN_SIGNATURE.to_owned(),
+            signature.into(),
+        )]));
+        ImageManifest::referrer(ARTIFACT_TYPE_COSIGN_SIGNATURE, subject, vec![layer])
+    }
+
+    /// Construct a manifest which attaches a Notation signature to the
+    /// `subject`. The `envelope` is stored as the only layer, which has one of
+    /// the media types [MEDIA_TYPE_NOTATION_JWS] or [MEDIA_TYPE_NOTATION_COSE].
+    /// The `thumbprints` are the hex encoded SHA-256 fingerprints of the
+    /// certificate chain which signed the envelope.
+    pub fn notation_signature(
+        subject: Descriptor,
+        envelope_media_type: MediaType,
+        envelope: &[u8],
+        thumbprints: &[String],
+    ) -> Self {
+        let layer = Descriptor::from_content(envelope_media_type, envelope);
+        let mut manifest =
+            ImageManifest::referrer(ARTIFACT_TYPE_NOTATION_SIGNATURE, subject, vec![layer]);
+        manifest.annotations = Some(HashMap::from([(
+            ANNOTATION_NOTATION_THUMBPRINTS.to_owned(),
+            serde_json::Value::from(thumbprints).to_string(),
+        )]));
+        manifest
+    }
+
+    /// Returns true if the manifest is a cosign or Notation signature. This
+    /// includes cosign signatures which are stored under a tag instead of
+    /// being attached through the referrers API.
+    pub fn is_signature(&self) -> bool {
+        matches!(
+            self.artifact_type.as_deref(),
+            Some(ARTIFACT_TYPE_COSIGN_SIGNATURE | ARTIFACT_TYPE_NOTATION_SIGNATURE)
+        ) || self
+            .layers
+            .iter()
+            .any(|layer| layer.media_type.to_string() == MEDIA_TYPE_COSIGN_SIMPLE_SIGNING)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subject() -> Descriptor {
+        Descriptor::new(
+            MediaType::ImageManifest,
+            7682,
+            "sha256:5b0bcabd1ed22e9fb1310cf6c2dec7cdef19f0ad69efa1f392e94a4333501270",
+        )
+    }
+
+    #[test]
+    fn cosign_signature() {
+        // arrange
+        let digest: Digest = subject().digest.parse().expect("parse digest");
+        let payload = SimpleSigning::new("docker.io/library/alpine", digest)
+            .to_vec()
+            .expect("serialize payload");
+
+        // act
+        let manifest = ImageManifest::cosign_signature(subject(), &payload, "MEUCIQ==");
+
+        // assert
+        assert!(manifest.is_signature());
+        let layer = &manifest.layers[0];
+        assert_eq!(layer.size, payload.len() as i64);
+        assert_eq!(
+            layer.annotations.as_ref().unwrap()[ANNOTATION_COSIGN_SIGNATURE],
+            "MEUCIQ=="
+        );
+        let json: serde_json::Value = serde_json::from_slice(&payload).unwrap();
+        assert_eq!(
+            json["critical"]["image"]["docker-manifest-digest"],
+            subject().digest
+        );
+        assert_eq!(json["critical"]["type"], COSIGN_SIGNATURE_TYPE);
+    }
+
+    #[test]
+    fn notation_signature() {
+        // arrange
+        let thumbprints =
+            vec!["b13a843be16b1f461f08d61c14f3eab7d87c073570da077217541a7eb31c084d".to_owned()];
+
+        // act
+        let manifest = ImageManifest::notation_signature(
+            subject(),
+            MediaType::from(MEDIA_TYPE_NOTATION_JWS),
+            b"{}",
+            &thumbprints,
+        );
+
+        // assert
+        assert!(manifest.is_signature());
+        assert_eq!(manifest.subject, Some(subject()));
+        assert_eq!(
+            manifest.annotations.as_ref().unwrap()[ANNOTATION_NOTATION_THUMBPRINTS],
+            r#"["b13a843be16b1f461f08d61c14f3eab7d87c073570da077217541a7eb31c084d"]"#
+        );
+        assert!(
+            !ImageManifest::referrer("application/spdx+json", subject(), vec![]).is_signature()
+        );
+    }
+}
@@ -4,36 +4,110 @@
 #[macro_use]
 mod macros;
 
+mod annotations;
+#[cfg(feature = "tokio")]
+mod async_io;
+mod bytes;
+#[cfg(feature = "cdi")]
+pub mod cdi;
+#[cfg(all(feature = "clap", feature = "image"))]
+mod cli;
+mod diff;
 #[cfg(feature = "distribution")]
 pub mod distribution;
 mod error;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
 #[cfg(feature = "image")]
 pub mod image;
+#[cfg(any(
+    feature = "cdi",
+    feature = "distribution",
+    feature = "image",
+    feature = "runtime"
+))]
+mod pretty;
 #[cfg(feature = "runtime")]
 pub mod runtime;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "yaml")]
+mod yaml;
 
-use std::{
-    fs::{self, OpenOptions},
-    io::{Read, Write},
-    path::Path,
-};
+#[cfg(all(has_fs, any(feature = "cdi", feature = "image", feature = "runtime")))]
+use std::fs;
+use std::io::{Read, Write};
+#[cfg(all(
+    has_fs,
+    any(
+        feature = "cdi",
+        feature = "distribution",
+        feature = "image",
+        feature = "runtime"
+    )
+))]
+use std::{fs::OpenOptions, path::Path};
 
-use serde::{de::DeserializeOwned, Serialize};
+use serde::{
+    de::{self, DeserializeOwned},
+    Deserialize, Deserializer, Serialize,
+};
+use serde_json::error::Category;
 
+pub use annotations::*;
+pub use diff::*;
 pub use error::*;
+#[cfg(any(
+    feature = "cdi",
+    feature = "distribution",
+    feature = "image",
+    feature = "runtime"
+))]
+pub use pretty::*;
+#[cfg(feature = "yaml")]
+pub use yaml::*;
 
+#[cfg(all(has_fs, any(feature = "cdi", feature = "image", feature = "runtime")))]
 fn from_file<P: AsRef<Path>, T: DeserializeOwned>(path: P) -> Result<T> {
     let path = path.as_ref();
     let manifest_file = fs::File::open(path)?;
-    let manifest = serde_json::from_reader(&manifest_file)?;
-    Ok(manifest)
+    from_reader(&manifest_file)
 }
 
 fn from_reader<R: Read, T: DeserializeOwned>(reader: R) -> Result<T> {
-    let manifest = serde_json::from_reader(reader)?;
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let manifest = from_deserializer(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(manifest)
+}
+
+fn from_slice<T: DeserializeOwned>(slice: &[u8]) -> Result<T> {
+    let mut deserializer = serde_json::Deserializer::from_slice(slice);
+    let manifest = from_deserializer(&mut deserializer)?;
+    deserializer.end()?;
     Ok(manifest)
 }
 
+/// Deserializes a document and, if it does not match the expected structure,
+/// prefixes the error with the path of the offending field, e.g.
+/// `process.user.uid: invalid type: string "root", expected u32 at line 4 column 19`.
+fn from_deserializer<'de, D, T>(deserializer: D) -> Result<T>
+where
+    D: Deserializer<'de, Error = serde_json::Error>,
+    T: Deserialize<'de>,
+{
+    serde_path_to_error::deserialize(deserializer).map_err(|err| {
+        let path = err.path().to_string();
+        let err = err.into_inner();
+        match err.classify() {
+            Category::Data if path != "." => de::Error::custom(format_args!("{}: {}", path, err)),
+            _ => err,
+        }
+        .into()
+    })
+}
+
+#[cfg(all(has_fs, any(feature = "cdi", feature = "image", feature = "runtime")))]
 fn to_file<P: AsRef<Path>, T: Serialize>(item: &T, path: P, pretty: bool) -> Result<()> {
     let path = path.as_ref();
     let file = OpenOptions::new()
@@ -50,6 +124,30 @@ fn to_file<P: AsRef<Path>, T: Serialize>(item: &T, path: P, pretty: bool) -> Res
     Ok(())
 }
 
+#[cfg(all(
+    has_fs,
+    any(
+        feature = "cdi",
+        feature = "distribution",
+        feature = "image",
+        feature = "runtime"
+    )
+))]
+fn to_file_with<P: AsRef<Path>, T: Serialize>(
+    item: &T,
+    path: P,
+    options: &PrettyOptions,
+) -> Result<()> {
+    let path = path.as_ref();
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?;
+
+    pretty::to_writer_with(item, &mut file, options)
+}
+
 fn to_writer<W: Write, T: Serialize>(item: &T, writer: &mut W, pretty: bool) -> Result<()> {
     match pretty {
         true => serde_json::to_writer_pretty(writer, item)?,
@@ -58,3 +156,12 @@ fn to_writer<W: Write, T: Serialize>(item: &T, writer: &mut W, pretty: bool) ->
 
     Ok(())
 }
+
+fn to_vec<T: Serialize>(item: &T, pretty: bool) -> Result<Vec<u8>> {
+    let vec = match pretty {
+        true => serde_json::to_vec_pretty(item)?,
+        false => serde_json::to_vec(item)?,
+    };
+
+    Ok(vec)
+}
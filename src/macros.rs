@@ -21,6 +21,20 @@ macro_rules! make_pub {
 
 #[cfg(feature = "builder")]
 macro_rules! make_pub {
+    // Assigns a field of the builder, keeping the `cfg` attributes of the
+    // field and skipping all others.
+    (@assign $builder:ident, $value:ident, $field:ident; [$($cfg:tt)*]) => {
+        $($cfg)* make_pub!(@set $builder, $value, $field);
+    };
+    (@assign $builder:ident, $value:ident, $field:ident; [$($cfg:tt)*] #[cfg $($args:tt)*] $($rest:tt)*) => {
+        make_pub!(@assign $builder, $value, $field; [$($cfg)* #[cfg $($args)*]] $($rest)*);
+    };
+    (@assign $builder:ident, $value:ident, $field:ident; [$($cfg:tt)*] #[$inner:ident $($args:tt)*] $($rest:tt)*) => {
+        make_pub!(@assign $builder, $value, $field; [$($cfg)*] $($rest)*);
+    };
+    (@set $builder:ident, $value:ident, $field:ident) => {
+        $builder.$field = Some($value.$field);
+    };
     {
         $(#[$outer:meta])*
         struct $name:ident {
@@ -34,8 +48,64 @@ macro_rules! make_pub {
         pub struct $name {
             $(
                 $(#[$inner $($args)*])*
-                $field: $t,
+                pub(crate) $field: $t,
             )*
         }
+
+        paste::paste! {
+            impl From<$name> for [<$name Builder>] {
+                #[allow(deprecated)]
+                fn from(value: $name) -> Self {
+                    let mut builder = [<$name Builder>]::default();
+                    $(make_pub!(@assign builder, value, $field; [] $(#[$inner $($args)*])*);)*
+                    builder
+                }
+            }
+
+            impl $name {
+                /// Returns a builder which is initialized with the values of
+                /// `self`, e.g. to change a single field of a loaded
+                /// configuration.
+                pub fn to_builder(&self) -> [<$name Builder>] {
+                    self.clone().into()
+                }
+            }
+        }
     }
 }
+
+/// Calls the given macro with the paths of all top-level documents of the
+/// specs, each preceded by the `cfg` attribute of its feature. The macro has
+/// to accept a list of `$(#[$cfg:meta])* $name:path` items.
+macro_rules! for_each_document {
+    ($callback:ident) => {
+        $callback!(
+            #[cfg(feature = "cdi")]
+            crate::cdi::CdiSpec,
+            #[cfg(feature = "distribution")]
+            crate::distribution::ErrorResponse,
+            #[cfg(feature = "distribution")]
+            crate::distribution::ExtensionList,
+            #[cfg(feature = "distribution")]
+            crate::distribution::RepositoryList,
+            #[cfg(feature = "distribution")]
+            crate::distribution::TagList,
+            #[cfg(feature = "distribution")]
+            crate::distribution::TokenResponse,
+            #[cfg(feature = "image")]
+            crate::image::ImageConfiguration,
+            #[cfg(feature = "image")]
+            crate::image::ImageIndex,
+            #[cfg(feature = "image")]
+            crate::image::ImageManifest,
+            #[cfg(feature = "image")]
+            crate::image::OciLayout,
+            #[cfg(feature = "runtime")]
+            crate::runtime::Features,
+            #[cfg(feature = "runtime")]
+            crate::runtime::Spec,
+            #[cfg(feature = "runtime")]
+            crate::runtime::State,
+        );
+    };
+}
@@ -0,0 +1,178 @@
+//! Formatting options for pretty printed JSON output.
+
+use std::io::Write;
+#[cfg(has_fs)]
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::{ser::PrettyFormatter, Map, Serializer, Value};
+
+use crate::error::Result;
+
+/// Options to control the formatting of pretty printed JSON documents, e.g. to
+/// produce byte-for-byte identical output to tools written in other languages
+/// when the written files are content addressed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrettyOptions {
+    /// The number of spaces used for each level of indentation. Defaults to
+    /// `2`.
+    pub indent: usize,
+    /// Whether a newline is appended after the document. Defaults to `false`.
+    pub trailing_newline: bool,
+    /// Whether the keys of all objects are sorted lexicographically instead of
+    /// being written in the order of the struct fields. Defaults to `false`.
+    pub sort_keys: bool,
+}
+
+impl Default for PrettyOptions {
+    fn default() -> Self {
+        PrettyOptions {
+            indent: 2,
+            trailing_newline: false,
+            sort_keys: false,
+        }
+    }
+}
+
+pub(crate) fn to_writer_with<W: Write, T: Serialize>(
+    item: &T,
+    writer: &mut W,
+    options: &PrettyOptions,
+) -> Result<()> {
+    let indent = vec![b' '; options.indent];
+    let mut serializer =
+        Serializer::with_formatter(&mut *writer, PrettyFormatter::with_indent(&indent));
+
+    if options.sort_keys {
+        sort_keys(serde_json::to_value(item)?).serialize(&mut serializer)?;
+    } else {
+        item.serialize(&mut serializer)?;
+    }
+
+    if options.trailing_newline {
+        writer.write_all(b"\n")?;
+    }
+
+    Ok(())
+}
+
+macro_rules! impl_pretty {
+    ($($(#[$cfg:meta])* $name:path),* $(,)?) => {
+        $(
+            $(#[$cfg])*
+            impl $name {
+                #[cfg(has_fs)]
+                /// Attempts to write the document to a file as JSON which is
+                /// pretty printed according to the given options. If the file
+                /// already exists, it will be overwritten.
+                /// # Errors
+                /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+                /// if the file cannot be written or an
+                /// [OciSpecError::SerDe](crate::OciSpecError::SerDe) if the
+                /// document cannot be serialized.
+                pub fn to_file_pretty_with<P: AsRef<Path>>(
+                    &self,
+                    path: P,
+                    options: &PrettyOptions,
+                ) -> Result<()> {
+                    crate::to_file_with(self, path, options)
+                }
+
+                /// Attempts to write the document to a stream as JSON which
+                /// is pretty printed according to the given options, e.g. to
+                /// match the output of other tools byte for byte.
+                /// # Errors
+                /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+                /// if the document cannot be serialized.
+                pub fn to_writer_pretty_with<W: Write>(
+                    &self,
+                    writer: &mut W,
+                    options: &PrettyOptions,
+                ) -> Result<()> {
+                    to_writer_with(self, writer, options)
+                }
+            }
+        )*
+    };
+}
+
+for_each_document!(impl_pretty);
+
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (k, sort_keys(v)))
+                    .collect::<Map<_, _>>(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn default_matches_serde_json() {
+        // arrange
+        let value = json!({"b": [1, 2], "a": {"d": null, "c": "x"}});
+        let mut actual = Vec::new();
+
+        // act
+        to_writer_with(&value, &mut actual, &PrettyOptions::default()).expect("to writer");
+
+        // assert
+        let expected = serde_json::to_vec_pretty(&value).expect("to vec");
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn custom_options() {
+        // arrange
+        let value = json!({"b": [1], "a": {"d": null, "c": "x"}});
+        let options = PrettyOptions {
+            indent: 4,
+            trailing_newline: true,
+            sort_keys: true,
+        };
+        let mut actual = Vec::new();
+
+        // act
+        to_writer_with(&value, &mut actual, &options).expect("to writer");
+
+        // assert
+        let expected = "{\n    \"a\": {\n        \"c\": \"x\",\n        \"d\": null\n    },\n    \"b\": [\n        1\n    ]\n}\n";
+        assert_eq!(String::from_utf8(actual).unwrap(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "runtime")]
+    fn runtime_spec_with_options() {
+        use crate::runtime::Spec;
+
+        // arrange
+        let spec = Spec::default();
+        let options = PrettyOptions {
+            trailing_newline: true,
+            ..Default::default()
+        };
+        let mut actual = Vec::new();
+
+        // act
+        spec.to_writer_pretty_with(&mut actual, &options)
+            .expect("to writer");
+
+        // assert
+        let mut expected = serde_json::to_vec_pretty(&spec).expect("to vec");
+        expected.push(b'\n');
+        assert_eq!(actual, expected);
+    }
+}
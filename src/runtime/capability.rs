@@ -2,12 +2,15 @@ use serde::{
     de::{Deserializer, Error},
     Deserialize, Serialize,
 };
-use std::collections::HashSet;
+use std::{collections::HashSet, fmt, str::FromStr};
+
+use crate::error::{oci_error, OciSpecError};
 
 /// Capabilities is a unique set of Capability values.
 pub type Capabilities = HashSet<Capability>;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 /// All available capabilities.
 ///
 /// For the purpose of performing permission checks, traditional UNIX
@@ -516,12 +519,144 @@ pub enum Capability {
     WakeAlarm,
 }
 
-impl<'de> Deserialize<'de> for Capability {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let input = String::deserialize(deserializer)?;
+impl Capability {
+    /// All capabilities known to the crate.
+    pub const ALL: [Capability; 41] = [
+        Capability::AuditControl,
+        Capability::AuditRead,
+        Capability::AuditWrite,
+        Capability::BlockSuspend,
+        Capability::Bpf,
+        Capability::CheckpointRestore,
+        Capability::Chown,
+        Capability::DacOverride,
+        Capability::DacReadSearch,
+        Capability::Fowner,
+        Capability::Fsetid,
+        Capability::IpcLock,
+        Capability::IpcOwner,
+        Capability::Kill,
+        Capability::Lease,
+        Capability::LinuxImmutable,
+        Capability::MacAdmin,
+        Capability::MacOverride,
+        Capability::Mknod,
+        Capability::NetAdmin,
+        Capability::NetBindService,
+        Capability::NetBroadcast,
+        Capability::NetRaw,
+        Capability::Perfmon,
+        Capability::Setgid,
+        Capability::Setfcap,
+        Capability::Setpcap,
+        Capability::Setuid,
+        Capability::SysAdmin,
+        Capability::SysBoot,
+        Capability::SysChroot,
+        Capability::SysModule,
+        Capability::SysNice,
+        Capability::SysPacct,
+        Capability::SysPtrace,
+        Capability::SysRawio,
+        Capability::SysResource,
+        Capability::SysTime,
+        Capability::SysTtyConfig,
+        Capability::Syslog,
+        Capability::WakeAlarm,
+    ];
+
+    /// The default bounding set of runc and moby, which is used by most
+    /// container engines for unprivileged containers.
+    pub const DEFAULTS: [Capability; 14] = [
+        Capability::AuditWrite,
+        Capability::Chown,
+        Capability::DacOverride,
+        Capability::Fowner,
+        Capability::Fsetid,
+        Capability::Kill,
+        Capability::Mknod,
+        Capability::NetBindService,
+        Capability::NetRaw,
+        Capability::Setfcap,
+        Capability::Setgid,
+        Capability::Setpcap,
+        Capability::Setuid,
+        Capability::SysChroot,
+    ];
+
+    /// Returns the set of all capabilities, as granted to privileged
+    /// containers.
+    pub fn all() -> Capabilities {
+        Self::ALL.iter().copied().collect()
+    }
+
+    /// Returns the default capability set of runc and moby, see
+    /// [Capability::DEFAULTS].
+    pub fn default_set() -> Capabilities {
+        Self::DEFAULTS.iter().copied().collect()
+    }
+
+    /// Returns the name of the capability including the `CAP_` prefix, as used
+    /// in the runtime configuration.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AuditControl => "CAP_AUDIT_CONTROL",
+            Self::AuditRead => "CAP_AUDIT_READ",
+            Self::AuditWrite => "CAP_AUDIT_WRITE",
+            Self::BlockSuspend => "CAP_BLOCK_SUSPEND",
+            Self::Bpf => "CAP_BPF",
+            Self::CheckpointRestore => "CAP_CHECKPOINT_RESTORE",
+            Self::Chown => "CAP_CHOWN",
+            Self::DacOverride => "CAP_DAC_OVERRIDE",
+            Self::DacReadSearch => "CAP_DAC_READ_SEARCH",
+            Self::Fowner => "CAP_FOWNER",
+            Self::Fsetid => "CAP_FSETID",
+            Self::IpcLock => "CAP_IPC_LOCK",
+            Self::IpcOwner => "CAP_IPC_OWNER",
+            Self::Kill => "CAP_KILL",
+            Self::Lease => "CAP_LEASE",
+            Self::LinuxImmutable => "CAP_LINUX_IMMUTABLE",
+            Self::MacAdmin => "CAP_MAC_ADMIN",
+            Self::MacOverride => "CAP_MAC_OVERRIDE",
+            Self::Mknod => "CAP_MKNOD",
+            Self::NetAdmin => "CAP_NET_ADMIN",
+            Self::NetBindService => "CAP_NET_BIND_SERVICE",
+            Self::NetBroadcast => "CAP_NET_BROADCAST",
+            Self::NetRaw => "CAP_NET_RAW",
+            Self::Perfmon => "CAP_PERFMON",
+            Self::Setgid => "CAP_SETGID",
+            Self::Setfcap => "CAP_SETFCAP",
+            Self::Setpcap => "CAP_SETPCAP",
+            Self::Setuid => "CAP_SETUID",
+            Self::SysAdmin => "CAP_SYS_ADMIN",
+            Self::SysBoot => "CAP_SYS_BOOT",
+            Self::SysChroot => "CAP_SYS_CHROOT",
+            Self::SysModule => "CAP_SYS_MODULE",
+            Self::SysNice => "CAP_SYS_NICE",
+            Self::SysPacct => "CAP_SYS_PACCT",
+            Self::SysPtrace => "CAP_SYS_PTRACE",
+            Self::SysRawio => "CAP_SYS_RAWIO",
+            Self::SysResource => "CAP_SYS_RESOURCE",
+            Self::SysTime => "CAP_SYS_TIME",
+            Self::SysTtyConfig => "CAP_SYS_TTY_CONFIG",
+            Self::Syslog => "CAP_SYSLOG",
+            Self::WakeAlarm => "CAP_WAKE_ALARM",
+        }
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Capability {
+    type Err = OciSpecError;
+
+    /// Parses a capability name case insensitively, with or without the `CAP_`
+    /// prefix.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
         let upper = input.to_uppercase();
         let stripped = upper.strip_prefix("CAP_").unwrap_or(&upper);
         match stripped {
@@ -566,7 +701,7 @@ impl<'de> Deserialize<'de> for Capability {
             "SYS_TTY_CONFIG" => Ok(Self::SysTtyConfig),
             "SYSLOG" => Ok(Self::Syslog),
             "WAKE_ALARM" => Ok(Self::WakeAlarm),
-            other => Err(Error::custom(format!(
+            other => Err(oci_error(format!(
                 "no variant for {} (converted to {})",
                 input, other,
             ))),
@@ -574,6 +709,16 @@ impl<'de> Deserialize<'de> for Capability {
     }
 }
 
+impl<'de> Deserialize<'de> for Capability {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let input = String::deserialize(deserializer)?;
+        input.parse().map_err(Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -588,13 +733,41 @@ mod tests {
 
     #[test]
     fn deserialize() -> Result<()> {
-        for case in vec!["SYSLOG", "CAP_SYSLOG", "cap_SYSLOG", "sySloG"] {
+        for case in &["SYSLOG", "CAP_SYSLOG", "cap_SYSLOG", "sySloG"] {
             let res: Capability = serde_json::from_str(&format!("\"{}\"", case))?;
             assert_eq!(Capability::Syslog, res);
         }
         Ok(())
     }
 
+    #[test]
+    fn parse_and_display() -> Result<()> {
+        assert_eq!("net_raw".parse::<Capability>()?, Capability::NetRaw);
+        assert_eq!(Capability::SysPtrace.to_string(), "CAP_SYS_PTRACE");
+        assert!("CAP_DOES_NOT_EXIST".parse::<Capability>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_all_forms() -> Result<()> {
+        for capability in Capability::ALL.iter() {
+            let canonical = capability.to_string();
+            let name = canonical.strip_prefix("CAP_").unwrap();
+            for input in [canonical.clone(), name.to_string(), name.to_lowercase()] {
+                assert_eq!(input.parse::<Capability>()?, *capability, "{}", input);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn capability_sets() {
+        let all = Capability::all();
+        assert_eq!(all.len(), Capability::ALL.len());
+        assert!(Capability::default_set().is_subset(&all));
+        assert!(!Capability::default_set().contains(&Capability::SysAdmin));
+    }
+
     #[test]
     fn capabilities() -> Result<()> {
         let res: Capabilities = serde_json::from_str(
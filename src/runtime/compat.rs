@@ -0,0 +1,194 @@
+//! Downgrading of runtime specs for runtimes which implement an older
+//! version of the specification.
+
+use super::Spec;
+
+/// A released version of the runtime specification which a spec can be
+/// written for, see [Spec::to_version].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SpecVersion {
+    /// The 1.0.x releases.
+    V1_0,
+    /// The 1.1.x releases, which added e.g. the process scheduler, the I/O
+    /// priority, time namespace offsets and idmapped mounts.
+    V1_1,
+    /// The 1.2.x releases and later, which added e.g. network devices,
+    /// memory policies and z/OS support. Specs are written unchanged.
+    V1_2,
+}
+
+impl SpecVersion {
+    /// Returns the major and minor version and the latest release of the
+    /// version, which downgraded specs declare as their `ociVersion`. Specs
+    /// for the latest version keep their `ociVersion`.
+    fn release(self) -> Option<((u64, u64), &'static str)> {
+        match self {
+            Self::V1_0 => Some(((1, 0), "1.0.2")),
+            Self::V1_1 => Some(((1, 1), "1.1.0")),
+            Self::V1_2 => None,
+        }
+    }
+}
+
+/// Returns the major and minor version of an `ociVersion`, if it is valid.
+fn parse_major_minor(version: &str) -> Option<(u64, u64)> {
+    let mut parts = version.splitn(3, '.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+fn drop_field<T>(field: &mut Option<T>, path: String, dropped: &mut Vec<String>) {
+    if field.take().is_some() {
+        dropped.push(path);
+    }
+}
+
+impl Spec {
+    /// Returns a copy of the spec without the properties which the given
+    /// version of the specification does not define, so that it can be
+    /// handed to a runtime which implements that version. If the spec claims a
+    /// newer `ociVersion`, it is set to the latest release of the given
+    /// version. The second value contains the JSON pointers of the values
+    /// which were dropped or rewritten, callers SHOULD warn about them as the
+    /// container may behave differently.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::{Spec, SpecVersion};
+    ///
+    /// let spec: Spec = serde_json::from_str(r#"{
+    ///     "ociVersion": "1.1.0",
+    ///     "process": {
+    ///         "cwd": "/",
+    ///         "user": {"uid": 0, "gid": 0},
+    ///         "ioPriority": {"class": "IOPRIO_CLASS_IDLE", "priority": 0}
+    ///     }
+    /// }"#).unwrap();
+    ///
+    /// let (spec, dropped) = spec.to_version(SpecVersion::V1_0);
+    /// assert_eq!(dropped, vec!["/ociVersion", "/process/ioPriority"]);
+    /// assert_eq!(serde_json::to_value(&spec).unwrap()["ociVersion"], "1.0.2");
+    /// assert!(!serde_json::to_string(&spec).unwrap().contains("ioPriority"));
+    /// ```
+    pub fn to_version(&self, version: SpecVersion) -> (Spec, Vec<String>) {
+        let mut spec = self.clone();
+        let mut dropped = Vec::new();
+
+        if let Some((major_minor, release)) = version.release() {
+            if parse_major_minor(&spec.version).is_some_and(|v| v > major_minor) {
+                spec.version = release.to_owned();
+                dropped.push("/ociVersion".to_owned());
+            }
+        }
+
+        if version < SpecVersion::V1_2 {
+            if let Some(process) = spec.process.as_mut() {
+                drop_field(
+                    &mut process.exec_cpu_affinity,
+                    "/process/execCPUAffinity".to_owned(),
+                    &mut dropped,
+                );
+            }
+            if let Some(linux) = spec.linux.as_mut() {
+                drop_field(
+                    &mut linux.memory_policy,
+                    "/linux/memoryPolicy".to_owned(),
+                    &mut dropped,
+                );
+                drop_field(
+                    &mut linux.net_devices,
+                    "/linux/netDevices".to_owned(),
+                    &mut dropped,
+                );
+                if let Some(cpu) = linux.resources.as_mut().and_then(|r| r.cpu.as_mut()) {
+                    drop_field(
+                        &mut cpu.burst,
+                        "/linux/resources/cpu/burst".to_owned(),
+                        &mut dropped,
+                    );
+                }
+            }
+            drop_field(&mut spec.zos, "/zos".to_owned(), &mut dropped);
+        }
+
+        if version < SpecVersion::V1_1 {
+            for (i, mount) in spec.mounts.iter_mut().flatten().enumerate() {
+                drop_field(
+                    &mut mount.uid_mappings,
+                    format!("/mounts/{}/uidMappings", i),
+                    &mut dropped,
+                );
+                drop_field(
+                    &mut mount.gid_mappings,
+                    format!("/mounts/{}/gidMappings", i),
+                    &mut dropped,
+                );
+            }
+            if let Some(process) = spec.process.as_mut() {
+                drop_field(
+                    &mut process.scheduler,
+                    "/process/scheduler".to_owned(),
+                    &mut dropped,
+                );
+                drop_field(
+                    &mut process.io_priority,
+                    "/process/ioPriority".to_owned(),
+                    &mut dropped,
+                );
+            }
+            if let Some(linux) = spec.linux.as_mut() {
+                drop_field(
+                    &mut linux.time_offsets,
+                    "/linux/timeOffsets".to_owned(),
+                    &mut dropped,
+                );
+                drop_field(
+                    &mut linux.personality,
+                    "/linux/personality".to_owned(),
+                    &mut dropped,
+                );
+                if let Some(memory) = linux.resources.as_mut().and_then(|r| r.memory.as_mut()) {
+                    drop_field(
+                        &mut memory.check_before_update,
+                        "/linux/resources/memory/checkBeforeUpdate".to_owned(),
+                        &mut dropped,
+                    );
+                }
+                if let Some(cpu) = linux.resources.as_mut().and_then(|r| r.cpu.as_mut()) {
+                    drop_field(
+                        &mut cpu.idle,
+                        "/linux/resources/cpu/idle".to_owned(),
+                        &mut dropped,
+                    );
+                }
+                if let Some(intel_rdt) = linux.intel_rdt.as_mut() {
+                    drop_field(
+                        &mut intel_rdt.enable_cmt,
+                        "/linux/intelRdt/enableCMT".to_owned(),
+                        &mut dropped,
+                    );
+                    drop_field(
+                        &mut intel_rdt.enable_mbm,
+                        "/linux/intelRdt/enableMBM".to_owned(),
+                        &mut dropped,
+                    );
+                }
+                if let Some(seccomp) = linux.seccomp.as_mut() {
+                    drop_field(
+                        &mut seccomp.listener_path,
+                        "/linux/seccomp/listenerPath".to_owned(),
+                        &mut dropped,
+                    );
+                    drop_field(
+                        &mut seccomp.listener_metadata,
+                        "/linux/seccomp/listenerMetadata".to_owned(),
+                        &mut dropped,
+                    );
+                }
+            }
+        }
+
+        dropped.sort();
+        (spec, dropped)
+    }
+}
@@ -0,0 +1,167 @@
+use std::{convert::TryFrom, path::PathBuf};
+
+use super::{LinuxDevice, LinuxDeviceCgroup, Mount};
+use crate::error::{oci_error, OciSpecError, Result};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// MountPropagation of the CRI API, which specifies how mounts are propagated
+/// between the host and the container.
+pub enum CriMountPropagation {
+    /// No mount propagation ("rprivate" in Linux terminology).
+    #[default]
+    Private,
+    /// Mounts get propagated from the host to the container ("rslave" in
+    /// Linux terminology).
+    HostToContainer,
+    /// Mounts get propagated from the host to the container and from the
+    /// container to the host ("rshared" in Linux terminology).
+    Bidirectional,
+}
+
+impl CriMountPropagation {
+    /// Returns the mount option which corresponds to the propagation mode.
+    pub fn as_option(&self) -> &str {
+        match self {
+            Self::Private => "rprivate",
+            Self::HostToContainer => "rslave",
+            Self::Bidirectional => "rshared",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+/// Mount of the CRI API, which specifies a host volume to mount into a
+/// container.
+pub struct CriMount {
+    /// Path of the mount within the container.
+    pub container_path: PathBuf,
+    /// Path of the mount on the host.
+    pub host_path: PathBuf,
+    /// If set, the mount is read-only.
+    pub readonly: bool,
+    /// If set, the mount needs SELinux relabeling. This has no equivalent
+    /// in the mount of the runtime spec and is not converted.
+    pub selinux_relabel: bool,
+    /// Requested propagation mode.
+    pub propagation: CriMountPropagation,
+    /// If set, the mount is made recursively read-only.
+    pub recursive_read_only: bool,
+}
+
+impl From<&CriMount> for Mount {
+    fn from(mount: &CriMount) -> Mount {
+        let access = if mount.recursive_read_only {
+            "rro"
+        } else if mount.readonly {
+            "ro"
+        } else {
+            "rw"
+        };
+
+        Mount {
+            destination: mount.container_path.clone(),
+            typ: Some("bind".to_owned()),
+            source: Some(mount.host_path.clone()),
+            options: Some(vec![
+                "rbind".to_owned(),
+                mount.propagation.as_option().to_owned(),
+                access.to_owned(),
+            ]),
+            uid_mappings: None,
+            gid_mappings: None,
+        }
+    }
+}
+
+impl TryFrom<&Mount> for CriMount {
+    type Error = OciSpecError;
+
+    fn try_from(mount: &Mount) -> Result<Self> {
+        let options = mount.options.as_deref().unwrap_or_default();
+        let has = |option: &str| options.iter().any(|o| o == option);
+
+        if mount.typ.as_deref() != Some("bind") && !has("bind") && !has("rbind") {
+            return Err(oci_error(format!(
+                "mount {} is not a bind mount",
+                mount.destination.display()
+            )));
+        }
+        let host_path = mount.source.clone().ok_or_else(|| {
+            oci_error(format!(
+                "bind mount {} has no source",
+                mount.destination.display()
+            ))
+        })?;
+
+        let propagation = options
+            .iter()
+            .rev()
+            .find_map(|o| match o.as_str() {
+                "rprivate" | "private" => Some(CriMountPropagation::Private),
+                "rslave" | "slave" => Some(CriMountPropagation::HostToContainer),
+                "rshared" | "shared" => Some(CriMountPropagation::Bidirectional),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        Ok(CriMount {
+            container_path: mount.destination.clone(),
+            host_path,
+            readonly: has("ro") || has("rro"),
+            selinux_relabel: false,
+            propagation,
+            recursive_read_only: has("rro"),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+/// Device of the CRI API, which specifies a host device to mount into a
+/// container.
+pub struct CriDevice {
+    /// Path of the device within the container.
+    pub container_path: PathBuf,
+    /// Path of the device on the host.
+    pub host_path: PathBuf,
+    /// Cgroups permissions of the device, candidates are one or more of
+    /// `r` - allows container to read from the specified device,
+    /// `w` - allows container to write to the specified device and
+    /// `m` - allows container to create device files that do not yet exist.
+    pub permissions: String,
+}
+
+impl CriDevice {
+    /// Creates the device node configuration of the runtime spec by
+    /// inspecting the device on the host.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the host device cannot be inspected or an
+    /// [OciSpecError::Other](crate::OciSpecError::Other) if the host path is
+    /// not a device.
+    #[cfg(unix)]
+    pub fn to_linux_device(&self) -> Result<LinuxDevice> {
+        Ok(LinuxDevice {
+            path: self.container_path.clone(),
+            ..LinuxDevice::from_path(&self.host_path)?
+        })
+    }
+
+    /// Creates the device cgroup rule which allows access to the given device
+    /// with the permissions of this device.
+    pub fn to_device_cgroup(&self, device: &LinuxDevice) -> LinuxDeviceCgroup {
+        LinuxDeviceCgroup {
+            access: Some(self.permissions.clone()),
+            ..device.into()
+        }
+    }
+}
+
+impl From<&LinuxDevice> for CriDevice {
+    fn from(device: &LinuxDevice) -> CriDevice {
+        CriDevice {
+            container_path: device.path.clone(),
+            host_path: device.path.clone(),
+            permissions: "rwm".to_owned(),
+        }
+    }
+}
@@ -0,0 +1,382 @@
+use serde::{Deserialize, Serialize};
+use std::{io::Read, path::PathBuf};
+
+#[cfg(has_fs)]
+use crate::from_file;
+use crate::{error::Result, from_reader};
+#[cfg(has_fs)]
+use std::path::Path;
+
+use super::{
+    Arch, Capabilities, Capability, LinuxSeccomp, LinuxSeccompAction, LinuxSeccompArg,
+    LinuxSeccompFilterFlag, LinuxSyscall,
+};
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get_mut = "pub", set = "pub")
+    )]
+    /// DockerSeccompProfile is a seccomp profile in the format used by Docker
+    /// and moby, which selects syscall rules depending on the architecture
+    /// and the capabilities of the container. It is converted into the
+    /// runtime spec format with [DockerSeccompProfile::to_linux_seccomp].
+    struct DockerSeccompProfile {
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// The default action to be done.
+        default_action: LinuxSeccompAction,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// The errno returned by the default action.
+        default_errno_ret: Option<u32>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// The architectures of the profile, used if no `archMap` is given.
+        architectures: Option<Vec<Arch>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// The architectures of the profile with their sub architectures.
+        arch_map: Option<Vec<DockerSeccompArchMap>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// Flags added to the seccomp restriction.
+        flags: Option<Vec<LinuxSeccompFilterFlag>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// The path of the UNIX socket of the seccomp agent.
+        listener_path: Option<PathBuf>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// Opaque data which is passed to the seccomp agent.
+        listener_metadata: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// The syscall rules of the profile.
+        syscalls: Option<Vec<DockerSeccompSyscall>>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get_mut = "pub", set = "pub")
+    )]
+    /// DockerSeccompArchMap maps an architecture to the sub architectures
+    /// which are allowed along with it, e.g. x86 and x32 for x86-64.
+    struct DockerSeccompArchMap {
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// The main architecture.
+        architecture: Arch,
+
+        #[serde(default)]
+        #[cfg_attr(feature = "builder", builder(default))]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// The sub architectures of the main architecture.
+        sub_architectures: Vec<Arch>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get_mut = "pub", set = "pub")
+    )]
+    /// DockerSeccompSyscall is a syscall rule of a Docker seccomp profile.
+    struct DockerSeccompSyscall {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// The name of a single syscall, used by older profiles.
+        name: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// The names of the syscalls.
+        names: Option<Vec<String>>,
+
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// The action to be done for the syscalls.
+        action: LinuxSeccompAction,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// The error return value.
+        errno_ret: Option<u32>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// The arguments for the syscalls.
+        args: Option<Vec<LinuxSeccompArg>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// A free form comment, which is not part of the converted rule.
+        comment: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// The conditions which all have to be met for the rule to apply.
+        includes: Option<DockerSeccompFilter>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// The conditions of which none may be met for the rule to apply.
+        excludes: Option<DockerSeccompFilter>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// DockerSeccompFilter is a condition of a syscall rule of a Docker
+    /// seccomp profile.
+    struct DockerSeccompFilter {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Go architecture names, e.g. `amd64` or `arm64`.
+        arches: Option<Vec<String>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Capability names, e.g. `CAP_SYS_ADMIN`.
+        caps: Option<Vec<String>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The minimum kernel version, e.g. `4.8`.
+        min_kernel: Option<String>,
+    }
+);
+
+impl DockerSeccompProfile {
+    #[cfg(has_fs)]
+    /// Attempts to load a Docker seccomp profile from a file.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the file does not exist or an
+    /// [OciSpecError::SerDe](crate::OciSpecError::SerDe) if the profile cannot
+    /// be deserialized.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::runtime::DockerSeccompProfile;
+    ///
+    /// let profile = DockerSeccompProfile::from_file("default.json").unwrap();
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<DockerSeccompProfile> {
+        from_file(path)
+    }
+
+    /// Attempts to load a Docker seccomp profile from a stream.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the profile cannot be deserialized.
+    pub fn from_reader<R: Read>(reader: R) -> Result<DockerSeccompProfile> {
+        from_reader(reader)
+    }
+
+    /// Converts the profile into the runtime spec format for a container on
+    /// the architecture `arch` with the `capabilities` in its bounding set,
+    /// in the same way as moby does. [Arch::ScmpArchNative] refers to the
+    /// architecture the crate was compiled for. Rules which are not included
+    /// or which are excluded for the architecture or capabilities are
+    /// dropped, and only the `archMap` entry of `arch` is kept. Minimum kernel
+    /// versions are not checked, i.e. the kernel is assumed to be recent
+    /// enough for all rules.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::{Arch, Capabilities, Capability, DockerSeccompProfile};
+    ///
+    /// let profile: DockerSeccompProfile = serde_json::from_str(
+    ///     r#"{
+    ///         "defaultAction": "SCMP_ACT_ERRNO",
+    ///         "syscalls": [
+    ///             {"names": ["read", "write"], "action": "SCMP_ACT_ALLOW"},
+    ///             {"names": ["mount"], "action": "SCMP_ACT_ALLOW",
+    ///              "includes": {"caps": ["CAP_SYS_ADMIN"]}}
+    ///         ]
+    ///     }"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let seccomp = profile.to_linux_seccomp(Arch::ScmpArchX86_64, &Capabilities::new());
+    /// assert_eq!(serde_json::to_value(&seccomp).unwrap()["syscalls"].as_array().unwrap().len(), 1);
+    /// ```
+    pub fn to_linux_seccomp(&self, arch: Arch, capabilities: &Capabilities) -> LinuxSeccomp {
+        let arch = match arch {
+            Arch::ScmpArchNative => Arch::native(),
+            arch => Some(arch),
+        };
+        let architectures = match &self.arch_map {
+            Some(arch_map) if !arch_map.is_empty() => Some(
+                arch_map
+                    .iter()
+                    .filter(|entry| Some(entry.architecture) == arch)
+                    .flat_map(|entry| {
+                        std::iter::once(entry.architecture)
+                            .chain(entry.sub_architectures.iter().copied())
+                    })
+                    .collect(),
+            ),
+            _ => self.architectures.clone(),
+        };
+
+        let go_arch = arch.and_then(go_arch);
+        let syscalls = self
+            .syscalls
+            .iter()
+            .flatten()
+            .filter(|syscall| syscall.applies_to(go_arch, capabilities))
+            .filter_map(|syscall| {
+                let names: Vec<String> = syscall
+                    .names
+                    .iter()
+                    .flatten()
+                    .chain(syscall.name.iter())
+                    .cloned()
+                    .collect();
+                if names.is_empty() {
+                    return None;
+                }
+                Some(LinuxSyscall {
+                    names,
+                    action: syscall.action,
+                    errno_ret: syscall.errno_ret,
+                    args: syscall.args.clone(),
+                })
+            })
+            .collect();
+
+        LinuxSeccomp {
+            default_action: self.default_action,
+            default_errno_ret: self.default_errno_ret,
+            architectures,
+            flags: self.flags.clone(),
+            listener_path: self.listener_path.clone(),
+            listener_metadata: self.listener_metadata.clone(),
+            syscalls: Some(syscalls),
+        }
+    }
+}
+
+impl DockerSeccompSyscall {
+    /// Returns true if the rule is included and not excluded for the
+    /// architecture and capabilities.
+    fn applies_to(&self, go_arch: Option<&str>, capabilities: &Capabilities) -> bool {
+        let has_arch = |arches: &Option<Vec<String>>| {
+            go_arch.is_some_and(|go_arch| arches.iter().flatten().any(|a| a == go_arch))
+        };
+        let has_cap = |cap: &String| {
+            cap.parse::<Capability>()
+                .is_ok_and(|cap| capabilities.contains(&cap))
+        };
+
+        if let Some(includes) = &self.includes {
+            if includes.arches.as_ref().is_some_and(|a| !a.is_empty())
+                && !has_arch(&includes.arches)
+            {
+                return false;
+            }
+            if !includes.caps.iter().flatten().all(has_cap) {
+                return false;
+            }
+        }
+        if let Some(excludes) = &self.excludes {
+            if has_arch(&excludes.arches) || excludes.caps.iter().flatten().any(has_cap) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Returns the Go architecture name, as used in the filters of Docker
+/// seccomp profiles, of the seccomp architecture.
+fn go_arch(arch: Arch) -> Option<&'static str> {
+    match arch {
+        Arch::ScmpArchNative => None,
+        Arch::ScmpArchX86 => Some("386"),
+        Arch::ScmpArchX86_64 | Arch::ScmpArchX32 => Some("amd64"),
+        Arch::ScmpArchArm => Some("arm"),
+        Arch::ScmpArchAarch64 => Some("arm64"),
+        Arch::ScmpArchMips => Some("mips"),
+        Arch::ScmpArchMipsel => Some("mipsle"),
+        Arch::ScmpArchMips64 | Arch::ScmpArchMips64n32 => Some("mips64"),
+        Arch::ScmpArchMipsel64 | Arch::ScmpArchMipsel64n32 => Some("mips64le"),
+        Arch::ScmpArchPpc => Some("ppc"),
+        Arch::ScmpArchPpc64 => Some("ppc64"),
+        Arch::ScmpArchPpc64le => Some("ppc64le"),
+        Arch::ScmpArchS390 => Some("s390"),
+        Arch::ScmpArchS390x => Some("s390x"),
+    }
+}
@@ -0,0 +1,770 @@
+#[cfg(has_fs)]
+use std::path::Path;
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{Read, Write},
+    path::PathBuf,
+};
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(has_fs)]
+use crate::from_file;
+use crate::{error::Result, from_reader, to_writer};
+
+use super::{Hook, Spec};
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// Features represents the supported features of the runtime, as reported
+    /// by e.g. `runc features`. Fields which are not set mean that the
+    /// corresponding feature is unknown, not that it is unsupported.
+    struct Features {
+        #[serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            rename = "ociVersionMin"
+        )]
+        /// The minimum OCI runtime spec version recognized by the runtime, e.g.
+        /// "1.0.0".
+        oci_version_min: Option<String>,
+
+        #[serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            rename = "ociVersionMax"
+        )]
+        /// The maximum OCI runtime spec version recognized by the runtime, e.g.
+        /// "1.1.0".
+        oci_version_max: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The list of the recognized hook names, e.g. "createRuntime".
+        hooks: Option<Vec<String>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The list of the recognized mount options, e.g. "ro". This list does not
+        /// include options which are passed to the mount syscall as data.
+        mount_options: Option<Vec<String>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Linux specific features.
+        linux: Option<LinuxFeature>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Annotations contains implementation-specific annotation strings, such
+        /// as the implementation version.
+        annotations: Option<HashMap<String, String>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The list of annotations which may alter the behavior of the runtime in
+        /// a potentially unsafe way.
+        potentially_unsafe_config_annotations: Option<Vec<String>>,
+    }
+);
+
+impl Features {
+    #[cfg(has_fs)]
+    /// Attempts to load a features document from a file.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the file does not exist or an
+    /// [OciSpecError::SerDe](crate::OciSpecError::SerDe) if the features
+    /// document cannot be deserialized.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::runtime::Features;
+    ///
+    /// let features = Features::from_file("features.json").unwrap();
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Features> {
+        from_file(path)
+    }
+
+    /// Attempts to load a features document from a stream, e.g. the standard
+    /// output of `runc features`.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the features document cannot be deserialized.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::runtime::Features;
+    /// use std::process::Command;
+    ///
+    /// let output = Command::new("runc").arg("features").output().unwrap();
+    /// let features = Features::from_reader(output.stdout.as_slice()).unwrap();
+    /// ```
+    pub fn from_reader<R: Read>(reader: R) -> Result<Features> {
+        from_reader(reader)
+    }
+
+    /// Attempts to write a features document to a stream as JSON.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the features document cannot be serialized.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::runtime::Features;
+    ///
+    /// let features = Features::from_file("features.json").unwrap();
+    /// features.to_writer(&mut std::io::stdout()).unwrap();
+    /// ```
+    pub fn to_writer<W: Write>(&self, writer: &mut W) -> Result<()> {
+        to_writer(&self, writer, false)
+    }
+
+    /// Attempts to write a features document to a stream as pretty printed
+    /// JSON.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the features document cannot be serialized.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::runtime::Features;
+    ///
+    /// let features = Features::from_file("features.json").unwrap();
+    /// features.to_writer_pretty(&mut std::io::stdout()).unwrap();
+    /// ```
+    pub fn to_writer_pretty<W: Write>(&self, writer: &mut W) -> Result<()> {
+        to_writer(&self, writer, true)
+    }
+}
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// LinuxFeature contains the Linux specific features of the runtime.
+    struct LinuxFeature {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The list of the recognized namespaces, e.g. "mount".
+        namespaces: Option<Vec<String>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The list of the recognized capabilities, e.g. "CAP_SYS_ADMIN".
+        capabilities: Option<Vec<String>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The available features related to cgroup.
+        cgroup: Option<CgroupFeature>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The available features related to seccomp.
+        seccomp: Option<SeccompFeature>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The available features related to AppArmor.
+        apparmor: Option<ApparmorFeature>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The available features related to SELinux.
+        selinux: Option<SelinuxFeature>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The available features related to Intel RDT.
+        intel_rdt: Option<IntelRdtFeature>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The available features related to mount extensions.
+        mount_extensions: Option<MountExtensionsFeature>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// CgroupFeature represents the cgroup features of the runtime.
+    struct CgroupFeature {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// V1 represents whether cgroup v1 support is compiled in.
+        v1: Option<bool>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// V2 represents whether cgroup v2 support is compiled in.
+        v2: Option<bool>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Systemd represents whether systemd-cgroup support is compiled in.
+        systemd: Option<bool>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// SystemdUser represents whether user-scoped systemd-cgroup support is
+        /// compiled in.
+        systemd_user: Option<bool>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Rdma represents whether RDMA cgroup support is compiled in.
+        rdma: Option<bool>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// SeccompFeature represents the seccomp features of the runtime.
+    struct SeccompFeature {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Enabled is true if seccomp support is compiled in.
+        enabled: Option<bool>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The list of the recognized actions, e.g. "SCMP_ACT_ALLOW".
+        actions: Option<Vec<String>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The list of the recognized operators, e.g. "SCMP_CMP_EQ".
+        operators: Option<Vec<String>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The list of the recognized architectures, e.g. "SCMP_ARCH_X86_64".
+        archs: Option<Vec<String>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The list of the recognized filter flags, e.g. "SECCOMP_FILTER_FLAG_LOG".
+        known_flags: Option<Vec<String>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The list of the supported filter flags. This list may be a subset of
+        /// `known_flags` due to some flags not supported by the current kernel.
+        supported_flags: Option<Vec<String>>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// ApparmorFeature represents the AppArmor features of the runtime.
+    struct ApparmorFeature {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Enabled is true if AppArmor support is compiled in.
+        enabled: Option<bool>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// SelinuxFeature represents the SELinux features of the runtime.
+    struct SelinuxFeature {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Enabled is true if SELinux support is compiled in.
+        enabled: Option<bool>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// IntelRdtFeature represents the Intel RDT features of the runtime.
+    struct IntelRdtFeature {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Enabled is true if Intel RDT support is compiled in.
+        enabled: Option<bool>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// MountExtensionsFeature represents the mount extensions of the runtime.
+    struct MountExtensionsFeature {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The available features related to idmapped mounts.
+        idmap: Option<IdMapFeature>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// IdMapFeature represents the idmapped mount features of the runtime.
+    struct IdMapFeature {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Enabled is true if idmapped mount support is compiled in.
+        enabled: Option<bool>,
+    }
+);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A setting of a [Spec] which is not supported by a runtime according to its
+/// [Features].
+pub enum UnsupportedFeature {
+    /// The OCI version of the spec is outside of the range supported by the
+    /// runtime.
+    OciVersion(String),
+    /// The hook is not supported.
+    Hook(String),
+    /// The option of the mount at the destination is not supported.
+    MountOption(PathBuf, String),
+    /// The mount at the destination is idmapped, which is not supported.
+    IdmappedMount(PathBuf),
+    /// The namespace is not supported.
+    Namespace(String),
+    /// The capability is not supported.
+    Capability(String),
+    /// RDMA cgroup resources are not supported.
+    CgroupRdma,
+    /// Seccomp is not supported.
+    Seccomp,
+    /// The seccomp action is not supported.
+    SeccompAction(String),
+    /// The seccomp operator is not supported.
+    SeccompOperator(String),
+    /// The seccomp architecture is not supported.
+    SeccompArch(String),
+    /// The seccomp filter flag is not supported.
+    SeccompFlag(String),
+    /// AppArmor is not supported.
+    Apparmor,
+    /// SELinux is not supported.
+    Selinux,
+    /// Intel RDT is not supported.
+    IntelRdt,
+}
+
+impl fmt::Display for UnsupportedFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OciVersion(version) => write!(f, "oci version {} is not supported", version),
+            Self::Hook(hook) => write!(f, "hook {} is not supported", hook),
+            Self::MountOption(destination, option) => write!(
+                f,
+                "option {} of mount {} is not supported",
+                option,
+                destination.display()
+            ),
+            Self::IdmappedMount(destination) => write!(
+                f,
+                "idmapped mount {} is not supported",
+                destination.display()
+            ),
+            Self::Namespace(namespace) => write!(f, "namespace {} is not supported", namespace),
+            Self::Capability(capability) => {
+                write!(f, "capability {} is not supported", capability)
+            }
+            Self::CgroupRdma => write!(f, "rdma cgroup is not supported"),
+            Self::Seccomp => write!(f, "seccomp is not supported"),
+            Self::SeccompAction(action) => write!(f, "seccomp action {} is not supported", action),
+            Self::SeccompOperator(operator) => {
+                write!(f, "seccomp operator {} is not supported", operator)
+            }
+            Self::SeccompArch(arch) => write!(f, "seccomp architecture {} is not supported", arch),
+            Self::SeccompFlag(flag) => write!(f, "seccomp flag {} is not supported", flag),
+            Self::Apparmor => write!(f, "apparmor is not supported"),
+            Self::Selinux => write!(f, "selinux is not supported"),
+            Self::IntelRdt => write!(f, "intel rdt is not supported"),
+        }
+    }
+}
+
+/// Mount options which are never passed to the mount syscall as data, so an
+/// option of this list which is not recognized by the runtime is unsupported.
+pub(crate) const FLAG_MOUNT_OPTIONS: &[&str] = &[
+    "idmap",
+    "ridmap",
+    "rro",
+    "rrw",
+    "rnosuid",
+    "rsuid",
+    "rnodev",
+    "rdev",
+    "rnoexec",
+    "rexec",
+    "rnodiratime",
+    "rdiratime",
+    "rrelatime",
+    "rnorelatime",
+    "rnoatime",
+    "ratime",
+    "rstrictatime",
+    "rnostrictatime",
+    "rnosymfollow",
+    "rsymfollow",
+    "nosymfollow",
+    "symfollow",
+    "tmpcopyup",
+];
+
+impl Spec {
+    /// Compares the spec against the features supported by a runtime and
+    /// returns the settings the runtime does not support, which is empty if
+    /// the spec can be run. Features which are unknown, because they are not
+    /// set in the features document, are assumed to be supported. Mount
+    /// options are only checked if they are never passed as data to the
+    /// mount syscall, since those cannot be recognized by the runtime.
+    pub fn unsupported_features(&self, features: &Features) -> Vec<UnsupportedFeature> {
+        let mut unsupported = Vec::new();
+        let mut report = |feature: UnsupportedFeature| {
+            if !unsupported.contains(&feature) {
+                unsupported.push(feature);
+            }
+        };
+
+        if let Some(version) = parse_version(&self.version) {
+            let below = features
+                .oci_version_min
+                .as_deref()
+                .and_then(parse_version)
+                .is_some_and(|min| version < min);
+            let above = features
+                .oci_version_max
+                .as_deref()
+                .and_then(parse_version)
+                .is_some_and(|max| version > max);
+            if below || above {
+                report(UnsupportedFeature::OciVersion(self.version.clone()));
+            }
+        }
+
+        if let (Some(hooks), Some(supported)) = (&self.hooks, &features.hooks) {
+            #[allow(deprecated)]
+            let all: [(&str, &Option<Vec<Hook>>); 6] = [
+                ("prestart", &hooks.prestart),
+                ("createRuntime", &hooks.create_runtime),
+                ("createContainer", &hooks.create_container),
+                ("startContainer", &hooks.start_container),
+                ("poststart", &hooks.poststart),
+                ("poststop", &hooks.poststop),
+            ];
+            for (name, hooks) in all.iter() {
+                if hooks.as_ref().is_some_and(|h| !h.is_empty()) && !contains(supported, name) {
+                    report(UnsupportedFeature::Hook(name.to_string()));
+                }
+            }
+        }
+
+        let linux_features = features.linux.as_ref();
+        let idmap = linux_features
+            .and_then(|l| l.mount_extensions.as_ref())
+            .and_then(|m| m.idmap.as_ref())
+            .and_then(|i| i.enabled);
+        for mount in self.mounts.iter().flatten() {
+            if mount.is_idmapped() && idmap == Some(false) {
+                report(UnsupportedFeature::IdmappedMount(mount.destination.clone()));
+            }
+
+            if let Some(supported) = &features.mount_options {
+                for option in mount.options.iter().flatten() {
+                    if FLAG_MOUNT_OPTIONS.contains(&option.as_str()) && !contains(supported, option)
+                    {
+                        report(UnsupportedFeature::MountOption(
+                            mount.destination.clone(),
+                            option.clone(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        let linux_features = match linux_features {
+            Some(linux_features) => linux_features,
+            None => return unsupported,
+        };
+
+        if let Some(capabilities) = self.process.as_ref().and_then(|p| p.capabilities.as_ref()) {
+            if let Some(supported) = &linux_features.capabilities {
+                let sets = [
+                    &capabilities.bounding,
+                    &capabilities.effective,
+                    &capabilities.inheritable,
+                    &capabilities.permitted,
+                    &capabilities.ambient,
+                ];
+                let mut names: Vec<String> = sets
+                    .iter()
+                    .copied()
+                    .flatten()
+                    .flatten()
+                    .map(serde_name)
+                    .collect();
+                names.sort();
+                for name in names {
+                    if !contains(supported, &name) {
+                        report(UnsupportedFeature::Capability(name));
+                    }
+                }
+            }
+        }
+
+        if let Some(process) = &self.process {
+            let enabled = linux_features.apparmor.as_ref().and_then(|a| a.enabled);
+            if process.apparmor_profile.is_some() && enabled == Some(false) {
+                report(UnsupportedFeature::Apparmor);
+            }
+        }
+
+        let selinux = linux_features.selinux.as_ref().and_then(|s| s.enabled);
+        let labeled = self
+            .process
+            .as_ref()
+            .is_some_and(|p| p.selinux_label.is_some())
+            || self.linux.as_ref().is_some_and(|l| l.mount_label.is_some());
+        if labeled && selinux == Some(false) {
+            report(UnsupportedFeature::Selinux);
+        }
+
+        let linux = match &self.linux {
+            Some(linux) => linux,
+            None => return unsupported,
+        };
+
+        if let Some(supported) = &linux_features.namespaces {
+            for namespace in linux.namespaces.iter().flatten() {
+                let name = serde_name(&namespace.typ);
+                if !contains(supported, &name) {
+                    report(UnsupportedFeature::Namespace(name));
+                }
+            }
+        }
+
+        let rdma = linux_features.cgroup.as_ref().and_then(|c| c.rdma);
+        let has_rdma = linux
+            .resources
+            .as_ref()
+            .and_then(|r| r.rdma.as_ref())
+            .is_some_and(|r| !r.is_empty());
+        if has_rdma && rdma == Some(false) {
+            report(UnsupportedFeature::CgroupRdma);
+        }
+
+        let intel_rdt = linux_features.intel_rdt.as_ref().and_then(|i| i.enabled);
+        if linux.intel_rdt.is_some() && intel_rdt == Some(false) {
+            report(UnsupportedFeature::IntelRdt);
+        }
+
+        if let (Some(seccomp), Some(features)) = (&linux.seccomp, &linux_features.seccomp) {
+            if features.enabled == Some(false) {
+                report(UnsupportedFeature::Seccomp);
+                return unsupported;
+            }
+
+            let syscalls = seccomp.syscalls.iter().flatten();
+            if let Some(actions) = &features.actions {
+                let used = std::iter::once(seccomp.default_action)
+                    .chain(syscalls.clone().map(|s| s.action))
+                    .map(|a| serde_name(&a));
+                for action in used {
+                    if !contains(actions, &action) {
+                        report(UnsupportedFeature::SeccompAction(action));
+                    }
+                }
+            }
+            if let Some(operators) = &features.operators {
+                let used = syscalls
+                    .flat_map(|s| s.args.iter().flatten())
+                    .map(|a| serde_name(&a.op));
+                for operator in used {
+                    if !contains(operators, &operator) {
+                        report(UnsupportedFeature::SeccompOperator(operator));
+                    }
+                }
+            }
+            if let Some(archs) = &features.archs {
+                for arch in seccomp.architectures.iter().flatten().map(serde_name) {
+                    if !contains(archs, &arch) {
+                        report(UnsupportedFeature::SeccompArch(arch));
+                    }
+                }
+            }
+            let flags = features
+                .supported_flags
+                .as_ref()
+                .or(features.known_flags.as_ref());
+            if let Some(supported) = flags {
+                for flag in seccomp.flags.iter().flatten().map(serde_name) {
+                    if !contains(supported, &flag) {
+                        report(UnsupportedFeature::SeccompFlag(flag));
+                    }
+                }
+            }
+        }
+
+        unsupported
+    }
+}
+
+fn contains(list: &[String], value: &str) -> bool {
+    list.iter().any(|v| v == value)
+}
+
+/// Returns the name an enum variant is serialized to.
+pub(super) fn serde_name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(ToOwned::to_owned))
+        .unwrap_or_default()
+}
+
+/// Parses the numeric components of a semantic version, ignoring any pre
+/// release or build metadata.
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let version = version.split(['-', '+']).next()?;
+    let mut parts = version.split('.').map(|p| p.parse::<u64>());
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+    let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+    Some((major, minor, patch))
+}
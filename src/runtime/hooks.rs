@@ -1,19 +1,28 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::{convert::TryFrom, path::PathBuf, time::Duration};
+
+use crate::error::{oci_error, Result};
 
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get = "pub")
+        getset(get = "pub", get_mut = "pub", set = "pub")
     )]
     /// Hooks specifies a command that is run in the container at a particular
     /// event in the lifecycle (setup and teardown) of a container.
@@ -69,20 +78,30 @@ make_pub!(
 
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
-            build_fn(error = "crate::error::OciSpecError")
-        )
+            build_fn(validate = "Self::validate", error = "crate::error::OciSpecError")
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// Hook specifies a command that is run at a particular event in the
     /// lifecycle of a container.
     struct Hook {
         #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzz::absolute_path))]
         /// Path to the binary to be executed. Following similar semantics to
         /// [IEEE Std 1003.1-2008 `execv`'s path](https://pubs.opengroup.org/onlinepubs/9699919799/functions/exec.html). This
         /// specification extends the IEEE standard in that path MUST be
@@ -109,3 +128,82 @@ make_pub!(
         timeout: Option<i64>,
     }
 );
+
+impl Hook {
+    /// Returns the timeout of the hook as a [Duration], or `None` if no
+    /// timeout is set or the timeout is not greater than zero.
+    pub fn timeout_duration(&self) -> Option<Duration> {
+        self.timeout
+            .filter(|timeout| *timeout > 0)
+            .map(|timeout| Duration::from_secs(timeout as u64))
+    }
+
+    /// Sets the timeout of the hook. Fractions of a second are rounded up,
+    /// as the timeout is serialized in whole seconds.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the timeout is zero or does not fit into the serialized timeout.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::Hook;
+    /// use std::time::Duration;
+    ///
+    /// let mut hook = Hook::default();
+    /// hook.set_timeout_duration(Duration::from_millis(1500)).unwrap();
+    /// assert_eq!(hook.timeout_duration(), Some(Duration::from_secs(2)));
+    /// assert!(hook.set_timeout_duration(Duration::ZERO).is_err());
+    /// ```
+    pub fn set_timeout_duration(&mut self, timeout: Duration) -> Result<()> {
+        self.timeout = Some(timeout_secs(timeout)?);
+        Ok(())
+    }
+
+    /// Validates that the timeout, if set, is greater than zero.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the timeout is not greater than zero.
+    pub fn validate(&self) -> Result<()> {
+        validate_timeout(self.timeout)
+    }
+}
+
+#[cfg(feature = "builder")]
+impl HookBuilder {
+    /// Sets the timeout of the hook from a [Duration], see
+    /// [Hook::set_timeout_duration].
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the timeout is zero or does not fit into the serialized timeout.
+    pub fn timeout_duration(self, timeout: Duration) -> Result<Self> {
+        Ok(self.timeout(timeout_secs(timeout)?))
+    }
+
+    fn validate(&self) -> Result<()> {
+        validate_timeout(self.timeout.flatten())
+    }
+}
+
+fn timeout_secs(timeout: Duration) -> Result<i64> {
+    let secs = timeout
+        .as_secs()
+        .checked_add(u64::from(timeout.subsec_nanos() > 0))
+        .and_then(|secs| i64::try_from(secs).ok());
+    match secs {
+        Some(secs) if secs > 0 => Ok(secs),
+        _ => Err(oci_error(format!(
+            "hook timeout {:?} must be greater than zero and at most {} seconds",
+            timeout,
+            i64::MAX
+        ))),
+    }
+}
+
+fn validate_timeout(timeout: Option<i64>) -> Result<()> {
+    match timeout {
+        Some(timeout) if timeout <= 0 => Err(oci_error(format!(
+            "hook timeout {} must be greater than zero",
+            timeout
+        ))),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,246 @@
+use std::str::FromStr;
+#[cfg(has_fs)]
+use std::{fs, path::Path};
+
+use crate::error::{oci_error, OciSpecError, Result};
+
+use super::{LinuxIdMapping, Spec};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A range of subordinate ids delegated to a user, as listed in
+/// `/etc/subuid` and `/etc/subgid`.
+pub struct SubordinateIdRange {
+    /// The user name or numeric id of the user owning the range.
+    pub owner: String,
+    /// The first id of the range.
+    pub start: u32,
+    /// The number of ids in the range.
+    pub count: u32,
+}
+
+impl SubordinateIdRange {
+    /// Parses the contents of a subordinate id file like `/etc/subuid`, which
+    /// consists of `owner:start:count` lines. Empty lines and comments are
+    /// skipped.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if a line is malformed.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::SubordinateIdRange;
+    ///
+    /// let ranges = SubordinateIdRange::parse("alice:100000:65536\n").unwrap();
+    /// assert_eq!(ranges[0].start, 100000);
+    /// assert_eq!(ranges[0].count, 65536);
+    /// ```
+    pub fn parse(contents: &str) -> Result<Vec<Self>> {
+        contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::parse)
+            .collect()
+    }
+
+    #[cfg(has_fs)]
+    /// Reads the ranges from a subordinate id file like `/etc/subuid`, see
+    /// [SubordinateIdRange::parse].
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the file cannot be read or an
+    /// [OciSpecError::Other](crate::OciSpecError::Other) if a line is
+    /// malformed.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Vec<Self>> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Returns true if the range belongs to the user with the given name or
+    /// numeric id.
+    pub fn is_owned_by(&self, name: &str, id: u32) -> bool {
+        self.owner == name || self.owner == id.to_string()
+    }
+}
+
+impl FromStr for SubordinateIdRange {
+    type Err = OciSpecError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || oci_error(format!("invalid subordinate id range {:?}", s));
+        let mut parts = s.split(':');
+        let (owner, start, count) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(owner), Some(start), Some(count), None) if !owner.is_empty() => {
+                (owner, start, count)
+            }
+            _ => return Err(invalid()),
+        };
+        let start: u32 = start.parse().map_err(|_| invalid())?;
+        let count: u32 = count.parse().map_err(|_| invalid())?;
+        if count == 0 || u64::from(start) + u64::from(count) > u64::from(u32::MAX) + 1 {
+            return Err(invalid());
+        }
+        Ok(SubordinateIdRange {
+            owner: owner.to_string(),
+            start,
+            count,
+        })
+    }
+}
+
+impl LinuxIdMapping {
+    /// Creates a mapping of `size` ids starting at `container_id` in the
+    /// container to the ids starting at `host_id` on the host.
+    pub fn new(container_id: u32, host_id: u32, size: u32) -> Self {
+        LinuxIdMapping {
+            host_id,
+            container_id,
+            size,
+        }
+    }
+
+    /// Creates the mappings for an unprivileged user, like rootless
+    /// container engines do: root in the container is mapped to `host_id`
+    /// and the ids from 1 onwards are mapped to the subordinate id ranges in
+    /// the given order.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::{LinuxIdMapping, SubordinateIdRange};
+    ///
+    /// let ranges = SubordinateIdRange::parse("alice:100000:65536").unwrap();
+    /// let mappings = LinuxIdMapping::rootless(1000, &ranges);
+    /// assert_eq!(
+    ///     mappings,
+    ///     vec![
+    ///         LinuxIdMapping::new(0, 1000, 1),
+    ///         LinuxIdMapping::new(1, 100000, 65536),
+    ///     ]
+    /// );
+    /// ```
+    pub fn rootless(host_id: u32, ranges: &[SubordinateIdRange]) -> Vec<LinuxIdMapping> {
+        let mut mappings = vec![LinuxIdMapping::new(0, host_id, 1)];
+        let mut container_id = 1u32;
+        for range in ranges {
+            let size = range.count.min(u32::MAX - container_id);
+            if size == 0 {
+                break;
+            }
+            mappings.push(LinuxIdMapping::new(container_id, range.start, size));
+            container_id += size;
+        }
+        mappings
+    }
+
+    /// Returns the host id which the container id `id` is mapped to, or
+    /// `None` if the id is not part of the mapping.
+    pub fn map_id(&self, id: u32) -> Option<u32> {
+        let offset = id.checked_sub(self.container_id)?;
+        if offset < self.size {
+            self.host_id.checked_add(offset)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the host id which the container id `id` is mapped to by any of the
+/// mappings, or `None` if it is not mapped.
+pub fn map_to_host(mappings: &[LinuxIdMapping], id: u32) -> Option<u32> {
+    mappings.iter().find_map(|mapping| mapping.map_id(id))
+}
+
+/// Validates that the mappings are not empty, do not exceed the id range and
+/// that neither their container nor their host ranges overlap, as required by
+/// the kernel.
+/// # Errors
+/// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+/// describing the first invalid mapping.
+pub fn validate_id_mappings(mappings: &[LinuxIdMapping]) -> Result<()> {
+    let end = |start: u32, size: u32| u64::from(start) + u64::from(size);
+    for (i, mapping) in mappings.iter().enumerate() {
+        if mapping.size == 0 {
+            return Err(oci_error(format!(
+                "id mapping {}:{}:0 must not be empty",
+                mapping.container_id, mapping.host_id
+            )));
+        }
+        if end(mapping.container_id, mapping.size) > u64::from(u32::MAX) + 1
+            || end(mapping.host_id, mapping.size) > u64::from(u32::MAX) + 1
+        {
+            return Err(oci_error(format!(
+                "id mapping {}:{}:{} exceeds the id range",
+                mapping.container_id, mapping.host_id, mapping.size
+            )));
+        }
+        for other in &mappings[..i] {
+            let overlaps = |a: u32, b: u32| {
+                u64::from(a) < end(b, other.size) && u64::from(b) < end(a, mapping.size)
+            };
+            if overlaps(mapping.container_id, other.container_id) {
+                return Err(oci_error(format!(
+                    "container ids of the mappings starting at {} and {} overlap",
+                    other.container_id, mapping.container_id
+                )));
+            }
+            if overlaps(mapping.host_id, other.host_id) {
+                return Err(oci_error(format!(
+                    "host ids of the mappings starting at {} and {} overlap",
+                    other.host_id, mapping.host_id
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Spec {
+    /// Validates the UID and GID mappings of the Linux section, see
+    /// [validate_id_mappings], and that the user and groups of the process
+    /// are mapped, as the process cannot be started otherwise. Ids are only
+    /// checked for mappings which are configured.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// describing the first invalid mapping or unmapped id.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::Spec;
+    ///
+    /// let mut spec = Spec::rootless(1000, 1000);
+    /// assert!(spec.validate_id_mappings().is_ok());
+    ///
+    /// spec.merge(&serde_json::json!({"process": {"user": {"uid": 1, "gid": 0}}}))
+    ///     .unwrap();
+    /// assert!(spec.validate_id_mappings().is_err());
+    /// ```
+    pub fn validate_id_mappings(&self) -> Result<()> {
+        let linux = match &self.linux {
+            Some(linux) => linux,
+            None => return Ok(()),
+        };
+        let uid_mappings = linux.uid_mappings.as_deref().unwrap_or_default();
+        let gid_mappings = linux.gid_mappings.as_deref().unwrap_or_default();
+        validate_id_mappings(uid_mappings)?;
+        validate_id_mappings(gid_mappings)?;
+
+        let user = match &self.process {
+            Some(process) => &process.user,
+            None => return Ok(()),
+        };
+        if !uid_mappings.is_empty() && map_to_host(uid_mappings, user.uid).is_none() {
+            return Err(oci_error(format!(
+                "uid {} of the process is not mapped",
+                user.uid
+            )));
+        }
+        if !gid_mappings.is_empty() {
+            let gids = std::iter::once(&user.gid).chain(user.additional_gids.iter().flatten());
+            for gid in gids {
+                if map_to_host(gid_mappings, *gid).is_none() {
+                    return Err(oci_error(format!(
+                        "gid {} of the process is not mapped",
+                        gid
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,287 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::error::{oci_error, OciSpecError};
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get_mut = "pub", set = "pub")
+    )]
+    /// LinuxLandlock specifies the Landlock unprivileged access control
+    /// settings of the container process.
+    struct LinuxLandlock {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// Ruleset identifies the access rights which are handled, and thus
+        /// denied unless allowed by a rule.
+        ruleset: Option<LandlockRuleset>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// Rules are the access rights which are allowed.
+        rules: Option<LandlockRules>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// DisableBestEffort makes the runtime fail if the kernel does not
+        /// support all of the requested access rights, instead of enforcing
+        /// the supported subset.
+        disable_best_effort: Option<bool>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// LandlockRuleset identifies the file system and network access rights
+    /// which are handled by the ruleset.
+    struct LandlockRuleset {
+        #[serde(
+            default,
+            rename = "handledAccessFS",
+            skip_serializing_if = "Option::is_none"
+        )]
+        /// HandledAccessFS are the handled file system access rights.
+        handled_access_fs: Option<Vec<LandlockFsAccess>>,
+
+        #[serde(
+            default,
+            rename = "handledAccessNetwork",
+            skip_serializing_if = "Option::is_none"
+        )]
+        /// HandledAccessNetwork are the handled network access rights.
+        handled_access_network: Option<Vec<LandlockNetAccess>>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// LandlockRules are the rules which allow access rights of the ruleset.
+    struct LandlockRules {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// PathBeneath are the rules which allow file system access beneath
+        /// a file hierarchy.
+        path_beneath: Option<Vec<LandlockRulePathBeneath>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// NetPort are the rules which allow network access to TCP ports.
+        net_port: Option<Vec<LandlockRuleNetPort>>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// LandlockRulePathBeneath allows file system access rights beneath the
+    /// given file hierarchies.
+    struct LandlockRulePathBeneath {
+        #[serde(default)]
+        /// AllowedAccess are the allowed file system access rights.
+        allowed_access: Vec<LandlockFsAccess>,
+
+        #[serde(default)]
+        /// Paths are the absolute paths of the file hierarchies.
+        paths: Vec<PathBuf>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// LandlockRuleNetPort allows network access rights to the given TCP
+    /// ports.
+    struct LandlockRuleNetPort {
+        #[serde(default)]
+        /// AllowedAccess are the allowed network access rights.
+        allowed_access: Vec<LandlockNetAccess>,
+
+        #[serde(default)]
+        /// Ports are the TCP ports.
+        ports: Vec<u16>,
+    }
+);
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+/// LandlockFsAccess is a file system access right of Landlock.
+pub enum LandlockFsAccess {
+    /// Execute a file.
+    Execute,
+    /// Open a file with write access.
+    WriteFile,
+    /// Open a file with read access.
+    ReadFile,
+    /// Open a directory or list its content.
+    ReadDir,
+    /// Remove an empty directory or rename one.
+    RemoveDir,
+    /// Unlink or rename a file.
+    RemoveFile,
+    /// Create, rename or link a character device.
+    MakeChar,
+    /// Create or rename a directory.
+    MakeDir,
+    /// Create, rename or link a regular file.
+    MakeReg,
+    /// Create, rename or link a UNIX domain socket.
+    MakeSock,
+    /// Create, rename or link a named pipe.
+    MakeFifo,
+    /// Create, rename or link a block device.
+    MakeBlock,
+    /// Create, rename or link a symbolic link.
+    MakeSym,
+    /// Link or rename a file from or to a different directory.
+    Refer,
+    /// Truncate a file.
+    Truncate,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+/// LandlockNetAccess is a network access right of Landlock.
+pub enum LandlockNetAccess {
+    /// Bind a TCP socket to a local port.
+    BindTcp,
+    /// Connect a TCP socket to a remote port.
+    ConnectTcp,
+}
+
+impl LinuxLandlock {
+    /// Validates that the rules only allow access rights which are handled
+    /// by the ruleset and that the paths of the rules are absolute.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// describing the first invalid rule.
+    pub fn validate(&self) -> Result<(), OciSpecError> {
+        let ruleset = self.ruleset.clone().unwrap_or_default();
+        let handled_fs = ruleset.handled_access_fs.unwrap_or_default();
+        let handled_net = ruleset.handled_access_network.unwrap_or_default();
+        let rules = match &self.rules {
+            Some(rules) => rules,
+            None => return Ok(()),
+        };
+
+        for rule in rules.path_beneath.iter().flatten() {
+            if let Some(access) = rule.allowed_access.iter().find(|a| !handled_fs.contains(a)) {
+                return Err(oci_error(format!(
+                    "file system access {:?} is not handled by the ruleset",
+                    access
+                )));
+            }
+            if let Some(path) = rule.paths.iter().find(|path| !path.is_absolute()) {
+                return Err(oci_error(format!(
+                    "landlock path {:?} must be absolute",
+                    path
+                )));
+            }
+        }
+        for rule in rules.net_port.iter().flatten() {
+            if let Some(access) = rule
+                .allowed_access
+                .iter()
+                .find(|a| !handled_net.contains(a))
+            {
+                return Err(oci_error(format!(
+                    "network access {:?} is not handled by the ruleset",
+                    access
+                )));
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,44 +1,64 @@
+use super::{LinuxLandlock, Propagation};
 use crate::error::{oci_error, OciSpecError};
 
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, convert::TryFrom, path::PathBuf};
+#[cfg(unix)]
+use std::path::Path;
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    fmt,
+    path::PathBuf,
+    str::FromStr,
+};
 
 make_pub!(
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get = "pub")
+        getset(get = "pub", get_mut = "pub")
     )]
     /// Linux contains platform-specific configuration for Linux based
     /// containers.
     struct Linux {
         #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
         /// UIDMappings specifies user mappings for supporting user namespaces.
         uid_mappings: Option<Vec<LinuxIdMapping>>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
         /// GIDMappings specifies group mappings for supporting user namespaces.
         gid_mappings: Option<Vec<LinuxIdMapping>>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
         /// Sysctl are a set of key value pairs that are set for the container
-        /// on start.
+        /// on start. Single sysctls are set with [Linux::set_sysctl].
         sysctl: Option<HashMap<String, String>>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
         /// Resources contain cgroup information for handling resource
         /// constraints for the container.
         resources: Option<LinuxResources>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
         /// CgroupsPath specifies the path to cgroups that are created and/or
         /// joined by the container. The path is expected to be relative
         /// to the cgroups mountpoint. If resources are specified,
@@ -46,47 +66,82 @@ make_pub!(
         cgroups_path: Option<PathBuf>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
         /// Namespaces contains the namespaces that are created and/or joined by
         /// the container.
         namespaces: Option<Vec<LinuxNamespace>>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
         /// Devices are a list of device nodes that are created for the
         /// container.
         devices: Option<Vec<LinuxDevice>>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
         /// Seccomp specifies the seccomp security settings for the container.
         seccomp: Option<LinuxSeccomp>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
         /// RootfsPropagation is the rootfs mount propagation mode for the
         /// container.
-        rootfs_propagation: Option<String>,
+        rootfs_propagation: Option<Propagation>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
         /// MaskedPaths masks over the provided paths inside the container.
         masked_paths: Option<Vec<String>>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
         /// ReadonlyPaths sets the provided paths as RO inside the container.
         readonly_paths: Option<Vec<String>>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
         /// MountLabel specifies the selinux context for the mounts in the
         /// container.
         mount_label: Option<String>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
         /// IntelRdt contains Intel Resource Director Technology (RDT)
         /// information for handling resource constraints (e.g., L3
         /// cache, memory bandwidth) for the container.
         intel_rdt: Option<LinuxIntelRdt>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
         /// Personality contains configuration for the Linux personality
         /// syscall.
         personality: Option<LinuxPersonality>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
+        /// TimeOffsets specifies the offsets of the clocks of the time
+        /// namespace, keyed by clock name, i.e. "boottime" or "monotonic". This
+        /// requires a time namespace.
+        time_offsets: Option<HashMap<String, LinuxTimeOffset>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
+        /// MemoryPolicy contains the NUMA memory policy of the container,
+        /// which is set using set_mempolicy(2).
+        memory_policy: Option<LinuxMemoryPolicy>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
+        /// Landlock contains the Landlock access control settings of the
+        /// container process.
+        landlock: Option<LinuxLandlock>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(set = "pub"))]
+        /// NetDevices are the network devices of the host which are moved
+        /// into the network namespace of the container, keyed by their name
+        /// on the host. This requires a network namespace.
+        net_devices: Option<HashMap<String, LinuxNetDevice>>,
     }
 );
 
@@ -132,23 +187,34 @@ impl Default for Linux {
             seccomp: None,
             intel_rdt: None,
             personality: None,
+            time_offsets: None,
+            memory_policy: None,
+            landlock: None,
+            net_devices: None,
         }
     }
 }
 
 make_pub!(
     #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get_copy = "pub")
+        getset(get_copy = "pub", get_mut = "pub", set = "pub")
     )]
     /// LinuxIDMapping specifies UID/GID mappings.
     struct LinuxIdMapping {
@@ -167,11 +233,14 @@ make_pub!(
     }
 );
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 /// Device types
 pub enum LinuxDeviceType {
     /// block (buffered)
+    #[default]
     B,
 
     /// character (unbuffered)
@@ -184,12 +253,6 @@ pub enum LinuxDeviceType {
     P,
 }
 
-impl Default for LinuxDeviceType {
-    fn default() -> LinuxDeviceType {
-        LinuxDeviceType::B
-    }
-}
-
 impl LinuxDeviceType {
     /// Retrieve a string reference for the device type.
     pub fn as_str(&self) -> &str {
@@ -204,15 +267,24 @@ impl LinuxDeviceType {
 
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// Represents a device rule for the devices specified to the device
     /// controller
@@ -242,8 +314,8 @@ make_pub!(
     }
 );
 
-impl ToString for LinuxDeviceCgroup {
-    fn to_string(&self) -> String {
+impl fmt::Display for LinuxDeviceCgroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let major = self
             .major
             .map(|mj| mj.to_string())
@@ -253,9 +325,10 @@ impl ToString for LinuxDeviceCgroup {
             .map(|mi| mi.to_string())
             .unwrap_or_else(|| "*".to_string());
         let access = self.access.as_deref().unwrap_or("");
-        format!(
+        write!(
+            f,
             "{} {}:{} {}",
-            &self.typ.unwrap_or_default().as_str(),
+            self.typ.as_ref().map_or("a", LinuxDeviceType::as_str),
             &major,
             &minor,
             &access
@@ -263,19 +336,82 @@ impl ToString for LinuxDeviceCgroup {
     }
 }
 
+impl FromStr for LinuxDeviceCgroup {
+    type Err = OciSpecError;
+
+    /// Parses an allow rule in the `"c 10:200 rwm"` syntax used by the device
+    /// cgroup and Docker's `--device-cgroup-rule`. The type `a` and the `*`
+    /// wildcard for major and minor numbers match all devices.
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            |reason: &str| oci_error(format!("invalid device rule {:?}: {}", rule, reason));
+
+        let parts: Vec<&str> = rule.split_whitespace().collect();
+        let (typ, numbers, access) = match parts.as_slice() {
+            [typ, numbers, access] => (*typ, *numbers, *access),
+            _ => return Err(invalid("expected <type> <major>:<minor> <access>")),
+        };
+
+        let typ = match typ {
+            "a" => None,
+            "b" => Some(LinuxDeviceType::B),
+            "c" => Some(LinuxDeviceType::C),
+            "u" => Some(LinuxDeviceType::U),
+            "p" => Some(LinuxDeviceType::P),
+            _ => return Err(invalid("unknown device type")),
+        };
+
+        let (major, minor) = numbers
+            .split_once(':')
+            .ok_or_else(|| invalid("expected <major>:<minor>"))?;
+        let parse_number = |number: &str| match number {
+            "*" => Ok(None),
+            number => number
+                .parse::<i64>()
+                .ok()
+                .filter(|n| *n >= 0)
+                .map(Some)
+                .ok_or_else(|| invalid("invalid device number")),
+        };
+
+        let mut seen = String::new();
+        for c in access.chars() {
+            if !"rwm".contains(c) || seen.contains(c) {
+                return Err(invalid("access must be a combination of r, w and m"));
+            }
+            seen.push(c);
+        }
+
+        Ok(LinuxDeviceCgroup {
+            allow: true,
+            typ,
+            major: parse_number(major)?,
+            minor: parse_number(minor)?,
+            access: Some(access.to_owned()),
+        })
+    }
+}
+
 make_pub!(
     #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get_copy = "pub")
+        getset(get_copy = "pub", get_mut = "pub", set = "pub")
     )]
     /// LinuxMemory for Linux cgroup 'memory' resource management.
     struct LinuxMemory {
@@ -311,21 +447,35 @@ make_pub!(
         #[serde(skip_serializing_if = "Option::is_none")]
         /// Enables hierarchical memory accounting
         use_hierarchy: Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        /// Enables checking if a new memory limit is lower than the current
+        /// usage during update, and if so, rejecting the new limit.
+        check_before_update: Option<bool>,
     }
 );
 
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// LinuxCPU for Linux cgroup 'cpu' resource management.
     struct LinuxCpu {
@@ -345,6 +495,12 @@ make_pub!(
         /// CPU period to be used for hardcapping (in usecs).
         period: Option<u64>,
 
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// CPU hardcap burst limit (in usecs). Allowed accumulated cpu time
+        /// additionally for burst in a given period.
+        burst: Option<u64>,
+
         #[serde(skip_serializing_if = "Option::is_none")]
         #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
         /// How much time realtime scheduling may use (in usecs).
@@ -365,21 +521,34 @@ make_pub!(
         /// List of memory nodes in the cpuset. Default is to use any available
         /// memory node.
         mems: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Idle sets the cgroup's cpu.idle value. If set to 1, the cgroup is
+        /// scheduled with the SCHED_IDLE policy (cgroup v2 only).
+        idle: Option<i64>,
     }
 );
 
 make_pub!(
     #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get_copy = "pub")
+        getset(get_copy = "pub", get_mut = "pub", set = "pub")
     )]
     /// LinuxPids for Linux cgroup 'pids' resource management (Linux 4.3).
     struct LinuxPids {
@@ -391,17 +560,25 @@ make_pub!(
 
 make_pub!(
     #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get_copy = "pub")
+        getset(get_copy = "pub", get_mut = "pub", set = "pub")
     )]
     /// LinuxWeightDevice struct holds a `major:minor weight` pair for
     /// weightDevice.
@@ -427,16 +604,23 @@ make_pub!(
 
 make_pub!(
     #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get_copy = "pub")
+        getset(get_copy = "pub", get_mut = "pub", set = "pub")
     )]
     /// LinuxThrottleDevice struct holds a `major:minor rate_per_second` pair.
     struct LinuxThrottleDevice {
@@ -454,18 +638,94 @@ make_pub!(
     }
 );
 
+impl LinuxWeightDevice {
+    /// Creates a weight device for the block device `major:minor`.
+    pub fn new(major: i64, minor: i64, weight: u16) -> Self {
+        LinuxWeightDevice {
+            major,
+            minor,
+            weight: Some(weight),
+            leaf_weight: None,
+        }
+    }
+
+    /// Creates a weight device for the block device at `path` on the host.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the device cannot be inspected or an
+    /// [OciSpecError::Other](crate::OciSpecError::Other) if the path is not a
+    /// block device.
+    #[cfg(unix)]
+    pub fn from_path<P: AsRef<Path>>(path: P, weight: u16) -> Result<Self, OciSpecError> {
+        let (major, minor) = block_device_numbers(path.as_ref())?;
+        Ok(Self::new(major, minor, weight))
+    }
+}
+
+impl LinuxThrottleDevice {
+    /// Creates a throttle device for the block device `major:minor`.
+    pub fn new(major: i64, minor: i64, rate: u64) -> Self {
+        LinuxThrottleDevice { major, minor, rate }
+    }
+
+    /// Creates a throttle device for the block device at `path` on the host.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the device cannot be inspected or an
+    /// [OciSpecError::Other](crate::OciSpecError::Other) if the path is not a
+    /// block device.
+    #[cfg(unix)]
+    pub fn from_path<P: AsRef<Path>>(path: P, rate: u64) -> Result<Self, OciSpecError> {
+        let (major, minor) = block_device_numbers(path.as_ref())?;
+        Ok(Self::new(major, minor, rate))
+    }
+}
+
+/// Returns the major and minor number of the block device at `path`.
+#[cfg(unix)]
+fn block_device_numbers(path: &Path) -> Result<(i64, i64), OciSpecError> {
+    use std::os::unix::fs::{FileTypeExt, MetadataExt};
+
+    let metadata = std::fs::metadata(path)?;
+    if !metadata.file_type().is_block_device() {
+        return Err(oci_error(format!(
+            "{} is not a block device",
+            path.display()
+        )));
+    }
+    Ok(device_numbers(metadata.rdev()))
+}
+
+/// Splits a device id as returned by stat(2) into its major and minor number.
+#[cfg(unix)]
+fn device_numbers(rdev: u64) -> (i64, i64) {
+    (
+        (((rdev >> 8) & 0xfff) | ((rdev >> 32) & !0xfff)) as i64,
+        ((rdev & 0xff) | ((rdev >> 12) & !0xff)) as i64,
+    )
+}
+
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// LinuxBlockIO for Linux cgroup 'blkio' resource management.
     struct LinuxBlockIo {
@@ -507,18 +767,122 @@ make_pub!(
     }
 );
 
+/// The range of valid blkio weights.
+const BLKIO_WEIGHT_RANGE: std::ops::RangeInclusive<u16> = 10..=1000;
+
+impl LinuxBlockIo {
+    /// Adds a read rate limit in bytes per second for the block device
+    /// `major:minor`.
+    pub fn throttle_read_bps(&mut self, major: i64, minor: i64, rate: u64) {
+        self.throttle_read_bps_device
+            .get_or_insert_with(Vec::new)
+            .push(LinuxThrottleDevice::new(major, minor, rate));
+    }
+
+    /// Adds a write rate limit in bytes per second for the block device
+    /// `major:minor`.
+    pub fn throttle_write_bps(&mut self, major: i64, minor: i64, rate: u64) {
+        self.throttle_write_bps_device
+            .get_or_insert_with(Vec::new)
+            .push(LinuxThrottleDevice::new(major, minor, rate));
+    }
+
+    /// Adds a read rate limit in IO operations per second for the block
+    /// device `major:minor`.
+    pub fn throttle_read_iops(&mut self, major: i64, minor: i64, rate: u64) {
+        self.throttle_read_iops_device
+            .get_or_insert_with(Vec::new)
+            .push(LinuxThrottleDevice::new(major, minor, rate));
+    }
+
+    /// Adds a write rate limit in IO operations per second for the block
+    /// device `major:minor`.
+    pub fn throttle_write_iops(&mut self, major: i64, minor: i64, rate: u64) {
+        self.throttle_write_iops_device
+            .get_or_insert_with(Vec::new)
+            .push(LinuxThrottleDevice::new(major, minor, rate));
+    }
+
+    /// Adds a weight for the block device `major:minor`.
+    pub fn add_weight_device(&mut self, major: i64, minor: i64, weight: u16) {
+        self.weight_device
+            .get_or_insert_with(Vec::new)
+            .push(LinuxWeightDevice::new(major, minor, weight));
+    }
+
+    /// Validates that all weights are within 10 and 1000 and that all rate
+    /// limits are non-zero.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::LinuxBlockIo;
+    ///
+    /// let mut block_io = LinuxBlockIo::default();
+    /// block_io.add_weight_device(8, 0, 500);
+    /// block_io.throttle_read_bps(8, 0, 1048576);
+    /// assert!(block_io.validate().is_ok());
+    ///
+    /// block_io.add_weight_device(8, 16, 5);
+    /// assert!(block_io.validate().is_err());
+    /// ```
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// describing the first invalid value.
+    pub fn validate(&self) -> Result<(), OciSpecError> {
+        let check_weight = |weight: Option<u16>, what: &str| match weight {
+            Some(weight) if !BLKIO_WEIGHT_RANGE.contains(&weight) => Err(oci_error(format!(
+                "{} {} must be between 10 and 1000",
+                what, weight
+            ))),
+            _ => Ok(()),
+        };
+
+        check_weight(self.weight, "weight")?;
+        check_weight(self.leaf_weight, "leaf weight")?;
+        for device in self.weight_device.iter().flatten() {
+            let what = format!("weight of device {}:{}", device.major, device.minor);
+            check_weight(device.weight, &what)?;
+            check_weight(device.leaf_weight, &format!("leaf {}", what))?;
+        }
+
+        let throttles = [
+            &self.throttle_read_bps_device,
+            &self.throttle_write_bps_device,
+            &self.throttle_read_iops_device,
+            &self.throttle_write_iops_device,
+        ];
+        for device in throttles.iter().copied().flatten().flatten() {
+            if device.rate == 0 {
+                return Err(oci_error(format!(
+                    "rate of device {}:{} must not be zero",
+                    device.major, device.minor
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// LinuxHugepageLimit structure corresponds to limiting kernel hugepages.
     struct LinuxHugepageLimit {
@@ -535,17 +899,107 @@ make_pub!(
     }
 );
 
+impl LinuxHugepageLimit {
+    /// Parses the page size of the limit.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the page size is malformed or not a power of two.
+    pub fn hugepage_size(&self) -> Result<HugepageSize, OciSpecError> {
+        self.page_size.parse()
+    }
+
+    /// Sets the page size of the limit.
+    pub fn set_hugepage_size(&mut self, size: HugepageSize) {
+        self.page_size = size.to_string();
+    }
+}
+
+const HUGEPAGE_UNITS: [(&str, u64); 3] = [("GB", 1 << 30), ("MB", 1 << 20), ("KB", 1 << 10)];
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// HugepageSize is the size of a hugepage in bytes, which is formatted with
+/// the largest binary unit dividing it, as in the hugetlb cgroup file names,
+/// e.g. "64KB", "2MB" or "1GB".
+pub struct HugepageSize(u64);
+
+impl HugepageSize {
+    /// A hugepage size of 2MB.
+    pub const SIZE_2MB: HugepageSize = HugepageSize(2 << 20);
+    /// A hugepage size of 1GB.
+    pub const SIZE_1GB: HugepageSize = HugepageSize(1 << 30);
+
+    /// Creates a hugepage size from a number of bytes.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the size is not a power of two or smaller than 1KB.
+    pub fn new(bytes: u64) -> Result<Self, OciSpecError> {
+        if !bytes.is_power_of_two() || bytes < 1 << 10 {
+            return Err(oci_error(format!(
+                "hugepage size {} must be a power of two of at least 1KB",
+                bytes
+            )));
+        }
+        Ok(HugepageSize(bytes))
+    }
+
+    /// Returns the size in bytes.
+    pub fn bytes(self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for HugepageSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (unit, factor) = HUGEPAGE_UNITS
+            .iter()
+            .copied()
+            .find(|(_, factor)| self.0.is_multiple_of(*factor))
+            .unwrap_or(("B", 1));
+        write!(f, "{}{}", self.0 / factor, unit)
+    }
+}
+
+impl FromStr for HugepageSize {
+    type Err = OciSpecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || oci_error(format!("invalid hugepage size {:?}", s));
+        let (number, factor) = HUGEPAGE_UNITS
+            .iter()
+            .find_map(|(unit, factor)| s.strip_suffix(unit).map(|n| (n, *factor)))
+            .ok_or_else(invalid)?;
+        if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let bytes = number
+            .parse::<u64>()
+            .ok()
+            .and_then(|n| n.checked_mul(factor))
+            .ok_or_else(invalid)?;
+        Self::new(bytes)
+    }
+}
+
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// LinuxInterfacePriority for network interfaces.
     struct LinuxInterfacePriority {
@@ -561,23 +1015,32 @@ make_pub!(
     }
 );
 
-impl ToString for LinuxInterfacePriority {
-    fn to_string(&self) -> String {
-        format!("{} {}\n", self.name, self.priority)
+impl fmt::Display for LinuxInterfacePriority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} {}", self.name, self.priority)
     }
 }
 
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// LinuxNetwork identification and priority configuration.
     struct LinuxNetwork {
@@ -593,18 +1056,151 @@ make_pub!(
     }
 );
 
+impl LinuxNetwork {
+    /// Returns the class identifier as a net_cls class id.
+    pub fn net_class_id(&self) -> Option<NetClassId> {
+        self.class_id.map(NetClassId::from)
+    }
+
+    /// Sets the class identifier from a net_cls class id.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::LinuxNetwork;
+    ///
+    /// let mut network = LinuxNetwork::default();
+    /// network.set_net_class_id("10:1".parse().unwrap());
+    /// assert_eq!(network.net_class_id().unwrap().to_string(), "0x00100001");
+    /// ```
+    pub fn set_net_class_id(&mut self, class_id: NetClassId) {
+        self.class_id = Some(class_id.into());
+    }
+
+    /// Sets the priority of the network interface `name`, replacing a
+    /// previously configured priority of the same interface.
+    pub fn set_priority<S: Into<String>>(&mut self, name: S, priority: u32) {
+        let name = name.into();
+        let priorities = self.priorities.get_or_insert_with(Vec::new);
+        match priorities.iter_mut().find(|p| p.name == name) {
+            Some(existing) => existing.priority = priority,
+            None => priorities.push(LinuxInterfacePriority { name, priority }),
+        }
+    }
+
+    /// Removes duplicated interface priorities, keeping the last definition of
+    /// each interface as the kernel would when applying them in order, and
+    /// sorts the priorities by interface name.
+    pub fn normalize_priorities(&mut self) {
+        if let Some(priorities) = &mut self.priorities {
+            let mut normalized: Vec<LinuxInterfacePriority> = Vec::new();
+            for priority in priorities.drain(..).rev() {
+                if !normalized.iter().any(|p| p.name == priority.name) {
+                    normalized.push(priority);
+                }
+            }
+            normalized.sort_by(|a, b| a.name.cmp(&b.name));
+            *priorities = normalized;
+        }
+    }
+
+    /// Validates that every interface priority names an interface and that
+    /// no interface is configured more than once.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// describing the first invalid priority.
+    pub fn validate(&self) -> Result<(), OciSpecError> {
+        let mut seen = HashSet::new();
+        for priority in self.priorities.iter().flatten() {
+            if priority.name.is_empty() {
+                return Err(oci_error("interface priority without interface name"));
+            }
+            if !seen.insert(priority.name.as_str()) {
+                return Err(oci_error(format!(
+                    "duplicate priority for interface {:?}",
+                    priority.name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+/// NetClassId is the class identifier of the net_cls cgroup, which consists of
+/// a major and a minor handle. It is formatted as 0xAAAABBBB, where AAAA is
+/// the major and BBBB the minor handle in hexadecimal, and can be parsed from
+/// that format or from the "major:minor" notation of tc(8), e.g. "10:1".
+pub struct NetClassId {
+    /// The major handle.
+    pub major: u16,
+    /// The minor handle.
+    pub minor: u16,
+}
+
+impl From<u32> for NetClassId {
+    fn from(class_id: u32) -> Self {
+        NetClassId {
+            major: (class_id >> 16) as u16,
+            minor: class_id as u16,
+        }
+    }
+}
+
+impl From<NetClassId> for u32 {
+    fn from(class_id: NetClassId) -> Self {
+        (class_id.major as u32) << 16 | class_id.minor as u32
+    }
+}
+
+impl fmt::Display for NetClassId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#010x}", u32::from(*self))
+    }
+}
+
+impl FromStr for NetClassId {
+    type Err = OciSpecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || oci_error(format!("invalid net_cls class id {:?}", s));
+        let hex = |n: &str, max_len| {
+            if n.is_empty() || n.len() > max_len || !n.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(invalid());
+            }
+            u32::from_str_radix(n, 16).map_err(|_| invalid())
+        };
+
+        if let Some(class_id) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            return hex(class_id, 8).map(NetClassId::from);
+        }
+        let (major, minor) = s.split_once(':').ok_or_else(invalid)?;
+        Ok(NetClassId {
+            major: hex(major, 4)? as u16,
+            minor: hex(minor, 4)? as u16,
+        })
+    }
+}
+
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// Resource constraints for container
     struct LinuxResources {
@@ -659,17 +1255,24 @@ make_pub!(
 
 make_pub!(
     #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get_copy = "pub")
+        getset(get_copy = "pub", get_mut = "pub", set = "pub")
     )]
     /// LinuxRdma for Linux cgroup 'rdma' resource management (Linux 4.11).
     struct LinuxRdma {
@@ -685,7 +1288,9 @@ make_pub!(
     }
 );
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, Hash)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 /// Available Linux namespaces.
 pub enum LinuxNamespaceType {
@@ -705,10 +1310,14 @@ pub enum LinuxNamespaceType {
     User = 0x10000000,
 
     /// PID Namespace for isolating process ids
+    #[default]
     Pid = 0x20000000,
 
     /// Network Namespace for isolating network devices, ports, stacks etc.
     Network = 0x40000000,
+
+    /// Time Namespace for isolating the clocks
+    Time = 0x00000080,
 }
 
 impl TryFrom<&str> for LinuxNamespaceType {
@@ -723,6 +1332,7 @@ impl TryFrom<&str> for LinuxNamespaceType {
             "user" => Ok(LinuxNamespaceType::User),
             "pid" => Ok(LinuxNamespaceType::Pid),
             "net" => Ok(LinuxNamespaceType::Network),
+            "time" => Ok(LinuxNamespaceType::Time),
             _ => Err(oci_error(format!(
                 "unknown namespace {}, could not convert",
                 namespace
@@ -731,23 +1341,26 @@ impl TryFrom<&str> for LinuxNamespaceType {
     }
 }
 
-impl Default for LinuxNamespaceType {
-    fn default() -> Self {
-        Self::Pid
-    }
-}
-
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// LinuxNamespace is the configuration for a Linux namespace.
     struct LinuxNamespace {
@@ -764,6 +1377,85 @@ make_pub!(
     }
 );
 
+impl LinuxNamespace {
+    /// Creates a namespace of the provided type which is newly created for the
+    /// container.
+    pub fn new(typ: LinuxNamespaceType) -> Self {
+        LinuxNamespace { typ, path: None }
+    }
+
+    /// Creates a namespace of the provided type which joins the existing
+    /// namespace at `path`, e.g. `/proc/1234/ns/net`.
+    pub fn join<P: Into<PathBuf>>(typ: LinuxNamespaceType, path: P) -> Self {
+        LinuxNamespace {
+            typ,
+            path: Some(path.into()),
+        }
+    }
+
+    /// Returns the conventional pid, network, ipc, uts and mount namespaces of
+    /// a container, see [get_default_namespaces].
+    pub fn default_set() -> Vec<LinuxNamespace> {
+        get_default_namespaces()
+    }
+}
+
+impl Linux {
+    /// Sets the namespace of type `typ` to join the existing namespace at
+    /// `path`, replacing a previously configured namespace of the same type.
+    pub fn join_namespace<P: Into<PathBuf>>(&mut self, typ: LinuxNamespaceType, path: P) {
+        let namespace = LinuxNamespace::join(typ, path);
+        let namespaces = self.namespaces.get_or_insert_with(Vec::new);
+        match namespaces.iter_mut().find(|ns| ns.typ == typ) {
+            Some(existing) => *existing = namespace,
+            None => namespaces.push(namespace),
+        }
+    }
+
+    /// Adds the default masked and readonly paths to the configured ones,
+    /// skipping paths which are already present. This hardens a configuration
+    /// which was created without the defaults, e.g. by deserialization.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::{Linux, DEFAULT_MASKED_PATHS};
+    ///
+    /// let mut linux: Linux = serde_json::from_str("{}").unwrap();
+    /// linux.add_default_paths();
+    /// let masked: String = serde_json::to_value(&linux).unwrap()["maskedPaths"].to_string();
+    /// assert!(DEFAULT_MASKED_PATHS.iter().all(|path| masked.contains(path)));
+    /// ```
+    pub fn add_default_paths(&mut self) {
+        let add = |paths: &mut Option<Vec<String>>, defaults: &[&str]| {
+            let paths = paths.get_or_insert_with(Vec::new);
+            for default in defaults {
+                if !paths.iter().any(|p| p == default) {
+                    paths.push(default.to_string());
+                }
+            }
+        };
+        add(&mut self.masked_paths, DEFAULT_MASKED_PATHS);
+        add(&mut self.readonly_paths, DEFAULT_READONLY_PATHS);
+    }
+
+    /// Validates that each namespace type is configured at most once, which
+    /// is required by the runtime specification.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// naming the first duplicated namespace type.
+    pub fn validate_namespaces(&self) -> Result<(), OciSpecError> {
+        let mut seen = HashSet::new();
+        for namespace in self.namespaces.iter().flatten() {
+            if !seen.insert(namespace.typ) {
+                return Err(oci_error(format!(
+                    "duplicate namespace type {:?}",
+                    namespace.typ
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Utility function to get default namespaces.
 pub fn get_default_namespaces() -> Vec<LinuxNamespace> {
     vec![
@@ -792,22 +1484,32 @@ pub fn get_default_namespaces() -> Vec<LinuxNamespace> {
 
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// LinuxDevice represents the mknod information for a Linux special device
     /// file.
     struct LinuxDevice {
         #[serde(default)]
         #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzz::absolute_path))]
         /// Path to the device.
         path: PathBuf,
 
@@ -843,13 +1545,61 @@ make_pub!(
     }
 );
 
+impl LinuxDevice {
+    /// Creates the device node configuration for the device at `path` on the
+    /// host, taking the type, device numbers, file mode and owner from
+    /// stat(2). The device is created at the same path in the container.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the device cannot be inspected or an
+    /// [OciSpecError::Other](crate::OciSpecError::Other) if the path is not a
+    /// block device, character device or FIFO.
+    /// # Example
+    /// ```no_run
+    /// use oci_spec::runtime::LinuxDevice;
+    ///
+    /// let device = LinuxDevice::from_path("/dev/null").unwrap();
+    /// let device = serde_json::to_value(&device).unwrap();
+    /// assert_eq!(device["type"], "c");
+    /// assert_eq!((device["major"].as_i64(), device["minor"].as_i64()), (Some(1), Some(3)));
+    /// ```
+    #[cfg(unix)]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, OciSpecError> {
+        use std::os::unix::fs::{FileTypeExt, MetadataExt};
+
+        let path = path.as_ref();
+        let metadata = std::fs::metadata(path)?;
+        let file_type = metadata.file_type();
+        let typ = if file_type.is_block_device() {
+            LinuxDeviceType::B
+        } else if file_type.is_char_device() {
+            LinuxDeviceType::C
+        } else if file_type.is_fifo() {
+            LinuxDeviceType::P
+        } else {
+            return Err(oci_error(format!("{} is not a device", path.display())));
+        };
+
+        let (major, minor) = device_numbers(metadata.rdev());
+        Ok(LinuxDevice {
+            path: path.to_path_buf(),
+            typ,
+            major,
+            minor,
+            file_mode: Some(metadata.mode() & 0o777),
+            uid: Some(metadata.uid()),
+            gid: Some(metadata.gid()),
+        })
+    }
+}
+
 impl From<&LinuxDevice> for LinuxDeviceCgroup {
     fn from(linux_device: &LinuxDevice) -> LinuxDeviceCgroup {
         LinuxDeviceCgroup {
             allow: true,
             typ: linux_device.typ.into(),
-            major: Some(linux_device.major as i64),
-            minor: Some(linux_device.minor as i64),
+            major: Some(linux_device.major),
+            minor: Some(linux_device.minor),
             access: "rwm".to_string().into(),
         }
     }
@@ -857,16 +1607,25 @@ impl From<&LinuxDevice> for LinuxDeviceCgroup {
 
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// LinuxSeccomp represents syscall restrictions.
     struct LinuxSeccomp {
@@ -874,6 +1633,12 @@ make_pub!(
         /// The default action to be done.
         default_action: LinuxSeccompAction,
 
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// The errno returned by the default action, if it is
+        /// `SCMP_ACT_ERRNO` or `SCMP_ACT_TRACE`.
+        default_errno_ret: Option<u32>,
+
         #[serde(default, skip_serializing_if = "Option::is_none")]
         #[cfg_attr(feature = "builder", getset(get = "pub"))]
         /// Available architectures for the restriction.
@@ -882,7 +1647,20 @@ make_pub!(
         #[serde(default, skip_serializing_if = "Option::is_none")]
         #[cfg_attr(feature = "builder", getset(get = "pub"))]
         /// Flags added to the seccomp restriction.
-        flags: Option<Vec<String>>,
+        flags: Option<Vec<LinuxSeccompFilterFlag>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// The path of the UNIX socket to which the runtime sends the seccomp
+        /// notify file descriptor, together with the container state. This is
+        /// required if any syscall uses the `SCMP_ACT_NOTIFY` action.
+        listener_path: Option<PathBuf>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// Opaque data which is passed to the seccomp agent listening on
+        /// `listener_path`.
+        listener_metadata: Option<String>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
         #[cfg_attr(feature = "builder", getset(get = "pub"))]
@@ -891,7 +1669,94 @@ make_pub!(
     }
 );
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+impl LinuxSeccomp {
+    /// Returns true if the profile uses the `SCMP_ACT_NOTIFY` action, which
+    /// requires a `listener_path` to be set.
+    pub fn requires_listener(&self) -> bool {
+        self.default_action == LinuxSeccompAction::ScmpActNotify
+            || self
+                .syscalls
+                .iter()
+                .flatten()
+                .any(|syscall| syscall.action == LinuxSeccompAction::ScmpActNotify)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+/// Flags which are passed to the seccomp(2) syscall when loading the filter.
+pub enum LinuxSeccompFilterFlag {
+    /// Synchronize the filter to all threads of the process.
+    Tsync,
+
+    /// Log all filter return actions except `SCMP_ACT_ALLOW`.
+    Log,
+
+    /// Disable the speculative store bypass mitigation.
+    SpecAllow,
+
+    /// Wait in a killable state for the notification response of the seccomp
+    /// agent.
+    WaitKillableRecv,
+
+    /// A flag which is not known to this crate, e.g. one added by a newer
+    /// kernel. Runtimes which do not recognize it are expected to reject it.
+    Other(String),
+}
+
+impl LinuxSeccompFilterFlag {
+    /// Returns the name of the flag as used in the runtime configuration.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Tsync => "SECCOMP_FILTER_FLAG_TSYNC",
+            Self::Log => "SECCOMP_FILTER_FLAG_LOG",
+            Self::SpecAllow => "SECCOMP_FILTER_FLAG_SPEC_ALLOW",
+            Self::WaitKillableRecv => "SECCOMP_FILTER_FLAG_WAIT_KILLABLE_RECV",
+            Self::Other(flag) => flag,
+        }
+    }
+}
+
+impl fmt::Display for LinuxSeccompFilterFlag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for LinuxSeccompFilterFlag {
+    fn from(flag: &str) -> Self {
+        match flag {
+            "SECCOMP_FILTER_FLAG_TSYNC" => Self::Tsync,
+            "SECCOMP_FILTER_FLAG_LOG" => Self::Log,
+            "SECCOMP_FILTER_FLAG_SPEC_ALLOW" => Self::SpecAllow,
+            "SECCOMP_FILTER_FLAG_WAIT_KILLABLE_RECV" => Self::WaitKillableRecv,
+            other => Self::Other(other.to_owned()),
+        }
+    }
+}
+
+impl Serialize for LinuxSeccompFilterFlag {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LinuxSeccompFilterFlag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let flag = String::deserialize(deserializer)?;
+        Ok(flag.as_str().into())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[repr(u32)]
 /// Available seccomp actions.
@@ -918,18 +1783,15 @@ pub enum LinuxSeccompAction {
     ScmpActLog = 0x7ffc0000,
 
     /// Allow the syscall to be executed.
+    #[default]
     ScmpActAllow = 0x7fff0000,
 }
 
-impl Default for LinuxSeccompAction {
-    fn default() -> Self {
-        Self::ScmpActAllow
-    }
-}
-
-#[allow(clippy::enum_clike_unportable_variant)]
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[repr(u32)]
 /// Available seccomp architectures.
 pub enum Arch {
     /// The native architecture.
@@ -987,7 +1849,124 @@ pub enum Arch {
     ScmpArchS390x = 0x80000016,
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg(feature = "syscalls")]
+impl LinuxSeccomp {
+    /// Returns the names of the syscalls referenced by the profile which do not
+    /// exist on any of its architectures. If the profile does not list any
+    /// architecture, the native one is used. Architectures without a syscall
+    /// table (see [Arch::has_syscall_table]) are not taken into account, so
+    /// the result is empty if none of the architectures can be checked.
+    pub fn unknown_syscalls(&self) -> Vec<String> {
+        let arches: Vec<Arch> = match &self.architectures {
+            Some(arches) if !arches.is_empty() => arches.clone(),
+            _ => vec![Arch::ScmpArchNative],
+        }
+        .into_iter()
+        .filter(Arch::has_syscall_table)
+        .collect();
+        if arches.is_empty() {
+            return Vec::new();
+        }
+
+        let mut unknown: Vec<String> = Vec::new();
+        let names = self
+            .syscalls
+            .iter()
+            .flatten()
+            .flat_map(|syscall| syscall.names.iter());
+        for name in names {
+            if arches
+                .iter()
+                .all(|arch| arch.syscall_number(name).is_none())
+                && !unknown.contains(name)
+            {
+                unknown.push(name.clone());
+            }
+        }
+        unknown
+    }
+}
+
+impl Arch {
+    /// Returns the architecture the crate was compiled for, which
+    /// [Arch::ScmpArchNative] refers to, or `None` if it is not known.
+    pub(crate) fn native() -> Option<Self> {
+        let little_endian = cfg!(target_endian = "little");
+        if cfg!(all(target_arch = "x86_64", target_pointer_width = "32")) {
+            Some(Self::ScmpArchX32)
+        } else if cfg!(target_arch = "x86_64") {
+            Some(Self::ScmpArchX86_64)
+        } else if cfg!(target_arch = "x86") {
+            Some(Self::ScmpArchX86)
+        } else if cfg!(target_arch = "aarch64") {
+            Some(Self::ScmpArchAarch64)
+        } else if cfg!(target_arch = "arm") {
+            Some(Self::ScmpArchArm)
+        } else if cfg!(target_arch = "mips") {
+            Some(if little_endian {
+                Self::ScmpArchMipsel
+            } else {
+                Self::ScmpArchMips
+            })
+        } else if cfg!(target_arch = "mips64") {
+            Some(if little_endian {
+                Self::ScmpArchMipsel64
+            } else {
+                Self::ScmpArchMips64
+            })
+        } else if cfg!(target_arch = "powerpc") {
+            Some(Self::ScmpArchPpc)
+        } else if cfg!(target_arch = "powerpc64") {
+            Some(if little_endian {
+                Self::ScmpArchPpc64le
+            } else {
+                Self::ScmpArchPpc64
+            })
+        } else if cfg!(target_arch = "s390x") {
+            Some(Self::ScmpArchS390x)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "syscalls")]
+impl Arch {
+    /// Looks up the number of the syscall `name` on this architecture, where
+    /// [Arch::ScmpArchNative] refers to the architecture the crate was compiled
+    /// for. Returns `None` if the syscall does not exist on the architecture or
+    /// if no syscall table is available for it.
+    pub fn syscall_number(&self, name: &str) -> Option<u32> {
+        let table = self.syscall_table()?;
+        table
+            .binary_search_by_key(&name, |(syscall, _)| syscall)
+            .ok()
+            .map(|i| table[i].1)
+    }
+
+    /// Returns true if syscall names can be resolved for this architecture.
+    /// Syscall tables are available for x86, x86-64, x32 and AArch64.
+    pub fn has_syscall_table(&self) -> bool {
+        self.syscall_table().is_some()
+    }
+
+    fn syscall_table(&self) -> Option<&'static [(&'static str, u32)]> {
+        use super::syscalls;
+
+        match self {
+            Self::ScmpArchNative => Self::native().and_then(|arch| arch.syscall_table()),
+            Self::ScmpArchX86 => Some(syscalls::X86),
+            Self::ScmpArchX86_64 => Some(syscalls::X86_64),
+            Self::ScmpArchX32 => Some(syscalls::X32),
+            Self::ScmpArchAarch64 => Some(syscalls::AARCH64),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[repr(u32)]
 /// The seccomp operator to be used for args.
@@ -1002,6 +1981,7 @@ pub enum LinuxSeccompOperator {
     ScmpCmpLe = 3,
 
     /// Refers to the SCMP_CMP_EQ operator (equal to).
+    #[default]
     ScmpCmpEq = 4,
 
     /// Refers to the SCMP_CMP_GE operator (greater equal).
@@ -1014,23 +1994,26 @@ pub enum LinuxSeccompOperator {
     ScmpCmpMaskedEq = 7,
 }
 
-impl Default for LinuxSeccompOperator {
-    fn default() -> Self {
-        Self::ScmpCmpEq
-    }
-}
-
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// LinuxSyscall is used to match a syscall in seccomp.
     struct LinuxSyscall {
@@ -1056,17 +2039,25 @@ make_pub!(
 
 make_pub!(
     #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get_copy = "pub")
+        getset(get_copy = "pub", get_mut = "pub", set = "pub")
     )]
     /// LinuxSeccompArg used for matching specific syscall arguments in seccomp.
     struct LinuxSeccompArg {
@@ -1085,55 +2076,79 @@ make_pub!(
     }
 );
 
-/// Default masks paths, cannot read these host files.
+/// Default masked paths, as used by runc and moby. The container cannot read
+/// these host files. For example host interfaces such as bluetooth cannot be
+/// accessed due to `/proc/acpi`.
+pub const DEFAULT_MASKED_PATHS: &[&str] = &[
+    "/proc/acpi",
+    "/proc/asound",
+    "/proc/kcore",
+    "/proc/keys",
+    "/proc/latency_stats",
+    "/proc/timer_list",
+    "/proc/timer_stats",
+    "/proc/sched_debug",
+    "/sys/firmware",
+    "/proc/scsi",
+];
+
+/// Default readonly paths, as used by runc and moby. For example most
+/// containers shouldn't have permission to write to `/proc/sys`.
+pub const DEFAULT_READONLY_PATHS: &[&str] = &[
+    "/proc/bus",
+    "/proc/fs",
+    "/proc/irq",
+    "/proc/sys",
+    "/proc/sysrq-trigger",
+];
+
+/// Default masks paths, cannot read these host files, see
+/// [DEFAULT_MASKED_PATHS].
 pub fn get_default_maskedpaths() -> Vec<String> {
-    vec![
-        // For example now host interfaces such as
-        // bluetooth cannot be accessed due to /proc/acpi
-        "/proc/acpi".to_string(),
-        "/proc/asound".to_string(),
-        "/proc/kcore".to_string(),
-        "/proc/keys".to_string(),
-        "/proc/latency_stats".to_string(),
-        "/proc/timer_list".to_string(),
-        "/proc/timer_stats".to_string(),
-        "/proc/sched_debug".to_string(),
-        "/sys/firmware".to_string(),
-        "/proc/scsi".to_string(),
-    ]
+    DEFAULT_MASKED_PATHS.iter().map(|p| p.to_string()).collect()
 }
 
 /// Default readonly paths, for example most containers shouldn't have permission to write to
-/// `/proc/sys`.
+/// `/proc/sys`, see [DEFAULT_READONLY_PATHS].
 pub fn get_default_readonly_paths() -> Vec<String> {
-    vec![
-        "/proc/bus".to_string(),
-        "/proc/fs".to_string(),
-        "/proc/irq".to_string(),
-        "/proc/sys".to_string(),
-        "/proc/sysrq-trigger".to_string(),
-    ]
+    DEFAULT_READONLY_PATHS
+        .iter()
+        .map(|p| p.to_string())
+        .collect()
 }
 
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get = "pub")
+        getset(get = "pub", get_mut = "pub", set = "pub")
     )]
     /// LinuxIntelRdt has container runtime resource constraints for Intel RDT
     /// CAT and MBA features which introduced in Linux 4.10 and 4.12 kernel.
     struct LinuxIntelRdt {
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        /// The identity for RDT Class of Service.
+        #[serde(
+            default,
+            rename = "closID",
+            alias = "closId",
+            skip_serializing_if = "Option::is_none"
+        )]
+        /// The identity for RDT Class of Service. Older runtimes wrote it as
+        /// `closId`, which is still accepted when deserializing.
         clos_id: Option<String>,
 
         #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1148,20 +2163,120 @@ make_pub!(
         /// default, and in "MBps" if MBA Software Controller is
         /// enabled.
         mem_bw_schema: Option<String>,
+
+        #[serde(default, rename = "enableCMT", skip_serializing_if = "Option::is_none")]
+        /// EnableCMT enables the Cache Monitoring Technology, which reports
+        /// the L3 cache occupancy of the container.
+        enable_cmt: Option<bool>,
+
+        #[serde(default, rename = "enableMBM", skip_serializing_if = "Option::is_none")]
+        /// EnableMBM enables the Memory Bandwidth Monitoring, which reports
+        /// the memory bandwidth usage of the container.
+        enable_mbm: Option<bool>,
     }
 );
 
+impl LinuxIntelRdt {
+    /// Sets the L3 cache schema to the given capacity bitmasks, keyed by
+    /// cache id.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::LinuxIntelRdt;
+    ///
+    /// let mut rdt = LinuxIntelRdt::default();
+    /// rdt.set_l3_cache_masks(vec![(0, 0xfff0), (1, 0xf)]);
+    /// assert!(rdt.validate().is_ok());
+    /// ```
+    pub fn set_l3_cache_masks<I: IntoIterator<Item = (u32, u64)>>(&mut self, masks: I) {
+        let entries: Vec<String> = masks
+            .into_iter()
+            .map(|(id, mask)| format!("{}={:x}", id, mask))
+            .collect();
+        self.l3_cache_schema = Some(format!("L3:{}", entries.join(";")));
+    }
+
+    /// Sets the memory bandwidth schema to the given bandwidths, keyed by
+    /// cache id.
+    pub fn set_mem_bw<I: IntoIterator<Item = (u32, u32)>>(&mut self, bandwidths: I) {
+        let entries: Vec<String> = bandwidths
+            .into_iter()
+            .map(|(id, bandwidth)| format!("{}={}", id, bandwidth))
+            .collect();
+        self.mem_bw_schema = Some(format!("MB:{}", entries.join(";")));
+    }
+
+    /// Validates the syntax of the CLOS id, the L3 cache schema and the
+    /// memory bandwidth schema, as they would be written to resctrl.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// describing the first malformed value.
+    pub fn validate(&self) -> Result<(), OciSpecError> {
+        if let Some(clos_id) = &self.clos_id {
+            if clos_id.is_empty() || clos_id == "." || clos_id == ".." || clos_id.contains('/') {
+                return Err(oci_error(format!("invalid closID {:?}", clos_id)));
+            }
+        }
+        if let Some(schema) = &self.l3_cache_schema {
+            validate_rdt_schema(schema, &["L3", "L3CODE", "L3DATA"], |mask| {
+                !mask.is_empty() && u64::from_str_radix(mask, 16).is_ok_and(|mask| mask != 0)
+            })?;
+        }
+        if let Some(schema) = &self.mem_bw_schema {
+            validate_rdt_schema(schema, &["MB"], |bandwidth| {
+                bandwidth.bytes().all(|b| b.is_ascii_digit()) && bandwidth.parse::<u32>().is_ok()
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates the lines of a resctrl schema in the format
+/// "<resource>:<id0>=<value0>;<id1>=<value1>;...".
+fn validate_rdt_schema<F: Fn(&str) -> bool>(
+    schema: &str,
+    resources: &[&str],
+    valid_value: F,
+) -> Result<(), OciSpecError> {
+    let invalid = |line: &str| oci_error(format!("invalid intel rdt schema line {:?}", line));
+
+    for line in schema.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let (resource, entries) = line.split_once(':').ok_or_else(|| invalid(line))?;
+        if !resources.contains(&resource.trim()) {
+            return Err(oci_error(format!(
+                "unexpected intel rdt resource {:?}, expected one of {:?}",
+                resource, resources
+            )));
+        }
+        for entry in entries.split(';') {
+            let (id, value) = entry.split_once('=').ok_or_else(|| invalid(line))?;
+            if id.trim().parse::<u32>().is_err() || !valid_value(value.trim()) {
+                return Err(invalid(line));
+            }
+        }
+    }
+    Ok(())
+}
+
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// LinuxPersonality represents the Linux personality syscall input.
     struct LinuxPersonality {
@@ -1176,11 +2291,14 @@ make_pub!(
     }
 );
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 /// Define domain and flags for LinuxPersonality.
 pub enum LinuxPersonalityDomain {
     #[serde(rename = "LINUX")]
     /// PerLinux is the standard Linux personality.
+    #[default]
     PerLinux,
 
     #[serde(rename = "LINUX32")]
@@ -1188,12 +2306,272 @@ pub enum LinuxPersonalityDomain {
     PerLinux32,
 }
 
-impl Default for LinuxPersonalityDomain {
-    fn default() -> Self {
-        Self::PerLinux
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// LinuxNetDevice specifies a network device of the host which is moved
+    /// into the network namespace of the container.
+    struct LinuxNetDevice {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Name of the device in the container. Defaults to the name on the
+        /// host.
+        name: Option<String>,
+    }
+);
+
+impl Linux {
+    /// Moves the host network device `host_name` into the network namespace
+    /// of the container, optionally renaming it to `name`.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if one of the names is not a valid interface name.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::Linux;
+    ///
+    /// let mut linux = Linux::default();
+    /// linux.add_net_device("eth1", Some("ctr0")).unwrap();
+    /// assert!(linux.add_net_device("eth1", Some("a/b")).is_err());
+    /// ```
+    pub fn add_net_device<S: Into<String>>(
+        &mut self,
+        host_name: S,
+        name: Option<S>,
+    ) -> Result<(), OciSpecError> {
+        let host_name = host_name.into();
+        let name = name.map(Into::into);
+        validate_interface_name(&host_name)?;
+        if let Some(name) = &name {
+            validate_interface_name(name)?;
+        }
+        self.net_devices
+            .get_or_insert_with(Default::default)
+            .insert(host_name, LinuxNetDevice { name });
+        Ok(())
+    }
+
+    /// Validates that the names of the network devices are valid interface
+    /// names, that no two devices get the same name in the container and
+    /// that the container has a network namespace to move them into.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// describing the first invalid device.
+    pub fn validate_net_devices(&self) -> Result<(), OciSpecError> {
+        let devices = match &self.net_devices {
+            Some(devices) if !devices.is_empty() => devices,
+            _ => return Ok(()),
+        };
+        if !self
+            .namespaces
+            .iter()
+            .flatten()
+            .any(|ns| ns.typ == LinuxNamespaceType::Network)
+        {
+            return Err(oci_error("net devices require a network namespace"));
+        }
+
+        let mut names: Vec<(&String, &String)> = devices
+            .iter()
+            .map(|(host_name, device)| (device.name.as_ref().unwrap_or(host_name), host_name))
+            .collect();
+        names.sort();
+        for (name, host_name) in &names {
+            validate_interface_name(host_name)?;
+            validate_interface_name(name)?;
+        }
+        if let Some(pair) = names.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            return Err(oci_error(format!(
+                "net devices {} and {} are both named {} in the container",
+                pair[0].1, pair[1].1, pair[0].0
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Validates a network interface name, which must be shorter than 16 bytes
+/// and must not contain `/`, `:` or whitespace.
+fn validate_interface_name(name: &str) -> Result<(), OciSpecError> {
+    let valid = !name.is_empty()
+        && name.len() < 16
+        && name != "."
+        && name != ".."
+        && !name.contains(|c: char| c == '/' || c == ':' || c.is_whitespace());
+    if !valid {
+        return Err(oci_error(format!("invalid interface name {:?}", name)));
+    }
+    Ok(())
+}
+
+/// The clock name of the boot time clock in [Linux::time_offsets].
+pub const TIME_OFFSET_BOOTTIME: &str = "boottime";
+/// The clock name of the monotonic clock in [Linux::time_offsets].
+pub const TIME_OFFSET_MONOTONIC: &str = "monotonic";
+
+make_pub!(
+    #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get_copy = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// LinuxTimeOffset specifies the offset of a clock in the time namespace.
+    struct LinuxTimeOffset {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Secs is the offset of the clock in seconds.
+        secs: Option<i64>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Nanosecs is the additional offset of the clock in nanoseconds.
+        nanosecs: Option<u32>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get_mut = "pub", set = "pub")
+    )]
+    /// LinuxMemoryPolicy represents the NUMA memory policy of a process.
+    struct LinuxMemoryPolicy {
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Mode is the memory policy mode.
+        mode: MemoryPolicyMode,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// Nodes is the list of NUMA nodes the policy applies to, in the
+        /// format of cpuset(7), e.g. "0-3,7".
+        nodes: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// Flags are the optional mode flags.
+        flags: Option<Vec<MemoryPolicyFlag>>,
+    }
+);
+
+impl LinuxMemoryPolicy {
+    /// Validates that nodes are given exactly for the modes which require
+    /// them and that the node list is well formed.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// describing the invalid policy.
+    pub fn validate(&self) -> Result<(), OciSpecError> {
+        let nodes = self.nodes.as_deref().filter(|nodes| !nodes.is_empty());
+        match (self.mode, nodes) {
+            (MemoryPolicyMode::MpolDefault, Some(_)) | (MemoryPolicyMode::MpolLocal, Some(_)) => {
+                Err(oci_error(format!(
+                    "memory policy mode {:?} must not have nodes",
+                    self.mode
+                )))
+            }
+            (MemoryPolicyMode::MpolDefault, None)
+            | (MemoryPolicyMode::MpolLocal, None)
+            | (MemoryPolicyMode::MpolPreferred, None) => Ok(()),
+            (mode, None) => Err(oci_error(format!(
+                "memory policy mode {:?} requires nodes",
+                mode
+            ))),
+            (_, Some(nodes)) => super::process::validate_list("node", nodes),
+        }
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+/// MemoryPolicyMode is the mode of a NUMA memory policy.
+pub enum MemoryPolicyMode {
+    /// Uses the default policy of the system, allocating on the local node.
+    #[default]
+    MpolDefault,
+
+    /// Restricts allocations to the given nodes.
+    MpolBind,
+
+    /// Interleaves allocations across the given nodes.
+    MpolInterleave,
+
+    /// Interleaves allocations across the given nodes according to the node
+    /// weights of the system.
+    MpolWeightedInterleave,
+
+    /// Prefers allocating on the given node, or on the local node if no node
+    /// is given.
+    MpolPreferred,
+
+    /// Prefers allocating on any of the given nodes.
+    MpolPreferredMany,
+
+    /// Allocates on the node of the CPU which triggers the allocation.
+    MpolLocal,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+/// MemoryPolicyFlag modifies the mode of a NUMA memory policy.
+pub enum MemoryPolicyFlag {
+    /// Enables NUMA balancing for the bound nodes.
+    MpolFNumaBalancing,
+
+    /// Interprets the nodes relative to the nodes allowed by the cpuset of
+    /// the process.
+    MpolFRelativeNodes,
+
+    /// Does not remap the nodes if the cpuset of the process changes.
+    MpolFStaticNodes,
+}
+
 #[cfg(feature = "proptests")]
 use quickcheck::{Arbitrary, Gen};
 
@@ -1244,6 +2622,7 @@ impl Arbitrary for LinuxMemory {
             swappiness: some_none_generator_util::<u64>(g),
             disable_oom_killer: some_none_generator_util::<bool>(g),
             use_hierarchy: some_none_generator_util::<bool>(g),
+            check_before_update: some_none_generator_util::<bool>(g),
         }
     }
 }
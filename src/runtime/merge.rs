@@ -0,0 +1,239 @@
+use serde_json::{Map, Value};
+
+use crate::{diff, error::Result, Difference};
+
+use super::Spec;
+
+impl Spec {
+    /// Applies a JSON merge patch as defined by RFC 7386 to the spec. Objects
+    /// are merged recursively, `null` values remove the corresponding field
+    /// and all other values, including arrays, replace the existing value.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the patched spec is not a valid spec, in which case the spec is
+    /// left unchanged.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::Spec;
+    ///
+    /// let mut spec = Spec::default();
+    /// spec.merge(&serde_json::json!({
+    ///     "hostname": "patched",
+    ///     "process": {"env": ["TERM=dumb"]},
+    /// }))
+    /// .unwrap();
+    ///
+    /// let patched = serde_json::to_value(&spec).unwrap();
+    /// assert_eq!(patched["hostname"], "patched");
+    /// assert_eq!(patched["process"]["env"], serde_json::json!(["TERM=dumb"]));
+    /// assert_eq!(patched["process"]["cwd"], "/");
+    /// ```
+    pub fn merge(&mut self, patch: &Value) -> Result<()> {
+        let mut value = serde_json::to_value(&*self)?;
+        merge_patch(&mut value, patch);
+        *self = crate::from_deserializer(value)?;
+        Ok(())
+    }
+
+    /// Overlays another spec onto this one, see [Spec::merge]. Fields which
+    /// are not set in `other` are kept, whereas set fields replace the
+    /// existing ones, with nested sections being merged field by field.
+    /// Fields which are always serialized, like `ociVersion`, are always
+    /// taken from `other`.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the merged spec is not a valid spec, in which case the spec is left
+    /// unchanged.
+    pub fn merge_spec(&mut self, other: &Spec) -> Result<()> {
+        let patch = serde_json::to_value(other)?;
+        self.merge(&patch)
+    }
+
+    /// Compares the spec with `other` and returns the differing values, see
+    /// [diff](crate::diff). Capability sets are compared regardless of the
+    /// order of their elements.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if one of the specs cannot be serialized.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::Spec;
+    ///
+    /// let before = Spec::default();
+    /// let mut after = before.clone();
+    /// after
+    ///     .merge(&serde_json::json!({"hostname": "container"}))
+    ///     .unwrap();
+    ///
+    /// let differences = before.diff(&after).unwrap();
+    /// assert_eq!(differences.len(), 1);
+    /// assert_eq!(differences[0].path, "/hostname");
+    /// assert_eq!(differences[0].to_string(), r#"/hostname: "youki" -> "container""#);
+    /// ```
+    pub fn diff(&self, other: &Spec) -> Result<Vec<Difference>> {
+        diff(&sorted_capabilities(self)?, &sorted_capabilities(other)?)
+    }
+}
+
+/// Serializes the spec with sorted capability sets, whose serialization order
+/// is otherwise unspecified.
+fn sorted_capabilities(spec: &Spec) -> Result<Value> {
+    let mut value = serde_json::to_value(spec)?;
+    if let Some(Value::Object(sets)) = value.pointer_mut("/process/capabilities") {
+        for set in sets.values_mut() {
+            if let Value::Array(capabilities) = set {
+                capabilities.sort_by(|a, b| a.as_str().cmp(&b.as_str()));
+            }
+        }
+    }
+    Ok(value)
+}
+
+/// Applies the JSON merge patch `patch` to `target` as defined by RFC 7386.
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let patch = match patch {
+        Value::Object(patch) => patch,
+        _ => {
+            *target = patch.clone();
+            return;
+        }
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merge_patch(target.entry(key.as_str()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn rfc7386_examples() {
+        // arrange
+        let cases = vec![
+            (json!({"a": "b"}), json!({"a": "c"}), json!({"a": "c"})),
+            (
+                json!({"a": "b"}),
+                json!({"b": "c"}),
+                json!({"a": "b", "b": "c"}),
+            ),
+            (json!({"a": "b"}), json!({"a": null}), json!({})),
+            (
+                json!({"a": "b", "b": "c"}),
+                json!({"a": null}),
+                json!({"b": "c"}),
+            ),
+            (json!({"a": ["b"]}), json!({"a": "c"}), json!({"a": "c"})),
+            (json!({"a": "c"}), json!({"a": ["b"]}), json!({"a": ["b"]})),
+            (
+                json!({"a": {"b": "c"}}),
+                json!({"a": {"b": "d", "c": null}}),
+                json!({"a": {"b": "d"}}),
+            ),
+            (
+                json!({"a": [{"b": "c"}]}),
+                json!({"a": [1]}),
+                json!({"a": [1]}),
+            ),
+            (json!(["a", "b"]), json!(["c", "d"]), json!(["c", "d"])),
+            (json!({"a": "b"}), json!(["c"]), json!(["c"])),
+            (json!({"a": "foo"}), json!(null), json!(null)),
+            (json!({"a": "foo"}), json!("bar"), json!("bar")),
+            (
+                json!({"e": null}),
+                json!({"a": 1}),
+                json!({"e": null, "a": 1}),
+            ),
+            (
+                json!([1, 2]),
+                json!({"a": "b", "c": null}),
+                json!({"a": "b"}),
+            ),
+            (
+                json!({}),
+                json!({"a": {"bb": {"ccc": null}}}),
+                json!({"a": {"bb": {}}}),
+            ),
+        ];
+
+        for (mut target, patch, expected) in cases {
+            // act
+            merge_patch(&mut target, &patch);
+
+            // assert
+            assert_eq!(target, expected, "patch {}", patch);
+        }
+    }
+
+    #[test]
+    fn merge_spec() {
+        // arrange
+        let mut base = Spec::default();
+        let mut overrides: Spec = serde_json::from_value(json!({
+            "ociVersion": base.version,
+            "hostname": "override",
+            "annotations": {"org.example.key": "value"}
+        }))
+        .unwrap();
+        overrides.root = None;
+
+        // act
+        base.merge_spec(&overrides).unwrap();
+
+        // assert
+        assert_eq!(base.hostname.as_deref(), Some("override"));
+        assert_eq!(
+            base.annotations.as_ref().unwrap()["org.example.key"],
+            "value"
+        );
+        assert!(base.root.is_some());
+        assert!(base.process.is_some());
+    }
+
+    #[test]
+    fn diff_capabilities() {
+        // arrange
+        let before = Spec::default();
+        let mut after = Spec::default();
+        after
+            .merge(&json!({"process": {"capabilities": {"bounding": ["CAP_KILL"]}}}))
+            .unwrap();
+
+        // act
+        let differences = before.diff(&after).unwrap();
+
+        // assert
+        assert!(before.diff(&before.clone()).unwrap().is_empty());
+        assert!(differences
+            .iter()
+            .all(|d| d.path.starts_with("/process/capabilities/bounding/")));
+        assert!(differences
+            .iter()
+            .any(|d| d.after == Some(json!("CAP_KILL"))));
+    }
+
+    #[test]
+    fn merge_invalid() {
+        // arrange
+        let mut spec = Spec::default();
+        let original = spec.clone();
+
+        // act
+        let result = spec.merge(&json!({"process": {"terminal": "yes"}}));
+
+        // assert
+        assert!(result.is_err());
+        assert_eq!(spec, original);
+    }
+}
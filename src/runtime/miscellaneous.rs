@@ -1,17 +1,29 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+use super::LinuxIdMapping;
+use crate::error::{oci_error, Result};
+
 make_pub!(
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// Root contains information about the container's root filesystem on the
     /// host.
@@ -42,19 +54,27 @@ impl Default for Root {
 
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get = "pub")
+        getset(get = "pub", get_mut = "pub", set = "pub")
     )]
     /// Mount specifies a mount for a container.
     struct Mount {
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzz::absolute_path))]
         /// Destination is the absolute path where the mount will be placed in
         /// the container.
         destination: PathBuf,
@@ -70,9 +90,59 @@ make_pub!(
         #[serde(default, skip_serializing_if = "Option::is_none")]
         /// Options are fstab style mount options.
         options: Option<Vec<String>>,
+
+        #[serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            rename = "uidMappings"
+        )]
+        /// UID mappings used for changing file owners w/o calling chown, fs
+        /// should support it. Every mount point could have its own mapping.
+        uid_mappings: Option<Vec<LinuxIdMapping>>,
+
+        #[serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            rename = "gidMappings"
+        )]
+        /// GID mappings used for changing file owners w/o calling chown, fs
+        /// should support it. Every mount point could have its own mapping.
+        gid_mappings: Option<Vec<LinuxIdMapping>>,
     }
 );
 
+impl Mount {
+    /// Returns true if the mount is an idmapped mount, i.e. it defines UID or
+    /// GID mappings.
+    pub fn is_idmapped(&self) -> bool {
+        self.uid_mappings.as_ref().is_some_and(|m| !m.is_empty())
+            || self.gid_mappings.as_ref().is_some_and(|m| !m.is_empty())
+    }
+
+    /// Validates the idmapped mount settings. UID and GID mappings have to be
+    /// specified together and every mapping must map at least one ID.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// describing the invalid mapping.
+    pub fn validate_id_mappings(&self) -> Result<()> {
+        let uid_mappings = self.uid_mappings.as_deref().unwrap_or_default();
+        let gid_mappings = self.gid_mappings.as_deref().unwrap_or_default();
+        if uid_mappings.is_empty() != gid_mappings.is_empty() {
+            return Err(oci_error(format!(
+                "mount {} must specify both uidMappings and gidMappings",
+                self.destination.display()
+            )));
+        }
+        if uid_mappings.iter().chain(gid_mappings).any(|m| m.size == 0) {
+            return Err(oci_error(format!(
+                "mount {} contains an empty id mapping",
+                self.destination.display()
+            )));
+        }
+        Ok(())
+    }
+}
+
 /// utility function to generate default config for mounts.
 pub fn get_default_mounts() -> Vec<Mount> {
     vec![
@@ -81,6 +151,8 @@ pub fn get_default_mounts() -> Vec<Mount> {
             typ: "proc".to_string().into(),
             source: PathBuf::from("proc").into(),
             options: None,
+            uid_mappings: None,
+            gid_mappings: None,
         },
         Mount {
             destination: PathBuf::from("/dev"),
@@ -93,6 +165,8 @@ pub fn get_default_mounts() -> Vec<Mount> {
                 "size=65536k".into(),
             ]
             .into(),
+            uid_mappings: None,
+            gid_mappings: None,
         },
         Mount {
             destination: PathBuf::from("/dev/pts"),
@@ -107,6 +181,8 @@ pub fn get_default_mounts() -> Vec<Mount> {
                 "gid=5".into(),
             ]
             .into(),
+            uid_mappings: None,
+            gid_mappings: None,
         },
         Mount {
             destination: PathBuf::from("/dev/shm"),
@@ -120,12 +196,16 @@ pub fn get_default_mounts() -> Vec<Mount> {
                 "size=65536k".into(),
             ]
             .into(),
+            uid_mappings: None,
+            gid_mappings: None,
         },
         Mount {
             destination: PathBuf::from("/dev/mqueue"),
             typ: "mqueue".to_string().into(),
             source: PathBuf::from("mqueue").into(),
             options: vec!["nosuid".into(), "noexec".into(), "nodev".into()].into(),
+            uid_mappings: None,
+            gid_mappings: None,
         },
         Mount {
             destination: PathBuf::from("/sys"),
@@ -138,6 +218,8 @@ pub fn get_default_mounts() -> Vec<Mount> {
                 "ro".into(),
             ]
             .into(),
+            uid_mappings: None,
+            gid_mappings: None,
         },
         Mount {
             destination: PathBuf::from("/sys/fs/cgroup"),
@@ -151,6 +233,8 @@ pub fn get_default_mounts() -> Vec<Mount> {
                 "ro".into(),
             ]
             .into(),
+            uid_mappings: None,
+            gid_mappings: None,
         },
     ]
 }
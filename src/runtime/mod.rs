@@ -1,49 +1,95 @@
 //! [OCI runtime spec](https://github.com/opencontainers/runtime-spec) types and definitions.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+#[cfg(has_fs)]
 use std::{
-    collections::HashMap,
     fs,
     path::{Path, PathBuf},
 };
 
+#[cfg(has_fs)]
 use crate::error::{oci_error, Result};
 
 mod capability;
+mod compat;
+#[cfg(feature = "cri")]
+mod cri;
+mod docker_seccomp;
+mod features;
 mod hooks;
+mod id_mapping;
+mod landlock;
 mod linux;
+mod merge;
 mod miscellaneous;
+mod mount_option;
 mod process;
+mod rootless;
 mod solaris;
+mod state;
+mod syscall;
+mod syscall_names;
+#[cfg(feature = "syscalls")]
+mod syscalls;
+mod sysctl;
 mod test;
+mod unified;
+mod validation;
 mod version;
 mod vm;
 mod windows;
+mod zos;
 
 // re-export for ease of use
 pub use capability::*;
+pub use compat::*;
+#[cfg(feature = "cri")]
+pub use cri::*;
+pub use docker_seccomp::*;
+pub use features::*;
 pub use hooks::*;
+pub use id_mapping::*;
+pub use landlock::*;
 pub use linux::*;
 pub use miscellaneous::*;
+pub use mount_option::*;
 pub use process::*;
+pub use rootless::*;
 pub use solaris::*;
+pub use state::*;
+pub use syscall::*;
+pub use sysctl::*;
+pub use unified::*;
+pub use validation::*;
 pub use version::*;
 pub use vm::*;
 pub use windows::*;
+pub use zos::*;
+
+/// The name of the file which contains the configuration of a bundle.
+pub const CONFIG_FILE: &str = "config.json";
 
 make_pub!(
     /// Base configuration for the container.
     #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get = "pub")
+        getset(get = "pub", get_mut = "pub", set = "pub")
     )]
     struct Spec {
         #[serde(default, rename = "ociVersion")]
@@ -133,6 +179,19 @@ make_pub!(
         #[serde(default, skip_serializing_if = "Option::is_none")]
         /// VM specifies configuration for Virtual Machine based containers.
         vm: Option<VM>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// ZOS is platform-specific configuration for z/OS based containers.
+        zos: Option<ZOS>,
+
+        #[cfg_attr(feature = "lossless", serde(flatten))]
+        #[cfg_attr(not(feature = "lossless"), serde(skip))]
+        #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+        /// Properties which are not defined by the runtime specification,
+        /// e.g. settings of a specific runtime. Without them a spec which is
+        /// loaded, modified and saved again would silently lose data. Unknown
+        /// properties are only collected with the `lossless` feature.
+        extensions: HashMap<String, serde_json::Value>,
     }
 );
 
@@ -157,12 +216,17 @@ impl Default for Spec {
             solaris: None,
             windows: None,
             vm: None,
+            zos: None,
+            extensions: Default::default(),
         }
     }
 }
 
+#[cfg(has_fs)]
 impl Spec {
-    /// Load a new `Spec` from the provided JSON file `path`.
+    /// Load a new `Spec` from the provided JSON file `path`. If `path` is a
+    /// directory, it is treated as a bundle and the spec is read from its
+    /// `config.json`.
     /// # Errors
     /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
     /// if the spec does not exist or an
@@ -172,15 +236,17 @@ impl Spec {
     /// use oci_spec::runtime::Spec;
     ///
     /// let spec = Spec::load("config.json").unwrap();
+    /// let spec = Spec::load("/var/run/bundle").unwrap();
     /// ```
     pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let path = path.as_ref();
+        let path = Self::config_path(path.as_ref());
         let file = fs::File::open(path)?;
-        let s = serde_json::from_reader(&file)?;
-        Ok(s)
+        crate::from_reader(&file)
     }
 
-    /// Save a `Spec` to the provided JSON file `path`.
+    /// Save a `Spec` to the provided JSON file `path`. If `path` is an
+    /// existing directory, it is treated as a bundle and the spec is written
+    /// to its `config.json`.
     /// # Errors
     /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
     /// if a file cannot be created at the provided path or an
@@ -192,14 +258,42 @@ impl Spec {
     ///
     /// let mut spec = Spec::load("config.json").unwrap();
     /// spec.save("my_config.json").unwrap();
+    /// spec.save("/var/run/bundle").unwrap();
     /// ```
     pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-        let path = path.as_ref();
+        let path = Self::config_path(path.as_ref());
         let file = fs::File::create(path)?;
         serde_json::to_writer(&file, self)?;
         Ok(())
     }
 
+    /// Load a `Spec` from the `config.json` of the `bundle` and canonicalize
+    /// its `root.path` against the bundle, as a runtime does when creating a
+    /// container.
+    /// # Errors
+    /// This function will return the errors of [Spec::load] and
+    /// [Spec::canonicalize_rootfs].
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::runtime::Spec;
+    ///
+    /// let spec = Spec::load_bundle("/var/run/bundle").unwrap();
+    /// ```
+    pub fn load_bundle<P: AsRef<Path>>(bundle: P) -> Result<Self> {
+        let bundle = bundle.as_ref();
+        let mut spec = Self::load(bundle.join(CONFIG_FILE))?;
+        spec.canonicalize_rootfs(bundle)?;
+        Ok(spec)
+    }
+
+    fn config_path(path: &Path) -> PathBuf {
+        if path.is_dir() {
+            path.join(CONFIG_FILE)
+        } else {
+            path.to_path_buf()
+        }
+    }
+
     #[cfg(not(feature = "builder"))]
     /// Canonicalize the `root.path` of the `Spec` for the provided `bundle`.
     pub fn canonicalize_rootfs<P: AsRef<Path>>(&mut self, bundle: P) -> Result<()> {
@@ -352,7 +446,7 @@ mod tests {
             ..Default::default()
         };
         let test_dir = tempfile::tempdir().expect("failed to create tmp test dir");
-        let spec_path = test_dir.into_path().join("config.json");
+        let spec_path = test_dir.path().join("config.json");
 
         // Test first save the default config, and then load the saved config.
         // The before and after should be the same.
@@ -363,4 +457,28 @@ mod tests {
             "The saved spec is not the same as the loaded spec"
         );
     }
+
+    #[test]
+    fn test_load_save_bundle() {
+        let bundle = tempfile::tempdir().expect("failed to create tmp test bundle dir");
+        fs::create_dir_all(bundle.path().join("rootfs")).expect("failed to create rootfs");
+        let spec = Spec::default();
+
+        // Saving to and loading from the bundle directory uses its config.json.
+        spec.save(bundle.path()).expect("failed to save spec");
+        assert!(bundle.path().join(CONFIG_FILE).is_file());
+        let loaded_spec = Spec::load(bundle.path()).expect("failed to load spec");
+        assert_eq!(spec, loaded_spec);
+
+        // The relative default rootfs is resolved against the bundle.
+        let loaded_spec = Spec::load_bundle(bundle.path()).expect("failed to load bundle");
+        let rootfs = fs::canonicalize(bundle.path().join("rootfs")).unwrap();
+        assert_eq!(
+            loaded_spec.root.as_ref().map(|root| root.path.as_path()),
+            Some(rootfs.as_path())
+        );
+
+        let empty = tempfile::tempdir().expect("failed to create tmp test dir");
+        assert!(Spec::load_bundle(empty.path()).is_err());
+    }
 }
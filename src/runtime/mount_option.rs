@@ -0,0 +1,645 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{oci_error, OciSpecError, Result};
+
+use super::Mount;
+
+const MS_RDONLY: u64 = 0x1;
+const MS_NOSUID: u64 = 0x2;
+const MS_NODEV: u64 = 0x4;
+const MS_NOEXEC: u64 = 0x8;
+const MS_SYNCHRONOUS: u64 = 0x10;
+const MS_REMOUNT: u64 = 0x20;
+const MS_MANDLOCK: u64 = 0x40;
+const MS_DIRSYNC: u64 = 0x80;
+const MS_NOSYMFOLLOW: u64 = 0x100;
+const MS_NOATIME: u64 = 0x400;
+const MS_NODIRATIME: u64 = 0x800;
+const MS_BIND: u64 = 0x1000;
+const MS_REC: u64 = 0x4000;
+const MS_SILENT: u64 = 0x8000;
+const MS_UNBINDABLE: u64 = 0x20000;
+const MS_PRIVATE: u64 = 0x40000;
+const MS_SLAVE: u64 = 0x80000;
+const MS_SHARED: u64 = 0x100000;
+const MS_RELATIME: u64 = 0x200000;
+const MS_I_VERSION: u64 = 0x800000;
+const MS_STRICTATIME: u64 = 0x1000000;
+const MS_LAZYTIME: u64 = 0x2000000;
+
+const MOUNT_ATTR_RDONLY: u64 = 0x1;
+const MOUNT_ATTR_NOSUID: u64 = 0x2;
+const MOUNT_ATTR_NODEV: u64 = 0x4;
+const MOUNT_ATTR_NOEXEC: u64 = 0x8;
+const MOUNT_ATTR__ATIME: u64 = 0x70;
+const MOUNT_ATTR_RELATIME: u64 = 0x0;
+const MOUNT_ATTR_NOATIME: u64 = 0x10;
+const MOUNT_ATTR_STRICTATIME: u64 = 0x20;
+const MOUNT_ATTR_NODIRATIME: u64 = 0x80;
+const MOUNT_ATTR_NOSYMFOLLOW: u64 = 0x200000;
+
+/// Filesystem specific options without a value, which are passed to the mount
+/// syscall as data like `key=value` options.
+const DATA_FLAGS: &[&str] = &[
+    "acl",
+    "discard",
+    "newinstance",
+    "noacl",
+    "nodiscard",
+    "nouser_xattr",
+    "tmpcopyup",
+    "user_xattr",
+    "userxattr",
+];
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// MountOption is an fstab-style option of a [Mount]. Options which are
+/// passed to the filesystem as data, e.g. `mode=755`, are preserved as
+/// [MountOption::Data].
+pub enum MountOption {
+    /// Use the default options.
+    Defaults,
+
+    /// Mount read-only.
+    Ro,
+
+    /// Mount read-write.
+    Rw,
+
+    /// Honor set-user-ID and set-group-ID bits.
+    Suid,
+
+    /// Ignore set-user-ID and set-group-ID bits.
+    Nosuid,
+
+    /// Interpret character and block special devices.
+    Dev,
+
+    /// Do not interpret character and block special devices.
+    Nodev,
+
+    /// Permit execution of binaries.
+    Exec,
+
+    /// Do not permit execution of binaries.
+    Noexec,
+
+    /// Perform all I/O synchronously.
+    Sync,
+
+    /// Perform all I/O asynchronously.
+    Async,
+
+    /// Perform directory updates synchronously.
+    Dirsync,
+
+    /// Remount an existing mount.
+    Remount,
+
+    /// Allow mandatory locks.
+    Mand,
+
+    /// Do not allow mandatory locks.
+    Nomand,
+
+    /// Update inode access times.
+    Atime,
+
+    /// Do not update inode access times.
+    Noatime,
+
+    /// Update directory inode access times.
+    Diratime,
+
+    /// Do not update directory inode access times.
+    Nodiratime,
+
+    /// Update inode access times relative to modify or change time.
+    Relatime,
+
+    /// Do not use relative access time updates.
+    Norelatime,
+
+    /// Always update inode access times.
+    Strictatime,
+
+    /// Do not always update inode access times.
+    Nostrictatime,
+
+    /// Only update times in memory.
+    Lazytime,
+
+    /// Do not only update times in memory.
+    Nolazytime,
+
+    /// Follow symlinks when resolving paths.
+    Symfollow,
+
+    /// Do not follow symlinks when resolving paths.
+    Nosymfollow,
+
+    /// Suppress some kernel warning messages.
+    Silent,
+
+    /// Do not suppress kernel warning messages.
+    Loud,
+
+    /// Update the inode version on every change.
+    Iversion,
+
+    /// Do not update the inode version on every change.
+    Noiversion,
+
+    /// Create a bind mount.
+    Bind,
+
+    /// Create a recursive bind mount.
+    Rbind,
+
+    /// Create an idmapped mount using the UID and GID mappings of the mount.
+    Idmap,
+
+    /// Recursively create an idmapped mount using the UID and GID mappings of the mount.
+    Ridmap,
+
+    /// Set the propagation type of the mount.
+    Propagation(Propagation),
+
+    /// Set or clear a mount attribute recursively on the mount and all its
+    /// submounts, e.g. `rro`.
+    Recursive(RecursiveAttr),
+
+    /// A filesystem specific option, e.g. `size=65536k` or `newinstance`,
+    /// which is passed to the mount syscall as data.
+    Data(String),
+}
+
+impl MountOption {
+    /// Returns the string representation of the option.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Defaults => "defaults",
+            Self::Ro => "ro",
+            Self::Rw => "rw",
+            Self::Suid => "suid",
+            Self::Nosuid => "nosuid",
+            Self::Dev => "dev",
+            Self::Nodev => "nodev",
+            Self::Exec => "exec",
+            Self::Noexec => "noexec",
+            Self::Sync => "sync",
+            Self::Async => "async",
+            Self::Dirsync => "dirsync",
+            Self::Remount => "remount",
+            Self::Mand => "mand",
+            Self::Nomand => "nomand",
+            Self::Atime => "atime",
+            Self::Noatime => "noatime",
+            Self::Diratime => "diratime",
+            Self::Nodiratime => "nodiratime",
+            Self::Relatime => "relatime",
+            Self::Norelatime => "norelatime",
+            Self::Strictatime => "strictatime",
+            Self::Nostrictatime => "nostrictatime",
+            Self::Lazytime => "lazytime",
+            Self::Nolazytime => "nolazytime",
+            Self::Symfollow => "symfollow",
+            Self::Nosymfollow => "nosymfollow",
+            Self::Silent => "silent",
+            Self::Loud => "loud",
+            Self::Iversion => "iversion",
+            Self::Noiversion => "noiversion",
+            Self::Bind => "bind",
+            Self::Rbind => "rbind",
+            Self::Idmap => "idmap",
+            Self::Ridmap => "ridmap",
+            Self::Propagation(propagation) => propagation.as_str(),
+            Self::Recursive(attr) => attr.as_str(),
+            Self::Data(data) => data,
+        }
+    }
+
+    /// Returns the mount flags set by the option and whether the flags are
+    /// cleared instead of set, e.g. `rw` clears `MS_RDONLY`. Returns `None` for
+    /// options which do not correspond to mount flags, like idmapped mounts,
+    /// recursive attributes or data options.
+    pub fn flags(&self) -> Option<(u64, bool)> {
+        let flags = match self {
+            Self::Defaults => (0, false),
+            Self::Ro => (MS_RDONLY, false),
+            Self::Rw => (MS_RDONLY, true),
+            Self::Suid => (MS_NOSUID, true),
+            Self::Nosuid => (MS_NOSUID, false),
+            Self::Dev => (MS_NODEV, true),
+            Self::Nodev => (MS_NODEV, false),
+            Self::Exec => (MS_NOEXEC, true),
+            Self::Noexec => (MS_NOEXEC, false),
+            Self::Sync => (MS_SYNCHRONOUS, false),
+            Self::Async => (MS_SYNCHRONOUS, true),
+            Self::Dirsync => (MS_DIRSYNC, false),
+            Self::Remount => (MS_REMOUNT, false),
+            Self::Mand => (MS_MANDLOCK, false),
+            Self::Nomand => (MS_MANDLOCK, true),
+            Self::Atime => (MS_NOATIME, true),
+            Self::Noatime => (MS_NOATIME, false),
+            Self::Diratime => (MS_NODIRATIME, true),
+            Self::Nodiratime => (MS_NODIRATIME, false),
+            Self::Relatime => (MS_RELATIME, false),
+            Self::Norelatime => (MS_RELATIME, true),
+            Self::Strictatime => (MS_STRICTATIME, false),
+            Self::Nostrictatime => (MS_STRICTATIME, true),
+            Self::Lazytime => (MS_LAZYTIME, false),
+            Self::Nolazytime => (MS_LAZYTIME, true),
+            Self::Symfollow => (MS_NOSYMFOLLOW, true),
+            Self::Nosymfollow => (MS_NOSYMFOLLOW, false),
+            Self::Silent => (MS_SILENT, false),
+            Self::Loud => (MS_SILENT, true),
+            Self::Iversion => (MS_I_VERSION, false),
+            Self::Noiversion => (MS_I_VERSION, true),
+            Self::Bind => (MS_BIND, false),
+            Self::Rbind => (MS_BIND | MS_REC, false),
+            Self::Propagation(propagation) => (propagation.flags(), false),
+            Self::Idmap | Self::Ridmap | Self::Recursive(_) | Self::Data(_) => return None,
+        };
+        Some(flags)
+    }
+
+    /// Returns true if the option changes the propagation type of the mount.
+    pub fn is_propagation(&self) -> bool {
+        self.flags().is_some_and(|(flags, _)| {
+            flags & (MS_PRIVATE | MS_SHARED | MS_SLAVE | MS_UNBINDABLE) != 0
+        })
+    }
+
+    /// Converts the options into the flags and the data argument of the mount
+    /// syscall. Later options override earlier ones, e.g. `ro,rw` results in a
+    /// read-write mount. Options without a flag equivalent which are not data
+    /// options are ignored.
+    pub fn to_mount_flags(options: &[MountOption]) -> (u64, String) {
+        let mut flags = 0;
+        let mut data = Vec::new();
+        for option in options {
+            match option.flags() {
+                Some((flag, true)) => flags &= !flag,
+                Some((flag, false)) => flags |= flag,
+                None => {
+                    if let Self::Data(d) = option {
+                        data.push(d.as_str());
+                    }
+                }
+            }
+        }
+        (flags, data.join(","))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+/// Propagation is the mount propagation type of the rootfs or of a mount. The
+/// `r` prefixed variants apply the type recursively to all submounts.
+pub enum Propagation {
+    /// Mount and unmount events propagate in both directions.
+    Shared,
+
+    /// Mount and unmount events propagate into, but not out of the mount.
+    Slave,
+
+    /// Mount and unmount events do not propagate.
+    Private,
+
+    /// Like private, and the mount cannot be bind mounted.
+    Unbindable,
+
+    /// Recursive variant of shared.
+    Rshared,
+
+    /// Recursive variant of slave.
+    Rslave,
+
+    /// Recursive variant of private.
+    Rprivate,
+
+    /// Recursive variant of unbindable.
+    Runbindable,
+}
+
+impl Propagation {
+    /// Returns the string representation of the propagation type.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Shared => "shared",
+            Self::Slave => "slave",
+            Self::Private => "private",
+            Self::Unbindable => "unbindable",
+            Self::Rshared => "rshared",
+            Self::Rslave => "rslave",
+            Self::Rprivate => "rprivate",
+            Self::Runbindable => "runbindable",
+        }
+    }
+
+    /// Returns true if the propagation type is applied recursively.
+    pub fn is_recursive(&self) -> bool {
+        matches!(
+            self,
+            Self::Rshared | Self::Rslave | Self::Rprivate | Self::Runbindable
+        )
+    }
+
+    /// Returns the mount flags of the propagation type.
+    pub fn flags(&self) -> u64 {
+        let flags = match self {
+            Self::Shared | Self::Rshared => MS_SHARED,
+            Self::Slave | Self::Rslave => MS_SLAVE,
+            Self::Private | Self::Rprivate => MS_PRIVATE,
+            Self::Unbindable | Self::Runbindable => MS_UNBINDABLE,
+        };
+        if self.is_recursive() {
+            flags | MS_REC
+        } else {
+            flags
+        }
+    }
+}
+
+impl FromStr for Propagation {
+    type Err = OciSpecError;
+
+    fn from_str(propagation: &str) -> Result<Self> {
+        match propagation {
+            "shared" => Ok(Self::Shared),
+            "slave" => Ok(Self::Slave),
+            "private" => Ok(Self::Private),
+            "unbindable" => Ok(Self::Unbindable),
+            "rshared" => Ok(Self::Rshared),
+            "rslave" => Ok(Self::Rslave),
+            "rprivate" => Ok(Self::Rprivate),
+            "runbindable" => Ok(Self::Runbindable),
+            unknown => Err(oci_error(format!("unknown propagation type: {}", unknown))),
+        }
+    }
+}
+
+impl fmt::Display for Propagation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<Propagation> for MountOption {
+    fn from(propagation: Propagation) -> Self {
+        MountOption::Propagation(propagation)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+/// RecursiveAttr is a mount attribute which is set or cleared recursively
+/// with `mount_setattr(2)` and `AT_RECURSIVE`, written as an `r` prefixed
+/// mount option like `rro` or `rnosuid`.
+pub enum RecursiveAttr {
+    /// Make the mounts read-only.
+    Ro,
+
+    /// Make the mounts read-write.
+    Rw,
+
+    /// Honor set-user-ID and set-group-ID bits.
+    Suid,
+
+    /// Ignore set-user-ID and set-group-ID bits.
+    Nosuid,
+
+    /// Interpret character and block special devices.
+    Dev,
+
+    /// Do not interpret character and block special devices.
+    Nodev,
+
+    /// Permit execution of binaries.
+    Exec,
+
+    /// Do not permit execution of binaries.
+    Noexec,
+
+    /// Update directory inode access times.
+    Diratime,
+
+    /// Do not update directory inode access times.
+    Nodiratime,
+
+    /// Update inode access times relative to modify or change time.
+    Relatime,
+
+    /// Do not use relative access time updates.
+    Norelatime,
+
+    /// Update inode access times.
+    Atime,
+
+    /// Do not update inode access times.
+    Noatime,
+
+    /// Always update inode access times.
+    Strictatime,
+
+    /// Do not always update inode access times.
+    Nostrictatime,
+
+    /// Follow symlinks when resolving paths.
+    Symfollow,
+
+    /// Do not follow symlinks when resolving paths.
+    Nosymfollow,
+}
+
+impl RecursiveAttr {
+    /// Returns the string representation of the attribute, including the `r`
+    /// prefix.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ro => "rro",
+            Self::Rw => "rrw",
+            Self::Suid => "rsuid",
+            Self::Nosuid => "rnosuid",
+            Self::Dev => "rdev",
+            Self::Nodev => "rnodev",
+            Self::Exec => "rexec",
+            Self::Noexec => "rnoexec",
+            Self::Diratime => "rdiratime",
+            Self::Nodiratime => "rnodiratime",
+            Self::Relatime => "rrelatime",
+            Self::Norelatime => "rnorelatime",
+            Self::Atime => "ratime",
+            Self::Noatime => "rnoatime",
+            Self::Strictatime => "rstrictatime",
+            Self::Nostrictatime => "rnostrictatime",
+            Self::Symfollow => "rsymfollow",
+            Self::Nosymfollow => "rnosymfollow",
+        }
+    }
+
+    /// Returns the `attr_set` and `attr_clr` fields of the `mount_attr`
+    /// argument of `mount_setattr(2)` for the attribute. Setting one of the
+    /// access time modes also clears the others, since they are mutually
+    /// exclusive.
+    pub fn attr(&self) -> (u64, u64) {
+        match self {
+            Self::Ro => (MOUNT_ATTR_RDONLY, 0),
+            Self::Rw => (0, MOUNT_ATTR_RDONLY),
+            Self::Suid => (0, MOUNT_ATTR_NOSUID),
+            Self::Nosuid => (MOUNT_ATTR_NOSUID, 0),
+            Self::Dev => (0, MOUNT_ATTR_NODEV),
+            Self::Nodev => (MOUNT_ATTR_NODEV, 0),
+            Self::Exec => (0, MOUNT_ATTR_NOEXEC),
+            Self::Noexec => (MOUNT_ATTR_NOEXEC, 0),
+            Self::Diratime => (0, MOUNT_ATTR_NODIRATIME),
+            Self::Nodiratime => (MOUNT_ATTR_NODIRATIME, 0),
+            Self::Relatime => (MOUNT_ATTR_RELATIME, MOUNT_ATTR__ATIME),
+            Self::Norelatime => (0, MOUNT_ATTR_RELATIME),
+            Self::Atime => (0, MOUNT_ATTR_NOATIME),
+            Self::Noatime => (MOUNT_ATTR_NOATIME, MOUNT_ATTR__ATIME),
+            Self::Strictatime => (MOUNT_ATTR_STRICTATIME, MOUNT_ATTR__ATIME),
+            Self::Nostrictatime => (0, MOUNT_ATTR_STRICTATIME),
+            Self::Symfollow => (0, MOUNT_ATTR_NOSYMFOLLOW),
+            Self::Nosymfollow => (MOUNT_ATTR_NOSYMFOLLOW, 0),
+        }
+    }
+}
+
+impl FromStr for RecursiveAttr {
+    type Err = OciSpecError;
+
+    fn from_str(attr: &str) -> Result<Self> {
+        match attr {
+            "rro" => Ok(Self::Ro),
+            "rrw" => Ok(Self::Rw),
+            "rsuid" => Ok(Self::Suid),
+            "rnosuid" => Ok(Self::Nosuid),
+            "rdev" => Ok(Self::Dev),
+            "rnodev" => Ok(Self::Nodev),
+            "rexec" => Ok(Self::Exec),
+            "rnoexec" => Ok(Self::Noexec),
+            "rdiratime" => Ok(Self::Diratime),
+            "rnodiratime" => Ok(Self::Nodiratime),
+            "rrelatime" => Ok(Self::Relatime),
+            "rnorelatime" => Ok(Self::Norelatime),
+            "ratime" => Ok(Self::Atime),
+            "rnoatime" => Ok(Self::Noatime),
+            "rstrictatime" => Ok(Self::Strictatime),
+            "rnostrictatime" => Ok(Self::Nostrictatime),
+            "rsymfollow" => Ok(Self::Symfollow),
+            "rnosymfollow" => Ok(Self::Nosymfollow),
+            unknown => Err(oci_error(format!(
+                "unknown recursive mount attribute: {}",
+                unknown
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for RecursiveAttr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<RecursiveAttr> for MountOption {
+    fn from(attr: RecursiveAttr) -> Self {
+        MountOption::Recursive(attr)
+    }
+}
+
+impl FromStr for MountOption {
+    type Err = OciSpecError;
+
+    /// Parses a mount option. Unknown options are rejected unless they are
+    /// `key=value` pairs or well known filesystem specific flags like
+    /// `newinstance`, which are treated as data options.
+    fn from_str(option: &str) -> Result<Self> {
+        if let Ok(propagation) = option.parse() {
+            return Ok(Self::Propagation(propagation));
+        }
+        if let Ok(attr) = option.parse() {
+            return Ok(Self::Recursive(attr));
+        }
+
+        let option = match option {
+            "defaults" => Self::Defaults,
+            "ro" => Self::Ro,
+            "rw" => Self::Rw,
+            "suid" => Self::Suid,
+            "nosuid" => Self::Nosuid,
+            "dev" => Self::Dev,
+            "nodev" => Self::Nodev,
+            "exec" => Self::Exec,
+            "noexec" => Self::Noexec,
+            "sync" => Self::Sync,
+            "async" => Self::Async,
+            "dirsync" => Self::Dirsync,
+            "remount" => Self::Remount,
+            "mand" => Self::Mand,
+            "nomand" => Self::Nomand,
+            "atime" => Self::Atime,
+            "noatime" => Self::Noatime,
+            "diratime" => Self::Diratime,
+            "nodiratime" => Self::Nodiratime,
+            "relatime" => Self::Relatime,
+            "norelatime" => Self::Norelatime,
+            "strictatime" => Self::Strictatime,
+            "nostrictatime" => Self::Nostrictatime,
+            "lazytime" => Self::Lazytime,
+            "nolazytime" => Self::Nolazytime,
+            "symfollow" => Self::Symfollow,
+            "nosymfollow" => Self::Nosymfollow,
+            "silent" => Self::Silent,
+            "loud" => Self::Loud,
+            "iversion" => Self::Iversion,
+            "noiversion" => Self::Noiversion,
+            "bind" => Self::Bind,
+            "rbind" => Self::Rbind,
+            "idmap" => Self::Idmap,
+            "ridmap" => Self::Ridmap,
+            data if data.contains('=') || DATA_FLAGS.contains(&data) => Self::Data(data.to_owned()),
+            unknown => return Err(oci_error(format!("unknown mount option: {}", unknown))),
+        };
+        Ok(option)
+    }
+}
+
+impl fmt::Display for MountOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<MountOption> for String {
+    fn from(option: MountOption) -> Self {
+        match option {
+            MountOption::Data(data) => data,
+            option => option.as_str().to_owned(),
+        }
+    }
+}
+
+impl Mount {
+    /// Parses the options of the mount.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if any of the options is unknown, see [MountOption::from_str].
+    pub fn mount_options(&self) -> Result<Vec<MountOption>> {
+        self.options
+            .iter()
+            .flatten()
+            .map(|option| option.parse())
+            .collect()
+    }
+
+    /// Sets the options of the mount.
+    pub fn set_mount_options<I: IntoIterator<Item = MountOption>>(&mut self, options: I) {
+        self.options = Some(options.into_iter().map(String::from).collect());
+    }
+}
@@ -1,19 +1,31 @@
-use crate::runtime::{Capabilities, Capability};
+use crate::{
+    error::{oci_error, OciSpecError, Result},
+    runtime::{Capabilities, Capability},
+};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::{fmt, path::PathBuf, str::FromStr};
 
 make_pub!(
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// Process contains information to start a specific application inside the
     /// container.
@@ -85,6 +97,28 @@ make_pub!(
         /// SelinuxLabel specifies the selinux context that the container
         /// process is run as.
         selinux_label: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// Scheduler specifies the scheduling attributes of the container
+        /// process.
+        scheduler: Option<Scheduler>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// IOPriority contains the I/O priority settings for the container
+        /// process.
+        io_priority: Option<LinuxIOPriority>,
+
+        #[serde(
+            default,
+            rename = "execCPUAffinity",
+            skip_serializing_if = "Option::is_none"
+        )]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// ExecCPUAffinity specifies the CPU affinity of processes which are
+        /// executed in the container with `exec`.
+        exec_cpu_affinity: Option<ExecCPUAffinity>,
     }
 );
 
@@ -126,22 +160,299 @@ impl Default for Process {
             .into(),
             oom_score_adj: None,
             command_line: None,
+            scheduler: None,
+            io_priority: None,
+            exec_cpu_affinity: None,
         }
     }
 }
 
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get_mut = "pub", set = "pub")
+    )]
+    /// Scheduler represents the scheduling attributes of a process, which are
+    /// set using sched_setattr(2).
+    struct Scheduler {
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Policy is the scheduling policy.
+        policy: LinuxSchedulerPolicy,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Nice is the nice value of the process, used by the SCHED_OTHER and
+        /// SCHED_BATCH policies.
+        nice: Option<i32>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Priority is the static priority of the process, used by the
+        /// SCHED_FIFO and SCHED_RR policies.
+        priority: Option<i32>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// Flags are the flags which modify the scheduling behavior.
+        flags: Option<Vec<LinuxSchedulerFlag>>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Runtime is the amount of time in nanoseconds during which the
+        /// process is allowed to run in a given period, used by the
+        /// SCHED_DEADLINE policy.
+        runtime: Option<u64>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Deadline is the absolute deadline in nanoseconds for the process to
+        /// complete its execution, used by the SCHED_DEADLINE policy.
+        deadline: Option<u64>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Period is the length of the period in nanoseconds, used by the
+        /// SCHED_DEADLINE policy.
+        period: Option<u64>,
+    }
+);
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+/// LinuxSchedulerPolicy is the scheduling policy of a process.
+pub enum LinuxSchedulerPolicy {
+    /// The standard round-robin time-sharing policy.
+    #[default]
+    SchedOther,
+
+    /// A first-in, first-out real-time policy.
+    SchedFifo,
+
+    /// A round-robin real-time policy.
+    SchedRr,
+
+    /// A policy for batch style execution of processes.
+    SchedBatch,
+
+    /// A policy for isochronous scheduling, which is not implemented by
+    /// mainline Linux.
+    SchedIso,
+
+    /// A policy for running very low priority background jobs.
+    SchedIdle,
+
+    /// A deadline based real-time policy.
+    SchedDeadline,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+/// LinuxSchedulerFlag modifies the scheduling behavior of a process.
+pub enum LinuxSchedulerFlag {
+    /// Children created by fork(2) do not inherit privileged scheduling
+    /// policies.
+    SchedFlagResetOnFork,
+
+    /// Allows a SCHED_DEADLINE process to reclaim bandwidth unused by other
+    /// real-time processes.
+    SchedFlagReclaim,
+
+    /// Sends SIGXCPU to a SCHED_DEADLINE process which overran its runtime.
+    SchedFlagDlOverrun,
+
+    /// Keeps the current scheduling policy.
+    SchedFlagKeepPolicy,
+
+    /// Keeps the current scheduling parameters.
+    SchedFlagKeepParams,
+
+    /// Sets the minimum utilization clamp of the process.
+    SchedFlagUtilClampMin,
+
+    /// Sets the maximum utilization clamp of the process.
+    SchedFlagUtilClampMax,
+}
+
 make_pub!(
     #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get_copy = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// LinuxIOPriority represents the I/O priority of a process, which is set
+    /// using ioprio_set(2).
+    struct LinuxIOPriority {
+        /// Class is the I/O scheduling class.
+        class: IOPriorityClass,
+
+        #[serde(default)]
+        /// Priority is the priority level within the class, ranging from 0
+        /// (highest) to 7 (lowest).
+        priority: i64,
+    }
+);
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+/// IOPriorityClass is the I/O scheduling class of a process.
+pub enum IOPriorityClass {
+    /// The real-time class, which is given first access to the disk.
+    IoprioClassRt,
+
+    /// The best-effort class, which is the default for any process.
+    #[default]
+    IoprioClassBe,
+
+    /// The idle class, which only gets disk time when no other process
+    /// needs it.
+    IoprioClassIdle,
+}
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get_copy = "pub")
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// ExecCPUAffinity specifies the CPU affinity of a process executed in the
+    /// container. Both values are cpuset lists in the format of
+    /// cpuset(7), e.g. `0-3,7`.
+    struct ExecCPUAffinity {
+        #[serde(default, rename = "initial", skip_serializing_if = "Option::is_none")]
+        /// Initial is the CPU affinity the process is started with, before it
+        /// joins the cgroup of the container.
+        cpu_affinity_initial: Option<String>,
+
+        #[serde(default, rename = "final", skip_serializing_if = "Option::is_none")]
+        /// Final is the CPU affinity of the process after it has joined the
+        /// cgroup of the container.
+        cpu_affinity_final: Option<String>,
+    }
+);
+
+impl ExecCPUAffinity {
+    /// Checks that the initial and final affinities are valid cpuset lists.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::ExecCPUAffinity;
+    ///
+    /// let affinity: ExecCPUAffinity =
+    ///     serde_json::from_str(r#"{"initial": "0-3,7", "final": "4"}"#).unwrap();
+    /// assert!(affinity.validate().is_ok());
+    ///
+    /// let affinity: ExecCPUAffinity = serde_json::from_str(r#"{"initial": "3-0"}"#).unwrap();
+    /// assert!(affinity.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        for cpus in [&self.cpu_affinity_initial, &self.cpu_affinity_final]
+            .iter()
+            .copied()
+            .flatten()
+        {
+            validate_list("cpu", cpus)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks that the value is a comma separated list of numbers and ascending
+/// ranges of numbers, as used for CPU and memory node lists, e.g. `0-3,7`.
+pub(super) fn validate_list(kind: &str, list: &str) -> Result<()> {
+    let number = |n: &str| {
+        if n.is_empty() || !n.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(oci_error(format!("invalid {} list {:?}", kind, list)));
+        }
+        n.parse::<u32>()
+            .map_err(|_| oci_error(format!("invalid {} list {:?}", kind, list)))
+    };
+
+    for item in list.split(',') {
+        match item.split_once('-') {
+            Some((start, end)) => {
+                if number(start)? > number(end)? {
+                    return Err(oci_error(format!(
+                        "invalid {} range {:?} in {} list {:?}",
+                        kind, item, kind, list
+                    )));
+                }
+            }
+            None => {
+                number(item)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+make_pub!(
+    #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(validate = "Self::validate", error = "crate::error::OciSpecError")
+        ),
+        getset(get_copy = "pub", get_mut = "pub", set = "pub")
     )]
     /// Box specifies dimensions of a rectangle. Used for specifying the size of
     /// a console.
@@ -156,11 +467,42 @@ make_pub!(
     }
 );
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+impl Box {
+    /// Validates that neither dimension of the box is zero.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the height or width is zero.
+    pub fn validate(&self) -> Result<()> {
+        validate_box(self.height, self.width)
+    }
+}
+
+#[cfg(feature = "builder")]
+impl BoxBuilder {
+    fn validate(&self) -> Result<()> {
+        validate_box(
+            self.height.unwrap_or_default(),
+            self.width.unwrap_or_default(),
+        )
+    }
+}
+
+fn validate_box(height: u64, width: u64) -> Result<()> {
+    if height == 0 || width == 0 {
+        return Err(oci_error(format!(
+            "box dimensions must not be zero, got {}x{}",
+            width, height
+        )));
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 /// Available rlimit types (see <https://man7.org/linux/man-pages/man2/getrlimit.2.html>)
 pub enum LinuxRlimitType {
     /// Limit in seconds of the amount of CPU time that the process can consume.
+    #[default]
     RlimitCpu,
 
     /// Maximum size in bytes of the files that the process creates.
@@ -213,55 +555,241 @@ pub enum LinuxRlimitType {
     /// process scheduled under a real-time scheduling policy may consume
     /// without making a blocking system call.
     RlimitRttime,
+
+    /// An rlimit type which is not known to this crate. It is kept as it was
+    /// read, so that specs using it can still be loaded and written back.
+    Other(String),
 }
 
-impl Default for LinuxRlimitType {
-    fn default() -> Self {
-        Self::RlimitCpu
+impl LinuxRlimitType {
+    /// All rlimit types defined by Linux.
+    pub const ALL: [LinuxRlimitType; 16] = [
+        Self::RlimitCpu,
+        Self::RlimitFsize,
+        Self::RlimitData,
+        Self::RlimitStack,
+        Self::RlimitCore,
+        Self::RlimitRss,
+        Self::RlimitNproc,
+        Self::RlimitNofile,
+        Self::RlimitMemlock,
+        Self::RlimitAs,
+        Self::RlimitLocks,
+        Self::RlimitSigpending,
+        Self::RlimitMsgqueue,
+        Self::RlimitNice,
+        Self::RlimitRtprio,
+        Self::RlimitRttime,
+    ];
+
+    /// Returns the name of the rlimit type as used in the spec, e.g.
+    /// `RLIMIT_NOFILE`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::RlimitCpu => "RLIMIT_CPU",
+            Self::RlimitFsize => "RLIMIT_FSIZE",
+            Self::RlimitData => "RLIMIT_DATA",
+            Self::RlimitStack => "RLIMIT_STACK",
+            Self::RlimitCore => "RLIMIT_CORE",
+            Self::RlimitRss => "RLIMIT_RSS",
+            Self::RlimitNproc => "RLIMIT_NPROC",
+            Self::RlimitNofile => "RLIMIT_NOFILE",
+            Self::RlimitMemlock => "RLIMIT_MEMLOCK",
+            Self::RlimitAs => "RLIMIT_AS",
+            Self::RlimitLocks => "RLIMIT_LOCKS",
+            Self::RlimitSigpending => "RLIMIT_SIGPENDING",
+            Self::RlimitMsgqueue => "RLIMIT_MSGQUEUE",
+            Self::RlimitNice => "RLIMIT_NICE",
+            Self::RlimitRtprio => "RLIMIT_RTPRIO",
+            Self::RlimitRttime => "RLIMIT_RTTIME",
+            Self::Other(typ) => typ,
+        }
+    }
+}
+
+impl fmt::Display for LinuxRlimitType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for LinuxRlimitType {
+    type Err = OciSpecError;
+
+    /// Parses an rlimit name case insensitively, with or without the
+    /// `RLIMIT_` prefix. Unlike deserialization, which keeps unknown names as
+    /// [LinuxRlimitType::Other], unknown names are rejected.
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        let upper = input.to_uppercase();
+        let stripped = upper.strip_prefix("RLIMIT_").unwrap_or(&upper);
+        match stripped {
+            "CPU" => Ok(Self::RlimitCpu),
+            "FSIZE" => Ok(Self::RlimitFsize),
+            "DATA" => Ok(Self::RlimitData),
+            "STACK" => Ok(Self::RlimitStack),
+            "CORE" => Ok(Self::RlimitCore),
+            "RSS" => Ok(Self::RlimitRss),
+            "NPROC" => Ok(Self::RlimitNproc),
+            "NOFILE" => Ok(Self::RlimitNofile),
+            "MEMLOCK" => Ok(Self::RlimitMemlock),
+            "AS" => Ok(Self::RlimitAs),
+            "LOCKS" => Ok(Self::RlimitLocks),
+            "SIGPENDING" => Ok(Self::RlimitSigpending),
+            "MSGQUEUE" => Ok(Self::RlimitMsgqueue),
+            "NICE" => Ok(Self::RlimitNice),
+            "RTPRIO" => Ok(Self::RlimitRtprio),
+            "RTTIME" => Ok(Self::RlimitRttime),
+            _ => Err(oci_error(format!("unknown rlimit type: {}", input))),
+        }
+    }
+}
+
+impl Serialize for LinuxRlimitType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LinuxRlimitType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let typ = String::deserialize(deserializer)?;
+        Ok(Self::ALL
+            .iter()
+            .find(|known| known.as_str() == typ)
+            .cloned()
+            .unwrap_or(Self::Other(typ)))
     }
 }
 
 make_pub!(
-    #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
-            build_fn(error = "crate::error::OciSpecError")
+            build_fn(validate = "Self::validate", error = "crate::error::OciSpecError")
         ),
-        getset(get_copy = "pub")
+        getset(get_mut = "pub", set = "pub")
     )]
     /// RLimit types and restrictions.
     struct LinuxRlimit {
         #[serde(rename = "type")]
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
         /// Type of Rlimit to set
         typ: LinuxRlimitType,
 
         #[serde(default)]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
         /// Hard limit for specified type
         hard: u64,
 
         #[serde(default)]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
         /// Soft limit for specified type
         soft: u64,
     }
 );
 
+impl LinuxRlimit {
+    /// Validates that the soft limit does not exceed the hard limit, which
+    /// setrlimit(2) rejects.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the soft limit is greater than the hard limit.
+    pub fn validate(&self) -> Result<()> {
+        validate_rlimit(&self.typ, self.soft, self.hard)
+    }
+}
+
+#[cfg(feature = "builder")]
+impl LinuxRlimitBuilder {
+    fn validate(&self) -> Result<()> {
+        validate_rlimit(
+            self.typ.as_ref().unwrap_or(&LinuxRlimitType::default()),
+            self.soft.unwrap_or_default(),
+            self.hard.unwrap_or_default(),
+        )
+    }
+}
+
+fn validate_rlimit(typ: &LinuxRlimitType, soft: u64, hard: u64) -> Result<()> {
+    if soft > hard {
+        return Err(oci_error(format!(
+            "soft limit {} of {} exceeds its hard limit {}",
+            soft, typ, hard
+        )));
+    }
+    Ok(())
+}
+
+impl Process {
+    /// Validates that every rlimit type is configured at most once and that
+    /// no soft limit exceeds its hard limit, see [LinuxRlimit::validate].
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// describing the first invalid rlimit.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::Process;
+    ///
+    /// let process: Process = serde_json::from_value(serde_json::json!({
+    ///     "user": {"uid": 0, "gid": 0},
+    ///     "cwd": "/",
+    ///     "rlimits": [{"type": "RLIMIT_NOFILE", "soft": 2048, "hard": 1024}]
+    /// }))
+    /// .unwrap();
+    /// assert!(process.validate_rlimits().is_err());
+    /// ```
+    pub fn validate_rlimits(&self) -> Result<()> {
+        let rlimits = self.rlimits.as_deref().unwrap_or_default();
+        for (i, rlimit) in rlimits.iter().enumerate() {
+            if rlimits[..i].iter().any(|r| r.typ == rlimit.typ) {
+                return Err(oci_error(format!("duplicate rlimit {}", rlimit.typ)));
+            }
+            rlimit.validate()?;
+        }
+        Ok(())
+    }
+}
+
 make_pub!(
     #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::CopyGetters, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
-        )
+        ),
+        getset(get_mut = "pub", set = "pub")
     )]
     /// User id (uid) and group id (gid) tracks file permssions.
     struct User {
@@ -295,16 +823,23 @@ make_pub!(
 
 make_pub!(
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
     #[cfg_attr(
         feature = "builder",
-        derive(derive_builder::Builder, getset::Getters),
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
         builder(
             default,
             pattern = "owned",
             setter(into, strip_option),
             build_fn(error = "crate::error::OciSpecError")
         ),
-        getset(get = "pub")
+        getset(get = "pub", get_mut = "pub", set = "pub")
     )]
     /// LinuxCapabilities specifies the list of allowed capabilities that are
     /// kept for a process. <http://man7.org/linux/man-pages/man7/capabilities.7.html>
@@ -331,6 +866,69 @@ make_pub!(
     }
 );
 
+impl LinuxCapabilities {
+    /// Creates capabilities where the bounding, effective and permitted sets
+    /// contain `capabilities`, while the inheritable and ambient sets are
+    /// empty. This matches how container engines set up the capabilities of
+    /// the container process.
+    pub fn from_set(capabilities: Capabilities) -> Self {
+        LinuxCapabilities {
+            bounding: Some(capabilities.clone()),
+            effective: Some(capabilities.clone()),
+            inheritable: Some(Capabilities::new()),
+            permitted: Some(capabilities),
+            ambient: Some(Capabilities::new()),
+        }
+    }
+
+    /// Adds the capabilities to the bounding, effective and permitted sets.
+    pub fn add<I: IntoIterator<Item = Capability>>(&mut self, capabilities: I) {
+        for capability in capabilities {
+            for set in [&mut self.bounding, &mut self.effective, &mut self.permitted] {
+                set.get_or_insert_with(Capabilities::new).insert(capability);
+            }
+        }
+    }
+
+    /// Removes the capabilities from all sets.
+    pub fn remove<I: IntoIterator<Item = Capability>>(&mut self, capabilities: I) {
+        let capabilities: Capabilities = capabilities.into_iter().collect();
+        for set in [
+            &mut self.bounding,
+            &mut self.effective,
+            &mut self.inheritable,
+            &mut self.permitted,
+            &mut self.ambient,
+        ]
+        .iter_mut()
+        .filter_map(|set| set.as_mut())
+        {
+            set.retain(|capability| !capabilities.contains(capability));
+        }
+    }
+
+    /// Removes all capabilities which are not part of the bounding set from
+    /// the other sets, since the kernel would refuse to grant them. Nothing is
+    /// removed if no bounding set is defined.
+    pub fn restrict_to_bounding(&mut self) {
+        let bounding = match &self.bounding {
+            Some(bounding) => bounding.clone(),
+            None => return,
+        };
+        for set in [
+            &mut self.effective,
+            &mut self.inheritable,
+            &mut self.permitted,
+            &mut self.ambient,
+        ]
+        .iter_mut()
+        .filter_map(|set| set.as_mut())
+        {
+            set.retain(|capability| bounding.contains(capability));
+        }
+    }
+}
+
 // Default container's linux capabilities:
 // CAP_AUDIT_WRITE gives container ability to write to linux audit logs,
 // CAP_KILL gives container ability to kill non root processes
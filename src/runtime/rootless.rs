@@ -0,0 +1,165 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use super::{LinuxIdMapping, LinuxNamespace, LinuxNamespaceType, Mount, Spec};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A single adjustment made by [`Spec::to_rootless`] in order to make a
+/// configuration usable by an unprivileged user.
+pub enum RootlessChange {
+    /// The network namespace was removed, because an unprivileged user cannot
+    /// configure networking for a new network namespace.
+    RemovedNetworkNamespace,
+    /// A user namespace was added to the configuration.
+    AddedUserNamespace,
+    /// The UID mappings were set to map the given host UID to root.
+    AddedUidMapping(u32),
+    /// The GID mappings were set to map the given host GID to root.
+    AddedGidMapping(u32),
+    /// The cgroup resource settings were removed, because they require
+    /// privileges to be applied.
+    RemovedResources,
+    /// The device nodes were removed, because they cannot be created inside
+    /// of a user namespace.
+    RemovedDevices,
+    /// The mount at the destination was replaced by a recursive bind mount of
+    /// the host path.
+    ReplacedMount(PathBuf),
+    /// The option was removed from the mount at the destination.
+    RemovedMountOption(PathBuf, String),
+}
+
+impl fmt::Display for RootlessChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RootlessChange::RemovedNetworkNamespace => write!(f, "removed network namespace"),
+            RootlessChange::AddedUserNamespace => write!(f, "added user namespace"),
+            RootlessChange::AddedUidMapping(uid) => write!(f, "mapped host uid {} to root", uid),
+            RootlessChange::AddedGidMapping(gid) => write!(f, "mapped host gid {} to root", gid),
+            RootlessChange::RemovedResources => write!(f, "removed cgroup resources"),
+            RootlessChange::RemovedDevices => write!(f, "removed device nodes"),
+            RootlessChange::ReplacedMount(destination) => {
+                write!(
+                    f,
+                    "replaced mount {} with a bind mount",
+                    destination.display()
+                )
+            }
+            RootlessChange::RemovedMountOption(destination, option) => write!(
+                f,
+                "removed option {} from mount {}",
+                option,
+                destination.display()
+            ),
+        }
+    }
+}
+
+impl Spec {
+    /// Creates a configuration suitable for running a container as the
+    /// unprivileged user `uid` and group `gid`, similar to the output of
+    /// `runc spec --rootless`. It is the default configuration with the
+    /// adjustments of [Spec::to_rootless] applied, so the container runs in a
+    /// user namespace mapping `uid` and `gid` to root, without a network
+    /// namespace, cgroup resources or device nodes.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::{validate, Spec};
+    ///
+    /// let spec = Spec::rootless(1000, 1000);
+    /// assert!(validate(&spec).is_empty());
+    /// ```
+    pub fn rootless(uid: u32, gid: u32) -> Self {
+        let mut spec = Spec::default();
+        spec.to_rootless(uid, gid);
+        spec
+    }
+
+    /// Adjusts the configuration on a best effort basis so that it can be run
+    /// by an unprivileged user, similar to `runc spec --rootless`. The
+    /// network namespace is dropped, a user namespace mapping `uid` and `gid`
+    /// to root is set up if none is configured, cgroup resources and device
+    /// nodes are removed, `/sys` is bind mounted from the host and `uid=` and
+    /// `gid=` mount options are stripped. Every adjustment is reported in the
+    /// returned list, which is empty if the configuration was already usable.
+    pub fn to_rootless(&mut self, uid: u32, gid: u32) -> Vec<RootlessChange> {
+        let mut changes = Vec::new();
+
+        let linux = self.linux.get_or_insert_with(Default::default);
+        let namespaces = linux.namespaces.get_or_insert_with(Vec::new);
+        if namespaces
+            .iter()
+            .any(|ns| ns.typ == LinuxNamespaceType::Network)
+        {
+            namespaces.retain(|ns| ns.typ != LinuxNamespaceType::Network);
+            changes.push(RootlessChange::RemovedNetworkNamespace);
+        }
+        if !namespaces
+            .iter()
+            .any(|ns| ns.typ == LinuxNamespaceType::User)
+        {
+            namespaces.push(LinuxNamespace {
+                typ: LinuxNamespaceType::User,
+                path: None,
+            });
+            changes.push(RootlessChange::AddedUserNamespace);
+        }
+
+        if linux.uid_mappings.as_ref().is_none_or(Vec::is_empty) {
+            linux.uid_mappings = Some(vec![LinuxIdMapping::new(0, uid, 1)]);
+            changes.push(RootlessChange::AddedUidMapping(uid));
+        }
+        if linux.gid_mappings.as_ref().is_none_or(Vec::is_empty) {
+            linux.gid_mappings = Some(vec![LinuxIdMapping::new(0, gid, 1)]);
+            changes.push(RootlessChange::AddedGidMapping(gid));
+        }
+
+        if linux.resources.take().is_some() {
+            changes.push(RootlessChange::RemovedResources);
+        }
+        if linux.devices.take().is_some_and(|d| !d.is_empty()) {
+            changes.push(RootlessChange::RemovedDevices);
+        }
+
+        for mount in self.mounts.iter_mut().flatten() {
+            if mount.destination.as_path() == Path::new("/sys") {
+                let sys = Mount {
+                    destination: PathBuf::from("/sys"),
+                    typ: Some("none".to_string()),
+                    source: Some(PathBuf::from("/sys")),
+                    options: Some(
+                        ["rbind", "nosuid", "noexec", "nodev", "ro"]
+                            .iter()
+                            .map(|o| o.to_string())
+                            .collect(),
+                    ),
+                    uid_mappings: None,
+                    gid_mappings: None,
+                };
+                if *mount != sys {
+                    *mount = sys;
+                    changes.push(RootlessChange::ReplacedMount(mount.destination.clone()));
+                }
+                continue;
+            }
+
+            let destination = &mount.destination;
+            if let Some(options) = &mut mount.options {
+                options.retain(|option| {
+                    let keep = !option.starts_with("uid=") && !option.starts_with("gid=");
+                    if !keep {
+                        changes.push(RootlessChange::RemovedMountOption(
+                            destination.clone(),
+                            option.clone(),
+                        ));
+                    }
+                    keep
+                });
+            }
+        }
+
+        changes
+    }
+}
@@ -0,0 +1,267 @@
+#[cfg(has_fs)]
+use std::path::Path;
+use std::{
+    collections::HashMap,
+    io::{Read, Write},
+    path::PathBuf,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::Result, from_reader, to_writer};
+#[cfg(has_fs)]
+use crate::{from_file, to_file};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+/// ContainerStatus is the runtime status of a container.
+pub enum ContainerStatus {
+    /// The container is being created.
+    #[default]
+    Creating,
+
+    /// The runtime has finished the create operation, but the user-specified
+    /// program has not been executed yet.
+    Created,
+
+    /// The container process has executed the user-specified program but has
+    /// not exited.
+    Running,
+
+    /// The container process has exited.
+    Stopped,
+}
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    #[non_exhaustive]
+    /// State holds information about the runtime state of the container, as
+    /// returned by the `state` operation of the runtime. The type is not
+    /// exhaustive as the `extensions` feature adds fields, so it has to be
+    /// constructed from [State::default] or with the builder.
+    struct State {
+        /// The version of the Open Container Initiative Runtime Specification
+        /// with which the state complies.
+        oci_version: String,
+
+        /// The container's ID. This MUST be unique across all containers on
+        /// this host.
+        id: String,
+
+        /// The runtime state of the container.
+        status: ContainerStatus,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The ID of the container process, as seen by the host. This is
+        /// REQUIRED when status is created or running on Linux.
+        pid: Option<i32>,
+
+        /// The absolute path to the container's bundle directory.
+        bundle: PathBuf,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The list of annotations associated with the container.
+        annotations: Option<HashMap<String, String>>,
+
+        #[cfg(feature = "extensions")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// How the container process terminated. This is not part of the
+        /// specification, but commonly persisted by shims once the container
+        /// has stopped.
+        exit_status: Option<ExitStatus>,
+
+        #[cfg(feature = "extensions")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The RFC 3339 formatted time at which the container process exited.
+        /// This is not part of the specification.
+        finished_at: Option<String>,
+    }
+);
+
+impl State {
+    #[cfg(has_fs)]
+    /// Attempts to load a container state from a file.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the file does not exist or an
+    /// [OciSpecError::SerDe](crate::OciSpecError::SerDe) if the container state
+    /// cannot be deserialized.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::runtime::State;
+    ///
+    /// let state = State::from_file("state.json").unwrap();
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<State> {
+        from_file(path)
+    }
+
+    /// Attempts to load a container state from a stream, e.g. the standard
+    /// input of a hook.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the container state cannot be deserialized.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::runtime::State;
+    ///
+    /// let state = State::from_reader(std::io::stdin()).unwrap();
+    /// ```
+    pub fn from_reader<R: Read>(reader: R) -> Result<State> {
+        from_reader(reader)
+    }
+
+    #[cfg(has_fs)]
+    /// Attempts to write a container state to a file as JSON. If the file
+    /// already exists, it will be overwritten.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the container state cannot be serialized.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::runtime::State;
+    ///
+    /// let state = State::from_file("state.json").unwrap();
+    /// state.to_file("my-state.json").unwrap();
+    /// ```
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        to_file(&self, path, false)
+    }
+
+    #[cfg(has_fs)]
+    /// Attempts to write a container state to a file as pretty printed JSON.
+    /// If the file already exists, it will be overwritten.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the container state cannot be serialized.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::runtime::State;
+    ///
+    /// let state = State::from_file("state.json").unwrap();
+    /// state.to_file_pretty("my-state.json").unwrap();
+    /// ```
+    pub fn to_file_pretty<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        to_file(&self, path, true)
+    }
+
+    /// Attempts to write a container state to a stream as JSON.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the container state cannot be serialized.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::runtime::State;
+    ///
+    /// let state = State::from_file("state.json").unwrap();
+    /// state.to_writer(&mut std::io::stdout()).unwrap();
+    /// ```
+    pub fn to_writer<W: Write>(&self, writer: &mut W) -> Result<()> {
+        to_writer(&self, writer, false)
+    }
+
+    /// Attempts to write a container state to a stream as pretty printed JSON.
+    /// # Errors
+    /// This function will return an [OciSpecError::SerDe](crate::OciSpecError::SerDe)
+    /// if the container state cannot be serialized.
+    /// # Example
+    /// ``` no_run
+    /// use oci_spec::runtime::State;
+    ///
+    /// let state = State::from_file("state.json").unwrap();
+    /// state.to_writer_pretty(&mut std::io::stdout()).unwrap();
+    /// ```
+    pub fn to_writer_pretty<W: Write>(&self, writer: &mut W) -> Result<()> {
+        to_writer(&self, writer, true)
+    }
+
+    /// Returns true if the container process is expected to exist, which is the
+    /// case for the created and running states.
+    pub fn has_process(&self) -> bool {
+        matches!(
+            self.status,
+            ContainerStatus::Created | ContainerStatus::Running
+        )
+    }
+}
+
+make_pub!(
+    #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get_copy = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// ExitStatus describes how a process terminated, either by exiting with a
+    /// code or by being killed by a signal.
+    struct ExitStatus {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The exit code of the process, if it exited normally.
+        code: Option<i32>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// The number of the signal which terminated the process, if any.
+        signal: Option<i32>,
+    }
+);
+
+impl ExitStatus {
+    /// Returns true if the process exited normally with a zero exit code.
+    pub fn success(&self) -> bool {
+        self.code == Some(0)
+    }
+
+    /// Returns the exit code as reported by a shell, which is 128 plus the
+    /// signal number for processes terminated by a signal.
+    pub fn shell_code(&self) -> Option<i32> {
+        self.code.or_else(|| self.signal.map(|signal| 128 + signal))
+    }
+}
+
+impl From<std::process::ExitStatus> for ExitStatus {
+    fn from(status: std::process::ExitStatus) -> Self {
+        #[cfg(unix)]
+        let signal = std::os::unix::process::ExitStatusExt::signal(&status);
+        #[cfg(not(unix))]
+        let signal = None;
+
+        ExitStatus {
+            code: status.code(),
+            signal,
+        }
+    }
+}
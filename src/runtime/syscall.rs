@@ -0,0 +1,74 @@
+use std::{borrow::Cow, fmt};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+/// Syscall is the name of a Linux syscall as used in seccomp profiles.
+///
+/// Every syscall known to the crate is available as an associated constant,
+/// e.g. [Syscall::OPENAT], which avoids typos in hand written profiles.
+/// Syscalls which are not known, for example because they were added in a
+/// newer kernel, can be created using [Syscall::other].
+pub struct Syscall(pub(crate) Cow<'static, str>);
+
+impl Syscall {
+    /// Creates a syscall from an arbitrary name, which is not checked against
+    /// the known syscalls.
+    pub fn other(name: impl Into<String>) -> Self {
+        Syscall(Cow::Owned(name.into()))
+    }
+
+    /// Returns the name of the syscall.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns true if the syscall exists on any of the architectures for which
+    /// a syscall table is available.
+    pub fn is_known(&self) -> bool {
+        Self::KNOWN.binary_search(&self.as_str()).is_ok()
+    }
+}
+
+impl fmt::Display for Syscall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Syscall {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<Syscall> for String {
+    fn from(syscall: Syscall) -> Self {
+        syscall.0.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn syscall_names() {
+        assert_eq!(Syscall::OPENAT.as_str(), "openat");
+        assert_eq!(String::from(Syscall::CLONE3), "clone3");
+        assert!(Syscall::_LLSEEK.is_known());
+        assert!(!Syscall::other("does_not_exist").is_known());
+        assert_eq!(Syscall::other("openat"), Syscall::OPENAT);
+        assert!(Syscall::KNOWN.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn serialize_syscall() {
+        let json = serde_json::to_string(&Syscall::PTRACE).expect("serialize");
+        assert_eq!(json, "\"ptrace\"");
+        let syscall: Syscall = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(syscall, Syscall::PTRACE);
+    }
+}
@@ -0,0 +1,1362 @@
+// This file is generated by `scripts/gen-syscalls.py` together with the
+// syscall tables in `syscalls.rs`. Do not edit it by hand.
+
+use std::borrow::Cow;
+
+use super::Syscall;
+
+impl Syscall {
+    /// The `_llseek` syscall.
+    pub const _LLSEEK: Syscall = Syscall(Cow::Borrowed("_llseek"));
+    /// The `_newselect` syscall.
+    pub const _NEWSELECT: Syscall = Syscall(Cow::Borrowed("_newselect"));
+    /// The `_sysctl` syscall.
+    pub const _SYSCTL: Syscall = Syscall(Cow::Borrowed("_sysctl"));
+    /// The `accept` syscall.
+    pub const ACCEPT: Syscall = Syscall(Cow::Borrowed("accept"));
+    /// The `accept4` syscall.
+    pub const ACCEPT4: Syscall = Syscall(Cow::Borrowed("accept4"));
+    /// The `access` syscall.
+    pub const ACCESS: Syscall = Syscall(Cow::Borrowed("access"));
+    /// The `acct` syscall.
+    pub const ACCT: Syscall = Syscall(Cow::Borrowed("acct"));
+    /// The `add_key` syscall.
+    pub const ADD_KEY: Syscall = Syscall(Cow::Borrowed("add_key"));
+    /// The `adjtimex` syscall.
+    pub const ADJTIMEX: Syscall = Syscall(Cow::Borrowed("adjtimex"));
+    /// The `afs_syscall` syscall.
+    pub const AFS_SYSCALL: Syscall = Syscall(Cow::Borrowed("afs_syscall"));
+    /// The `alarm` syscall.
+    pub const ALARM: Syscall = Syscall(Cow::Borrowed("alarm"));
+    /// The `arch_prctl` syscall.
+    pub const ARCH_PRCTL: Syscall = Syscall(Cow::Borrowed("arch_prctl"));
+    /// The `bdflush` syscall.
+    pub const BDFLUSH: Syscall = Syscall(Cow::Borrowed("bdflush"));
+    /// The `bind` syscall.
+    pub const BIND: Syscall = Syscall(Cow::Borrowed("bind"));
+    /// The `bpf` syscall.
+    pub const BPF: Syscall = Syscall(Cow::Borrowed("bpf"));
+    /// The `break` syscall.
+    pub const BREAK: Syscall = Syscall(Cow::Borrowed("break"));
+    /// The `brk` syscall.
+    pub const BRK: Syscall = Syscall(Cow::Borrowed("brk"));
+    /// The `capget` syscall.
+    pub const CAPGET: Syscall = Syscall(Cow::Borrowed("capget"));
+    /// The `capset` syscall.
+    pub const CAPSET: Syscall = Syscall(Cow::Borrowed("capset"));
+    /// The `chdir` syscall.
+    pub const CHDIR: Syscall = Syscall(Cow::Borrowed("chdir"));
+    /// The `chmod` syscall.
+    pub const CHMOD: Syscall = Syscall(Cow::Borrowed("chmod"));
+    /// The `chown` syscall.
+    pub const CHOWN: Syscall = Syscall(Cow::Borrowed("chown"));
+    /// The `chown32` syscall.
+    pub const CHOWN32: Syscall = Syscall(Cow::Borrowed("chown32"));
+    /// The `chroot` syscall.
+    pub const CHROOT: Syscall = Syscall(Cow::Borrowed("chroot"));
+    /// The `clock_adjtime` syscall.
+    pub const CLOCK_ADJTIME: Syscall = Syscall(Cow::Borrowed("clock_adjtime"));
+    /// The `clock_adjtime64` syscall.
+    pub const CLOCK_ADJTIME64: Syscall = Syscall(Cow::Borrowed("clock_adjtime64"));
+    /// The `clock_getres` syscall.
+    pub const CLOCK_GETRES: Syscall = Syscall(Cow::Borrowed("clock_getres"));
+    /// The `clock_getres_time64` syscall.
+    pub const CLOCK_GETRES_TIME64: Syscall = Syscall(Cow::Borrowed("clock_getres_time64"));
+    /// The `clock_gettime` syscall.
+    pub const CLOCK_GETTIME: Syscall = Syscall(Cow::Borrowed("clock_gettime"));
+    /// The `clock_gettime64` syscall.
+    pub const CLOCK_GETTIME64: Syscall = Syscall(Cow::Borrowed("clock_gettime64"));
+    /// The `clock_nanosleep` syscall.
+    pub const CLOCK_NANOSLEEP: Syscall = Syscall(Cow::Borrowed("clock_nanosleep"));
+    /// The `clock_nanosleep_time64` syscall.
+    pub const CLOCK_NANOSLEEP_TIME64: Syscall = Syscall(Cow::Borrowed("clock_nanosleep_time64"));
+    /// The `clock_settime` syscall.
+    pub const CLOCK_SETTIME: Syscall = Syscall(Cow::Borrowed("clock_settime"));
+    /// The `clock_settime64` syscall.
+    pub const CLOCK_SETTIME64: Syscall = Syscall(Cow::Borrowed("clock_settime64"));
+    /// The `clone` syscall.
+    pub const CLONE: Syscall = Syscall(Cow::Borrowed("clone"));
+    /// The `clone3` syscall.
+    pub const CLONE3: Syscall = Syscall(Cow::Borrowed("clone3"));
+    /// The `close` syscall.
+    pub const CLOSE: Syscall = Syscall(Cow::Borrowed("close"));
+    /// The `close_range` syscall.
+    pub const CLOSE_RANGE: Syscall = Syscall(Cow::Borrowed("close_range"));
+    /// The `connect` syscall.
+    pub const CONNECT: Syscall = Syscall(Cow::Borrowed("connect"));
+    /// The `copy_file_range` syscall.
+    pub const COPY_FILE_RANGE: Syscall = Syscall(Cow::Borrowed("copy_file_range"));
+    /// The `creat` syscall.
+    pub const CREAT: Syscall = Syscall(Cow::Borrowed("creat"));
+    /// The `create_module` syscall.
+    pub const CREATE_MODULE: Syscall = Syscall(Cow::Borrowed("create_module"));
+    /// The `delete_module` syscall.
+    pub const DELETE_MODULE: Syscall = Syscall(Cow::Borrowed("delete_module"));
+    /// The `dup` syscall.
+    pub const DUP: Syscall = Syscall(Cow::Borrowed("dup"));
+    /// The `dup2` syscall.
+    pub const DUP2: Syscall = Syscall(Cow::Borrowed("dup2"));
+    /// The `dup3` syscall.
+    pub const DUP3: Syscall = Syscall(Cow::Borrowed("dup3"));
+    /// The `epoll_create` syscall.
+    pub const EPOLL_CREATE: Syscall = Syscall(Cow::Borrowed("epoll_create"));
+    /// The `epoll_create1` syscall.
+    pub const EPOLL_CREATE1: Syscall = Syscall(Cow::Borrowed("epoll_create1"));
+    /// The `epoll_ctl` syscall.
+    pub const EPOLL_CTL: Syscall = Syscall(Cow::Borrowed("epoll_ctl"));
+    /// The `epoll_ctl_old` syscall.
+    pub const EPOLL_CTL_OLD: Syscall = Syscall(Cow::Borrowed("epoll_ctl_old"));
+    /// The `epoll_pwait` syscall.
+    pub const EPOLL_PWAIT: Syscall = Syscall(Cow::Borrowed("epoll_pwait"));
+    /// The `epoll_pwait2` syscall.
+    pub const EPOLL_PWAIT2: Syscall = Syscall(Cow::Borrowed("epoll_pwait2"));
+    /// The `epoll_wait` syscall.
+    pub const EPOLL_WAIT: Syscall = Syscall(Cow::Borrowed("epoll_wait"));
+    /// The `epoll_wait_old` syscall.
+    pub const EPOLL_WAIT_OLD: Syscall = Syscall(Cow::Borrowed("epoll_wait_old"));
+    /// The `eventfd` syscall.
+    pub const EVENTFD: Syscall = Syscall(Cow::Borrowed("eventfd"));
+    /// The `eventfd2` syscall.
+    pub const EVENTFD2: Syscall = Syscall(Cow::Borrowed("eventfd2"));
+    /// The `execve` syscall.
+    pub const EXECVE: Syscall = Syscall(Cow::Borrowed("execve"));
+    /// The `execveat` syscall.
+    pub const EXECVEAT: Syscall = Syscall(Cow::Borrowed("execveat"));
+    /// The `exit` syscall.
+    pub const EXIT: Syscall = Syscall(Cow::Borrowed("exit"));
+    /// The `exit_group` syscall.
+    pub const EXIT_GROUP: Syscall = Syscall(Cow::Borrowed("exit_group"));
+    /// The `faccessat` syscall.
+    pub const FACCESSAT: Syscall = Syscall(Cow::Borrowed("faccessat"));
+    /// The `faccessat2` syscall.
+    pub const FACCESSAT2: Syscall = Syscall(Cow::Borrowed("faccessat2"));
+    /// The `fadvise64` syscall.
+    pub const FADVISE64: Syscall = Syscall(Cow::Borrowed("fadvise64"));
+    /// The `fadvise64_64` syscall.
+    pub const FADVISE64_64: Syscall = Syscall(Cow::Borrowed("fadvise64_64"));
+    /// The `fallocate` syscall.
+    pub const FALLOCATE: Syscall = Syscall(Cow::Borrowed("fallocate"));
+    /// The `fanotify_init` syscall.
+    pub const FANOTIFY_INIT: Syscall = Syscall(Cow::Borrowed("fanotify_init"));
+    /// The `fanotify_mark` syscall.
+    pub const FANOTIFY_MARK: Syscall = Syscall(Cow::Borrowed("fanotify_mark"));
+    /// The `fchdir` syscall.
+    pub const FCHDIR: Syscall = Syscall(Cow::Borrowed("fchdir"));
+    /// The `fchmod` syscall.
+    pub const FCHMOD: Syscall = Syscall(Cow::Borrowed("fchmod"));
+    /// The `fchmodat` syscall.
+    pub const FCHMODAT: Syscall = Syscall(Cow::Borrowed("fchmodat"));
+    /// The `fchown` syscall.
+    pub const FCHOWN: Syscall = Syscall(Cow::Borrowed("fchown"));
+    /// The `fchown32` syscall.
+    pub const FCHOWN32: Syscall = Syscall(Cow::Borrowed("fchown32"));
+    /// The `fchownat` syscall.
+    pub const FCHOWNAT: Syscall = Syscall(Cow::Borrowed("fchownat"));
+    /// The `fcntl` syscall.
+    pub const FCNTL: Syscall = Syscall(Cow::Borrowed("fcntl"));
+    /// The `fcntl64` syscall.
+    pub const FCNTL64: Syscall = Syscall(Cow::Borrowed("fcntl64"));
+    /// The `fdatasync` syscall.
+    pub const FDATASYNC: Syscall = Syscall(Cow::Borrowed("fdatasync"));
+    /// The `fgetxattr` syscall.
+    pub const FGETXATTR: Syscall = Syscall(Cow::Borrowed("fgetxattr"));
+    /// The `finit_module` syscall.
+    pub const FINIT_MODULE: Syscall = Syscall(Cow::Borrowed("finit_module"));
+    /// The `flistxattr` syscall.
+    pub const FLISTXATTR: Syscall = Syscall(Cow::Borrowed("flistxattr"));
+    /// The `flock` syscall.
+    pub const FLOCK: Syscall = Syscall(Cow::Borrowed("flock"));
+    /// The `fork` syscall.
+    pub const FORK: Syscall = Syscall(Cow::Borrowed("fork"));
+    /// The `fremovexattr` syscall.
+    pub const FREMOVEXATTR: Syscall = Syscall(Cow::Borrowed("fremovexattr"));
+    /// The `fsconfig` syscall.
+    pub const FSCONFIG: Syscall = Syscall(Cow::Borrowed("fsconfig"));
+    /// The `fsetxattr` syscall.
+    pub const FSETXATTR: Syscall = Syscall(Cow::Borrowed("fsetxattr"));
+    /// The `fsmount` syscall.
+    pub const FSMOUNT: Syscall = Syscall(Cow::Borrowed("fsmount"));
+    /// The `fsopen` syscall.
+    pub const FSOPEN: Syscall = Syscall(Cow::Borrowed("fsopen"));
+    /// The `fspick` syscall.
+    pub const FSPICK: Syscall = Syscall(Cow::Borrowed("fspick"));
+    /// The `fstat` syscall.
+    pub const FSTAT: Syscall = Syscall(Cow::Borrowed("fstat"));
+    /// The `fstat64` syscall.
+    pub const FSTAT64: Syscall = Syscall(Cow::Borrowed("fstat64"));
+    /// The `fstatat64` syscall.
+    pub const FSTATAT64: Syscall = Syscall(Cow::Borrowed("fstatat64"));
+    /// The `fstatfs` syscall.
+    pub const FSTATFS: Syscall = Syscall(Cow::Borrowed("fstatfs"));
+    /// The `fstatfs64` syscall.
+    pub const FSTATFS64: Syscall = Syscall(Cow::Borrowed("fstatfs64"));
+    /// The `fsync` syscall.
+    pub const FSYNC: Syscall = Syscall(Cow::Borrowed("fsync"));
+    /// The `ftime` syscall.
+    pub const FTIME: Syscall = Syscall(Cow::Borrowed("ftime"));
+    /// The `ftruncate` syscall.
+    pub const FTRUNCATE: Syscall = Syscall(Cow::Borrowed("ftruncate"));
+    /// The `ftruncate64` syscall.
+    pub const FTRUNCATE64: Syscall = Syscall(Cow::Borrowed("ftruncate64"));
+    /// The `futex` syscall.
+    pub const FUTEX: Syscall = Syscall(Cow::Borrowed("futex"));
+    /// The `futex_time64` syscall.
+    pub const FUTEX_TIME64: Syscall = Syscall(Cow::Borrowed("futex_time64"));
+    /// The `futex_waitv` syscall.
+    pub const FUTEX_WAITV: Syscall = Syscall(Cow::Borrowed("futex_waitv"));
+    /// The `futimesat` syscall.
+    pub const FUTIMESAT: Syscall = Syscall(Cow::Borrowed("futimesat"));
+    /// The `get_kernel_syms` syscall.
+    pub const GET_KERNEL_SYMS: Syscall = Syscall(Cow::Borrowed("get_kernel_syms"));
+    /// The `get_mempolicy` syscall.
+    pub const GET_MEMPOLICY: Syscall = Syscall(Cow::Borrowed("get_mempolicy"));
+    /// The `get_robust_list` syscall.
+    pub const GET_ROBUST_LIST: Syscall = Syscall(Cow::Borrowed("get_robust_list"));
+    /// The `get_thread_area` syscall.
+    pub const GET_THREAD_AREA: Syscall = Syscall(Cow::Borrowed("get_thread_area"));
+    /// The `getcpu` syscall.
+    pub const GETCPU: Syscall = Syscall(Cow::Borrowed("getcpu"));
+    /// The `getcwd` syscall.
+    pub const GETCWD: Syscall = Syscall(Cow::Borrowed("getcwd"));
+    /// The `getdents` syscall.
+    pub const GETDENTS: Syscall = Syscall(Cow::Borrowed("getdents"));
+    /// The `getdents64` syscall.
+    pub const GETDENTS64: Syscall = Syscall(Cow::Borrowed("getdents64"));
+    /// The `getegid` syscall.
+    pub const GETEGID: Syscall = Syscall(Cow::Borrowed("getegid"));
+    /// The `getegid32` syscall.
+    pub const GETEGID32: Syscall = Syscall(Cow::Borrowed("getegid32"));
+    /// The `geteuid` syscall.
+    pub const GETEUID: Syscall = Syscall(Cow::Borrowed("geteuid"));
+    /// The `geteuid32` syscall.
+    pub const GETEUID32: Syscall = Syscall(Cow::Borrowed("geteuid32"));
+    /// The `getgid` syscall.
+    pub const GETGID: Syscall = Syscall(Cow::Borrowed("getgid"));
+    /// The `getgid32` syscall.
+    pub const GETGID32: Syscall = Syscall(Cow::Borrowed("getgid32"));
+    /// The `getgroups` syscall.
+    pub const GETGROUPS: Syscall = Syscall(Cow::Borrowed("getgroups"));
+    /// The `getgroups32` syscall.
+    pub const GETGROUPS32: Syscall = Syscall(Cow::Borrowed("getgroups32"));
+    /// The `getitimer` syscall.
+    pub const GETITIMER: Syscall = Syscall(Cow::Borrowed("getitimer"));
+    /// The `getpeername` syscall.
+    pub const GETPEERNAME: Syscall = Syscall(Cow::Borrowed("getpeername"));
+    /// The `getpgid` syscall.
+    pub const GETPGID: Syscall = Syscall(Cow::Borrowed("getpgid"));
+    /// The `getpgrp` syscall.
+    pub const GETPGRP: Syscall = Syscall(Cow::Borrowed("getpgrp"));
+    /// The `getpid` syscall.
+    pub const GETPID: Syscall = Syscall(Cow::Borrowed("getpid"));
+    /// The `getpmsg` syscall.
+    pub const GETPMSG: Syscall = Syscall(Cow::Borrowed("getpmsg"));
+    /// The `getppid` syscall.
+    pub const GETPPID: Syscall = Syscall(Cow::Borrowed("getppid"));
+    /// The `getpriority` syscall.
+    pub const GETPRIORITY: Syscall = Syscall(Cow::Borrowed("getpriority"));
+    /// The `getrandom` syscall.
+    pub const GETRANDOM: Syscall = Syscall(Cow::Borrowed("getrandom"));
+    /// The `getresgid` syscall.
+    pub const GETRESGID: Syscall = Syscall(Cow::Borrowed("getresgid"));
+    /// The `getresgid32` syscall.
+    pub const GETRESGID32: Syscall = Syscall(Cow::Borrowed("getresgid32"));
+    /// The `getresuid` syscall.
+    pub const GETRESUID: Syscall = Syscall(Cow::Borrowed("getresuid"));
+    /// The `getresuid32` syscall.
+    pub const GETRESUID32: Syscall = Syscall(Cow::Borrowed("getresuid32"));
+    /// The `getrlimit` syscall.
+    pub const GETRLIMIT: Syscall = Syscall(Cow::Borrowed("getrlimit"));
+    /// The `getrusage` syscall.
+    pub const GETRUSAGE: Syscall = Syscall(Cow::Borrowed("getrusage"));
+    /// The `getsid` syscall.
+    pub const GETSID: Syscall = Syscall(Cow::Borrowed("getsid"));
+    /// The `getsockname` syscall.
+    pub const GETSOCKNAME: Syscall = Syscall(Cow::Borrowed("getsockname"));
+    /// The `getsockopt` syscall.
+    pub const GETSOCKOPT: Syscall = Syscall(Cow::Borrowed("getsockopt"));
+    /// The `gettid` syscall.
+    pub const GETTID: Syscall = Syscall(Cow::Borrowed("gettid"));
+    /// The `gettimeofday` syscall.
+    pub const GETTIMEOFDAY: Syscall = Syscall(Cow::Borrowed("gettimeofday"));
+    /// The `getuid` syscall.
+    pub const GETUID: Syscall = Syscall(Cow::Borrowed("getuid"));
+    /// The `getuid32` syscall.
+    pub const GETUID32: Syscall = Syscall(Cow::Borrowed("getuid32"));
+    /// The `getxattr` syscall.
+    pub const GETXATTR: Syscall = Syscall(Cow::Borrowed("getxattr"));
+    /// The `gtty` syscall.
+    pub const GTTY: Syscall = Syscall(Cow::Borrowed("gtty"));
+    /// The `idle` syscall.
+    pub const IDLE: Syscall = Syscall(Cow::Borrowed("idle"));
+    /// The `init_module` syscall.
+    pub const INIT_MODULE: Syscall = Syscall(Cow::Borrowed("init_module"));
+    /// The `inotify_add_watch` syscall.
+    pub const INOTIFY_ADD_WATCH: Syscall = Syscall(Cow::Borrowed("inotify_add_watch"));
+    /// The `inotify_init` syscall.
+    pub const INOTIFY_INIT: Syscall = Syscall(Cow::Borrowed("inotify_init"));
+    /// The `inotify_init1` syscall.
+    pub const INOTIFY_INIT1: Syscall = Syscall(Cow::Borrowed("inotify_init1"));
+    /// The `inotify_rm_watch` syscall.
+    pub const INOTIFY_RM_WATCH: Syscall = Syscall(Cow::Borrowed("inotify_rm_watch"));
+    /// The `io_cancel` syscall.
+    pub const IO_CANCEL: Syscall = Syscall(Cow::Borrowed("io_cancel"));
+    /// The `io_destroy` syscall.
+    pub const IO_DESTROY: Syscall = Syscall(Cow::Borrowed("io_destroy"));
+    /// The `io_getevents` syscall.
+    pub const IO_GETEVENTS: Syscall = Syscall(Cow::Borrowed("io_getevents"));
+    /// The `io_pgetevents` syscall.
+    pub const IO_PGETEVENTS: Syscall = Syscall(Cow::Borrowed("io_pgetevents"));
+    /// The `io_pgetevents_time64` syscall.
+    pub const IO_PGETEVENTS_TIME64: Syscall = Syscall(Cow::Borrowed("io_pgetevents_time64"));
+    /// The `io_setup` syscall.
+    pub const IO_SETUP: Syscall = Syscall(Cow::Borrowed("io_setup"));
+    /// The `io_submit` syscall.
+    pub const IO_SUBMIT: Syscall = Syscall(Cow::Borrowed("io_submit"));
+    /// The `io_uring_enter` syscall.
+    pub const IO_URING_ENTER: Syscall = Syscall(Cow::Borrowed("io_uring_enter"));
+    /// The `io_uring_register` syscall.
+    pub const IO_URING_REGISTER: Syscall = Syscall(Cow::Borrowed("io_uring_register"));
+    /// The `io_uring_setup` syscall.
+    pub const IO_URING_SETUP: Syscall = Syscall(Cow::Borrowed("io_uring_setup"));
+    /// The `ioctl` syscall.
+    pub const IOCTL: Syscall = Syscall(Cow::Borrowed("ioctl"));
+    /// The `ioperm` syscall.
+    pub const IOPERM: Syscall = Syscall(Cow::Borrowed("ioperm"));
+    /// The `iopl` syscall.
+    pub const IOPL: Syscall = Syscall(Cow::Borrowed("iopl"));
+    /// The `ioprio_get` syscall.
+    pub const IOPRIO_GET: Syscall = Syscall(Cow::Borrowed("ioprio_get"));
+    /// The `ioprio_set` syscall.
+    pub const IOPRIO_SET: Syscall = Syscall(Cow::Borrowed("ioprio_set"));
+    /// The `ipc` syscall.
+    pub const IPC: Syscall = Syscall(Cow::Borrowed("ipc"));
+    /// The `kcmp` syscall.
+    pub const KCMP: Syscall = Syscall(Cow::Borrowed("kcmp"));
+    /// The `kexec_file_load` syscall.
+    pub const KEXEC_FILE_LOAD: Syscall = Syscall(Cow::Borrowed("kexec_file_load"));
+    /// The `kexec_load` syscall.
+    pub const KEXEC_LOAD: Syscall = Syscall(Cow::Borrowed("kexec_load"));
+    /// The `keyctl` syscall.
+    pub const KEYCTL: Syscall = Syscall(Cow::Borrowed("keyctl"));
+    /// The `kill` syscall.
+    pub const KILL: Syscall = Syscall(Cow::Borrowed("kill"));
+    /// The `landlock_add_rule` syscall.
+    pub const LANDLOCK_ADD_RULE: Syscall = Syscall(Cow::Borrowed("landlock_add_rule"));
+    /// The `landlock_create_ruleset` syscall.
+    pub const LANDLOCK_CREATE_RULESET: Syscall = Syscall(Cow::Borrowed("landlock_create_ruleset"));
+    /// The `landlock_restrict_self` syscall.
+    pub const LANDLOCK_RESTRICT_SELF: Syscall = Syscall(Cow::Borrowed("landlock_restrict_self"));
+    /// The `lchown` syscall.
+    pub const LCHOWN: Syscall = Syscall(Cow::Borrowed("lchown"));
+    /// The `lchown32` syscall.
+    pub const LCHOWN32: Syscall = Syscall(Cow::Borrowed("lchown32"));
+    /// The `lgetxattr` syscall.
+    pub const LGETXATTR: Syscall = Syscall(Cow::Borrowed("lgetxattr"));
+    /// The `link` syscall.
+    pub const LINK: Syscall = Syscall(Cow::Borrowed("link"));
+    /// The `linkat` syscall.
+    pub const LINKAT: Syscall = Syscall(Cow::Borrowed("linkat"));
+    /// The `listen` syscall.
+    pub const LISTEN: Syscall = Syscall(Cow::Borrowed("listen"));
+    /// The `listxattr` syscall.
+    pub const LISTXATTR: Syscall = Syscall(Cow::Borrowed("listxattr"));
+    /// The `llistxattr` syscall.
+    pub const LLISTXATTR: Syscall = Syscall(Cow::Borrowed("llistxattr"));
+    /// The `lock` syscall.
+    pub const LOCK: Syscall = Syscall(Cow::Borrowed("lock"));
+    /// The `lookup_dcookie` syscall.
+    pub const LOOKUP_DCOOKIE: Syscall = Syscall(Cow::Borrowed("lookup_dcookie"));
+    /// The `lremovexattr` syscall.
+    pub const LREMOVEXATTR: Syscall = Syscall(Cow::Borrowed("lremovexattr"));
+    /// The `lseek` syscall.
+    pub const LSEEK: Syscall = Syscall(Cow::Borrowed("lseek"));
+    /// The `lsetxattr` syscall.
+    pub const LSETXATTR: Syscall = Syscall(Cow::Borrowed("lsetxattr"));
+    /// The `lstat` syscall.
+    pub const LSTAT: Syscall = Syscall(Cow::Borrowed("lstat"));
+    /// The `lstat64` syscall.
+    pub const LSTAT64: Syscall = Syscall(Cow::Borrowed("lstat64"));
+    /// The `madvise` syscall.
+    pub const MADVISE: Syscall = Syscall(Cow::Borrowed("madvise"));
+    /// The `mbind` syscall.
+    pub const MBIND: Syscall = Syscall(Cow::Borrowed("mbind"));
+    /// The `membarrier` syscall.
+    pub const MEMBARRIER: Syscall = Syscall(Cow::Borrowed("membarrier"));
+    /// The `memfd_create` syscall.
+    pub const MEMFD_CREATE: Syscall = Syscall(Cow::Borrowed("memfd_create"));
+    /// The `memfd_secret` syscall.
+    pub const MEMFD_SECRET: Syscall = Syscall(Cow::Borrowed("memfd_secret"));
+    /// The `migrate_pages` syscall.
+    pub const MIGRATE_PAGES: Syscall = Syscall(Cow::Borrowed("migrate_pages"));
+    /// The `mincore` syscall.
+    pub const MINCORE: Syscall = Syscall(Cow::Borrowed("mincore"));
+    /// The `mkdir` syscall.
+    pub const MKDIR: Syscall = Syscall(Cow::Borrowed("mkdir"));
+    /// The `mkdirat` syscall.
+    pub const MKDIRAT: Syscall = Syscall(Cow::Borrowed("mkdirat"));
+    /// The `mknod` syscall.
+    pub const MKNOD: Syscall = Syscall(Cow::Borrowed("mknod"));
+    /// The `mknodat` syscall.
+    pub const MKNODAT: Syscall = Syscall(Cow::Borrowed("mknodat"));
+    /// The `mlock` syscall.
+    pub const MLOCK: Syscall = Syscall(Cow::Borrowed("mlock"));
+    /// The `mlock2` syscall.
+    pub const MLOCK2: Syscall = Syscall(Cow::Borrowed("mlock2"));
+    /// The `mlockall` syscall.
+    pub const MLOCKALL: Syscall = Syscall(Cow::Borrowed("mlockall"));
+    /// The `mmap` syscall.
+    pub const MMAP: Syscall = Syscall(Cow::Borrowed("mmap"));
+    /// The `mmap2` syscall.
+    pub const MMAP2: Syscall = Syscall(Cow::Borrowed("mmap2"));
+    /// The `modify_ldt` syscall.
+    pub const MODIFY_LDT: Syscall = Syscall(Cow::Borrowed("modify_ldt"));
+    /// The `mount` syscall.
+    pub const MOUNT: Syscall = Syscall(Cow::Borrowed("mount"));
+    /// The `mount_setattr` syscall.
+    pub const MOUNT_SETATTR: Syscall = Syscall(Cow::Borrowed("mount_setattr"));
+    /// The `move_mount` syscall.
+    pub const MOVE_MOUNT: Syscall = Syscall(Cow::Borrowed("move_mount"));
+    /// The `move_pages` syscall.
+    pub const MOVE_PAGES: Syscall = Syscall(Cow::Borrowed("move_pages"));
+    /// The `mprotect` syscall.
+    pub const MPROTECT: Syscall = Syscall(Cow::Borrowed("mprotect"));
+    /// The `mpx` syscall.
+    pub const MPX: Syscall = Syscall(Cow::Borrowed("mpx"));
+    /// The `mq_getsetattr` syscall.
+    pub const MQ_GETSETATTR: Syscall = Syscall(Cow::Borrowed("mq_getsetattr"));
+    /// The `mq_notify` syscall.
+    pub const MQ_NOTIFY: Syscall = Syscall(Cow::Borrowed("mq_notify"));
+    /// The `mq_open` syscall.
+    pub const MQ_OPEN: Syscall = Syscall(Cow::Borrowed("mq_open"));
+    /// The `mq_timedreceive` syscall.
+    pub const MQ_TIMEDRECEIVE: Syscall = Syscall(Cow::Borrowed("mq_timedreceive"));
+    /// The `mq_timedreceive_time64` syscall.
+    pub const MQ_TIMEDRECEIVE_TIME64: Syscall = Syscall(Cow::Borrowed("mq_timedreceive_time64"));
+    /// The `mq_timedsend` syscall.
+    pub const MQ_TIMEDSEND: Syscall = Syscall(Cow::Borrowed("mq_timedsend"));
+    /// The `mq_timedsend_time64` syscall.
+    pub const MQ_TIMEDSEND_TIME64: Syscall = Syscall(Cow::Borrowed("mq_timedsend_time64"));
+    /// The `mq_unlink` syscall.
+    pub const MQ_UNLINK: Syscall = Syscall(Cow::Borrowed("mq_unlink"));
+    /// The `mremap` syscall.
+    pub const MREMAP: Syscall = Syscall(Cow::Borrowed("mremap"));
+    /// The `msgctl` syscall.
+    pub const MSGCTL: Syscall = Syscall(Cow::Borrowed("msgctl"));
+    /// The `msgget` syscall.
+    pub const MSGGET: Syscall = Syscall(Cow::Borrowed("msgget"));
+    /// The `msgrcv` syscall.
+    pub const MSGRCV: Syscall = Syscall(Cow::Borrowed("msgrcv"));
+    /// The `msgsnd` syscall.
+    pub const MSGSND: Syscall = Syscall(Cow::Borrowed("msgsnd"));
+    /// The `msync` syscall.
+    pub const MSYNC: Syscall = Syscall(Cow::Borrowed("msync"));
+    /// The `munlock` syscall.
+    pub const MUNLOCK: Syscall = Syscall(Cow::Borrowed("munlock"));
+    /// The `munlockall` syscall.
+    pub const MUNLOCKALL: Syscall = Syscall(Cow::Borrowed("munlockall"));
+    /// The `munmap` syscall.
+    pub const MUNMAP: Syscall = Syscall(Cow::Borrowed("munmap"));
+    /// The `name_to_handle_at` syscall.
+    pub const NAME_TO_HANDLE_AT: Syscall = Syscall(Cow::Borrowed("name_to_handle_at"));
+    /// The `nanosleep` syscall.
+    pub const NANOSLEEP: Syscall = Syscall(Cow::Borrowed("nanosleep"));
+    /// The `newfstatat` syscall.
+    pub const NEWFSTATAT: Syscall = Syscall(Cow::Borrowed("newfstatat"));
+    /// The `nfsservctl` syscall.
+    pub const NFSSERVCTL: Syscall = Syscall(Cow::Borrowed("nfsservctl"));
+    /// The `nice` syscall.
+    pub const NICE: Syscall = Syscall(Cow::Borrowed("nice"));
+    /// The `oldfstat` syscall.
+    pub const OLDFSTAT: Syscall = Syscall(Cow::Borrowed("oldfstat"));
+    /// The `oldlstat` syscall.
+    pub const OLDLSTAT: Syscall = Syscall(Cow::Borrowed("oldlstat"));
+    /// The `oldolduname` syscall.
+    pub const OLDOLDUNAME: Syscall = Syscall(Cow::Borrowed("oldolduname"));
+    /// The `oldstat` syscall.
+    pub const OLDSTAT: Syscall = Syscall(Cow::Borrowed("oldstat"));
+    /// The `olduname` syscall.
+    pub const OLDUNAME: Syscall = Syscall(Cow::Borrowed("olduname"));
+    /// The `open` syscall.
+    pub const OPEN: Syscall = Syscall(Cow::Borrowed("open"));
+    /// The `open_by_handle_at` syscall.
+    pub const OPEN_BY_HANDLE_AT: Syscall = Syscall(Cow::Borrowed("open_by_handle_at"));
+    /// The `open_tree` syscall.
+    pub const OPEN_TREE: Syscall = Syscall(Cow::Borrowed("open_tree"));
+    /// The `openat` syscall.
+    pub const OPENAT: Syscall = Syscall(Cow::Borrowed("openat"));
+    /// The `openat2` syscall.
+    pub const OPENAT2: Syscall = Syscall(Cow::Borrowed("openat2"));
+    /// The `pause` syscall.
+    pub const PAUSE: Syscall = Syscall(Cow::Borrowed("pause"));
+    /// The `perf_event_open` syscall.
+    pub const PERF_EVENT_OPEN: Syscall = Syscall(Cow::Borrowed("perf_event_open"));
+    /// The `personality` syscall.
+    pub const PERSONALITY: Syscall = Syscall(Cow::Borrowed("personality"));
+    /// The `pidfd_getfd` syscall.
+    pub const PIDFD_GETFD: Syscall = Syscall(Cow::Borrowed("pidfd_getfd"));
+    /// The `pidfd_open` syscall.
+    pub const PIDFD_OPEN: Syscall = Syscall(Cow::Borrowed("pidfd_open"));
+    /// The `pidfd_send_signal` syscall.
+    pub const PIDFD_SEND_SIGNAL: Syscall = Syscall(Cow::Borrowed("pidfd_send_signal"));
+    /// The `pipe` syscall.
+    pub const PIPE: Syscall = Syscall(Cow::Borrowed("pipe"));
+    /// The `pipe2` syscall.
+    pub const PIPE2: Syscall = Syscall(Cow::Borrowed("pipe2"));
+    /// The `pivot_root` syscall.
+    pub const PIVOT_ROOT: Syscall = Syscall(Cow::Borrowed("pivot_root"));
+    /// The `pkey_alloc` syscall.
+    pub const PKEY_ALLOC: Syscall = Syscall(Cow::Borrowed("pkey_alloc"));
+    /// The `pkey_free` syscall.
+    pub const PKEY_FREE: Syscall = Syscall(Cow::Borrowed("pkey_free"));
+    /// The `pkey_mprotect` syscall.
+    pub const PKEY_MPROTECT: Syscall = Syscall(Cow::Borrowed("pkey_mprotect"));
+    /// The `poll` syscall.
+    pub const POLL: Syscall = Syscall(Cow::Borrowed("poll"));
+    /// The `ppoll` syscall.
+    pub const PPOLL: Syscall = Syscall(Cow::Borrowed("ppoll"));
+    /// The `ppoll_time64` syscall.
+    pub const PPOLL_TIME64: Syscall = Syscall(Cow::Borrowed("ppoll_time64"));
+    /// The `prctl` syscall.
+    pub const PRCTL: Syscall = Syscall(Cow::Borrowed("prctl"));
+    /// The `pread64` syscall.
+    pub const PREAD64: Syscall = Syscall(Cow::Borrowed("pread64"));
+    /// The `preadv` syscall.
+    pub const PREADV: Syscall = Syscall(Cow::Borrowed("preadv"));
+    /// The `preadv2` syscall.
+    pub const PREADV2: Syscall = Syscall(Cow::Borrowed("preadv2"));
+    /// The `prlimit64` syscall.
+    pub const PRLIMIT64: Syscall = Syscall(Cow::Borrowed("prlimit64"));
+    /// The `process_madvise` syscall.
+    pub const PROCESS_MADVISE: Syscall = Syscall(Cow::Borrowed("process_madvise"));
+    /// The `process_mrelease` syscall.
+    pub const PROCESS_MRELEASE: Syscall = Syscall(Cow::Borrowed("process_mrelease"));
+    /// The `process_vm_readv` syscall.
+    pub const PROCESS_VM_READV: Syscall = Syscall(Cow::Borrowed("process_vm_readv"));
+    /// The `process_vm_writev` syscall.
+    pub const PROCESS_VM_WRITEV: Syscall = Syscall(Cow::Borrowed("process_vm_writev"));
+    /// The `prof` syscall.
+    pub const PROF: Syscall = Syscall(Cow::Borrowed("prof"));
+    /// The `profil` syscall.
+    pub const PROFIL: Syscall = Syscall(Cow::Borrowed("profil"));
+    /// The `pselect6` syscall.
+    pub const PSELECT6: Syscall = Syscall(Cow::Borrowed("pselect6"));
+    /// The `pselect6_time64` syscall.
+    pub const PSELECT6_TIME64: Syscall = Syscall(Cow::Borrowed("pselect6_time64"));
+    /// The `ptrace` syscall.
+    pub const PTRACE: Syscall = Syscall(Cow::Borrowed("ptrace"));
+    /// The `putpmsg` syscall.
+    pub const PUTPMSG: Syscall = Syscall(Cow::Borrowed("putpmsg"));
+    /// The `pwrite64` syscall.
+    pub const PWRITE64: Syscall = Syscall(Cow::Borrowed("pwrite64"));
+    /// The `pwritev` syscall.
+    pub const PWRITEV: Syscall = Syscall(Cow::Borrowed("pwritev"));
+    /// The `pwritev2` syscall.
+    pub const PWRITEV2: Syscall = Syscall(Cow::Borrowed("pwritev2"));
+    /// The `query_module` syscall.
+    pub const QUERY_MODULE: Syscall = Syscall(Cow::Borrowed("query_module"));
+    /// The `quotactl` syscall.
+    pub const QUOTACTL: Syscall = Syscall(Cow::Borrowed("quotactl"));
+    /// The `quotactl_fd` syscall.
+    pub const QUOTACTL_FD: Syscall = Syscall(Cow::Borrowed("quotactl_fd"));
+    /// The `read` syscall.
+    pub const READ: Syscall = Syscall(Cow::Borrowed("read"));
+    /// The `readahead` syscall.
+    pub const READAHEAD: Syscall = Syscall(Cow::Borrowed("readahead"));
+    /// The `readdir` syscall.
+    pub const READDIR: Syscall = Syscall(Cow::Borrowed("readdir"));
+    /// The `readlink` syscall.
+    pub const READLINK: Syscall = Syscall(Cow::Borrowed("readlink"));
+    /// The `readlinkat` syscall.
+    pub const READLINKAT: Syscall = Syscall(Cow::Borrowed("readlinkat"));
+    /// The `readv` syscall.
+    pub const READV: Syscall = Syscall(Cow::Borrowed("readv"));
+    /// The `reboot` syscall.
+    pub const REBOOT: Syscall = Syscall(Cow::Borrowed("reboot"));
+    /// The `recvfrom` syscall.
+    pub const RECVFROM: Syscall = Syscall(Cow::Borrowed("recvfrom"));
+    /// The `recvmmsg` syscall.
+    pub const RECVMMSG: Syscall = Syscall(Cow::Borrowed("recvmmsg"));
+    /// The `recvmmsg_time64` syscall.
+    pub const RECVMMSG_TIME64: Syscall = Syscall(Cow::Borrowed("recvmmsg_time64"));
+    /// The `recvmsg` syscall.
+    pub const RECVMSG: Syscall = Syscall(Cow::Borrowed("recvmsg"));
+    /// The `remap_file_pages` syscall.
+    pub const REMAP_FILE_PAGES: Syscall = Syscall(Cow::Borrowed("remap_file_pages"));
+    /// The `removexattr` syscall.
+    pub const REMOVEXATTR: Syscall = Syscall(Cow::Borrowed("removexattr"));
+    /// The `rename` syscall.
+    pub const RENAME: Syscall = Syscall(Cow::Borrowed("rename"));
+    /// The `renameat` syscall.
+    pub const RENAMEAT: Syscall = Syscall(Cow::Borrowed("renameat"));
+    /// The `renameat2` syscall.
+    pub const RENAMEAT2: Syscall = Syscall(Cow::Borrowed("renameat2"));
+    /// The `request_key` syscall.
+    pub const REQUEST_KEY: Syscall = Syscall(Cow::Borrowed("request_key"));
+    /// The `restart_syscall` syscall.
+    pub const RESTART_SYSCALL: Syscall = Syscall(Cow::Borrowed("restart_syscall"));
+    /// The `rmdir` syscall.
+    pub const RMDIR: Syscall = Syscall(Cow::Borrowed("rmdir"));
+    /// The `rseq` syscall.
+    pub const RSEQ: Syscall = Syscall(Cow::Borrowed("rseq"));
+    /// The `rt_sigaction` syscall.
+    pub const RT_SIGACTION: Syscall = Syscall(Cow::Borrowed("rt_sigaction"));
+    /// The `rt_sigpending` syscall.
+    pub const RT_SIGPENDING: Syscall = Syscall(Cow::Borrowed("rt_sigpending"));
+    /// The `rt_sigprocmask` syscall.
+    pub const RT_SIGPROCMASK: Syscall = Syscall(Cow::Borrowed("rt_sigprocmask"));
+    /// The `rt_sigqueueinfo` syscall.
+    pub const RT_SIGQUEUEINFO: Syscall = Syscall(Cow::Borrowed("rt_sigqueueinfo"));
+    /// The `rt_sigreturn` syscall.
+    pub const RT_SIGRETURN: Syscall = Syscall(Cow::Borrowed("rt_sigreturn"));
+    /// The `rt_sigsuspend` syscall.
+    pub const RT_SIGSUSPEND: Syscall = Syscall(Cow::Borrowed("rt_sigsuspend"));
+    /// The `rt_sigtimedwait` syscall.
+    pub const RT_SIGTIMEDWAIT: Syscall = Syscall(Cow::Borrowed("rt_sigtimedwait"));
+    /// The `rt_sigtimedwait_time64` syscall.
+    pub const RT_SIGTIMEDWAIT_TIME64: Syscall = Syscall(Cow::Borrowed("rt_sigtimedwait_time64"));
+    /// The `rt_tgsigqueueinfo` syscall.
+    pub const RT_TGSIGQUEUEINFO: Syscall = Syscall(Cow::Borrowed("rt_tgsigqueueinfo"));
+    /// The `sched_get_priority_max` syscall.
+    pub const SCHED_GET_PRIORITY_MAX: Syscall = Syscall(Cow::Borrowed("sched_get_priority_max"));
+    /// The `sched_get_priority_min` syscall.
+    pub const SCHED_GET_PRIORITY_MIN: Syscall = Syscall(Cow::Borrowed("sched_get_priority_min"));
+    /// The `sched_getaffinity` syscall.
+    pub const SCHED_GETAFFINITY: Syscall = Syscall(Cow::Borrowed("sched_getaffinity"));
+    /// The `sched_getattr` syscall.
+    pub const SCHED_GETATTR: Syscall = Syscall(Cow::Borrowed("sched_getattr"));
+    /// The `sched_getparam` syscall.
+    pub const SCHED_GETPARAM: Syscall = Syscall(Cow::Borrowed("sched_getparam"));
+    /// The `sched_getscheduler` syscall.
+    pub const SCHED_GETSCHEDULER: Syscall = Syscall(Cow::Borrowed("sched_getscheduler"));
+    /// The `sched_rr_get_interval` syscall.
+    pub const SCHED_RR_GET_INTERVAL: Syscall = Syscall(Cow::Borrowed("sched_rr_get_interval"));
+    /// The `sched_rr_get_interval_time64` syscall.
+    pub const SCHED_RR_GET_INTERVAL_TIME64: Syscall =
+        Syscall(Cow::Borrowed("sched_rr_get_interval_time64"));
+    /// The `sched_setaffinity` syscall.
+    pub const SCHED_SETAFFINITY: Syscall = Syscall(Cow::Borrowed("sched_setaffinity"));
+    /// The `sched_setattr` syscall.
+    pub const SCHED_SETATTR: Syscall = Syscall(Cow::Borrowed("sched_setattr"));
+    /// The `sched_setparam` syscall.
+    pub const SCHED_SETPARAM: Syscall = Syscall(Cow::Borrowed("sched_setparam"));
+    /// The `sched_setscheduler` syscall.
+    pub const SCHED_SETSCHEDULER: Syscall = Syscall(Cow::Borrowed("sched_setscheduler"));
+    /// The `sched_yield` syscall.
+    pub const SCHED_YIELD: Syscall = Syscall(Cow::Borrowed("sched_yield"));
+    /// The `seccomp` syscall.
+    pub const SECCOMP: Syscall = Syscall(Cow::Borrowed("seccomp"));
+    /// The `security` syscall.
+    pub const SECURITY: Syscall = Syscall(Cow::Borrowed("security"));
+    /// The `select` syscall.
+    pub const SELECT: Syscall = Syscall(Cow::Borrowed("select"));
+    /// The `semctl` syscall.
+    pub const SEMCTL: Syscall = Syscall(Cow::Borrowed("semctl"));
+    /// The `semget` syscall.
+    pub const SEMGET: Syscall = Syscall(Cow::Borrowed("semget"));
+    /// The `semop` syscall.
+    pub const SEMOP: Syscall = Syscall(Cow::Borrowed("semop"));
+    /// The `semtimedop` syscall.
+    pub const SEMTIMEDOP: Syscall = Syscall(Cow::Borrowed("semtimedop"));
+    /// The `semtimedop_time64` syscall.
+    pub const SEMTIMEDOP_TIME64: Syscall = Syscall(Cow::Borrowed("semtimedop_time64"));
+    /// The `sendfile` syscall.
+    pub const SENDFILE: Syscall = Syscall(Cow::Borrowed("sendfile"));
+    /// The `sendfile64` syscall.
+    pub const SENDFILE64: Syscall = Syscall(Cow::Borrowed("sendfile64"));
+    /// The `sendmmsg` syscall.
+    pub const SENDMMSG: Syscall = Syscall(Cow::Borrowed("sendmmsg"));
+    /// The `sendmsg` syscall.
+    pub const SENDMSG: Syscall = Syscall(Cow::Borrowed("sendmsg"));
+    /// The `sendto` syscall.
+    pub const SENDTO: Syscall = Syscall(Cow::Borrowed("sendto"));
+    /// The `set_mempolicy` syscall.
+    pub const SET_MEMPOLICY: Syscall = Syscall(Cow::Borrowed("set_mempolicy"));
+    /// The `set_mempolicy_home_node` syscall.
+    pub const SET_MEMPOLICY_HOME_NODE: Syscall = Syscall(Cow::Borrowed("set_mempolicy_home_node"));
+    /// The `set_robust_list` syscall.
+    pub const SET_ROBUST_LIST: Syscall = Syscall(Cow::Borrowed("set_robust_list"));
+    /// The `set_thread_area` syscall.
+    pub const SET_THREAD_AREA: Syscall = Syscall(Cow::Borrowed("set_thread_area"));
+    /// The `set_tid_address` syscall.
+    pub const SET_TID_ADDRESS: Syscall = Syscall(Cow::Borrowed("set_tid_address"));
+    /// The `setdomainname` syscall.
+    pub const SETDOMAINNAME: Syscall = Syscall(Cow::Borrowed("setdomainname"));
+    /// The `setfsgid` syscall.
+    pub const SETFSGID: Syscall = Syscall(Cow::Borrowed("setfsgid"));
+    /// The `setfsgid32` syscall.
+    pub const SETFSGID32: Syscall = Syscall(Cow::Borrowed("setfsgid32"));
+    /// The `setfsuid` syscall.
+    pub const SETFSUID: Syscall = Syscall(Cow::Borrowed("setfsuid"));
+    /// The `setfsuid32` syscall.
+    pub const SETFSUID32: Syscall = Syscall(Cow::Borrowed("setfsuid32"));
+    /// The `setgid` syscall.
+    pub const SETGID: Syscall = Syscall(Cow::Borrowed("setgid"));
+    /// The `setgid32` syscall.
+    pub const SETGID32: Syscall = Syscall(Cow::Borrowed("setgid32"));
+    /// The `setgroups` syscall.
+    pub const SETGROUPS: Syscall = Syscall(Cow::Borrowed("setgroups"));
+    /// The `setgroups32` syscall.
+    pub const SETGROUPS32: Syscall = Syscall(Cow::Borrowed("setgroups32"));
+    /// The `sethostname` syscall.
+    pub const SETHOSTNAME: Syscall = Syscall(Cow::Borrowed("sethostname"));
+    /// The `setitimer` syscall.
+    pub const SETITIMER: Syscall = Syscall(Cow::Borrowed("setitimer"));
+    /// The `setns` syscall.
+    pub const SETNS: Syscall = Syscall(Cow::Borrowed("setns"));
+    /// The `setpgid` syscall.
+    pub const SETPGID: Syscall = Syscall(Cow::Borrowed("setpgid"));
+    /// The `setpriority` syscall.
+    pub const SETPRIORITY: Syscall = Syscall(Cow::Borrowed("setpriority"));
+    /// The `setregid` syscall.
+    pub const SETREGID: Syscall = Syscall(Cow::Borrowed("setregid"));
+    /// The `setregid32` syscall.
+    pub const SETREGID32: Syscall = Syscall(Cow::Borrowed("setregid32"));
+    /// The `setresgid` syscall.
+    pub const SETRESGID: Syscall = Syscall(Cow::Borrowed("setresgid"));
+    /// The `setresgid32` syscall.
+    pub const SETRESGID32: Syscall = Syscall(Cow::Borrowed("setresgid32"));
+    /// The `setresuid` syscall.
+    pub const SETRESUID: Syscall = Syscall(Cow::Borrowed("setresuid"));
+    /// The `setresuid32` syscall.
+    pub const SETRESUID32: Syscall = Syscall(Cow::Borrowed("setresuid32"));
+    /// The `setreuid` syscall.
+    pub const SETREUID: Syscall = Syscall(Cow::Borrowed("setreuid"));
+    /// The `setreuid32` syscall.
+    pub const SETREUID32: Syscall = Syscall(Cow::Borrowed("setreuid32"));
+    /// The `setrlimit` syscall.
+    pub const SETRLIMIT: Syscall = Syscall(Cow::Borrowed("setrlimit"));
+    /// The `setsid` syscall.
+    pub const SETSID: Syscall = Syscall(Cow::Borrowed("setsid"));
+    /// The `setsockopt` syscall.
+    pub const SETSOCKOPT: Syscall = Syscall(Cow::Borrowed("setsockopt"));
+    /// The `settimeofday` syscall.
+    pub const SETTIMEOFDAY: Syscall = Syscall(Cow::Borrowed("settimeofday"));
+    /// The `setuid` syscall.
+    pub const SETUID: Syscall = Syscall(Cow::Borrowed("setuid"));
+    /// The `setuid32` syscall.
+    pub const SETUID32: Syscall = Syscall(Cow::Borrowed("setuid32"));
+    /// The `setxattr` syscall.
+    pub const SETXATTR: Syscall = Syscall(Cow::Borrowed("setxattr"));
+    /// The `sgetmask` syscall.
+    pub const SGETMASK: Syscall = Syscall(Cow::Borrowed("sgetmask"));
+    /// The `shmat` syscall.
+    pub const SHMAT: Syscall = Syscall(Cow::Borrowed("shmat"));
+    /// The `shmctl` syscall.
+    pub const SHMCTL: Syscall = Syscall(Cow::Borrowed("shmctl"));
+    /// The `shmdt` syscall.
+    pub const SHMDT: Syscall = Syscall(Cow::Borrowed("shmdt"));
+    /// The `shmget` syscall.
+    pub const SHMGET: Syscall = Syscall(Cow::Borrowed("shmget"));
+    /// The `shutdown` syscall.
+    pub const SHUTDOWN: Syscall = Syscall(Cow::Borrowed("shutdown"));
+    /// The `sigaction` syscall.
+    pub const SIGACTION: Syscall = Syscall(Cow::Borrowed("sigaction"));
+    /// The `sigaltstack` syscall.
+    pub const SIGALTSTACK: Syscall = Syscall(Cow::Borrowed("sigaltstack"));
+    /// The `signal` syscall.
+    pub const SIGNAL: Syscall = Syscall(Cow::Borrowed("signal"));
+    /// The `signalfd` syscall.
+    pub const SIGNALFD: Syscall = Syscall(Cow::Borrowed("signalfd"));
+    /// The `signalfd4` syscall.
+    pub const SIGNALFD4: Syscall = Syscall(Cow::Borrowed("signalfd4"));
+    /// The `sigpending` syscall.
+    pub const SIGPENDING: Syscall = Syscall(Cow::Borrowed("sigpending"));
+    /// The `sigprocmask` syscall.
+    pub const SIGPROCMASK: Syscall = Syscall(Cow::Borrowed("sigprocmask"));
+    /// The `sigreturn` syscall.
+    pub const SIGRETURN: Syscall = Syscall(Cow::Borrowed("sigreturn"));
+    /// The `sigsuspend` syscall.
+    pub const SIGSUSPEND: Syscall = Syscall(Cow::Borrowed("sigsuspend"));
+    /// The `socket` syscall.
+    pub const SOCKET: Syscall = Syscall(Cow::Borrowed("socket"));
+    /// The `socketcall` syscall.
+    pub const SOCKETCALL: Syscall = Syscall(Cow::Borrowed("socketcall"));
+    /// The `socketpair` syscall.
+    pub const SOCKETPAIR: Syscall = Syscall(Cow::Borrowed("socketpair"));
+    /// The `splice` syscall.
+    pub const SPLICE: Syscall = Syscall(Cow::Borrowed("splice"));
+    /// The `ssetmask` syscall.
+    pub const SSETMASK: Syscall = Syscall(Cow::Borrowed("ssetmask"));
+    /// The `stat` syscall.
+    pub const STAT: Syscall = Syscall(Cow::Borrowed("stat"));
+    /// The `stat64` syscall.
+    pub const STAT64: Syscall = Syscall(Cow::Borrowed("stat64"));
+    /// The `statfs` syscall.
+    pub const STATFS: Syscall = Syscall(Cow::Borrowed("statfs"));
+    /// The `statfs64` syscall.
+    pub const STATFS64: Syscall = Syscall(Cow::Borrowed("statfs64"));
+    /// The `statx` syscall.
+    pub const STATX: Syscall = Syscall(Cow::Borrowed("statx"));
+    /// The `stime` syscall.
+    pub const STIME: Syscall = Syscall(Cow::Borrowed("stime"));
+    /// The `stty` syscall.
+    pub const STTY: Syscall = Syscall(Cow::Borrowed("stty"));
+    /// The `swapoff` syscall.
+    pub const SWAPOFF: Syscall = Syscall(Cow::Borrowed("swapoff"));
+    /// The `swapon` syscall.
+    pub const SWAPON: Syscall = Syscall(Cow::Borrowed("swapon"));
+    /// The `symlink` syscall.
+    pub const SYMLINK: Syscall = Syscall(Cow::Borrowed("symlink"));
+    /// The `symlinkat` syscall.
+    pub const SYMLINKAT: Syscall = Syscall(Cow::Borrowed("symlinkat"));
+    /// The `sync` syscall.
+    pub const SYNC: Syscall = Syscall(Cow::Borrowed("sync"));
+    /// The `sync_file_range` syscall.
+    pub const SYNC_FILE_RANGE: Syscall = Syscall(Cow::Borrowed("sync_file_range"));
+    /// The `syncfs` syscall.
+    pub const SYNCFS: Syscall = Syscall(Cow::Borrowed("syncfs"));
+    /// The `sysfs` syscall.
+    pub const SYSFS: Syscall = Syscall(Cow::Borrowed("sysfs"));
+    /// The `sysinfo` syscall.
+    pub const SYSINFO: Syscall = Syscall(Cow::Borrowed("sysinfo"));
+    /// The `syslog` syscall.
+    pub const SYSLOG: Syscall = Syscall(Cow::Borrowed("syslog"));
+    /// The `tee` syscall.
+    pub const TEE: Syscall = Syscall(Cow::Borrowed("tee"));
+    /// The `tgkill` syscall.
+    pub const TGKILL: Syscall = Syscall(Cow::Borrowed("tgkill"));
+    /// The `time` syscall.
+    pub const TIME: Syscall = Syscall(Cow::Borrowed("time"));
+    /// The `timer_create` syscall.
+    pub const TIMER_CREATE: Syscall = Syscall(Cow::Borrowed("timer_create"));
+    /// The `timer_delete` syscall.
+    pub const TIMER_DELETE: Syscall = Syscall(Cow::Borrowed("timer_delete"));
+    /// The `timer_getoverrun` syscall.
+    pub const TIMER_GETOVERRUN: Syscall = Syscall(Cow::Borrowed("timer_getoverrun"));
+    /// The `timer_gettime` syscall.
+    pub const TIMER_GETTIME: Syscall = Syscall(Cow::Borrowed("timer_gettime"));
+    /// The `timer_gettime64` syscall.
+    pub const TIMER_GETTIME64: Syscall = Syscall(Cow::Borrowed("timer_gettime64"));
+    /// The `timer_settime` syscall.
+    pub const TIMER_SETTIME: Syscall = Syscall(Cow::Borrowed("timer_settime"));
+    /// The `timer_settime64` syscall.
+    pub const TIMER_SETTIME64: Syscall = Syscall(Cow::Borrowed("timer_settime64"));
+    /// The `timerfd_create` syscall.
+    pub const TIMERFD_CREATE: Syscall = Syscall(Cow::Borrowed("timerfd_create"));
+    /// The `timerfd_gettime` syscall.
+    pub const TIMERFD_GETTIME: Syscall = Syscall(Cow::Borrowed("timerfd_gettime"));
+    /// The `timerfd_gettime64` syscall.
+    pub const TIMERFD_GETTIME64: Syscall = Syscall(Cow::Borrowed("timerfd_gettime64"));
+    /// The `timerfd_settime` syscall.
+    pub const TIMERFD_SETTIME: Syscall = Syscall(Cow::Borrowed("timerfd_settime"));
+    /// The `timerfd_settime64` syscall.
+    pub const TIMERFD_SETTIME64: Syscall = Syscall(Cow::Borrowed("timerfd_settime64"));
+    /// The `times` syscall.
+    pub const TIMES: Syscall = Syscall(Cow::Borrowed("times"));
+    /// The `tkill` syscall.
+    pub const TKILL: Syscall = Syscall(Cow::Borrowed("tkill"));
+    /// The `truncate` syscall.
+    pub const TRUNCATE: Syscall = Syscall(Cow::Borrowed("truncate"));
+    /// The `truncate64` syscall.
+    pub const TRUNCATE64: Syscall = Syscall(Cow::Borrowed("truncate64"));
+    /// The `tuxcall` syscall.
+    pub const TUXCALL: Syscall = Syscall(Cow::Borrowed("tuxcall"));
+    /// The `ugetrlimit` syscall.
+    pub const UGETRLIMIT: Syscall = Syscall(Cow::Borrowed("ugetrlimit"));
+    /// The `ulimit` syscall.
+    pub const ULIMIT: Syscall = Syscall(Cow::Borrowed("ulimit"));
+    /// The `umask` syscall.
+    pub const UMASK: Syscall = Syscall(Cow::Borrowed("umask"));
+    /// The `umount` syscall.
+    pub const UMOUNT: Syscall = Syscall(Cow::Borrowed("umount"));
+    /// The `umount2` syscall.
+    pub const UMOUNT2: Syscall = Syscall(Cow::Borrowed("umount2"));
+    /// The `uname` syscall.
+    pub const UNAME: Syscall = Syscall(Cow::Borrowed("uname"));
+    /// The `unlink` syscall.
+    pub const UNLINK: Syscall = Syscall(Cow::Borrowed("unlink"));
+    /// The `unlinkat` syscall.
+    pub const UNLINKAT: Syscall = Syscall(Cow::Borrowed("unlinkat"));
+    /// The `unshare` syscall.
+    pub const UNSHARE: Syscall = Syscall(Cow::Borrowed("unshare"));
+    /// The `uselib` syscall.
+    pub const USELIB: Syscall = Syscall(Cow::Borrowed("uselib"));
+    /// The `userfaultfd` syscall.
+    pub const USERFAULTFD: Syscall = Syscall(Cow::Borrowed("userfaultfd"));
+    /// The `ustat` syscall.
+    pub const USTAT: Syscall = Syscall(Cow::Borrowed("ustat"));
+    /// The `utime` syscall.
+    pub const UTIME: Syscall = Syscall(Cow::Borrowed("utime"));
+    /// The `utimensat` syscall.
+    pub const UTIMENSAT: Syscall = Syscall(Cow::Borrowed("utimensat"));
+    /// The `utimensat_time64` syscall.
+    pub const UTIMENSAT_TIME64: Syscall = Syscall(Cow::Borrowed("utimensat_time64"));
+    /// The `utimes` syscall.
+    pub const UTIMES: Syscall = Syscall(Cow::Borrowed("utimes"));
+    /// The `vfork` syscall.
+    pub const VFORK: Syscall = Syscall(Cow::Borrowed("vfork"));
+    /// The `vhangup` syscall.
+    pub const VHANGUP: Syscall = Syscall(Cow::Borrowed("vhangup"));
+    /// The `vm86` syscall.
+    pub const VM86: Syscall = Syscall(Cow::Borrowed("vm86"));
+    /// The `vm86old` syscall.
+    pub const VM86OLD: Syscall = Syscall(Cow::Borrowed("vm86old"));
+    /// The `vmsplice` syscall.
+    pub const VMSPLICE: Syscall = Syscall(Cow::Borrowed("vmsplice"));
+    /// The `vserver` syscall.
+    pub const VSERVER: Syscall = Syscall(Cow::Borrowed("vserver"));
+    /// The `wait4` syscall.
+    pub const WAIT4: Syscall = Syscall(Cow::Borrowed("wait4"));
+    /// The `waitid` syscall.
+    pub const WAITID: Syscall = Syscall(Cow::Borrowed("waitid"));
+    /// The `waitpid` syscall.
+    pub const WAITPID: Syscall = Syscall(Cow::Borrowed("waitpid"));
+    /// The `write` syscall.
+    pub const WRITE: Syscall = Syscall(Cow::Borrowed("write"));
+    /// The `writev` syscall.
+    pub const WRITEV: Syscall = Syscall(Cow::Borrowed("writev"));
+
+    /// The names of all syscalls known to any of the supported architectures,
+    /// in sorted order.
+    pub const KNOWN: &'static [&'static str] = &[
+        "_llseek",
+        "_newselect",
+        "_sysctl",
+        "accept",
+        "accept4",
+        "access",
+        "acct",
+        "add_key",
+        "adjtimex",
+        "afs_syscall",
+        "alarm",
+        "arch_prctl",
+        "bdflush",
+        "bind",
+        "bpf",
+        "break",
+        "brk",
+        "capget",
+        "capset",
+        "chdir",
+        "chmod",
+        "chown",
+        "chown32",
+        "chroot",
+        "clock_adjtime",
+        "clock_adjtime64",
+        "clock_getres",
+        "clock_getres_time64",
+        "clock_gettime",
+        "clock_gettime64",
+        "clock_nanosleep",
+        "clock_nanosleep_time64",
+        "clock_settime",
+        "clock_settime64",
+        "clone",
+        "clone3",
+        "close",
+        "close_range",
+        "connect",
+        "copy_file_range",
+        "creat",
+        "create_module",
+        "delete_module",
+        "dup",
+        "dup2",
+        "dup3",
+        "epoll_create",
+        "epoll_create1",
+        "epoll_ctl",
+        "epoll_ctl_old",
+        "epoll_pwait",
+        "epoll_pwait2",
+        "epoll_wait",
+        "epoll_wait_old",
+        "eventfd",
+        "eventfd2",
+        "execve",
+        "execveat",
+        "exit",
+        "exit_group",
+        "faccessat",
+        "faccessat2",
+        "fadvise64",
+        "fadvise64_64",
+        "fallocate",
+        "fanotify_init",
+        "fanotify_mark",
+        "fchdir",
+        "fchmod",
+        "fchmodat",
+        "fchown",
+        "fchown32",
+        "fchownat",
+        "fcntl",
+        "fcntl64",
+        "fdatasync",
+        "fgetxattr",
+        "finit_module",
+        "flistxattr",
+        "flock",
+        "fork",
+        "fremovexattr",
+        "fsconfig",
+        "fsetxattr",
+        "fsmount",
+        "fsopen",
+        "fspick",
+        "fstat",
+        "fstat64",
+        "fstatat64",
+        "fstatfs",
+        "fstatfs64",
+        "fsync",
+        "ftime",
+        "ftruncate",
+        "ftruncate64",
+        "futex",
+        "futex_time64",
+        "futex_waitv",
+        "futimesat",
+        "get_kernel_syms",
+        "get_mempolicy",
+        "get_robust_list",
+        "get_thread_area",
+        "getcpu",
+        "getcwd",
+        "getdents",
+        "getdents64",
+        "getegid",
+        "getegid32",
+        "geteuid",
+        "geteuid32",
+        "getgid",
+        "getgid32",
+        "getgroups",
+        "getgroups32",
+        "getitimer",
+        "getpeername",
+        "getpgid",
+        "getpgrp",
+        "getpid",
+        "getpmsg",
+        "getppid",
+        "getpriority",
+        "getrandom",
+        "getresgid",
+        "getresgid32",
+        "getresuid",
+        "getresuid32",
+        "getrlimit",
+        "getrusage",
+        "getsid",
+        "getsockname",
+        "getsockopt",
+        "gettid",
+        "gettimeofday",
+        "getuid",
+        "getuid32",
+        "getxattr",
+        "gtty",
+        "idle",
+        "init_module",
+        "inotify_add_watch",
+        "inotify_init",
+        "inotify_init1",
+        "inotify_rm_watch",
+        "io_cancel",
+        "io_destroy",
+        "io_getevents",
+        "io_pgetevents",
+        "io_pgetevents_time64",
+        "io_setup",
+        "io_submit",
+        "io_uring_enter",
+        "io_uring_register",
+        "io_uring_setup",
+        "ioctl",
+        "ioperm",
+        "iopl",
+        "ioprio_get",
+        "ioprio_set",
+        "ipc",
+        "kcmp",
+        "kexec_file_load",
+        "kexec_load",
+        "keyctl",
+        "kill",
+        "landlock_add_rule",
+        "landlock_create_ruleset",
+        "landlock_restrict_self",
+        "lchown",
+        "lchown32",
+        "lgetxattr",
+        "link",
+        "linkat",
+        "listen",
+        "listxattr",
+        "llistxattr",
+        "lock",
+        "lookup_dcookie",
+        "lremovexattr",
+        "lseek",
+        "lsetxattr",
+        "lstat",
+        "lstat64",
+        "madvise",
+        "mbind",
+        "membarrier",
+        "memfd_create",
+        "memfd_secret",
+        "migrate_pages",
+        "mincore",
+        "mkdir",
+        "mkdirat",
+        "mknod",
+        "mknodat",
+        "mlock",
+        "mlock2",
+        "mlockall",
+        "mmap",
+        "mmap2",
+        "modify_ldt",
+        "mount",
+        "mount_setattr",
+        "move_mount",
+        "move_pages",
+        "mprotect",
+        "mpx",
+        "mq_getsetattr",
+        "mq_notify",
+        "mq_open",
+        "mq_timedreceive",
+        "mq_timedreceive_time64",
+        "mq_timedsend",
+        "mq_timedsend_time64",
+        "mq_unlink",
+        "mremap",
+        "msgctl",
+        "msgget",
+        "msgrcv",
+        "msgsnd",
+        "msync",
+        "munlock",
+        "munlockall",
+        "munmap",
+        "name_to_handle_at",
+        "nanosleep",
+        "newfstatat",
+        "nfsservctl",
+        "nice",
+        "oldfstat",
+        "oldlstat",
+        "oldolduname",
+        "oldstat",
+        "olduname",
+        "open",
+        "open_by_handle_at",
+        "open_tree",
+        "openat",
+        "openat2",
+        "pause",
+        "perf_event_open",
+        "personality",
+        "pidfd_getfd",
+        "pidfd_open",
+        "pidfd_send_signal",
+        "pipe",
+        "pipe2",
+        "pivot_root",
+        "pkey_alloc",
+        "pkey_free",
+        "pkey_mprotect",
+        "poll",
+        "ppoll",
+        "ppoll_time64",
+        "prctl",
+        "pread64",
+        "preadv",
+        "preadv2",
+        "prlimit64",
+        "process_madvise",
+        "process_mrelease",
+        "process_vm_readv",
+        "process_vm_writev",
+        "prof",
+        "profil",
+        "pselect6",
+        "pselect6_time64",
+        "ptrace",
+        "putpmsg",
+        "pwrite64",
+        "pwritev",
+        "pwritev2",
+        "query_module",
+        "quotactl",
+        "quotactl_fd",
+        "read",
+        "readahead",
+        "readdir",
+        "readlink",
+        "readlinkat",
+        "readv",
+        "reboot",
+        "recvfrom",
+        "recvmmsg",
+        "recvmmsg_time64",
+        "recvmsg",
+        "remap_file_pages",
+        "removexattr",
+        "rename",
+        "renameat",
+        "renameat2",
+        "request_key",
+        "restart_syscall",
+        "rmdir",
+        "rseq",
+        "rt_sigaction",
+        "rt_sigpending",
+        "rt_sigprocmask",
+        "rt_sigqueueinfo",
+        "rt_sigreturn",
+        "rt_sigsuspend",
+        "rt_sigtimedwait",
+        "rt_sigtimedwait_time64",
+        "rt_tgsigqueueinfo",
+        "sched_get_priority_max",
+        "sched_get_priority_min",
+        "sched_getaffinity",
+        "sched_getattr",
+        "sched_getparam",
+        "sched_getscheduler",
+        "sched_rr_get_interval",
+        "sched_rr_get_interval_time64",
+        "sched_setaffinity",
+        "sched_setattr",
+        "sched_setparam",
+        "sched_setscheduler",
+        "sched_yield",
+        "seccomp",
+        "security",
+        "select",
+        "semctl",
+        "semget",
+        "semop",
+        "semtimedop",
+        "semtimedop_time64",
+        "sendfile",
+        "sendfile64",
+        "sendmmsg",
+        "sendmsg",
+        "sendto",
+        "set_mempolicy",
+        "set_mempolicy_home_node",
+        "set_robust_list",
+        "set_thread_area",
+        "set_tid_address",
+        "setdomainname",
+        "setfsgid",
+        "setfsgid32",
+        "setfsuid",
+        "setfsuid32",
+        "setgid",
+        "setgid32",
+        "setgroups",
+        "setgroups32",
+        "sethostname",
+        "setitimer",
+        "setns",
+        "setpgid",
+        "setpriority",
+        "setregid",
+        "setregid32",
+        "setresgid",
+        "setresgid32",
+        "setresuid",
+        "setresuid32",
+        "setreuid",
+        "setreuid32",
+        "setrlimit",
+        "setsid",
+        "setsockopt",
+        "settimeofday",
+        "setuid",
+        "setuid32",
+        "setxattr",
+        "sgetmask",
+        "shmat",
+        "shmctl",
+        "shmdt",
+        "shmget",
+        "shutdown",
+        "sigaction",
+        "sigaltstack",
+        "signal",
+        "signalfd",
+        "signalfd4",
+        "sigpending",
+        "sigprocmask",
+        "sigreturn",
+        "sigsuspend",
+        "socket",
+        "socketcall",
+        "socketpair",
+        "splice",
+        "ssetmask",
+        "stat",
+        "stat64",
+        "statfs",
+        "statfs64",
+        "statx",
+        "stime",
+        "stty",
+        "swapoff",
+        "swapon",
+        "symlink",
+        "symlinkat",
+        "sync",
+        "sync_file_range",
+        "syncfs",
+        "sysfs",
+        "sysinfo",
+        "syslog",
+        "tee",
+        "tgkill",
+        "time",
+        "timer_create",
+        "timer_delete",
+        "timer_getoverrun",
+        "timer_gettime",
+        "timer_gettime64",
+        "timer_settime",
+        "timer_settime64",
+        "timerfd_create",
+        "timerfd_gettime",
+        "timerfd_gettime64",
+        "timerfd_settime",
+        "timerfd_settime64",
+        "times",
+        "tkill",
+        "truncate",
+        "truncate64",
+        "tuxcall",
+        "ugetrlimit",
+        "ulimit",
+        "umask",
+        "umount",
+        "umount2",
+        "uname",
+        "unlink",
+        "unlinkat",
+        "unshare",
+        "uselib",
+        "userfaultfd",
+        "ustat",
+        "utime",
+        "utimensat",
+        "utimensat_time64",
+        "utimes",
+        "vfork",
+        "vhangup",
+        "vm86",
+        "vm86old",
+        "vmsplice",
+        "vserver",
+        "wait4",
+        "waitid",
+        "waitpid",
+        "write",
+        "writev",
+    ];
+}
@@ -0,0 +1,134 @@
+use crate::error::{oci_error, Result};
+
+use super::{Linux, LinuxNamespaceType, Spec};
+
+/// The IPC namespaced sysctls of the `kernel` tree.
+const IPC_KERNEL_SYSCTLS: &[&str] = &[
+    "kernel.msgmax",
+    "kernel.msgmnb",
+    "kernel.msgmni",
+    "kernel.sem",
+    "kernel.shmall",
+    "kernel.shmmax",
+    "kernel.shmmni",
+    "kernel.shm_rmid_forced",
+];
+
+/// The UTS namespaced sysctls of the `kernel` tree.
+const UTS_KERNEL_SYSCTLS: &[&str] = &["kernel.domainname", "kernel.hostname"];
+
+/// Returns the namespace a sysctl belongs to, or `None` if the sysctl is not
+/// namespaced and thus cannot be set for a container without affecting the
+/// host. Keys may use `.` or `/` as separator, like sysctl(8).
+/// # Example
+/// ```
+/// use oci_spec::runtime::{sysctl_namespace, LinuxNamespaceType};
+///
+/// assert_eq!(
+///     sysctl_namespace("net.ipv4.ip_forward"),
+///     Some(LinuxNamespaceType::Network)
+/// );
+/// assert_eq!(sysctl_namespace("kernel.shmmax"), Some(LinuxNamespaceType::Ipc));
+/// assert_eq!(sysctl_namespace("vm.swappiness"), None);
+/// ```
+pub fn sysctl_namespace(key: &str) -> Option<LinuxNamespaceType> {
+    let key = key.replace('/', ".");
+    if key.starts_with("net.") {
+        Some(LinuxNamespaceType::Network)
+    } else if key.starts_with("fs.mqueue.") || IPC_KERNEL_SYSCTLS.contains(&key.as_str()) {
+        Some(LinuxNamespaceType::Ipc)
+    } else if UTS_KERNEL_SYSCTLS.contains(&key.as_str()) {
+        Some(LinuxNamespaceType::Uts)
+    } else {
+        None
+    }
+}
+
+/// Validates the syntax of a sysctl key, which consists of non-empty
+/// components separated by `.` or `/` without whitespace or `=`.
+fn validate_sysctl_key(key: &str) -> Result<()> {
+    let valid = !key.is_empty()
+        && key
+            .split(['.', '/'])
+            .all(|c| !c.is_empty() && !c.contains(|ch: char| ch.is_whitespace() || ch == '='));
+    if !valid {
+        return Err(oci_error(format!("invalid sysctl key {:?}", key)));
+    }
+    Ok(())
+}
+
+impl Linux {
+    /// Sets the sysctl `key` to `value`, replacing a previous value.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the key is malformed or the sysctl is not namespaced.
+    /// # Example
+    /// ```
+    /// use oci_spec::runtime::Linux;
+    ///
+    /// let mut linux = Linux::default();
+    /// linux.set_sysctl("net.ipv4.ip_unprivileged_port_start", "0").unwrap();
+    /// assert!(linux.set_sysctl("vm.swappiness", "0").is_err());
+    /// ```
+    pub fn set_sysctl<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) -> Result<()> {
+        let key = key.into();
+        validate_sysctl_key(&key)?;
+        if sysctl_namespace(&key).is_none() {
+            return Err(oci_error(format!(
+                "sysctl {} is not namespaced and cannot be set for a container",
+                key
+            )));
+        }
+        self.sysctl
+            .get_or_insert_with(Default::default)
+            .insert(key, value.into());
+        Ok(())
+    }
+
+    /// Validates that all sysctls are well formed, namespaced and that their
+    /// namespace is configured for the container. Network sysctls are
+    /// accepted when joining an existing network namespace, as the sysctls
+    /// still only affect that namespace.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// describing the first invalid sysctl.
+    pub fn validate_sysctls(&self) -> Result<()> {
+        let mut keys: Vec<&String> = self.sysctl.iter().flatten().map(|(k, _)| k).collect();
+        keys.sort();
+        for key in keys {
+            validate_sysctl_key(key)?;
+            let namespace = sysctl_namespace(key).ok_or_else(|| {
+                oci_error(format!(
+                    "sysctl {} is not namespaced and cannot be set for a container",
+                    key
+                ))
+            })?;
+            if !self
+                .namespaces
+                .iter()
+                .flatten()
+                .any(|ns| ns.typ == namespace)
+            {
+                return Err(oci_error(format!(
+                    "sysctl {} requires a {} namespace",
+                    key,
+                    super::features::serde_name(&namespace)
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Spec {
+    /// Sets the sysctl `key` to `value` in the Linux section, which is
+    /// created if it does not exist yet, see [Linux::set_sysctl].
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the key is malformed or the sysctl is not namespaced.
+    pub fn set_sysctl<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) -> Result<()> {
+        self.linux
+            .get_or_insert_with(Default::default)
+            .set_sysctl(key, value)
+    }
+}
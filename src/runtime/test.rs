@@ -52,3 +52,2012 @@ fn test_linux_device_cgroup_to_string() {
     };
     assert_eq!(ldc.to_string(), "b 1:9 rwm");
 }
+
+#[test]
+#[cfg(feature = "syscalls")]
+fn test_seccomp_syscall_numbers() {
+    assert_eq!(Arch::ScmpArchX86_64.syscall_number("openat"), Some(257));
+    assert_eq!(Arch::ScmpArchAarch64.syscall_number("openat"), Some(56));
+    assert_eq!(Arch::ScmpArchX86.syscall_number("read"), Some(3));
+    assert_eq!(Arch::ScmpArchX32.syscall_number("read"), Some(0x40000000));
+    assert_eq!(Arch::ScmpArchAarch64.syscall_number("open"), None);
+    assert_eq!(Arch::ScmpArchX86_64.syscall_number("opneat"), None);
+    assert!(!Arch::ScmpArchS390x.has_syscall_table());
+    assert_eq!(Arch::ScmpArchS390x.syscall_number("read"), None);
+}
+
+#[test]
+#[cfg(feature = "syscalls")]
+fn test_seccomp_unknown_syscalls() {
+    let seccomp: LinuxSeccomp = serde_json::from_str(
+        r#"{
+            "defaultAction": "SCMP_ACT_ERRNO",
+            "architectures": ["SCMP_ARCH_X86_64", "SCMP_ARCH_AARCH64"],
+            "syscalls": [
+                {"names": ["open", "openat", "raed"], "action": "SCMP_ACT_ALLOW"},
+                {"names": ["raed", "clone3"], "action": "SCMP_ACT_ALLOW"}
+            ]
+        }"#,
+    )
+    .expect("deserialize seccomp");
+    assert_eq!(seccomp.unknown_syscalls(), vec!["raed".to_string()]);
+
+    let seccomp: LinuxSeccomp = serde_json::from_str(
+        r#"{
+            "defaultAction": "SCMP_ACT_ERRNO",
+            "architectures": ["SCMP_ARCH_S390X"],
+            "syscalls": [{"names": ["raed"], "action": "SCMP_ACT_ALLOW"}]
+        }"#,
+    )
+    .expect("deserialize seccomp");
+    assert!(seccomp.unknown_syscalls().is_empty());
+}
+
+#[test]
+fn test_spec_to_rootless() {
+    let mut spec: Spec = Default::default();
+
+    let changes = spec.to_rootless(1000, 1000);
+    assert_eq!(
+        changes,
+        vec![
+            RootlessChange::RemovedNetworkNamespace,
+            RootlessChange::AddedUserNamespace,
+            RootlessChange::AddedUidMapping(1000),
+            RootlessChange::AddedGidMapping(1000),
+            RootlessChange::RemovedResources,
+            RootlessChange::RemovedMountOption("/dev/pts".into(), "gid=5".into()),
+            RootlessChange::ReplacedMount("/sys".into()),
+        ]
+    );
+    assert_eq!(
+        changes[5].to_string(),
+        "removed option gid=5 from mount /dev/pts"
+    );
+
+    let linux = spec.linux.as_ref().unwrap();
+    assert!(linux.resources.is_none());
+    assert_eq!(linux.uid_mappings.as_ref().unwrap()[0].host_id, 1000);
+    assert!(linux
+        .namespaces
+        .as_ref()
+        .unwrap()
+        .iter()
+        .any(|ns| ns.typ == LinuxNamespaceType::User));
+
+    assert!(spec.to_rootless(1000, 1000).is_empty());
+}
+
+#[test]
+#[cfg(feature = "cri")]
+fn test_cri_mount_conversion() {
+    use std::convert::TryFrom;
+
+    let cri = CriMount {
+        container_path: "/data".into(),
+        host_path: "/var/lib/data".into(),
+        readonly: true,
+        propagation: CriMountPropagation::HostToContainer,
+        ..Default::default()
+    };
+
+    let mount = Mount::from(&cri);
+    assert_eq!(mount.typ.as_deref(), Some("bind"));
+    assert_eq!(
+        mount.options.as_deref().unwrap(),
+        &["rbind".to_owned(), "rslave".to_owned(), "ro".to_owned()]
+    );
+    assert_eq!(CriMount::try_from(&mount).unwrap(), cri);
+
+    let proc = get_default_mounts().remove(0);
+    assert!(CriMount::try_from(&proc).is_err());
+}
+
+#[test]
+#[cfg(all(feature = "cri", target_os = "linux"))]
+fn test_cri_device_conversion() {
+    let cri = CriDevice {
+        container_path: "/dev/mynull".into(),
+        host_path: "/dev/null".into(),
+        permissions: "rw".to_owned(),
+    };
+
+    let device = cri.to_linux_device().unwrap();
+    assert_eq!(device.path, PathBuf::from("/dev/mynull"));
+    assert_eq!(device.typ, LinuxDeviceType::C);
+    assert_eq!((device.major, device.minor), (1, 3));
+
+    let rule = cri.to_device_cgroup(&device);
+    assert_eq!(rule.to_string(), "c 1:3 rw");
+
+    assert_eq!(CriDevice::from(&device).host_path, device.path);
+
+    let dir = CriDevice {
+        host_path: "/dev".into(),
+        ..cri
+    };
+    assert!(dir.to_linux_device().is_err());
+}
+
+#[test]
+fn test_spec_unsupported_features() {
+    let features: Features = serde_json::from_str(
+        r#"{
+            "ociVersionMin": "1.0.0",
+            "ociVersionMax": "1.1.0",
+            "hooks": ["prestart", "createRuntime", "poststop"],
+            "mountOptions": ["ro", "rw", "rro"],
+            "linux": {
+                "namespaces": ["ipc", "mount", "network", "pid", "user", "uts"],
+                "capabilities": ["CAP_AUDIT_WRITE", "CAP_KILL", "CAP_NET_BIND_SERVICE"],
+                "cgroup": {"v1": true, "v2": true, "rdma": false},
+                "seccomp": {
+                    "enabled": true,
+                    "actions": ["SCMP_ACT_ALLOW", "SCMP_ACT_ERRNO"],
+                    "operators": ["SCMP_CMP_EQ"],
+                    "archs": ["SCMP_ARCH_X86_64"],
+                    "knownFlags": ["SECCOMP_FILTER_FLAG_LOG"]
+                },
+                "apparmor": {"enabled": false},
+                "mountExtensions": {"idmap": {"enabled": false}}
+            },
+            "annotations": {"org.opencontainers.runc.version": "1.1.0"}
+        }"#,
+    )
+    .expect("deserialize features");
+
+    let mut spec: Spec = Default::default();
+    assert_eq!(spec.unsupported_features(&features), vec![]);
+
+    spec.version = "1.2.0".to_owned();
+    let mounts = spec.mounts.as_mut().unwrap();
+    mounts[0].options = Some(vec!["rro".to_owned(), "ridmap".to_owned()]);
+    mounts[1].uid_mappings = Some(vec![LinuxIdMapping {
+        host_id: 1000,
+        container_id: 0,
+        size: 1,
+    }]);
+    let process = spec.process.as_mut().unwrap();
+    process.apparmor_profile = Some("default".to_owned());
+    process
+        .capabilities
+        .as_mut()
+        .unwrap()
+        .bounding
+        .as_mut()
+        .unwrap()
+        .insert(Capability::SysAdmin);
+    let linux = spec.linux.as_mut().unwrap();
+    linux.namespaces.as_mut().unwrap().push(LinuxNamespace {
+        typ: LinuxNamespaceType::Cgroup,
+        path: None,
+    });
+    linux.seccomp = Some(
+        serde_json::from_str(
+            r#"{
+                "defaultAction": "SCMP_ACT_LOG",
+                "architectures": ["SCMP_ARCH_X86_64"],
+                "syscalls": [{"names": ["ptrace"], "action": "SCMP_ACT_ERRNO"}]
+            }"#,
+        )
+        .expect("deserialize seccomp"),
+    );
+
+    let unsupported = spec.unsupported_features(&features);
+    assert_eq!(
+        unsupported,
+        vec![
+            UnsupportedFeature::OciVersion("1.2.0".to_owned()),
+            UnsupportedFeature::MountOption("/proc".into(), "ridmap".to_owned()),
+            UnsupportedFeature::IdmappedMount("/dev".into()),
+            UnsupportedFeature::Capability("CAP_SYS_ADMIN".to_owned()),
+            UnsupportedFeature::Apparmor,
+            UnsupportedFeature::Namespace("cgroup".to_owned()),
+            UnsupportedFeature::SeccompAction("SCMP_ACT_LOG".to_owned()),
+        ]
+    );
+    assert_eq!(
+        unsupported[5].to_string(),
+        "namespace cgroup is not supported"
+    );
+
+    assert_eq!(spec.unsupported_features(&Features::default()), vec![]);
+}
+
+#[test]
+fn test_state_extensions() {
+    let state = r#"{
+        "ociVersion": "1.0.2",
+        "id": "oci-container1",
+        "status": "stopped",
+        "bundle": "/containers/redis",
+        "annotations": {"myKey": "myValue"},
+        "exitStatus": {"signal": 9},
+        "finishedAt": "2021-10-17T10:00:00Z"
+    }"#;
+
+    let state: State = serde_json::from_str(state).expect("deserialize state");
+    assert_eq!(state.status, ContainerStatus::Stopped);
+    assert_eq!(state.pid, None);
+
+    #[cfg(feature = "extensions")]
+    {
+        let exit_status = state.exit_status.expect("exit status");
+        assert!(!exit_status.success());
+        assert_eq!(exit_status.shell_code(), Some(137));
+        assert_eq!(state.finished_at.as_deref(), Some("2021-10-17T10:00:00Z"));
+    }
+
+    let serialized = serde_json::to_value(&state).expect("serialize state");
+    assert_eq!(serialized["status"], "stopped");
+    assert_eq!(
+        serialized.get("exitStatus").is_some(),
+        cfg!(feature = "extensions")
+    );
+}
+
+#[test]
+fn test_state_file_roundtrip() {
+    let state = r#"{
+        "ociVersion": "1.0.2",
+        "id": "oci-container1",
+        "status": "running",
+        "pid": 4422,
+        "bundle": "/containers/redis",
+        "annotations": {"myKey": "myValue"}
+    }"#;
+
+    let state = State::from_reader(state.as_bytes()).expect("read state");
+    assert_eq!(state.id, "oci-container1");
+    assert_eq!(state.pid, Some(4422));
+    assert!(state.has_process());
+
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let path = dir.path().join("state.json");
+    state.to_file_pretty(&path).expect("write state");
+    assert_eq!(State::from_file(&path).expect("load state"), state);
+
+    let mut writer = Vec::new();
+    state.to_writer(&mut writer).expect("write state");
+    assert_eq!(
+        State::from_reader(writer.as_slice()).expect("read state"),
+        state
+    );
+}
+
+#[test]
+fn test_load_features() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test/data/features.json");
+    let features = Features::from_file(path).expect("load features");
+
+    assert_eq!(features.oci_version_max.as_deref(), Some("1.1.0-rc.2"));
+    let linux = features.linux.as_ref().expect("linux features");
+    let cgroup = linux.cgroup.as_ref().expect("cgroup features");
+    assert_eq!(cgroup.v2, Some(true));
+    assert_eq!(cgroup.systemd_user, Some(true));
+    let seccomp = linux.seccomp.as_ref().expect("seccomp features");
+    assert!(seccomp
+        .actions
+        .as_ref()
+        .unwrap()
+        .contains(&"SCMP_ACT_NOTIFY".to_owned()));
+    assert_eq!(
+        linux
+            .mount_extensions
+            .as_ref()
+            .and_then(|m| m.idmap.as_ref())
+            .and_then(|i| i.enabled),
+        Some(false)
+    );
+
+    let mut writer = Vec::new();
+    features.to_writer(&mut writer).expect("write features");
+    assert_eq!(
+        Features::from_reader(writer.as_slice()).expect("read features"),
+        features
+    );
+}
+
+#[test]
+fn test_seccomp_listener() {
+    let seccomp: LinuxSeccomp = serde_json::from_str(
+        r#"{
+            "defaultAction": "SCMP_ACT_ERRNO",
+            "flags": [
+                "SECCOMP_FILTER_FLAG_TSYNC",
+                "SECCOMP_FILTER_FLAG_SPEC_ALLOW",
+                "SECCOMP_FILTER_FLAG_NEW_LISTENER"
+            ],
+            "listenerPath": "/run/seccomp-agent.socket",
+            "listenerMetadata": "profile=strict",
+            "syscalls": [{"names": ["mount"], "action": "SCMP_ACT_NOTIFY"}]
+        }"#,
+    )
+    .expect("deserialize seccomp");
+
+    assert_eq!(
+        seccomp.flags,
+        Some(vec![
+            LinuxSeccompFilterFlag::Tsync,
+            LinuxSeccompFilterFlag::SpecAllow,
+            LinuxSeccompFilterFlag::Other("SECCOMP_FILTER_FLAG_NEW_LISTENER".to_owned())
+        ])
+    );
+    assert_eq!(
+        seccomp.listener_path,
+        Some(PathBuf::from("/run/seccomp-agent.socket"))
+    );
+    assert_eq!(seccomp.listener_metadata.as_deref(), Some("profile=strict"));
+    assert!(seccomp.requires_listener());
+
+    let serialized = serde_json::to_value(&seccomp).expect("serialize seccomp");
+    assert_eq!(serialized["listenerPath"], "/run/seccomp-agent.socket");
+    assert_eq!(serialized["flags"][0], "SECCOMP_FILTER_FLAG_TSYNC");
+    assert_eq!(serialized["flags"][2], "SECCOMP_FILTER_FLAG_NEW_LISTENER");
+}
+
+#[test]
+fn test_linux_capabilities_helpers() {
+    let mut capabilities = LinuxCapabilities::from_set(Capability::default_set());
+    capabilities.add([Capability::SysPtrace]);
+    capabilities.remove([Capability::NetRaw, Capability::Mknod]);
+    capabilities.ambient = Some([Capability::SysPtrace, Capability::SysAdmin].into());
+    capabilities.restrict_to_bounding();
+
+    let bounding = capabilities.bounding.as_ref().unwrap();
+    assert_eq!(bounding.len(), Capability::DEFAULTS.len() - 1);
+    assert!(bounding.contains(&Capability::SysPtrace));
+    assert!(!bounding.contains(&Capability::NetRaw));
+    assert_eq!(capabilities.effective, capabilities.permitted);
+    assert_eq!(capabilities.inheritable, Some(Capabilities::new()));
+    assert_eq!(capabilities.ambient, Some([Capability::SysPtrace].into()));
+}
+
+#[test]
+fn test_unified_accessors() {
+    let mut resources = LinuxResources {
+        unified: Some(
+            [
+                ("memory.max", "max"),
+                ("cpu.max", "50000 100000"),
+                ("io.weight", "default 200\n8:0 50"),
+                ("memory.high", "1073741824"),
+            ]
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        ),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        resources.unified_memory_max().unwrap(),
+        Some(CgroupLimit::Max)
+    );
+    assert_eq!(
+        resources.unified_cpu_max().unwrap(),
+        Some(CpuMax {
+            quota: CgroupLimit::Value(50000),
+            period: Some(100000)
+        })
+    );
+    assert_eq!(resources.unified_io_weight().unwrap(), Some(200));
+    assert_eq!(resources.unified_pids_max().unwrap(), None);
+
+    resources.set_unified_pids_max(CgroupLimit::Value(100));
+    resources.set_unified_memory_swap_max(CgroupLimit::Value(0));
+    resources.set_unified_cpu_max(CpuMax {
+        quota: CgroupLimit::Max,
+        period: None,
+    });
+    assert!(resources.set_unified_io_weight(0).is_err());
+    resources.set_unified_io_weight(10000).unwrap();
+
+    let unified = resources.unified.as_ref().unwrap();
+    assert_eq!(unified["pids.max"], "100");
+    assert_eq!(unified["memory.swap.max"], "0");
+    assert_eq!(unified["cpu.max"], "max");
+    assert_eq!(unified["io.weight"], "default 10000");
+    assert_eq!(unified["memory.high"], "1073741824");
+
+    resources
+        .unified
+        .as_mut()
+        .unwrap()
+        .insert("memory.max".to_owned(), "lots".to_owned());
+    assert!(resources.unified_memory_max().is_err());
+}
+
+#[test]
+fn test_resources_to_unified() {
+    let resources: LinuxResources = serde_json::from_str(
+        r#"{
+            "memory": {"limit": 536870912, "reservation": 268435456, "swap": 1073741824},
+            "cpu": {"shares": 1024, "quota": 50000, "cpus": "0-3"},
+            "pids": {"limit": -1},
+            "blockIO": {"weight": 500},
+            "hugepageLimits": [{"pageSize": "2MB", "limit": 209715200}],
+            "unified": {"cpu.weight": "42"}
+        }"#,
+    )
+    .expect("deserialize resources");
+
+    let unified = resources.to_unified().expect("convert resources");
+    let expected: HashMap<String, String> = [
+        ("memory.max", "536870912"),
+        ("memory.low", "268435456"),
+        ("memory.swap.max", "536870912"),
+        ("cpu.weight", "42"),
+        ("cpu.max", "50000 100000"),
+        ("cpuset.cpus", "0-3"),
+        ("pids.max", "max"),
+        ("io.weight", "default 4950"),
+        ("hugetlb.2MB.max", "209715200"),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+    assert_eq!(unified, expected);
+
+    let resources: LinuxResources =
+        serde_json::from_str(r#"{"memory": {"swap": 1073741824}}"#).unwrap();
+    assert!(resources.to_unified().is_err());
+
+    let resources: LinuxResources = serde_json::from_str(r#"{"pids": {"limit": 0}}"#).unwrap();
+    assert!(resources
+        .to_unified()
+        .expect("convert resources")
+        .is_empty());
+}
+
+#[test]
+fn test_linux_device_cgroup_from_str() {
+    let rule: LinuxDeviceCgroup = "c 10:200 rwm".parse().expect("parse rule");
+    assert!(rule.allow);
+    assert_eq!(rule.typ, Some(LinuxDeviceType::C));
+    assert_eq!((rule.major, rule.minor), (Some(10), Some(200)));
+    assert_eq!(rule.access.as_deref(), Some("rwm"));
+    assert_eq!(rule.to_string(), "c 10:200 rwm");
+
+    let rule: LinuxDeviceCgroup = "a *:* m".parse().expect("parse rule");
+    assert_eq!(rule.typ, None);
+    assert_eq!((rule.major, rule.minor), (None, None));
+    assert_eq!(rule.to_string(), "a *:* m");
+
+    let rule: LinuxDeviceCgroup = "b 8:* r".parse().expect("parse rule");
+    assert_eq!((rule.major, rule.minor), (Some(8), None));
+
+    for invalid in &[
+        "c 10:200",
+        "x 1:2 rwm",
+        "c 1-2 rwm",
+        "c a:2 r",
+        "c 1:2 rx",
+        "c 1:2 rr",
+    ] {
+        assert!(invalid.parse::<LinuxDeviceCgroup>().is_err(), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_linux_namespaces() {
+    let mut linux = Linux {
+        namespaces: Some(LinuxNamespace::default_set()),
+        ..Default::default()
+    };
+    assert!(linux.validate_namespaces().is_ok());
+
+    linux.join_namespace(LinuxNamespaceType::Network, "/proc/42/ns/net");
+    linux.join_namespace(LinuxNamespaceType::User, "/proc/42/ns/user");
+    let namespaces = linux.namespaces.as_ref().unwrap();
+    assert_eq!(namespaces.len(), 6);
+    assert_eq!(
+        namespaces[1],
+        LinuxNamespace::join(LinuxNamespaceType::Network, "/proc/42/ns/net")
+    );
+    assert!(linux.validate_namespaces().is_ok());
+
+    linux
+        .namespaces
+        .as_mut()
+        .unwrap()
+        .push(LinuxNamespace::new(LinuxNamespaceType::Pid));
+    assert!(linux.validate_namespaces().is_err());
+}
+
+#[test]
+fn test_idmapped_mount() {
+    let mount: Mount = serde_json::from_str(
+        r#"{
+            "destination": "/data",
+            "type": "bind",
+            "source": "/var/lib/data",
+            "options": ["rbind", "idmap"],
+            "uidMappings": [{"containerID": 0, "hostID": 100000, "size": 65536}],
+            "gidMappings": [{"containerID": 0, "hostID": 100000, "size": 65536}]
+        }"#,
+    )
+    .expect("deserialize mount");
+    assert!(mount.is_idmapped());
+    assert!(mount.validate_id_mappings().is_ok());
+
+    let serialized = serde_json::to_value(&mount).expect("serialize mount");
+    assert_eq!(serialized["uidMappings"][0]["hostID"], 100000);
+
+    let mut mount = mount;
+    mount.gid_mappings = None;
+    assert!(mount.validate_id_mappings().is_err());
+    mount.uid_mappings = None;
+    assert!(!mount.is_idmapped());
+    assert!(mount.validate_id_mappings().is_ok());
+}
+
+#[test]
+fn test_mount_options() {
+    let mut mount = get_default_mounts()
+        .into_iter()
+        .find(|m| m.destination == Path::new("/dev/pts"))
+        .expect("devpts mount");
+    let options = mount.mount_options().expect("parse options");
+    assert_eq!(
+        options,
+        vec![
+            MountOption::Nosuid,
+            MountOption::Noexec,
+            MountOption::Data("newinstance".to_owned()),
+            MountOption::Data("ptmxmode=0666".to_owned()),
+            MountOption::Data("mode=0620".to_owned()),
+            MountOption::Data("gid=5".to_owned()),
+        ]
+    );
+
+    let (flags, data) = MountOption::to_mount_flags(&[
+        MountOption::Ro,
+        MountOption::Rbind,
+        Propagation::Rprivate.into(),
+        MountOption::Nodev,
+        MountOption::Rw,
+        MountOption::Data("size=64k".to_owned()),
+    ]);
+    assert_eq!(flags, 0x1000 | 0x4000 | 0x40000 | 0x4);
+    assert_eq!(data, "size=64k");
+    assert!(MountOption::Propagation(Propagation::Rslave).is_propagation());
+    assert_eq!(
+        "rshared".parse::<MountOption>().unwrap(),
+        MountOption::Propagation(Propagation::Rshared)
+    );
+    assert!(!MountOption::Rbind.is_propagation());
+
+    mount.options = Some(vec!["nosiud".to_owned()]);
+    assert!(mount.mount_options().is_err());
+    mount.set_mount_options(vec![MountOption::Ro, MountOption::Idmap]);
+    assert_eq!(
+        mount.options,
+        Some(vec!["ro".to_owned(), "idmap".to_owned()])
+    );
+}
+
+#[test]
+fn test_mount_options_flag_options() {
+    for option in features::FLAG_MOUNT_OPTIONS {
+        let parsed: MountOption = option.parse().expect("parse flag mount option");
+        assert_eq!(parsed.as_str(), *option);
+    }
+
+    for option in &["symfollow", "silent", "loud", "iversion", "noiversion"] {
+        let parsed: MountOption = option.parse().expect("parse mount option");
+        assert_eq!(parsed.as_str(), *option);
+        assert!(parsed.flags().is_some());
+    }
+
+    assert_eq!(
+        "rro".parse::<MountOption>().unwrap(),
+        MountOption::Recursive(RecursiveAttr::Ro)
+    );
+    assert_eq!("rro".parse::<MountOption>().unwrap().flags(), None);
+    assert_eq!(RecursiveAttr::Rw.attr(), (0, 0x1));
+    assert_eq!(RecursiveAttr::Noatime.attr(), (0x10, 0x70));
+    assert_eq!(
+        MountOption::to_mount_flags(&[MountOption::Silent, MountOption::Nosymfollow]),
+        (0x8000 | 0x100, String::new())
+    );
+    assert!("rnosiud".parse::<MountOption>().is_err());
+}
+
+#[test]
+fn test_rootfs_propagation() {
+    let linux: Linux =
+        serde_json::from_str(r#"{"rootfsPropagation": "rslave"}"#).expect("deserialize linux");
+    assert_eq!(linux.rootfs_propagation, Some(Propagation::Rslave));
+    assert_eq!(
+        serde_json::to_value(&linux).unwrap()["rootfsPropagation"],
+        "rslave"
+    );
+    assert!(serde_json::from_str::<Linux>(r#"{"rootfsPropagation": "slaved"}"#).is_err());
+}
+
+#[test]
+fn test_validate_spec() {
+    let mut spec: Spec = serde_json::from_str(
+        r#"{
+            "ociVersion": "1.0.2-dev",
+            "root": {"path": "rootfs"},
+            "process": {
+                "cwd": "relative",
+                "args": [],
+                "env": ["PATH=/bin", "BROKEN"],
+                "user": {"uid": 0, "gid": 0},
+                "rlimits": [
+                    {"type": "RLIMIT_NOFILE", "hard": 1024, "soft": 1024},
+                    {"type": "RLIMIT_NOFILE", "hard": 2048, "soft": 2048}
+                ],
+                "capabilities": {
+                    "effective": ["CAP_KILL", "CAP_SYS_ADMIN"],
+                    "permitted": ["CAP_KILL"]
+                }
+            },
+            "hostname": "test",
+            "mounts": [{"destination": "proc", "type": "proc"}],
+            "hooks": {"prestart": [{"path": "/bin/hook", "timeout": 0}]},
+            "linux": {
+                "namespaces": [{"type": "pid"}, {"type": "pid"}],
+                "uidMappings": [{"containerID": 0, "hostID": 1000, "size": 1}],
+                "maskedPaths": ["/proc/kcore", "proc/keys"]
+            }
+        }"#,
+    )
+    .expect("deserialize spec");
+
+    let errors: Vec<String> = validate(&spec).iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        errors,
+        vec![
+            "mount destination \"proc\" must be an absolute path",
+            "process args must not be empty",
+            "process.cwd \"relative\" must be an absolute path",
+            "environment variable \"BROKEN\" must be in KEY=value format",
+            "duplicate rlimit RLIMIT_NOFILE",
+            "capability CAP_SYS_ADMIN of the effective set is not in the permitted set",
+            "timeout 0 of hook \"/bin/hook\" must be greater than zero",
+            "duplicate namespace type pid",
+            "uid and gid mappings require a user namespace",
+            "hostname requires a new uts namespace",
+            "masked path \"proc/keys\" must be an absolute path",
+        ]
+    );
+
+    spec.version = "1.0".to_owned();
+    assert_eq!(
+        validate(&spec)[0],
+        ValidationError::InvalidVersion("1.0".to_owned())
+    );
+    assert!(validate(&Spec::default()).is_empty());
+
+    let spec: Spec = serde_json::from_value(serde_json::json!({
+        "ociVersion": "1.0.2",
+        "process": {"cwd": "C:\\", "args": ["cmd.exe"], "user": {"uid": 0, "gid": 0}},
+        "mounts": [
+            {"destination": "C:\\data", "source": "\\\\?\\Volume{1}"},
+            {"destination": "/data"}
+        ],
+        "windows": {"layerFolders": []}
+    }))
+    .expect("deserialize windows spec");
+    assert_eq!(
+        validate(&spec),
+        vec![ValidationError::RelativePath {
+            field: "mount destination".to_owned(),
+            path: "/data".into(),
+        }]
+    );
+}
+
+#[test]
+fn test_spec_rootless() {
+    let mut spec = Spec::rootless(1000, 100);
+
+    let linux = spec.linux.as_ref().unwrap();
+    assert_eq!(linux.uid_mappings.as_ref().unwrap()[0].host_id, 1000);
+    assert_eq!(linux.gid_mappings.as_ref().unwrap()[0].host_id, 100);
+    assert!(linux.resources.is_none());
+    assert!(linux.devices.is_none());
+    let devpts = spec
+        .mounts
+        .iter()
+        .flatten()
+        .find(|m| m.destination == Path::new("/dev/pts"))
+        .unwrap();
+    assert!(!devpts
+        .options
+        .as_ref()
+        .unwrap()
+        .contains(&"gid=5".to_owned()));
+
+    assert!(validate(&spec).is_empty());
+    assert!(spec.to_rootless(1000, 100).is_empty());
+}
+
+#[test]
+fn test_windows_roundtrip() {
+    let json = serde_json::json!({
+        "layerFolders": ["C:\\Layers\\layer1", "C:\\Layers\\layer2"],
+        "devices": [{"id": "24E552D7-6523-47F7-A647-D3465BF1F5CA", "idType": "class"}],
+        "resources": {
+            "memory": {"limit": 2097152},
+            "cpu": {"count": 2, "maximum": 5000},
+            "storage": {"iops": 50, "sandboxSize": 1073741824}
+        },
+        "credentialSpec": {"CmsPlugins": ["ActiveDirectory"], "DomainJoinConfig": null},
+        "servicing": true,
+        "ignoreFlushesDuringBoot": true,
+        "hyperv": {"utilityVMPath": "C:\\path\\to\\utilityvm"},
+        "network": {
+            "endpointList": ["7a010682-17e0-4455-a838-02e5d9655fe6"],
+            "allowUnqualifiedDNSQuery": true,
+            "DNSSearchList": ["a.com"]
+        }
+    });
+
+    let windows: Windows = serde_json::from_value(json.clone()).expect("deserialize windows");
+    let devices = windows.devices.as_ref().unwrap();
+    assert_eq!(devices[0].id_type, "class");
+    assert_eq!(windows.servicing, Some(true));
+    assert_eq!(windows.ignore_flushes_during_boot, Some(true));
+    assert_eq!(
+        windows.hyperv.as_ref().unwrap().utility_vm_path.as_deref(),
+        Some("C:\\path\\to\\utilityvm")
+    );
+    assert!(windows
+        .credential_spec
+        .as_ref()
+        .unwrap()
+        .contains_key("CmsPlugins"));
+
+    assert_eq!(serde_json::to_value(&windows).unwrap(), json);
+}
+
+#[test]
+fn test_vm_roundtrip() {
+    let json = serde_json::json!({
+        "hypervisor": {
+            "path": "/path/to/vmm",
+            "parameters": ["opts1=foo", "opts2=bar"]
+        },
+        "kernel": {
+            "path": "/path/to/vmlinuz",
+            "parameters": ["foo=bar", "hello world"],
+            "initrd": "/path/to/initrd.img"
+        },
+        "image": {
+            "path": "/path/to/vm/rootfs.img",
+            "format": "raw"
+        }
+    });
+
+    let vm: VM = serde_json::from_value(json.clone()).expect("deserialize vm");
+    assert_eq!(vm.kernel.initrd.as_deref(), Some("/path/to/initrd.img"));
+    assert_eq!(serde_json::to_value(&vm).unwrap(), json);
+
+    let mut spec = Spec {
+        vm: Some(vm),
+        ..Default::default()
+    };
+    assert!(validate(&spec).is_empty());
+
+    let vm = spec.vm.as_mut().unwrap();
+    vm.kernel.path = "vmlinuz".into();
+    vm.image.as_mut().unwrap().format = "iso".to_owned();
+    assert_eq!(
+        validate(&spec),
+        vec![
+            ValidationError::RelativePath {
+                field: "vm kernel path".to_owned(),
+                path: "vmlinuz".into(),
+            },
+            ValidationError::InvalidVmImageFormat("iso".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn test_zos_roundtrip() {
+    let json = serde_json::json!({
+        "devices": [{
+            "path": "/dev/null",
+            "type": "c",
+            "major": 1,
+            "minor": 3,
+            "fileMode": 438,
+            "uid": 0,
+            "gid": 0
+        }]
+    });
+
+    let spec: Spec = serde_json::from_value(serde_json::json!({
+        "ociVersion": "1.2.0",
+        "zos": json.clone()
+    }))
+    .expect("deserialize spec");
+    let zos = spec.zos.as_ref().expect("zos section");
+    let device = &zos.devices.as_ref().unwrap()[0];
+    assert_eq!(device.typ, LinuxDeviceType::C);
+    assert_eq!(device.file_mode, Some(0o666));
+
+    assert_eq!(serde_json::to_value(zos).unwrap(), json);
+}
+
+#[test]
+fn test_time_offsets() {
+    let mut spec: Spec = serde_json::from_value(serde_json::json!({
+        "ociVersion": "1.2.0",
+        "linux": {
+            "namespaces": [{"type": "time"}],
+            "timeOffsets": {
+                "boottime": {"secs": 3600, "nanosecs": 500},
+                "monotonic": {"secs": -60}
+            }
+        }
+    }))
+    .expect("deserialize spec");
+
+    let linux = spec.linux.as_ref().unwrap();
+    let offsets = linux.time_offsets.as_ref().unwrap();
+    assert_eq!(offsets[TIME_OFFSET_BOOTTIME].secs, Some(3600));
+    assert_eq!(offsets[TIME_OFFSET_BOOTTIME].nanosecs, Some(500));
+    assert_eq!(offsets[TIME_OFFSET_MONOTONIC].nanosecs, None);
+    assert!(validate(&spec).is_empty());
+
+    spec.linux.as_mut().unwrap().namespaces = None;
+    assert_eq!(validate(&spec), vec![ValidationError::MissingTimeNamespace]);
+}
+
+#[test]
+fn test_process_scheduler() {
+    let json = serde_json::json!({
+        "policy": "SCHED_DEADLINE",
+        "nice": 0,
+        "flags": ["SCHED_FLAG_RESET_ON_FORK", "SCHED_FLAG_DL_OVERRUN"],
+        "runtime": 10000000,
+        "deadline": 30000000,
+        "period": 100000000
+    });
+
+    let scheduler: Scheduler = serde_json::from_value(json.clone()).expect("deserialize");
+    assert_eq!(scheduler.policy, LinuxSchedulerPolicy::SchedDeadline);
+    assert_eq!(
+        scheduler.flags,
+        Some(vec![
+            LinuxSchedulerFlag::SchedFlagResetOnFork,
+            LinuxSchedulerFlag::SchedFlagDlOverrun
+        ])
+    );
+    assert_eq!(serde_json::to_value(&scheduler).unwrap(), json);
+
+    let process = Process {
+        scheduler: Some(scheduler),
+        ..Default::default()
+    };
+    let serialized = serde_json::to_value(&process).unwrap();
+    assert_eq!(serialized["scheduler"]["policy"], "SCHED_DEADLINE");
+    assert!(
+        serde_json::from_value::<Scheduler>(serde_json::json!({"policy": "SCHED_FAST"})).is_err()
+    );
+}
+
+#[test]
+fn test_process_io_priority() {
+    let json = serde_json::json!({
+        "class": "IOPRIO_CLASS_RT",
+        "priority": 4
+    });
+
+    let io_priority: LinuxIOPriority = serde_json::from_value(json.clone()).expect("deserialize");
+    assert_eq!(io_priority.class, IOPriorityClass::IoprioClassRt);
+    assert_eq!(io_priority.priority, 4);
+    assert_eq!(serde_json::to_value(io_priority).unwrap(), json);
+
+    let process = Process {
+        io_priority: Some(io_priority),
+        ..Default::default()
+    };
+    let serialized = serde_json::to_value(&process).unwrap();
+    assert_eq!(serialized["ioPriority"]["class"], "IOPRIO_CLASS_RT");
+
+    let process: Process = serde_json::from_value(serialized).unwrap();
+    assert_eq!(process.io_priority, Some(io_priority));
+}
+
+#[test]
+fn test_process_exec_cpu_affinity() {
+    let json = serde_json::json!({
+        "args": ["sh"],
+        "cwd": "/",
+        "user": {"uid": 0, "gid": 0},
+        "execCPUAffinity": {
+            "initial": "7",
+            "final": "0-3,7"
+        }
+    });
+
+    let process: Process = serde_json::from_value(json).expect("deserialize");
+    let affinity = process.exec_cpu_affinity.clone().unwrap();
+    assert_eq!(affinity.cpu_affinity_initial.as_deref(), Some("7"));
+    assert_eq!(affinity.cpu_affinity_final.as_deref(), Some("0-3,7"));
+    assert!(affinity.validate().is_ok());
+
+    let serialized = serde_json::to_value(&process).unwrap();
+    assert_eq!(serialized["execCPUAffinity"]["final"], "0-3,7");
+
+    for invalid in &["", "0,", "a", "3-1", "1-", "-1", "0 - 3"] {
+        let affinity = ExecCPUAffinity {
+            cpu_affinity_final: Some(invalid.to_string()),
+            ..Default::default()
+        };
+        assert!(
+            affinity.validate().is_err(),
+            "{:?} should be invalid",
+            invalid
+        );
+    }
+
+    let spec = Spec {
+        process: Some(Process {
+            exec_cpu_affinity: Some(ExecCPUAffinity {
+                cpu_affinity_initial: Some("1-0".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert!(matches!(
+        validate(&spec).as_slice(),
+        [ValidationError::InvalidExecCpuAffinity(_)]
+    ));
+}
+
+#[test]
+fn test_linux_personality() {
+    let json = serde_json::json!({
+        "domain": "LINUX32",
+        "flags": ["ADDR_NO_RANDOMIZE"]
+    });
+
+    let personality: LinuxPersonality = serde_json::from_value(json.clone()).expect("deserialize");
+    assert_eq!(personality.domain, LinuxPersonalityDomain::PerLinux32);
+    assert_eq!(serde_json::to_value(&personality).unwrap(), json);
+
+    let linux = Linux {
+        personality: Some(personality.clone()),
+        ..Default::default()
+    };
+    let serialized = serde_json::to_value(&linux).unwrap();
+    assert_eq!(serialized["personality"], json);
+
+    let linux: Linux = serde_json::from_value(serialized).unwrap();
+    assert_eq!(linux.personality, Some(personality));
+    assert!(
+        serde_json::from_value::<LinuxPersonality>(serde_json::json!({"domain": "LINUX64"}))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_linux_intel_rdt() {
+    let json = serde_json::json!({
+        "closID": "guaranteed_group",
+        "l3CacheSchema": "L3:0=7f0;1=1f",
+        "memBwSchema": "MB:0=20;1=70",
+        "enableCMT": true,
+        "enableMBM": true
+    });
+
+    let rdt: LinuxIntelRdt = serde_json::from_value(json.clone()).expect("deserialize");
+    assert_eq!(rdt.enable_cmt, Some(true));
+    assert_eq!(rdt.enable_mbm, Some(true));
+    assert!(rdt.validate().is_ok());
+    assert_eq!(serde_json::to_value(&rdt).unwrap(), json);
+
+    let rdt: LinuxIntelRdt =
+        serde_json::from_value(serde_json::json!({"closId": "guaranteed_group"})).unwrap();
+    assert_eq!(rdt.clos_id.as_deref(), Some("guaranteed_group"));
+
+    let mut rdt = LinuxIntelRdt::default();
+    rdt.set_l3_cache_masks(vec![(0, 0x7f0), (1, 0x1f)]);
+    rdt.set_mem_bw(vec![(0, 20), (1, 70)]);
+    assert_eq!(rdt.l3_cache_schema.as_deref(), Some("L3:0=7f0;1=1f"));
+    assert_eq!(rdt.mem_bw_schema.as_deref(), Some("MB:0=20;1=70"));
+    assert!(rdt.validate().is_ok());
+
+    let cdp = LinuxIntelRdt {
+        l3_cache_schema: Some("L3DATA:0=fffff\nL3CODE:0=fffff".to_string()),
+        ..Default::default()
+    };
+    assert!(cdp.validate().is_ok());
+
+    for (l3, mb) in &[
+        (Some("L3:0=fffff;1"), None),
+        (Some("L3:0=0"), None),
+        (Some("L3:0=xyz"), None),
+        (Some("MB:0=20"), None),
+        (Some("0=fffff"), None),
+        (None, Some("MB:0=fast")),
+        (None, Some("L3:0=20")),
+    ] {
+        let rdt = LinuxIntelRdt {
+            l3_cache_schema: l3.map(String::from),
+            mem_bw_schema: mb.map(String::from),
+            ..Default::default()
+        };
+        assert!(
+            rdt.validate().is_err(),
+            "{:?} {:?} should be invalid",
+            l3,
+            mb
+        );
+    }
+
+    let rdt = LinuxIntelRdt {
+        clos_id: Some("../escape".to_string()),
+        ..Default::default()
+    };
+    let spec = Spec {
+        linux: Some(Linux {
+            intel_rdt: Some(rdt),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert!(matches!(
+        validate(&spec).as_slice(),
+        [ValidationError::InvalidIntelRdt(_)]
+    ));
+}
+
+#[test]
+fn test_hugepage_size() {
+    let cases = [
+        ("64KB", 64 << 10),
+        ("2MB", 2 << 20),
+        ("1GB", 1 << 30),
+        ("16GB", 16 << 30),
+        ("2048KB", 2 << 20),
+    ];
+    for (input, bytes) in cases.iter() {
+        let size: HugepageSize = input.parse().expect(input);
+        assert_eq!(size.bytes(), *bytes);
+    }
+    assert_eq!(HugepageSize::new(2 << 20).unwrap(), HugepageSize::SIZE_2MB);
+    assert_eq!(HugepageSize::SIZE_2MB.to_string(), "2MB");
+    assert_eq!(HugepageSize::new(1 << 30).unwrap().to_string(), "1GB");
+    assert_eq!(HugepageSize::new(512 << 10).unwrap().to_string(), "512KB");
+    assert_eq!("2048KB".parse::<HugepageSize>().unwrap().to_string(), "2MB");
+
+    for invalid in &["", "MB", "3MB", "2mb", "2 MB", "-2MB", "2TB", "0KB", "512"] {
+        assert!(
+            invalid.parse::<HugepageSize>().is_err(),
+            "{:?} should be invalid",
+            invalid
+        );
+    }
+    assert!(HugepageSize::new(512).is_err());
+
+    let mut limit = LinuxHugepageLimit {
+        page_size: "2MB".to_string(),
+        limit: 209715200,
+    };
+    assert_eq!(limit.hugepage_size().unwrap(), HugepageSize::SIZE_2MB);
+    limit.set_hugepage_size(HugepageSize::SIZE_1GB);
+    assert_eq!(limit.page_size, "1GB");
+
+    limit.page_size = "3MB".to_string();
+    let spec = Spec {
+        linux: Some(Linux {
+            resources: Some(LinuxResources {
+                hugepage_limits: Some(vec![limit]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        validate(&spec),
+        vec![ValidationError::InvalidHugepageSize("3MB".to_string())]
+    );
+}
+
+#[test]
+fn test_net_class_id() {
+    let class_id: NetClassId = "10:1".parse().unwrap();
+    assert_eq!(
+        class_id,
+        NetClassId {
+            major: 0x10,
+            minor: 1
+        }
+    );
+    assert_eq!(u32::from(class_id), 0x0010_0001);
+    assert_eq!(class_id.to_string(), "0x00100001");
+    assert_eq!("0x00100001".parse::<NetClassId>().unwrap(), class_id);
+    assert_eq!(
+        "ffff:ffff".parse::<NetClassId>().unwrap(),
+        NetClassId::from(u32::MAX)
+    );
+
+    for invalid in &[
+        "",
+        "10",
+        "10:",
+        ":1",
+        "10:1:2",
+        "g:1",
+        "10000:1",
+        "0x",
+        "0x100000001",
+    ] {
+        assert!(
+            invalid.parse::<NetClassId>().is_err(),
+            "{:?} should be invalid",
+            invalid
+        );
+    }
+
+    let mut network = LinuxNetwork::default();
+    network.set_net_class_id(class_id);
+    assert_eq!(network.class_id, Some(0x0010_0001));
+    assert_eq!(network.net_class_id(), Some(class_id));
+}
+
+#[test]
+fn test_network_priorities() {
+    let mut network = LinuxNetwork::default();
+    network.set_priority("eth0", 5);
+    network.set_priority("lo", 2);
+    network.set_priority("eth0", 10);
+    assert_eq!(network.priorities.as_ref().unwrap().len(), 2);
+    assert!(network.validate().is_ok());
+
+    network.priorities = Some(vec![
+        LinuxInterfacePriority {
+            name: "eth0".to_string(),
+            priority: 5,
+        },
+        LinuxInterfacePriority {
+            name: "lo".to_string(),
+            priority: 2,
+        },
+        LinuxInterfacePriority {
+            name: "eth0".to_string(),
+            priority: 10,
+        },
+    ]);
+    assert!(network.validate().is_err());
+
+    network.normalize_priorities();
+    let priorities: Vec<(&str, u32)> = network
+        .priorities
+        .iter()
+        .flatten()
+        .map(|p| (p.name.as_str(), p.priority))
+        .collect();
+    assert_eq!(priorities, vec![("eth0", 10), ("lo", 2)]);
+    assert!(network.validate().is_ok());
+}
+
+#[test]
+fn test_block_io_helpers() {
+    let mut block_io = LinuxBlockIo {
+        weight: Some(500),
+        ..Default::default()
+    };
+    block_io.add_weight_device(8, 0, 300);
+    block_io.throttle_read_bps(8, 0, 1048576);
+    block_io.throttle_write_bps(8, 0, 1048576);
+    block_io.throttle_read_iops(8, 0, 1000);
+    block_io.throttle_write_iops(8, 16, 500);
+    assert!(block_io.validate().is_ok());
+    assert_eq!(
+        block_io.throttle_write_iops_device,
+        Some(vec![LinuxThrottleDevice::new(8, 16, 500)])
+    );
+
+    let json = serde_json::to_value(&block_io).unwrap();
+    assert_eq!(
+        json["weightDevice"],
+        serde_json::json!([{"major": 8, "minor": 0, "weight": 300}])
+    );
+
+    let mut invalid = block_io.clone();
+    invalid.weight = Some(1001);
+    assert!(invalid.validate().is_err());
+
+    let mut invalid = block_io.clone();
+    invalid.weight_device = Some(vec![LinuxWeightDevice {
+        leaf_weight: Some(9),
+        ..LinuxWeightDevice::new(8, 0, 100)
+    }]);
+    assert!(invalid.validate().is_err());
+
+    let mut invalid = block_io;
+    invalid.throttle_read_bps(8, 32, 0);
+    let spec = Spec {
+        linux: Some(Linux {
+            resources: Some(LinuxResources {
+                block_io: Some(invalid),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert!(matches!(
+        validate(&spec).as_slice(),
+        [ValidationError::InvalidBlockIo(_)]
+    ));
+}
+
+#[test]
+#[cfg(unix)]
+fn test_block_io_device_from_path() {
+    assert!(LinuxThrottleDevice::from_path("/dev/null", 1024).is_err());
+    assert!(LinuxWeightDevice::from_path("/nonexistent/device", 100).is_err());
+}
+
+#[test]
+fn test_cpu_burst_and_idle() {
+    let resources: LinuxResources = serde_json::from_str(
+        r#"{"cpu": {"quota": 50000, "period": 100000, "burst": 20000, "idle": 1}}"#,
+    )
+    .expect("deserialize resources");
+    let cpu = resources.cpu.as_ref().unwrap();
+    assert_eq!(cpu.burst, Some(20000));
+    assert_eq!(cpu.idle, Some(1));
+
+    let serialized = serde_json::to_value(cpu).unwrap();
+    assert_eq!(serialized["burst"], 20000);
+    assert_eq!(serialized["idle"], 1);
+
+    let unified = resources.to_unified().expect("convert resources");
+    assert_eq!(unified[UNIFIED_CPU_MAX], "50000 100000");
+    assert_eq!(unified[UNIFIED_CPU_MAX_BURST], "20000");
+    assert_eq!(unified[UNIFIED_CPU_IDLE], "1");
+
+    let cpu = serde_json::to_value(LinuxCpu::default()).unwrap();
+    assert_eq!(cpu, serde_json::json!({}));
+}
+
+#[test]
+fn test_memory_check_before_update() {
+    let json = serde_json::json!({
+        "limit": 536870912,
+        "useHierarchy": true,
+        "checkBeforeUpdate": true
+    });
+
+    let memory: LinuxMemory = serde_json::from_value(json.clone()).expect("deserialize");
+    assert_eq!(memory.use_hierarchy, Some(true));
+    assert_eq!(memory.check_before_update, Some(true));
+    assert_eq!(serde_json::to_value(memory).unwrap(), json);
+}
+
+#[test]
+#[cfg(feature = "builder")]
+fn test_memory_builder_check_before_update() {
+    let memory = LinuxMemoryBuilder::default()
+        .limit(536870912)
+        .use_hierarchy(true)
+        .check_before_update(true)
+        .build()
+        .expect("build memory");
+    assert_eq!(memory.use_hierarchy(), Some(true));
+    assert_eq!(memory.check_before_update(), Some(true));
+}
+
+#[test]
+fn test_linux_memory_policy() {
+    let json = serde_json::json!({
+        "mode": "MPOL_INTERLEAVE",
+        "nodes": "0-1,3",
+        "flags": ["MPOL_F_STATIC_NODES"]
+    });
+
+    let policy: LinuxMemoryPolicy = serde_json::from_value(json.clone()).expect("deserialize");
+    assert_eq!(policy.mode, MemoryPolicyMode::MpolInterleave);
+    assert_eq!(policy.flags, Some(vec![MemoryPolicyFlag::MpolFStaticNodes]));
+    assert!(policy.validate().is_ok());
+    assert_eq!(serde_json::to_value(&policy).unwrap(), json);
+
+    let linux = Linux {
+        memory_policy: Some(policy),
+        ..Default::default()
+    };
+    assert_eq!(serde_json::to_value(&linux).unwrap()["memoryPolicy"], json);
+
+    let policy = |mode, nodes: Option<&str>| LinuxMemoryPolicy {
+        mode,
+        nodes: nodes.map(String::from),
+        flags: None,
+    };
+    assert!(policy(MemoryPolicyMode::MpolDefault, None)
+        .validate()
+        .is_ok());
+    assert!(policy(MemoryPolicyMode::MpolPreferred, None)
+        .validate()
+        .is_ok());
+    assert!(policy(MemoryPolicyMode::MpolBind, Some("0"))
+        .validate()
+        .is_ok());
+    assert!(policy(MemoryPolicyMode::MpolLocal, Some("0"))
+        .validate()
+        .is_err());
+    assert!(policy(MemoryPolicyMode::MpolBind, None).validate().is_err());
+    assert!(policy(MemoryPolicyMode::MpolBind, Some("1-0"))
+        .validate()
+        .is_err());
+
+    let spec = Spec {
+        linux: Some(Linux {
+            memory_policy: Some(policy(MemoryPolicyMode::MpolPreferredMany, None)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert!(matches!(
+        validate(&spec).as_slice(),
+        [ValidationError::InvalidMemoryPolicy(_)]
+    ));
+}
+
+#[test]
+fn test_linux_landlock() {
+    let json = serde_json::json!({
+        "ruleset": {
+            "handledAccessFS": ["execute", "read_file", "read_dir", "write_file"],
+            "handledAccessNetwork": ["bind_tcp", "connect_tcp"]
+        },
+        "rules": {
+            "pathBeneath": [
+                {"allowedAccess": ["execute", "read_file", "read_dir"], "paths": ["/usr", "/bin"]},
+                {"allowedAccess": ["write_file"], "paths": ["/tmp"]}
+            ],
+            "netPort": [{"allowedAccess": ["connect_tcp"], "ports": [443]}]
+        },
+        "disableBestEffort": true
+    });
+
+    let landlock: LinuxLandlock = serde_json::from_value(json.clone()).expect("deserialize");
+    let rules = landlock.rules.as_ref().unwrap();
+    assert_eq!(
+        rules.path_beneath.as_ref().unwrap()[1].allowed_access,
+        vec![LandlockFsAccess::WriteFile]
+    );
+    assert_eq!(rules.net_port.as_ref().unwrap()[0].ports, vec![443]);
+    assert!(landlock.validate().is_ok());
+    assert_eq!(serde_json::to_value(&landlock).unwrap(), json);
+
+    let linux = Linux {
+        landlock: Some(landlock.clone()),
+        ..Default::default()
+    };
+    assert_eq!(serde_json::to_value(&linux).unwrap()["landlock"], json);
+
+    let mut unhandled = landlock.clone();
+    unhandled.ruleset.as_mut().unwrap().handled_access_network = None;
+    assert!(unhandled.validate().is_err());
+
+    let mut relative = landlock;
+    relative.rules.as_mut().unwrap().path_beneath = Some(vec![LandlockRulePathBeneath {
+        allowed_access: vec![LandlockFsAccess::ReadFile],
+        paths: vec![PathBuf::from("usr")],
+    }]);
+    let spec = Spec {
+        linux: Some(Linux {
+            landlock: Some(relative),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert!(matches!(
+        validate(&spec).as_slice(),
+        [ValidationError::InvalidLandlock(_)]
+    ));
+}
+
+#[test]
+fn test_rlimit_type_names() {
+    for typ in LinuxRlimitType::ALL.iter() {
+        let serialized = serde_json::to_value(typ).unwrap();
+        assert_eq!(serialized, typ.as_str());
+        assert_eq!(&typ.to_string().parse::<LinuxRlimitType>().unwrap(), typ);
+    }
+    assert_eq!(
+        "nofile".parse::<LinuxRlimitType>().unwrap(),
+        LinuxRlimitType::RlimitNofile
+    );
+    assert_eq!(
+        "rlimit_nproc".parse::<LinuxRlimitType>().unwrap(),
+        LinuxRlimitType::RlimitNproc
+    );
+    assert!("RLIMIT_NOFILES".parse::<LinuxRlimitType>().is_err());
+
+    let value = serde_json::json!({"type": "RLIMIT_NOFILES", "hard": 1024, "soft": 1024});
+    let rlimit: LinuxRlimit = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(
+        rlimit.typ,
+        LinuxRlimitType::Other("RLIMIT_NOFILES".to_string())
+    );
+    assert_eq!(serde_json::to_value(&rlimit).unwrap(), value);
+}
+
+#[test]
+fn test_console_size() {
+    let console_size = Box {
+        height: 24,
+        width: 80,
+    };
+    assert!(console_size.validate().is_ok());
+    assert!(Box {
+        height: 0,
+        width: 80
+    }
+    .validate()
+    .is_err());
+
+    let spec = |terminal, console_size| Spec {
+        process: Some(Process {
+            terminal,
+            console_size,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert!(validate(&spec(Some(true), Some(console_size))).is_empty());
+    assert!(validate(&spec(None, None)).is_empty());
+    assert_eq!(
+        validate(&spec(Some(false), Some(console_size))),
+        vec![ValidationError::ConsoleSizeWithoutTerminal]
+    );
+    assert_eq!(
+        validate(&spec(Some(true), Some(Box::default()))),
+        vec![ValidationError::InvalidConsoleSize]
+    );
+}
+
+#[test]
+#[cfg(feature = "builder")]
+fn test_console_size_builder() {
+    let console_size = BoxBuilder::default()
+        .height(24u64)
+        .width(80u64)
+        .build()
+        .expect("build console size");
+    assert_eq!(console_size.width(), 80);
+
+    assert!(BoxBuilder::default().height(24u64).build().is_err());
+    assert!(BoxBuilder::default()
+        .height(0u64)
+        .width(80u64)
+        .build()
+        .is_err());
+}
+
+#[test]
+fn test_hook_timeout_duration() {
+    let mut hook = Hook {
+        path: PathBuf::from("/bin/hook"),
+        ..Default::default()
+    };
+    assert_eq!(hook.timeout_duration(), None);
+
+    hook.set_timeout_duration(std::time::Duration::from_secs(5))
+        .unwrap();
+    assert_eq!(hook.timeout, Some(5));
+    assert_eq!(
+        hook.timeout_duration(),
+        Some(std::time::Duration::from_secs(5))
+    );
+    assert_eq!(serde_json::to_value(&hook).unwrap()["timeout"], 5);
+
+    hook.set_timeout_duration(std::time::Duration::from_millis(1))
+        .unwrap();
+    assert_eq!(hook.timeout, Some(1));
+    assert!(hook
+        .set_timeout_duration(std::time::Duration::ZERO)
+        .is_err());
+    assert!(hook
+        .set_timeout_duration(std::time::Duration::from_secs(u64::MAX))
+        .is_err());
+    assert!(hook.set_timeout_duration(std::time::Duration::MAX).is_err());
+    assert_eq!(hook.timeout, Some(1));
+    assert!(hook.validate().is_ok());
+
+    hook.timeout = Some(-1);
+    assert_eq!(hook.timeout_duration(), None);
+    assert!(hook.validate().is_err());
+}
+
+#[test]
+#[cfg(feature = "builder")]
+fn test_hook_builder_timeout() {
+    let hook = HookBuilder::default()
+        .path("/bin/hook")
+        .timeout_duration(std::time::Duration::from_secs(10))
+        .unwrap()
+        .build()
+        .expect("build hook");
+    assert_eq!(hook.timeout(), Some(10));
+
+    assert!(HookBuilder::default()
+        .path("/bin/hook")
+        .timeout(0)
+        .build()
+        .is_err());
+    assert!(HookBuilder::default().path("/bin/hook").build().is_ok());
+}
+
+#[test]
+fn test_default_paths() {
+    let linux = Linux::default();
+    assert_eq!(linux.masked_paths, Some(get_default_maskedpaths()));
+    assert_eq!(linux.readonly_paths, Some(get_default_readonly_paths()));
+    assert!(get_default_maskedpaths().contains(&"/proc/kcore".to_string()));
+    assert!(get_default_readonly_paths().contains(&"/proc/sys".to_string()));
+
+    let mut linux = Linux {
+        masked_paths: Some(vec!["/proc/kcore".to_string(), "/custom".to_string()]),
+        readonly_paths: None,
+        ..Default::default()
+    };
+    linux.add_default_paths();
+    let masked = linux.masked_paths.unwrap();
+    assert_eq!(masked.len(), DEFAULT_MASKED_PATHS.len() + 1);
+    assert_eq!(
+        masked[..2],
+        ["/proc/kcore".to_string(), "/custom".to_string()]
+    );
+    assert_eq!(linux.readonly_paths, Some(get_default_readonly_paths()));
+}
+
+#[test]
+fn test_sysctls() {
+    assert_eq!(
+        sysctl_namespace("net/ipv4/ip_forward"),
+        Some(LinuxNamespaceType::Network)
+    );
+    assert_eq!(
+        sysctl_namespace("fs.mqueue.msg_max"),
+        Some(LinuxNamespaceType::Ipc)
+    );
+    assert_eq!(
+        sysctl_namespace("kernel.hostname"),
+        Some(LinuxNamespaceType::Uts)
+    );
+    assert_eq!(sysctl_namespace("kernel.pid_max"), None);
+    assert_eq!(sysctl_namespace("kernel.shm"), None);
+
+    let mut spec = Spec::default();
+    spec.set_sysctl("kernel.shmmax", "68719476736").unwrap();
+    spec.set_sysctl("kernel.shmmax", "4294967296").unwrap();
+    assert!(spec.set_sysctl("kernel.pid_max", "4194304").is_err());
+    for invalid in &[
+        "",
+        "net..core",
+        "net.core.",
+        "net.core.somaxconn=1",
+        "net core",
+    ] {
+        assert!(
+            spec.set_sysctl(*invalid, "1").is_err(),
+            "{:?} should be invalid",
+            invalid
+        );
+    }
+    let linux = spec.linux.as_ref().unwrap();
+    assert_eq!(linux.sysctl.as_ref().unwrap().len(), 1);
+    assert_eq!(
+        linux.sysctl.as_ref().unwrap()["kernel.shmmax"],
+        "4294967296"
+    );
+    assert!(linux.validate_sysctls().is_ok());
+    assert!(validate(&spec).is_empty());
+
+    // A rootless configuration has no network namespace.
+    let mut spec = Spec::rootless(1000, 1000);
+    spec.set_sysctl("net.ipv4.ping_group_range", "0 0").unwrap();
+    assert!(matches!(
+        validate(&spec).as_slice(),
+        [ValidationError::InvalidSysctl(_)]
+    ));
+
+    let linux = Linux {
+        sysctl: Some(
+            [("vm.overcommit_memory".to_string(), "1".to_string())]
+                .iter()
+                .cloned()
+                .collect(),
+        ),
+        ..Default::default()
+    };
+    assert!(linux.validate_sysctls().is_err());
+}
+
+#[test]
+fn test_docker_seccomp_profile() {
+    let profile = DockerSeccompProfile::from_file("test/data/docker_seccomp.json")
+        .expect("load docker seccomp profile");
+    let names = |seccomp: &LinuxSeccomp| -> Vec<String> {
+        seccomp
+            .syscalls
+            .iter()
+            .flatten()
+            .flat_map(|s| s.names.clone())
+            .collect()
+    };
+
+    let seccomp = profile.to_linux_seccomp(Arch::ScmpArchX86_64, &Capabilities::new());
+    assert_eq!(seccomp.default_action, LinuxSeccompAction::ScmpActErrno);
+    assert_eq!(seccomp.default_errno_ret, Some(1));
+    assert_eq!(
+        seccomp.architectures,
+        Some(vec![
+            Arch::ScmpArchX86_64,
+            Arch::ScmpArchX86,
+            Arch::ScmpArchX32
+        ])
+    );
+    assert_eq!(
+        names(&seccomp),
+        vec![
+            "accept",
+            "read",
+            "write",
+            "personality",
+            "arch_prctl",
+            "socket"
+        ]
+    );
+    let socket = seccomp.syscalls.as_ref().unwrap().last().unwrap();
+    assert_eq!(socket.errno_ret, Some(38));
+    assert!(seccomp.syscalls.as_ref().unwrap()[1].args.is_some());
+
+    let capabilities: Capabilities = vec![Capability::SysAdmin, Capability::NetRaw]
+        .into_iter()
+        .collect();
+    let seccomp = profile.to_linux_seccomp(Arch::ScmpArchAarch64, &capabilities);
+    assert_eq!(
+        seccomp.architectures,
+        Some(vec![Arch::ScmpArchAarch64, Arch::ScmpArchArm])
+    );
+    assert_eq!(
+        names(&seccomp),
+        vec!["accept", "read", "write", "personality", "mount", "umount2"]
+    );
+
+    let seccomp = profile.to_linux_seccomp(Arch::ScmpArchS390x, &Capabilities::new());
+    assert_eq!(seccomp.architectures, Some(vec![]));
+    assert!(names(&seccomp).contains(&"clone".to_string()));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_linux_device_from_path() {
+    let device = LinuxDevice::from_path("/dev/null").unwrap();
+    assert_eq!(device.path, PathBuf::from("/dev/null"));
+    assert_eq!(device.typ, LinuxDeviceType::C);
+    assert_eq!((device.major, device.minor), (1, 3));
+    assert_eq!(device.file_mode, Some(0o666));
+    assert!(device.uid.is_some() && device.gid.is_some());
+
+    assert!(LinuxDevice::from_path("/").is_err());
+    assert!(LinuxDevice::from_path("/nonexistent/device").is_err());
+}
+
+#[test]
+#[cfg(feature = "builder")]
+fn test_builder_setters_and_mut_getters() {
+    let mut spec = Spec::default();
+    spec.set_hostname(Some("container".to_string()))
+        .set_annotations(None);
+    assert_eq!(spec.hostname().as_deref(), Some("container"));
+
+    let process = spec.process_mut().as_mut().unwrap();
+    process.set_terminal(Some(true));
+    process
+        .env_mut()
+        .get_or_insert_with(Vec::new)
+        .push("TERM=xterm".to_string());
+    process.user_mut().set_uid(1000);
+    assert_eq!(spec.process().as_ref().unwrap().terminal(), Some(true));
+    assert_eq!(spec.process().as_ref().unwrap().user().uid(), 1000);
+
+    let linux = spec.linux_mut().as_mut().unwrap();
+    linux.set_sysctl("net.ipv4.ip_forward", "1").unwrap();
+    linux
+        .sysctl_mut()
+        .as_mut()
+        .unwrap()
+        .remove("net.ipv4.ip_forward");
+    linux.set_masked_paths(None);
+    assert!(spec.linux().as_ref().unwrap().masked_paths().is_none());
+    assert_eq!(
+        spec.linux().as_ref().unwrap().sysctl(),
+        &Some(HashMap::new())
+    );
+}
+
+#[test]
+#[cfg(feature = "builder")]
+fn test_to_builder() {
+    let spec = Spec::default();
+    let process = spec.process().clone().unwrap();
+
+    let changed = spec
+        .to_builder()
+        .hostname("container".to_string())
+        .process(
+            process
+                .to_builder()
+                .cwd(PathBuf::from("/home"))
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    assert_eq!(changed.hostname().as_deref(), Some("container"));
+    assert_eq!(
+        changed.process().as_ref().unwrap().cwd(),
+        &PathBuf::from("/home")
+    );
+    assert_eq!(changed.linux(), spec.linux());
+    assert_eq!(changed.mounts(), spec.mounts());
+    assert_eq!(SpecBuilder::from(spec.clone()).build().unwrap(), spec);
+
+    let state = State::default();
+    assert_eq!(state.to_builder().build().unwrap(), state);
+}
+
+#[test]
+fn test_subordinate_id_ranges() {
+    let ranges =
+        SubordinateIdRange::parse("# comment\nalice:100000:65536\n\n1000:165536:1000\n").unwrap();
+    assert_eq!(ranges.len(), 2);
+    assert!(ranges[0].is_owned_by("alice", 1000));
+    assert!(ranges[1].is_owned_by("alice", 1000));
+    assert!(!ranges[1].is_owned_by("bob", 1001));
+
+    for invalid in [
+        "alice:100000",
+        "alice:x:1",
+        ":1:1",
+        "alice:1:0",
+        "alice:4294967295:2",
+    ] {
+        assert!(
+            invalid.parse::<SubordinateIdRange>().is_err(),
+            "{}",
+            invalid
+        );
+    }
+
+    let mappings = LinuxIdMapping::rootless(1000, &ranges);
+    assert_eq!(
+        mappings,
+        vec![
+            LinuxIdMapping::new(0, 1000, 1),
+            LinuxIdMapping::new(1, 100000, 65536),
+            LinuxIdMapping::new(65537, 165536, 1000),
+        ]
+    );
+    assert!(validate_id_mappings(&mappings).is_ok());
+    assert_eq!(map_to_host(&mappings, 0), Some(1000));
+    assert_eq!(map_to_host(&mappings, 65537), Some(165536));
+    assert_eq!(map_to_host(&mappings, 66537), None);
+}
+
+#[test]
+fn test_validate_id_mappings() {
+    assert!(validate_id_mappings(&[LinuxIdMapping::new(0, 1000, 0)]).is_err());
+    assert!(validate_id_mappings(&[LinuxIdMapping::new(1, 1000, u32::MAX)]).is_err());
+    assert!(validate_id_mappings(&[
+        LinuxIdMapping::new(0, 1000, 10),
+        LinuxIdMapping::new(5, 2000, 10),
+    ])
+    .is_err());
+    assert!(validate_id_mappings(&[
+        LinuxIdMapping::new(0, 1000, 10),
+        LinuxIdMapping::new(10, 1009, 10),
+    ])
+    .is_err());
+    assert!(validate_id_mappings(&[
+        LinuxIdMapping::new(0, 1000, 10),
+        LinuxIdMapping::new(10, 1010, 10),
+    ])
+    .is_ok());
+
+    let mut spec = Spec::rootless(1000, 1000);
+    assert!(validate(&spec).is_empty());
+    spec.merge(&serde_json::json!({
+        "process": {"user": {"uid": 0, "gid": 0, "additionalGids": [5]}}
+    }))
+    .unwrap();
+    assert_eq!(
+        validate(&spec),
+        vec![ValidationError::InvalidIdMappings(
+            "gid 5 of the process is not mapped".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_validate_rlimits() {
+    let rlimit = |typ, soft, hard| LinuxRlimit { typ, soft, hard };
+    assert!(rlimit(LinuxRlimitType::RlimitCore, u64::MAX, u64::MAX)
+        .validate()
+        .is_ok());
+    assert!(rlimit(LinuxRlimitType::RlimitCore, u64::MAX, 0)
+        .validate()
+        .is_err());
+
+    let mut process = Process {
+        rlimits: Some(vec![
+            rlimit(LinuxRlimitType::RlimitNofile, 1024, 4096),
+            rlimit(LinuxRlimitType::RlimitNproc, 512, 256),
+            rlimit(LinuxRlimitType::RlimitNofile, 1024, 1024),
+        ]),
+        ..Default::default()
+    };
+    assert!(process.validate_rlimits().is_err());
+
+    let spec = Spec {
+        process: Some(process.clone()),
+        ..Default::default()
+    };
+    assert_eq!(
+        validate(&spec),
+        vec![
+            ValidationError::RlimitSoftExceedsHard {
+                typ: "RLIMIT_NPROC".to_string(),
+                soft: 512,
+                hard: 256,
+            },
+            ValidationError::DuplicateRlimit("RLIMIT_NOFILE".to_string()),
+        ]
+    );
+
+    process.rlimits.as_mut().unwrap().truncate(1);
+    assert!(process.validate_rlimits().is_ok());
+}
+
+#[test]
+#[cfg(feature = "builder")]
+fn test_rlimit_builder_validation() {
+    assert!(LinuxRlimitBuilder::default()
+        .typ(LinuxRlimitType::RlimitNofile)
+        .soft(2048u64)
+        .hard(1024u64)
+        .build()
+        .is_err());
+}
+
+#[test]
+fn test_linux_net_devices() {
+    let linux: Linux = serde_json::from_value(serde_json::json!({
+        "namespaces": [{"type": "network"}],
+        "netDevices": {"eth1": {"name": "ctr0"}, "eth2": {}}
+    }))
+    .unwrap();
+    let devices = linux.net_devices.as_ref().unwrap();
+    assert_eq!(devices["eth1"].name.as_deref(), Some("ctr0"));
+    assert_eq!(devices["eth2"].name, None);
+    assert!(linux.validate_net_devices().is_ok());
+    assert_eq!(
+        serde_json::to_value(&linux).unwrap()["netDevices"]["eth2"],
+        serde_json::json!({})
+    );
+
+    let mut renamed = linux.clone();
+    renamed.add_net_device("eth3", Some("eth2")).unwrap();
+    assert!(renamed.validate_net_devices().is_err());
+    assert!(renamed
+        .add_net_device("averyveryverylongname", None)
+        .is_err());
+
+    let spec = Spec {
+        linux: Some(Linux {
+            namespaces: None,
+            ..linux
+        }),
+        ..Default::default()
+    };
+    assert!(
+        validate(&spec).contains(&ValidationError::InvalidNetDevices(
+            "net devices require a network namespace".to_string()
+        ))
+    );
+}
+
+#[test]
+#[cfg(feature = "lossless")]
+fn spec_preserves_unknown_fields() {
+    let json = r#"{
+        "ociVersion": "1.0.2",
+        "root": {"path": "rootfs"},
+        "org.example.runtime": {"sandbox": true}
+    }"#;
+
+    let mut spec: Spec = serde_json::from_str(json).expect("deserialize spec");
+    spec.hostname = Some("example".to_owned());
+    let serialized = serde_json::to_value(&spec).expect("serialize spec");
+
+    assert_eq!(serialized["org.example.runtime"]["sandbox"], true);
+    assert_eq!(serialized["hostname"], "example");
+    assert!(!spec.extensions.contains_key("root"));
+}
+
+#[test]
+fn test_spec_to_version() {
+    let spec: Spec = serde_json::from_value(serde_json::json!({
+        "ociVersion": "1.2.0",
+        "mounts": [
+            {"destination": "/proc", "type": "proc"},
+            {
+                "destination": "/data",
+                "uidMappings": [{"containerID": 0, "hostID": 1000, "size": 1}]
+            }
+        ],
+        "linux": {
+            "timeOffsets": {"monotonic": {"secs": 1, "nanosecs": 0}},
+            "netDevices": {"eth1": {}},
+            "resources": {"cpu": {"shares": 1024, "burst": 1000, "idle": 1}},
+            "intelRdt": {"closID": "guaranteed", "enableCMT": true, "enableMBM": true}
+        },
+        "zos": {}
+    }))
+    .expect("deserialize spec");
+
+    let (latest, unchanged) = spec.to_version(SpecVersion::V1_2);
+    assert_eq!(latest, spec);
+    assert!(unchanged.is_empty());
+
+    let (_, dropped) = spec.to_version(SpecVersion::V1_1);
+    assert_eq!(
+        dropped,
+        vec![
+            "/linux/netDevices",
+            "/linux/resources/cpu/burst",
+            "/ociVersion",
+            "/zos"
+        ]
+    );
+
+    let (older, dropped) = spec.to_version(SpecVersion::V1_0);
+    assert_eq!(
+        dropped,
+        vec![
+            "/linux/intelRdt/enableCMT",
+            "/linux/intelRdt/enableMBM",
+            "/linux/netDevices",
+            "/linux/resources/cpu/burst",
+            "/linux/resources/cpu/idle",
+            "/linux/timeOffsets",
+            "/mounts/1/uidMappings",
+            "/ociVersion",
+            "/zos"
+        ]
+    );
+    assert_eq!(older.version, "1.0.2");
+    let (_, dropped) = older.to_version(SpecVersion::V1_1);
+    assert!(dropped.is_empty());
+    let value = serde_json::to_value(&older).expect("serialize spec");
+    assert!(value["linux"].get("timeOffsets").is_none());
+    assert_eq!(
+        value["linux"]["resources"]["cpu"],
+        serde_json::json!({"shares": 1024})
+    );
+    assert_eq!(
+        value["linux"]["intelRdt"],
+        serde_json::json!({"closID": "guaranteed"})
+    );
+    assert_eq!(value["mounts"][1]["destination"], "/data");
+}
@@ -0,0 +1,337 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use crate::error::{oci_error, OciSpecError, Result};
+
+use super::LinuxResources;
+
+/// The cgroup v2 key for the memory usage hard limit.
+pub const UNIFIED_MEMORY_MAX: &str = "memory.max";
+/// The cgroup v2 key for the swap usage hard limit.
+pub const UNIFIED_MEMORY_SWAP_MAX: &str = "memory.swap.max";
+/// The cgroup v2 key for the CPU bandwidth limit.
+pub const UNIFIED_CPU_MAX: &str = "cpu.max";
+/// The cgroup v2 key for the maximum number of processes.
+pub const UNIFIED_PIDS_MAX: &str = "pids.max";
+/// The cgroup v2 key for the proportional IO weight.
+pub const UNIFIED_IO_WEIGHT: &str = "io.weight";
+/// The cgroup v2 key for the CPU bandwidth burst limit.
+pub const UNIFIED_CPU_MAX_BURST: &str = "cpu.max.burst";
+/// The cgroup v2 key for the idle scheduling of the cgroup.
+pub const UNIFIED_CPU_IDLE: &str = "cpu.idle";
+/// The cgroup v2 key for the proportional CPU weight.
+pub const UNIFIED_CPU_WEIGHT: &str = "cpu.weight";
+/// The cgroup v2 key for the best-effort memory protection.
+pub const UNIFIED_MEMORY_LOW: &str = "memory.low";
+/// The cgroup v2 key for the CPUs the cgroup may run on.
+pub const UNIFIED_CPUSET_CPUS: &str = "cpuset.cpus";
+/// The cgroup v2 key for the memory nodes the cgroup may use.
+pub const UNIFIED_CPUSET_MEMS: &str = "cpuset.mems";
+
+/// The default period of the CPU bandwidth limit in microseconds.
+const DEFAULT_CPU_PERIOD: u64 = 100000;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// CgroupLimit is a cgroup v2 limit, which is either a number or the literal
+/// `max` for no limit.
+pub enum CgroupLimit {
+    /// No limit.
+    Max,
+
+    /// The limit, e.g. a number of bytes or processes.
+    Value(u64),
+}
+
+impl FromStr for CgroupLimit {
+    type Err = OciSpecError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "max" => Ok(CgroupLimit::Max),
+            value => value
+                .parse()
+                .map(CgroupLimit::Value)
+                .map_err(|_| oci_error(format!("invalid cgroup limit: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for CgroupLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CgroupLimit::Max => f.write_str("max"),
+            CgroupLimit::Value(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// CpuMax is the value of `cpu.max`, the maximum bandwidth limit in the
+/// format `$MAX $PERIOD`, where the period is optional.
+pub struct CpuMax {
+    /// The time in microseconds the cgroup may run during each period.
+    pub quota: CgroupLimit,
+
+    /// The length of the period in microseconds. The kernel default of 100000
+    /// is used if not set.
+    pub period: Option<u64>,
+}
+
+impl FromStr for CpuMax {
+    type Err = OciSpecError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parts = s.split_whitespace();
+        let quota = parts
+            .next()
+            .ok_or_else(|| oci_error("empty cpu.max value"))?
+            .parse()?;
+        let period = parts
+            .next()
+            .map(|period| {
+                period
+                    .parse()
+                    .map_err(|_| oci_error(format!("invalid cpu.max period: {}", period)))
+            })
+            .transpose()?;
+        if parts.next().is_some() {
+            return Err(oci_error(format!("invalid cpu.max value: {}", s)));
+        }
+
+        Ok(CpuMax { quota, period })
+    }
+}
+
+impl fmt::Display for CpuMax {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.period {
+            Some(period) => write!(f, "{} {}", self.quota, period),
+            None => write!(f, "{}", self.quota),
+        }
+    }
+}
+
+/// Typed accessors for well known keys of the cgroup v2 `unified` map. The
+/// getters return `Ok(None)` if the key is not set and an error if its value
+/// cannot be parsed. The setters leave all other keys untouched.
+impl LinuxResources {
+    /// Returns the value of `memory.max`.
+    pub fn unified_memory_max(&self) -> Result<Option<CgroupLimit>> {
+        self.unified_value(UNIFIED_MEMORY_MAX)
+    }
+
+    /// Sets the value of `memory.max`.
+    pub fn set_unified_memory_max(&mut self, limit: CgroupLimit) {
+        self.set_unified_value(UNIFIED_MEMORY_MAX, limit)
+    }
+
+    /// Returns the value of `memory.swap.max`.
+    pub fn unified_memory_swap_max(&self) -> Result<Option<CgroupLimit>> {
+        self.unified_value(UNIFIED_MEMORY_SWAP_MAX)
+    }
+
+    /// Sets the value of `memory.swap.max`.
+    pub fn set_unified_memory_swap_max(&mut self, limit: CgroupLimit) {
+        self.set_unified_value(UNIFIED_MEMORY_SWAP_MAX, limit)
+    }
+
+    /// Returns the value of `cpu.max`.
+    pub fn unified_cpu_max(&self) -> Result<Option<CpuMax>> {
+        self.unified_value(UNIFIED_CPU_MAX)
+    }
+
+    /// Sets the value of `cpu.max`.
+    pub fn set_unified_cpu_max(&mut self, cpu_max: CpuMax) {
+        self.set_unified_value(UNIFIED_CPU_MAX, cpu_max)
+    }
+
+    /// Returns the value of `pids.max`.
+    pub fn unified_pids_max(&self) -> Result<Option<CgroupLimit>> {
+        self.unified_value(UNIFIED_PIDS_MAX)
+    }
+
+    /// Sets the value of `pids.max`.
+    pub fn set_unified_pids_max(&mut self, limit: CgroupLimit) {
+        self.set_unified_value(UNIFIED_PIDS_MAX, limit)
+    }
+
+    /// Returns the default weight of `io.weight`, which is written either as
+    /// `default $WEIGHT` or as a plain `$WEIGHT`. Per device weights are
+    /// ignored.
+    pub fn unified_io_weight(&self) -> Result<Option<u16>> {
+        let value = match self.unified.as_ref().and_then(|u| u.get(UNIFIED_IO_WEIGHT)) {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        let invalid = || oci_error(format!("invalid io.weight value: {}", value));
+        for line in value.lines() {
+            let mut parts = line.split_whitespace();
+            let weight = match (parts.next(), parts.next()) {
+                (Some("default"), Some(weight)) | (Some(weight), None) => weight,
+                (Some(_), Some(_)) => continue,
+                (None, _) => continue,
+            };
+            let weight: u16 = weight.parse().map_err(|_| invalid())?;
+            if !(1..=10000).contains(&weight) {
+                return Err(invalid());
+            }
+            return Ok(Some(weight));
+        }
+        Ok(None)
+    }
+
+    /// Sets the default weight of `io.weight`, which must be between 1 and
+    /// 10000.
+    pub fn set_unified_io_weight(&mut self, weight: u16) -> Result<()> {
+        if !(1..=10000).contains(&weight) {
+            return Err(oci_error(format!("invalid io.weight: {}", weight)));
+        }
+        self.set_unified_value(UNIFIED_IO_WEIGHT, format!("default {}", weight));
+        Ok(())
+    }
+
+    fn unified_value<T: FromStr<Err = OciSpecError>>(&self, key: &str) -> Result<Option<T>> {
+        self.unified
+            .as_ref()
+            .and_then(|unified| unified.get(key))
+            .map(|value| value.parse())
+            .transpose()
+    }
+
+    fn set_unified_value<T: ToString>(&mut self, key: &str, value: T) {
+        self.unified
+            .get_or_insert_with(HashMap::new)
+            .insert(key.to_owned(), value.to_string());
+    }
+
+    /// Translates the cgroup v1 resources into cgroup v2 `unified` values, in
+    /// the same way as runc does when running on a cgroup v2 host. Values which
+    /// are already present in `unified` take precedence over the translated
+    /// ones. Per device block IO settings and v1 only settings like the kernel
+    /// memory limit or swappiness are not translated.
+    /// # Errors
+    /// This function will return an [OciSpecError::Other](crate::OciSpecError::Other)
+    /// if the memory limits cannot be represented on cgroup v2, e.g. if a swap
+    /// limit is set without a memory limit.
+    pub fn to_unified(&self) -> Result<HashMap<String, String>> {
+        let mut unified = HashMap::new();
+        let mut set = |key: &str, value: String| {
+            unified.insert(key.to_owned(), value);
+        };
+
+        if let Some(cpu) = &self.cpu {
+            if let Some(shares) = cpu.shares {
+                if shares != 0 {
+                    set(UNIFIED_CPU_WEIGHT, cpu_shares_to_weight(shares).to_string());
+                }
+            }
+            if cpu.quota.is_some() || cpu.period.is_some() {
+                let quota = match cpu.quota {
+                    Some(quota) if quota > 0 => CgroupLimit::Value(quota as u64),
+                    _ => CgroupLimit::Max,
+                };
+                let cpu_max = CpuMax {
+                    quota,
+                    period: Some(cpu.period.unwrap_or(DEFAULT_CPU_PERIOD)),
+                };
+                set(UNIFIED_CPU_MAX, cpu_max.to_string());
+            }
+            if let Some(burst) = cpu.burst {
+                set(UNIFIED_CPU_MAX_BURST, burst.to_string());
+            }
+            if let Some(idle) = cpu.idle {
+                set(UNIFIED_CPU_IDLE, idle.to_string());
+            }
+            if let Some(cpus) = &cpu.cpus {
+                set(UNIFIED_CPUSET_CPUS, cpus.clone());
+            }
+            if let Some(mems) = &cpu.mems {
+                set(UNIFIED_CPUSET_MEMS, mems.clone());
+            }
+        }
+
+        if let Some(weight) = self.block_io.as_ref().and_then(|b| b.weight) {
+            if weight != 0 {
+                set(
+                    UNIFIED_IO_WEIGHT,
+                    format!("default {}", blkio_weight_to_io_weight(weight)),
+                );
+            }
+        }
+
+        if let Some(memory) = &self.memory {
+            let limit = memory.limit.unwrap_or(0);
+            if limit != 0 {
+                set(UNIFIED_MEMORY_MAX, memory_limit(limit).to_string());
+            }
+            if let Some(reservation) = memory.reservation.filter(|r| *r != 0) {
+                set(UNIFIED_MEMORY_LOW, memory_limit(reservation).to_string());
+            }
+            let swap = memory_swap(memory.swap.unwrap_or(0), limit)?;
+            if swap != 0 {
+                set(UNIFIED_MEMORY_SWAP_MAX, memory_limit(swap).to_string());
+            }
+        }
+
+        if let Some(pids) = self.pids.as_ref().filter(|p| p.limit != 0) {
+            set(UNIFIED_PIDS_MAX, memory_limit(pids.limit).to_string());
+        }
+
+        for hugepage in self.hugepage_limits.iter().flatten() {
+            set(
+                &format!("hugetlb.{}.max", hugepage.page_size),
+                memory_limit(hugepage.limit).to_string(),
+            );
+        }
+
+        if let Some(explicit) = &self.unified {
+            unified.extend(explicit.clone());
+        }
+
+        Ok(unified)
+    }
+}
+
+/// Converts the cgroup v1 CPU shares in the range [2, 262144] to the cgroup v2
+/// CPU weight in the range [1, 10000].
+fn cpu_shares_to_weight(shares: u64) -> u64 {
+    1 + (shares.clamp(2, 262144) - 2) * 9999 / 262142
+}
+
+/// Converts the cgroup v1 blkio weight in the range [10, 1000] to the cgroup v2
+/// IO weight in the range [1, 10000].
+fn blkio_weight_to_io_weight(weight: u16) -> u64 {
+    1 + (u64::from(weight.clamp(10, 1000)) - 10) * 9999 / 990
+}
+
+/// Converts a limit, where negative values mean unlimited.
+fn memory_limit(limit: i64) -> CgroupLimit {
+    if limit < 0 {
+        CgroupLimit::Max
+    } else {
+        CgroupLimit::Value(limit as u64)
+    }
+}
+
+/// Converts the cgroup v1 memory+swap limit to the cgroup v2 swap limit, which
+/// excludes the memory. Zero means unset and -1 means unlimited.
+fn memory_swap(swap: i64, memory: i64) -> Result<i64> {
+    if memory == -1 && swap == 0 {
+        return Ok(-1);
+    }
+    if swap == -1 || swap == 0 {
+        return Ok(swap);
+    }
+    if memory == 0 || memory == -1 {
+        return Err(oci_error("unable to set swap limit without memory limit"));
+    }
+    if memory < 0 {
+        return Err(oci_error(format!("invalid memory limit: {}", memory)));
+    }
+    if swap < memory {
+        return Err(oci_error(format!(
+            "memory+swap limit {} should be larger than memory limit {}",
+            swap, memory
+        )));
+    }
+    Ok(swap - memory)
+}
@@ -0,0 +1,432 @@
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use super::{features::serde_name, Capabilities, Hook, LinuxNamespaceType, LinuxRlimitType, Spec};
+
+/// The disk image formats of a VM defined by the spec.
+const VM_IMAGE_FORMATS: &[&str] = &["raw", "qcow2", "vdi", "vmdk", "vhd"];
+
+/// A violation of a rule of the runtime spec, as reported by [validate].
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ValidationError {
+    /// The ociVersion is not a valid semantic version.
+    #[error("ociVersion {0:?} must be in SemVer v2.0.0 format")]
+    InvalidVersion(String),
+    /// The root path is empty.
+    #[error("root path must not be empty")]
+    EmptyRootPath,
+    /// The process has neither args nor a command line.
+    #[error("process args must not be empty")]
+    MissingArgs,
+    /// A path which has to be absolute is relative.
+    #[error("{field} {path:?} must be an absolute path")]
+    RelativePath {
+        /// The field which contains the path, e.g. `process.cwd`.
+        field: String,
+        /// The relative path.
+        path: PathBuf,
+    },
+    /// An environment variable is not in `KEY=value` format.
+    #[error("environment variable {0:?} must be in KEY=value format")]
+    InvalidEnv(String),
+    /// An rlimit type is configured more than once.
+    #[error("duplicate rlimit {0}")]
+    DuplicateRlimit(String),
+    /// The soft limit of an rlimit exceeds its hard limit.
+    #[error("soft limit {soft} of rlimit {typ} exceeds its hard limit {hard}")]
+    RlimitSoftExceedsHard {
+        /// The rlimit type, e.g. `RLIMIT_NOFILE`.
+        typ: String,
+        /// The soft limit.
+        soft: u64,
+        /// The hard limit.
+        hard: u64,
+    },
+    /// A capability of a capability set is missing from the set which limits
+    /// it, e.g. an effective capability which is not permitted.
+    #[error("capability {capability} of the {set} set is not in the {limit} set")]
+    InconsistentCapability {
+        /// The name of the capability, e.g. `CAP_SYS_ADMIN`.
+        capability: String,
+        /// The set which contains the capability.
+        set: &'static str,
+        /// The set which lacks the capability.
+        limit: &'static str,
+    },
+    /// A hook has a timeout which is not greater than zero.
+    #[error("timeout {timeout} of hook {path:?} must be greater than zero")]
+    InvalidHookTimeout {
+        /// The path of the hook.
+        path: PathBuf,
+        /// The invalid timeout.
+        timeout: i64,
+    },
+    /// A namespace type is configured more than once.
+    #[error("duplicate namespace type {0}")]
+    DuplicateNamespace(String),
+    /// UID or GID mappings are configured without a user namespace.
+    #[error("uid and gid mappings require a user namespace")]
+    MissingUserNamespace,
+    /// Time offsets are configured without a new time namespace.
+    #[error("time offsets require a new time namespace")]
+    MissingTimeNamespace,
+    /// A hostname is configured without a new UTS namespace.
+    #[error("hostname requires a new uts namespace")]
+    MissingUtsNamespace,
+    /// The disk image format of the VM is not one of the formats defined by
+    /// the spec.
+    #[error("vm image format {0:?} must be one of raw, qcow2, vdi, vmdk or vhd")]
+    InvalidVmImageFormat(String),
+    /// The console size of the process has a zero dimension.
+    #[error("process.consoleSize must not have a zero dimension")]
+    InvalidConsoleSize,
+    /// The console size of the process is set without a terminal, in which
+    /// case it is ignored by the runtime.
+    #[error("process.consoleSize is ignored because process.terminal is not true")]
+    ConsoleSizeWithoutTerminal,
+    /// The exec CPU affinity of the process is not a valid cpuset list.
+    #[error("invalid process.execCPUAffinity: {0}")]
+    InvalidExecCpuAffinity(String),
+    /// The page size of a hugepage limit is malformed or not a power of two.
+    #[error("invalid hugepage size {0:?}")]
+    InvalidHugepageSize(String),
+    /// The block IO weights or rate limits are invalid.
+    #[error("invalid linux.resources.blockIO: {0}")]
+    InvalidBlockIo(String),
+    /// The network interface priorities are invalid.
+    #[error("invalid linux.resources.network: {0}")]
+    InvalidNetwork(String),
+    /// The NUMA memory policy is invalid.
+    #[error("invalid linux.memoryPolicy: {0}")]
+    InvalidMemoryPolicy(String),
+    /// A sysctl is malformed, not namespaced or lacks its namespace.
+    #[error("invalid linux.sysctl: {0}")]
+    InvalidSysctl(String),
+    /// The Landlock rules are invalid.
+    #[error("invalid linux.landlock: {0}")]
+    InvalidLandlock(String),
+    /// The network devices are invalid or lack a network namespace.
+    #[error("invalid linux.netDevices: {0}")]
+    InvalidNetDevices(String),
+    /// The Intel RDT configuration is malformed.
+    #[error("invalid linux.intelRdt: {0}")]
+    InvalidIntelRdt(String),
+    /// The UID or GID mappings overlap or do not map the user of the process.
+    #[error("invalid linux id mappings: {0}")]
+    InvalidIdMappings(String),
+    /// The id mappings of a mount are invalid.
+    #[error("invalid id mappings of mount {destination:?}: {reason}")]
+    InvalidMountIdMappings {
+        /// The destination of the mount.
+        destination: PathBuf,
+        /// The reason why the mappings are invalid.
+        reason: String,
+    },
+}
+
+/// Validates the spec against the rules of the runtime spec which can be
+/// checked without access to the bundle, and returns all violations found.
+/// Besides the MUST rules, this reports a few SHOULD rules which runtimes
+/// commonly enforce, e.g. [ValidationError::ConsoleSizeWithoutTerminal].
+/// Paths are checked against the conventions of the target platform of the
+/// spec, i.e. Windows paths if `windows` is set and Unix paths otherwise.
+/// # Example
+/// ```
+/// use oci_spec::runtime::{validate, Spec};
+///
+/// let spec = Spec::default();
+/// assert!(validate(&spec).is_empty());
+/// ```
+pub fn validate(spec: &Spec) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    let windows = spec.windows.is_some();
+
+    if !is_semver(&spec.version) {
+        errors.push(ValidationError::InvalidVersion(spec.version.clone()));
+    }
+
+    if spec
+        .root
+        .as_ref()
+        .is_some_and(|root| root.path.as_os_str().is_empty())
+    {
+        errors.push(ValidationError::EmptyRootPath);
+    }
+
+    for mount in spec.mounts.iter().flatten() {
+        check_absolute(
+            &mut errors,
+            windows,
+            "mount destination",
+            &mount.destination,
+        );
+        if let Err(err) = mount.validate_id_mappings() {
+            errors.push(ValidationError::InvalidMountIdMappings {
+                destination: mount.destination.clone(),
+                reason: err.to_string(),
+            });
+        }
+    }
+
+    if let Some(process) = &spec.process {
+        let args = process.args.as_ref().is_some_and(|args| !args.is_empty());
+        if !args && process.command_line.is_none() {
+            errors.push(ValidationError::MissingArgs);
+        }
+
+        check_absolute(&mut errors, windows, "process.cwd", &process.cwd);
+
+        for var in process.env.iter().flatten() {
+            if !var.contains('=') {
+                errors.push(ValidationError::InvalidEnv(var.clone()));
+            }
+        }
+
+        let mut rlimits: Vec<LinuxRlimitType> = Vec::new();
+        for rlimit in process.rlimits.iter().flatten() {
+            if rlimits.contains(&rlimit.typ) {
+                errors.push(ValidationError::DuplicateRlimit(rlimit.typ.to_string()));
+            } else {
+                rlimits.push(rlimit.typ.clone());
+            }
+            if rlimit.validate().is_err() {
+                errors.push(ValidationError::RlimitSoftExceedsHard {
+                    typ: rlimit.typ.to_string(),
+                    soft: rlimit.soft,
+                    hard: rlimit.hard,
+                });
+            }
+        }
+
+        if let Some(console_size) = &process.console_size {
+            if console_size.validate().is_err() {
+                errors.push(ValidationError::InvalidConsoleSize);
+            }
+            if process.terminal != Some(true) {
+                errors.push(ValidationError::ConsoleSizeWithoutTerminal);
+            }
+        }
+
+        if let Some(Err(err)) = process.exec_cpu_affinity.as_ref().map(|a| a.validate()) {
+            errors.push(ValidationError::InvalidExecCpuAffinity(err.to_string()));
+        }
+
+        if let Some(capabilities) = &process.capabilities {
+            let mut check =
+                |set: &Option<Capabilities>, name, limit: &Option<Capabilities>, limit_name| {
+                    if let (Some(set), Some(limit)) = (set, limit) {
+                        let mut missing: Vec<String> =
+                            set.difference(limit).map(serde_name).collect();
+                        missing.sort();
+                        errors.extend(missing.into_iter().map(|capability| {
+                            ValidationError::InconsistentCapability {
+                                capability,
+                                set: name,
+                                limit: limit_name,
+                            }
+                        }));
+                    }
+                };
+            check(
+                &capabilities.effective,
+                "effective",
+                &capabilities.permitted,
+                "permitted",
+            );
+            check(
+                &capabilities.ambient,
+                "ambient",
+                &capabilities.permitted,
+                "permitted",
+            );
+            check(
+                &capabilities.ambient,
+                "ambient",
+                &capabilities.inheritable,
+                "inheritable",
+            );
+        }
+    }
+
+    if let Some(hooks) = &spec.hooks {
+        #[allow(deprecated)]
+        let all = [
+            &hooks.prestart,
+            &hooks.create_runtime,
+            &hooks.create_container,
+            &hooks.start_container,
+            &hooks.poststart,
+            &hooks.poststop,
+        ];
+        for hook in all.iter().copied().flatten().flatten() {
+            check_hook(&mut errors, windows, hook);
+        }
+    }
+
+    if let Some(linux) = &spec.linux {
+        let namespaces = linux.namespaces.as_deref().unwrap_or_default();
+        let mut types: Vec<LinuxNamespaceType> = Vec::new();
+        for namespace in namespaces {
+            if types.contains(&namespace.typ) {
+                errors.push(ValidationError::DuplicateNamespace(serde_name(
+                    &namespace.typ,
+                )));
+            } else {
+                types.push(namespace.typ);
+            }
+        }
+
+        let has_mappings = linux.uid_mappings.as_ref().is_some_and(|m| !m.is_empty())
+            || linux.gid_mappings.as_ref().is_some_and(|m| !m.is_empty());
+        if has_mappings && !types.contains(&LinuxNamespaceType::User) {
+            errors.push(ValidationError::MissingUserNamespace);
+        }
+        if let Err(err) = spec.validate_id_mappings() {
+            errors.push(ValidationError::InvalidIdMappings(err.to_string()));
+        }
+
+        let new_uts = namespaces
+            .iter()
+            .any(|ns| ns.typ == LinuxNamespaceType::Uts && ns.path.is_none());
+        if spec.hostname.is_some() && !new_uts {
+            errors.push(ValidationError::MissingUtsNamespace);
+        }
+
+        let new_time = namespaces
+            .iter()
+            .any(|ns| ns.typ == LinuxNamespaceType::Time && ns.path.is_none());
+        if linux.time_offsets.as_ref().is_some_and(|o| !o.is_empty()) && !new_time {
+            errors.push(ValidationError::MissingTimeNamespace);
+        }
+
+        let resources = linux.resources.as_ref();
+        let hugepage_limits = resources.and_then(|r| r.hugepage_limits.as_ref());
+        for limit in hugepage_limits.into_iter().flatten() {
+            if limit.hugepage_size().is_err() {
+                errors.push(ValidationError::InvalidHugepageSize(
+                    limit.page_size.clone(),
+                ));
+            }
+        }
+
+        if let Some(Err(err)) = resources
+            .and_then(|r| r.block_io.as_ref())
+            .map(|block_io| block_io.validate())
+        {
+            errors.push(ValidationError::InvalidBlockIo(err.to_string()));
+        }
+
+        if let Some(Err(err)) = resources
+            .and_then(|r| r.network.as_ref())
+            .map(|network| network.validate())
+        {
+            errors.push(ValidationError::InvalidNetwork(err.to_string()));
+        }
+
+        if let Some(Err(err)) = linux.intel_rdt.as_ref().map(|rdt| rdt.validate()) {
+            errors.push(ValidationError::InvalidIntelRdt(err.to_string()));
+        }
+
+        if let Some(Err(err)) = linux.memory_policy.as_ref().map(|p| p.validate()) {
+            errors.push(ValidationError::InvalidMemoryPolicy(err.to_string()));
+        }
+
+        if let Err(err) = linux.validate_sysctls() {
+            errors.push(ValidationError::InvalidSysctl(err.to_string()));
+        }
+
+        if let Some(Err(err)) = linux.landlock.as_ref().map(|l| l.validate()) {
+            errors.push(ValidationError::InvalidLandlock(err.to_string()));
+        }
+
+        if let Err(err) = linux.validate_net_devices() {
+            errors.push(ValidationError::InvalidNetDevices(err.to_string()));
+        }
+
+        for path in linux.masked_paths.iter().flatten() {
+            check_absolute(&mut errors, windows, "masked path", Path::new(path));
+        }
+        for path in linux.readonly_paths.iter().flatten() {
+            check_absolute(&mut errors, windows, "readonly path", Path::new(path));
+        }
+    }
+
+    if let Some(vm) = &spec.vm {
+        if let Some(hypervisor) = &vm.hypervisor {
+            check_absolute(&mut errors, windows, "vm hypervisor path", &hypervisor.path);
+        }
+        check_absolute(&mut errors, windows, "vm kernel path", &vm.kernel.path);
+        if let Some(image) = &vm.image {
+            check_absolute(&mut errors, windows, "vm image path", &image.path);
+            if !VM_IMAGE_FORMATS.contains(&image.format.as_str()) {
+                errors.push(ValidationError::InvalidVmImageFormat(image.format.clone()));
+            }
+        }
+    }
+
+    errors
+}
+
+fn check_hook(errors: &mut Vec<ValidationError>, windows: bool, hook: &Hook) {
+    check_absolute(errors, windows, "hook path", &hook.path);
+    if let Some(timeout) = hook.timeout.filter(|timeout| *timeout <= 0) {
+        errors.push(ValidationError::InvalidHookTimeout {
+            path: hook.path.clone(),
+            timeout,
+        });
+    }
+}
+
+fn check_absolute(errors: &mut Vec<ValidationError>, windows: bool, field: &str, path: &Path) {
+    if !is_absolute(path, windows) {
+        errors.push(ValidationError::RelativePath {
+            field: field.to_owned(),
+            path: path.to_path_buf(),
+        });
+    }
+}
+
+/// Returns true if the path is absolute on the target platform of the spec,
+/// independently of the platform the validation runs on.
+fn is_absolute(path: &Path, windows: bool) -> bool {
+    let path = path.to_string_lossy();
+    if !windows {
+        return path.starts_with('/');
+    }
+    let is_separator = |b: u8| b == b'\\' || b == b'/';
+    match path.as_bytes() {
+        [a, b, ..] if is_separator(*a) && is_separator(*b) => true,
+        [drive, b':', separator, ..] => drive.is_ascii_alphabetic() && is_separator(*separator),
+        _ => false,
+    }
+}
+
+/// Returns true if the version is a valid semantic version as defined by
+/// SemVer v2.0.0, including pre-release and build metadata.
+fn is_semver(version: &str) -> bool {
+    let (version, build) = match version.split_once('+') {
+        Some((version, build)) => (version, Some(build)),
+        None => (version, None),
+    };
+    let (core, pre) = match version.split_once('-') {
+        Some((core, pre)) => (core, Some(pre)),
+        None => (version, None),
+    };
+
+    let numeric = |part: &str| {
+        !part.is_empty()
+            && part.bytes().all(|b| b.is_ascii_digit())
+            && (part == "0" || !part.starts_with('0'))
+    };
+    let identifiers = |s: &str| {
+        s.split('.')
+            .all(|id| !id.is_empty() && id.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-'))
+    };
+
+    let parts: Vec<&str> = core.split('.').collect();
+    parts.len() == 3
+        && parts.iter().all(|part| numeric(part))
+        && pre.is_none_or(identifiers)
+        && build.is_none_or(identifiers)
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use super::LinuxDeviceType;
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get = "pub", get_mut = "pub", set = "pub")
+    )]
+    /// ZOS contains platform-specific configuration for z/OS based containers.
+    struct ZOS {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        /// Devices are a list of device nodes that are created for the
+        /// container.
+        devices: Option<Vec<ZOSDevice>>,
+    }
+);
+
+make_pub!(
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(rename_all = "camelCase")]
+    #[cfg_attr(
+        feature = "builder",
+        derive(
+            derive_builder::Builder,
+            getset::CopyGetters,
+            getset::Getters,
+            getset::MutGetters,
+            getset::Setters
+        ),
+        builder(
+            default,
+            pattern = "owned",
+            setter(into, strip_option),
+            build_fn(error = "crate::error::OciSpecError")
+        ),
+        getset(get_mut = "pub", set = "pub")
+    )]
+    /// ZOSDevice represents the mknod information for a z/OS special device
+    /// file.
+    struct ZOSDevice {
+        #[cfg_attr(feature = "builder", getset(get = "pub"))]
+        /// Path to the device.
+        path: PathBuf,
+
+        #[serde(rename = "type")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Device type, block, char, etc..
+        typ: LinuxDeviceType,
+
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Major is the device's major number.
+        major: i64,
+
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Minor is the device's minor number.
+        minor: i64,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// FileMode permission bits for the device.
+        file_mode: Option<u32>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// UID of the device.
+        uid: Option<u32>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "builder", getset(get_copy = "pub"))]
+        /// Gid of the device.
+        gid: Option<u32>,
+    }
+);
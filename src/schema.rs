@@ -0,0 +1,114 @@
+//! Implementations of [schemars::JsonSchema] for the types with hand written
+//! serde implementations, which are all represented as plain strings.
+
+use std::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+macro_rules! impl_string_schema {
+    ($($(#[$cfg:meta])* $name:ident => $path:literal, $description:literal),* $(,)?) => {
+        $(
+            $(#[$cfg])*
+            impl JsonSchema for $name {
+                fn schema_name() -> Cow<'static, str> {
+                    Cow::Borrowed(stringify!($name))
+                }
+
+                fn schema_id() -> Cow<'static, str> {
+                    Cow::Borrowed($path)
+                }
+
+                fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                    json_schema!({
+                        "type": "string",
+                        "description": $description,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "distribution")]
+use crate::distribution::ErrorCode;
+#[cfg(feature = "image")]
+use crate::image::{Arch, MediaType, Os};
+#[cfg(feature = "runtime")]
+use crate::runtime::{Capability, LinuxRlimitType, LinuxSeccompFilterFlag};
+
+impl_string_schema!(
+    #[cfg(feature = "distribution")]
+    ErrorCode => "oci_spec::distribution::ErrorCode",
+        "The code of a registry error, e.g. `BLOB_UNKNOWN`.",
+    #[cfg(feature = "image")]
+    MediaType => "oci_spec::image::MediaType",
+        "A media type as defined by RFC 6838, e.g. `application/vnd.oci.image.manifest.v1+json`.",
+    #[cfg(feature = "image")]
+    Os => "oci_spec::image::Os",
+        "The name of an operating system as used by `GOOS`, e.g. `linux`.",
+    #[cfg(feature = "image")]
+    Arch => "oci_spec::image::Arch",
+        "The name of a CPU architecture as used by `GOARCH`, e.g. `amd64`.",
+    #[cfg(feature = "runtime")]
+    Capability => "oci_spec::runtime::Capability",
+        "A Linux capability, e.g. `CAP_CHOWN`. The `CAP_` prefix may be omitted and the name is case insensitive.",
+    #[cfg(feature = "runtime")]
+    LinuxSeccompFilterFlag => "oci_spec::runtime::LinuxSeccompFilterFlag",
+        "A seccomp filter flag, e.g. `SECCOMP_FILTER_FLAG_LOG`.",
+    #[cfg(feature = "runtime")]
+    LinuxRlimitType => "oci_spec::runtime::LinuxRlimitType",
+        "A resource limit type, e.g. `RLIMIT_NOFILE`.",
+);
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "image")]
+    fn manifest_schema() {
+        use crate::image::ImageManifest;
+
+        // act
+        let schema = serde_json::to_value(schemars::schema_for!(ImageManifest)).unwrap();
+
+        // assert
+        assert_eq!(schema["title"], "ImageManifest");
+        let required = schema["required"].as_array().unwrap();
+        assert!(required.contains(&"schemaVersion".into()));
+        assert!(required.contains(&"config".into()));
+        assert_eq!(schema["$defs"]["MediaType"]["type"], "string");
+        assert!(schema["$defs"]["Descriptor"]["properties"]["artifactType"].is_object());
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn configuration_schema() {
+        use crate::image::ImageConfiguration;
+
+        // act
+        let schema = serde_json::to_value(schemars::schema_for!(ImageConfiguration)).unwrap();
+
+        // assert
+        let config = &schema["$defs"]["Config"]["properties"];
+        assert!(config["ExposedPorts"]["type"]
+            .as_array()
+            .unwrap()
+            .contains(&"object".into()));
+        assert_eq!(schema["$defs"]["Digest"]["type"], "string");
+    }
+
+    #[test]
+    #[cfg(feature = "runtime")]
+    fn spec_schema() {
+        use crate::runtime::Spec;
+
+        // act
+        let schema = serde_json::to_value(schemars::schema_for!(Spec)).unwrap();
+
+        // assert
+        let properties = schema["properties"].as_object().unwrap();
+        assert!(properties.contains_key("ociVersion"));
+        assert!(properties.contains_key("mounts"));
+        assert!(!properties.contains_key("oci_version"));
+        assert_eq!(schema["$defs"]["Capability"]["type"], "string");
+    }
+}
@@ -0,0 +1,152 @@
+//! YAML serialization of the spec documents.
+
+use std::io::{Read, Write};
+#[cfg(has_fs)]
+use std::{
+    fs::{self, OpenOptions},
+    path::Path,
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::error::Result;
+
+/// YamlDocument reads and writes spec documents as YAML instead of JSON. The
+/// documents are represented by the same serde model, so field names and
+/// optional fields behave exactly like in their JSON form.
+/// # Example
+/// ```
+/// use oci_spec::runtime::Spec;
+/// use oci_spec::YamlDocument;
+///
+/// let yaml = Spec::default().to_yaml_string().unwrap();
+/// assert!(yaml.starts_with("ociVersion:"));
+/// assert_eq!(Spec::from_yaml_str(&yaml).unwrap(), Spec::default());
+/// ```
+pub trait YamlDocument: Serialize + DeserializeOwned {
+    #[cfg(has_fs)]
+    /// Attempts to load a document from a YAML file.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the file does not exist or an
+    /// [OciSpecError::Yaml](crate::OciSpecError::Yaml) if the document
+    /// cannot be deserialized.
+    fn from_yaml_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_yaml_reader(fs::File::open(path)?)
+    }
+
+    /// Attempts to load a document from a YAML stream.
+    /// # Errors
+    /// This function will return an [OciSpecError::Yaml](crate::OciSpecError::Yaml)
+    /// if the document cannot be deserialized.
+    fn from_yaml_reader<R: Read>(reader: R) -> Result<Self> {
+        Ok(serde_yaml::from_reader(reader)?)
+    }
+
+    /// Attempts to load a document from a YAML string.
+    /// # Errors
+    /// This function will return an [OciSpecError::Yaml](crate::OciSpecError::Yaml)
+    /// if the document cannot be deserialized.
+    fn from_yaml_str(yaml: &str) -> Result<Self> {
+        Ok(serde_yaml::from_str(yaml)?)
+    }
+
+    #[cfg(has_fs)]
+    /// Attempts to write the document to a file as YAML. If the file already
+    /// exists, it will be overwritten.
+    /// # Errors
+    /// This function will return an [OciSpecError::Io](crate::OciSpecError::Io)
+    /// if the file cannot be written or an
+    /// [OciSpecError::Yaml](crate::OciSpecError::Yaml) if the document
+    /// cannot be serialized.
+    fn to_yaml_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        self.to_yaml_writer(file)
+    }
+
+    /// Attempts to write the document to a stream as YAML.
+    /// # Errors
+    /// This function will return an [OciSpecError::Yaml](crate::OciSpecError::Yaml)
+    /// if the document cannot be serialized.
+    fn to_yaml_writer<W: Write>(&self, writer: W) -> Result<()> {
+        Ok(serde_yaml::to_writer(writer, self)?)
+    }
+
+    /// Attempts to serialize the document as YAML string.
+    /// # Errors
+    /// This function will return an [OciSpecError::Yaml](crate::OciSpecError::Yaml)
+    /// if the document cannot be serialized.
+    fn to_yaml_string(&self) -> Result<String> {
+        Ok(serde_yaml::to_string(self)?)
+    }
+}
+
+macro_rules! impl_yaml_document {
+    ($($(#[$cfg:meta])* $name:path),* $(,)?) => {
+        $(
+            $(#[$cfg])*
+            impl YamlDocument for $name {}
+        )*
+    };
+}
+
+for_each_document!(impl_yaml_document);
+
+#[cfg(all(test, any(feature = "image", feature = "runtime")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "runtime")]
+    fn spec_yaml_file() -> Result<()> {
+        use crate::runtime::Spec;
+
+        // arrange
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("config.yaml");
+        let spec = Spec::rootless(1000, 1000);
+
+        // act
+        spec.to_yaml_file(&path)?;
+        let loaded = Spec::from_yaml_file(&path)?;
+
+        // assert
+        assert_eq!(loaded, spec);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn manifest_yaml() -> Result<()> {
+        use crate::image::{ImageManifest, MediaType};
+
+        // arrange
+        let yaml = "
+schemaVersion: 2
+config:
+  mediaType: application/vnd.oci.image.config.v1+json
+  digest: sha256:b5b2b2c507a0944348e0303114d8d93aaaa081732b86451d9bce1f432a537bc7
+  size: 7023
+layers: []
+";
+
+        // act
+        let manifest = ImageManifest::from_yaml_reader(yaml.as_bytes())?;
+        let mut written = Vec::new();
+        manifest.to_yaml_writer(&mut written)?;
+
+        // assert
+        assert_eq!(manifest.config.media_type, MediaType::ImageConfig);
+        assert_eq!(
+            ImageManifest::from_yaml_reader(written.as_slice())?,
+            manifest
+        );
+        let err = ImageManifest::from_yaml_str("schemaVersion: [").unwrap_err();
+        assert!(err.to_string().starts_with("yaml serde failed: "));
+        Ok(())
+    }
+}